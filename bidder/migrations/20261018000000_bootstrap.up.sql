@@ -0,0 +1,30 @@
+-- Every bid the agent places (or would place in dry-run mode)
+CREATE TABLE IF NOT EXISTS bids
+(
+    id                INTEGER  NOT NULL PRIMARY KEY AUTOINCREMENT,
+    frame_id          INTEGER  NOT NULL,
+    seat              INTEGER  NOT NULL,
+    mining_account    TEXT     NOT NULL,
+    bid               TEXT     NOT NULL, -- u128 microgons
+    previous_bid      TEXT,
+    block_hash        TEXT     NOT NULL,
+    status            TEXT     NOT NULL, -- submitted, failed, dry_run
+    error             TEXT,
+    created_at        DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS bids_frame_idx ON bids (frame_id);
+
+-- The result of each frame the agent bid on
+CREATE TABLE IF NOT EXISTS frame_outcomes
+(
+    frame_id          INTEGER  NOT NULL PRIMARY KEY,
+    seats_won         INTEGER  NOT NULL,
+    target_seats      INTEGER  NOT NULL,
+    argons_bid        TEXT     NOT NULL, -- u128 microgons
+    bids_count        INTEGER  NOT NULL,
+    bid_amount_min    TEXT     NOT NULL,
+    bid_amount_max    TEXT     NOT NULL,
+    dry_run           BOOLEAN  NOT NULL,
+    created_at        DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,385 @@
+use anyhow::anyhow;
+use argon_client::{
+	api::{constants, runtime_types, storage, tx},
+	signer::Signer,
+	types::AccountId32 as ApiAccountId,
+	ArgonConfig, ArgonTxProgress, FetchAt, MainchainClient,
+};
+use argon_primitives::{prelude::FrameId, Balance, ADDRESS_PREFIX};
+use futures::StreamExt;
+use polkadot_sdk::*;
+use sp_core::{crypto::Ss58Codec, ed25519, sr25519, Pair, H256};
+use sp_runtime::{traits::IdentifyAccount, MultiSigner};
+use std::collections::BTreeMap;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+use crate::{
+	db::{BidRecord, BidStatus, BidderDb, FrameOutcome},
+	strategy::{insert_bid, plan_bids, BidPolicy, BiddingState, CohortBid},
+};
+
+type SessionKeys = runtime_types::argon_runtime::SessionKeys;
+
+/// The mining account and session keys used for one seat in a frame.
+///
+/// Each seat of each frame gets its own accounts so a new bid never overlaps an active
+/// registration. Keys are derived as `<miner suri>//<frame id>//<seat>` (sr25519 mining account)
+/// plus `//seal` and `//grandpa` (ed25519 session keys), so the mining node can insert them into
+/// its keystore ahead of the frame.
+pub struct SeatKeys {
+	pub account_id: ApiAccountId,
+	pub keys: SessionKeys,
+}
+
+impl SeatKeys {
+	pub fn derive(miner_suri: &str, frame_id: FrameId, seat: u32) -> anyhow::Result<Self> {
+		let uri = format!("{miner_suri}//{frame_id}//{seat}");
+		let account = sr25519::Pair::from_string(&uri, None)
+			.map_err(|e| anyhow!("Invalid miner suri: {e:?}"))?;
+		let seal = ed25519::Pair::from_string(&format!("{uri}//seal"), None)
+			.map_err(|e| anyhow!("Invalid miner suri: {e:?}"))?;
+		let grandpa = ed25519::Pair::from_string(&format!("{uri}//grandpa"), None)
+			.map_err(|e| anyhow!("Invalid miner suri: {e:?}"))?;
+		Ok(Self {
+			account_id: MultiSigner::from(account.public()).into_account().into(),
+			keys: SessionKeys {
+				grandpa: runtime_types::sp_consensus_grandpa::app::Public(grandpa.public().0),
+				block_seal_authority: runtime_types::argon_primitives::block_seal::app::Public(
+					seal.public().0,
+				),
+			},
+		})
+	}
+}
+
+/// The frame currently taking bids
+struct OpenFrame {
+	frame_id: FrameId,
+	seats: Vec<SeatKeys>,
+	/// Our bids applied to the cohort (only used in dry-run mode)
+	simulated_bids: BTreeMap<u32, CohortBid<ApiAccountId>>,
+	last_cohort: Vec<CohortBid<ApiAccountId>>,
+	bid_stats: (u32, Balance, Balance),
+	/// Bids submitted to the transaction pool that have not been included or dropped yet. No new
+	/// bids are submitted for the frame until they resolve.
+	in_flight_bids: Option<JoinHandle<Vec<BidRecord>>>,
+}
+
+pub struct BidAgent<S> {
+	client: MainchainClient,
+	signer: S,
+	db: BidderDb,
+	policy: BidPolicy,
+	miner_suri: String,
+	dry_run: bool,
+	bid_increment: Balance,
+	open_frame: Option<OpenFrame>,
+}
+
+impl<S> BidAgent<S>
+where
+	S: Signer<ArgonConfig> + Send + Sync,
+{
+	pub fn new(
+		client: MainchainClient,
+		signer: S,
+		db: BidderDb,
+		policy: BidPolicy,
+		miner_suri: String,
+		dry_run: bool,
+	) -> anyhow::Result<Self> {
+		let bid_increment =
+			client.live.constants().at(&constants().mining_slot().bid_increments())?;
+		Ok(Self {
+			client,
+			signer,
+			db,
+			policy,
+			miner_suri,
+			dry_run,
+			bid_increment,
+			open_frame: None,
+		})
+	}
+
+	pub async fn run(&mut self) -> anyhow::Result<()> {
+		info!(
+			account = self.signer.account_id().to_ss58check_with_version(ADDRESS_PREFIX.into()),
+			policy = ?self.policy,
+			dry_run = self.dry_run,
+			"Starting mining bid agent"
+		);
+		let mut blocks = self.client.live.blocks().subscribe_best().await?;
+		while let Some(block) = blocks.next().await {
+			let block = match block {
+				Ok(block) => block,
+				Err(err) => {
+					warn!(?err, "Error reading best block");
+					continue;
+				},
+			};
+			let block_hash = block.hash();
+			if let Err(err) = self.on_best_block(block_hash).await {
+				warn!(?block_hash, ?err, "Error processing best block");
+			}
+		}
+		Err(anyhow!("Best block subscription closed"))
+	}
+
+	async fn on_best_block(&mut self, block_hash: H256) -> anyhow::Result<()> {
+		let at = FetchAt::Block(block_hash);
+		let next_frame_id = self
+			.client
+			.fetch_storage(&storage().mining_slot().next_frame_id(), at)
+			.await?
+			.unwrap_or_default();
+
+		if let Some(open_frame) = self.open_frame.take_if(|x| x.frame_id != next_frame_id) {
+			self.record_outcome(open_frame, block_hash).await?;
+		}
+
+		let is_open = self
+			.client
+			.fetch_storage(&storage().mining_slot().is_next_slot_bidding_open(), at)
+			.await?
+			.unwrap_or_default();
+		if !is_open {
+			return Ok(());
+		}
+
+		if self.open_frame.is_none() {
+			let seats = (0..self.policy.target_seats)
+				.map(|seat| SeatKeys::derive(&self.miner_suri, next_frame_id, seat))
+				.collect::<anyhow::Result<Vec<_>>>()?;
+			info!(frame_id = next_frame_id, "Bidding opened for frame");
+			self.open_frame = Some(OpenFrame {
+				frame_id: next_frame_id,
+				seats,
+				simulated_bids: BTreeMap::new(),
+				last_cohort: vec![],
+				bid_stats: (0, 0, 0),
+				in_flight_bids: None,
+			});
+		}
+
+		if let Some(open_frame) = self.open_frame.as_mut() {
+			if let Some(in_flight_bids) = open_frame.in_flight_bids.take_if(|x| x.is_finished()) {
+				for record in in_flight_bids.await? {
+					self.db.record_bid(&record).await?;
+				}
+			}
+			if open_frame.in_flight_bids.is_some() {
+				// re-bidding now would conflict with the pending bids' nonces
+				return Ok(());
+			}
+		}
+
+		let state = self.bidding_state(block_hash).await?;
+		let bid_stats = self
+			.client
+			.fetch_storage(&storage().mining_slot().historical_bids_per_slot(), at)
+			.await?
+			.and_then(|x| x.0.first().map(|s| (s.bids_count, s.bid_amount_min, s.bid_amount_max)))
+			.unwrap_or_default();
+		let Some(open_frame) = self.open_frame.as_mut() else { return Ok(()) };
+		open_frame.bid_stats = bid_stats;
+		open_frame.last_cohort = state.cohort.clone();
+
+		let accounts = open_frame.seats.iter().map(|x| x.account_id.clone()).collect::<Vec<_>>();
+		let planned = plan_bids(&self.policy, &state, &accounts);
+		let mut next_nonce = None;
+		let mut submitted = vec![];
+		for bid in planned {
+			let mut record = BidRecord {
+				frame_id: open_frame.frame_id,
+				seat: bid.seat,
+				mining_account: bid.account_id.to_address(),
+				bid: bid.bid,
+				previous_bid: bid.previous_bid,
+				block_hash: format!("{block_hash:?}"),
+				status: BidStatus::DryRun,
+				error: None,
+			};
+			if self.dry_run {
+				info!(?record, "Dry run bid");
+				open_frame.simulated_bids.insert(
+					bid.seat,
+					CohortBid { account_id: bid.account_id.clone(), bid: bid.bid },
+				);
+				insert_bid(
+					&mut open_frame.last_cohort,
+					CohortBid { account_id: bid.account_id, bid: bid.bid },
+					state.cohort_size,
+				);
+				self.db.record_bid(&record).await?;
+				continue;
+			}
+
+			let seat = &open_frame.seats[bid.seat as usize];
+			let call =
+				tx().mining_slot()
+					.bid(bid.bid, seat.keys.clone(), Some(seat.account_id.clone()));
+			// the bids are submitted back to back, so only the first nonce comes from the chain
+			let nonce = match next_nonce {
+				Some(nonce) => nonce,
+				None => self.client.get_account_nonce(&self.signer.account_id()).await?,
+			};
+			let params = MainchainClient::ext_params_builder().nonce(nonce.into()).build();
+			match self
+				.client
+				.live
+				.tx()
+				.sign_and_submit_then_watch(&call, &self.signer, params)
+				.await
+			{
+				Ok(tx_progress) => {
+					info!(?record, "Submitted bid");
+					record.status = BidStatus::Submitted;
+					next_nonce = Some(nonce + 1);
+					submitted.push((record, tx_progress));
+				},
+				Err(e) => {
+					warn!(?record, ?e, "Failed to submit bid");
+					record.status = BidStatus::Failed;
+					record.error = Some(e.to_string());
+					next_nonce = Some(nonce);
+					self.db.record_bid(&record).await?;
+				},
+			}
+		}
+		if !submitted.is_empty() {
+			open_frame.in_flight_bids = Some(tokio::spawn(watch_bids(submitted)));
+		}
+		Ok(())
+	}
+
+	async fn bidding_state(&self, block_hash: H256) -> anyhow::Result<BiddingState<ApiAccountId>> {
+		let at = FetchAt::Block(block_hash);
+		let mining_slot = storage().mining_slot();
+		let mut cohort = self
+			.client
+			.fetch_storage(&mining_slot.bids_for_next_slot_cohort(), at)
+			.await?
+			.map(|x| x.0)
+			.unwrap_or_default()
+			.into_iter()
+			.map(|x| CohortBid { account_id: x.account_id, bid: x.bid })
+			.collect::<Vec<_>>();
+		let cohort_size = self
+			.client
+			.fetch_storage(&mining_slot.next_cohort_size(), at)
+			.await?
+			.unwrap_or_default();
+		let argonots_per_seat = self
+			.client
+			.fetch_storage(&mining_slot.argonots_per_mining_seat(), at)
+			.await?
+			.unwrap_or_default();
+		let available_argonots = self
+			.client
+			.get_ownership(&self.signer.account_id(), at)
+			.await
+			.map(|x| x.free)
+			.unwrap_or_default();
+
+		if let Some(open_frame) = self.open_frame.as_ref() {
+			for bid in open_frame.simulated_bids.values() {
+				insert_bid(&mut cohort, bid.clone(), cohort_size);
+			}
+		}
+
+		Ok(BiddingState {
+			cohort,
+			cohort_size,
+			bid_increment: self.bid_increment,
+			argonots_per_seat,
+			available_argonots,
+		})
+	}
+
+	async fn record_outcome(
+		&self,
+		mut open_frame: OpenFrame,
+		block_hash: H256,
+	) -> anyhow::Result<()> {
+		if let Some(in_flight_bids) = open_frame.in_flight_bids.take() {
+			if in_flight_bids.is_finished() {
+				for record in in_flight_bids.await? {
+					self.db.record_bid(&record).await?;
+				}
+			} else {
+				warn!(
+					frame_id = open_frame.frame_id,
+					"Bids were still pending when bidding closed"
+				);
+				in_flight_bids.abort();
+			}
+		}
+		let cohort = if self.dry_run {
+			open_frame.last_cohort
+		} else {
+			self.client
+				.fetch_storage(
+					&storage().mining_slot().miners_by_cohort(open_frame.frame_id),
+					FetchAt::Block(block_hash),
+				)
+				.await?
+				.map(|x| x.0)
+				.unwrap_or_default()
+				.into_iter()
+				.map(|x| CohortBid { account_id: x.account_id, bid: x.bid })
+				.collect()
+		};
+		let won = cohort
+			.iter()
+			.filter(|x| open_frame.seats.iter().any(|s| s.account_id == x.account_id))
+			.collect::<Vec<_>>();
+		let (bids_count, bid_amount_min, bid_amount_max) = open_frame.bid_stats;
+		let outcome = FrameOutcome {
+			frame_id: open_frame.frame_id,
+			seats_won: won.len() as u32,
+			target_seats: self.policy.target_seats,
+			argons_bid: won.iter().map(|x| x.bid).sum(),
+			bids_count,
+			bid_amount_min,
+			bid_amount_max,
+			dry_run: self.dry_run,
+		};
+		info!(?outcome, "Bidding closed for frame");
+		self.db.record_outcome(&outcome).await
+	}
+}
+
+/// Waits for each submitted bid to be included in a block or dropped from the transaction pool
+async fn watch_bids(submitted: Vec<(BidRecord, ArgonTxProgress)>) -> Vec<BidRecord> {
+	let mut records = Vec::with_capacity(submitted.len());
+	for (mut record, tx_progress) in submitted {
+		match MainchainClient::wait_for_ext_in_block(tx_progress, false).await {
+			Ok(in_block) => info!(?record, block_hash = ?in_block.block_hash(), "Bid included"),
+			Err(e) => {
+				warn!(?record, ?e, "Bid was not included");
+				record.status = BidStatus::Failed;
+				record.error = Some(e.to_string());
+			},
+		}
+		records.push(record);
+	}
+	records
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn derives_distinct_seat_keys() {
+		let a = SeatKeys::derive("//Ferdie", 10, 0).unwrap();
+		let b = SeatKeys::derive("//Ferdie", 10, 1).unwrap();
+		let c = SeatKeys::derive("//Ferdie", 11, 0).unwrap();
+		assert_ne!(a.account_id, b.account_id);
+		assert_ne!(a.account_id, c.account_id);
+		assert_ne!(a.keys.block_seal_authority.0, a.keys.grandpa.0);
+		assert_eq!(a.account_id, SeatKeys::derive("//Ferdie", 10, 0).unwrap().account_id);
+	}
+}
@@ -0,0 +1,222 @@
+use anyhow::Context;
+use argon_primitives::Balance;
+use sqlx::{
+	migrate::MigrateDatabase,
+	sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions},
+	FromRow, Sqlite, SqlitePool,
+};
+use std::{fs::create_dir_all, path::Path, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BidStatus {
+	Submitted,
+	Failed,
+	DryRun,
+}
+
+impl BidStatus {
+	fn as_str(&self) -> &'static str {
+		match self {
+			BidStatus::Submitted => "submitted",
+			BidStatus::Failed => "failed",
+			BidStatus::DryRun => "dry_run",
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BidRecord {
+	pub frame_id: u64,
+	pub seat: u32,
+	pub mining_account: String,
+	pub bid: Balance,
+	pub previous_bid: Option<Balance>,
+	pub block_hash: String,
+	pub status: BidStatus,
+	pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameOutcome {
+	pub frame_id: u64,
+	pub seats_won: u32,
+	pub target_seats: u32,
+	pub argons_bid: Balance,
+	pub bids_count: u32,
+	pub bid_amount_min: Balance,
+	pub bid_amount_max: Balance,
+	pub dry_run: bool,
+}
+
+#[derive(FromRow)]
+struct FrameOutcomeRow {
+	frame_id: i64,
+	seats_won: i64,
+	target_seats: i64,
+	argons_bid: String,
+	bids_count: i64,
+	bid_amount_min: String,
+	bid_amount_max: String,
+	dry_run: bool,
+}
+
+impl TryFrom<FrameOutcomeRow> for FrameOutcome {
+	type Error = anyhow::Error;
+
+	fn try_from(row: FrameOutcomeRow) -> Result<Self, Self::Error> {
+		Ok(Self {
+			frame_id: row.frame_id as u64,
+			seats_won: row.seats_won as u32,
+			target_seats: row.target_seats as u32,
+			argons_bid: row.argons_bid.parse()?,
+			bids_count: row.bids_count as u32,
+			bid_amount_min: row.bid_amount_min.parse()?,
+			bid_amount_max: row.bid_amount_max.parse()?,
+			dry_run: row.dry_run,
+		})
+	}
+}
+
+/// Local record of the bids placed by the agent and the outcome of each frame. Balances are
+/// stored as text since sqlite integers can't hold a u128.
+#[derive(Clone)]
+pub struct BidderDb {
+	pool: SqlitePool,
+}
+
+impl BidderDb {
+	pub async fn open(path: &str) -> anyhow::Result<Self> {
+		if let Some(dir) = Path::new(path).parent() &&
+			!dir.as_os_str().is_empty() &&
+			!dir.exists()
+		{
+			create_dir_all(dir).with_context(|| {
+				format!(
+					"Could not create the parent directory ({}) for the database",
+					dir.display()
+				)
+			})?;
+		}
+		if !Sqlite::database_exists(path).await.unwrap_or(false) {
+			Sqlite::create_database(path).await?;
+		}
+		let options = SqliteConnectOptions::from_str(path)?.journal_mode(SqliteJournalMode::Wal);
+		let pool = SqlitePoolOptions::new().connect_with(options).await?;
+		Self::from_pool(pool).await
+	}
+
+	pub async fn from_pool(pool: SqlitePool) -> anyhow::Result<Self> {
+		sqlx::migrate!().run(&pool).await?;
+		Ok(Self { pool })
+	}
+
+	pub async fn record_bid(&self, record: &BidRecord) -> anyhow::Result<()> {
+		sqlx::query(
+			"INSERT INTO bids (frame_id, seat, mining_account, bid, previous_bid, block_hash, status, error) \
+			VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+		)
+		.bind(record.frame_id as i64)
+		.bind(record.seat as i64)
+		.bind(&record.mining_account)
+		.bind(record.bid.to_string())
+		.bind(record.previous_bid.map(|a| a.to_string()))
+		.bind(&record.block_hash)
+		.bind(record.status.as_str())
+		.bind(&record.error)
+		.execute(&self.pool)
+		.await?;
+		Ok(())
+	}
+
+	pub async fn record_outcome(&self, outcome: &FrameOutcome) -> anyhow::Result<()> {
+		sqlx::query(
+			"INSERT OR REPLACE INTO frame_outcomes (frame_id, seats_won, target_seats, argons_bid, \
+			bids_count, bid_amount_min, bid_amount_max, dry_run) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+		)
+		.bind(outcome.frame_id as i64)
+		.bind(outcome.seats_won as i64)
+		.bind(outcome.target_seats as i64)
+		.bind(outcome.argons_bid.to_string())
+		.bind(outcome.bids_count as i64)
+		.bind(outcome.bid_amount_min.to_string())
+		.bind(outcome.bid_amount_max.to_string())
+		.bind(outcome.dry_run)
+		.execute(&self.pool)
+		.await?;
+		Ok(())
+	}
+
+	/// The most recent frame outcomes (newest first)
+	pub async fn frame_outcomes(&self, limit: u32) -> anyhow::Result<Vec<FrameOutcome>> {
+		let rows = sqlx::query_as::<_, FrameOutcomeRow>(
+			"SELECT frame_id, seats_won, target_seats, argons_bid, bids_count, bid_amount_min, \
+			bid_amount_max, dry_run FROM frame_outcomes ORDER BY frame_id DESC LIMIT ?",
+		)
+		.bind(limit as i64)
+		.fetch_all(&self.pool)
+		.await?;
+		rows.into_iter().map(TryInto::try_into).collect()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	impl BidderDb {
+		async fn bid_count(&self, frame_id: u64, status: BidStatus) -> anyhow::Result<u32> {
+			let count: i64 =
+				sqlx::query_scalar("SELECT COUNT(*) FROM bids WHERE frame_id = ? AND status = ?")
+					.bind(frame_id as i64)
+					.bind(status.as_str())
+					.fetch_one(&self.pool)
+					.await?;
+			Ok(count as u32)
+		}
+	}
+
+	#[tokio::test]
+	async fn records_bids_and_outcomes() {
+		// a single connection so every query sees the same in-memory database
+		let pool = SqlitePoolOptions::new()
+			.max_connections(1)
+			.connect("sqlite::memory:")
+			.await
+			.unwrap();
+		let db = BidderDb::from_pool(pool).await.expect("migrate");
+
+		let bid = BidRecord {
+			frame_id: 5,
+			seat: 0,
+			mining_account: "account".to_string(),
+			bid: u128::MAX,
+			previous_bid: None,
+			block_hash: "0x01".to_string(),
+			status: BidStatus::DryRun,
+			error: None,
+		};
+		db.record_bid(&bid).await.unwrap();
+		db.record_bid(&BidRecord { seat: 1, ..bid }).await.unwrap();
+		assert_eq!(db.bid_count(5, BidStatus::DryRun).await.unwrap(), 2);
+		assert_eq!(db.bid_count(5, BidStatus::Submitted).await.unwrap(), 0);
+
+		let outcome = FrameOutcome {
+			frame_id: 5,
+			seats_won: 1,
+			target_seats: 2,
+			argons_bid: u128::MAX,
+			bids_count: 10,
+			bid_amount_min: 1,
+			bid_amount_max: 2,
+			dry_run: true,
+		};
+		db.record_outcome(&outcome).await.unwrap();
+		db.record_outcome(&FrameOutcome { frame_id: 6, seats_won: 2, ..outcome.clone() })
+			.await
+			.unwrap();
+		let outcomes = db.frame_outcomes(10).await.unwrap();
+		assert_eq!(outcomes.len(), 2);
+		assert_eq!(outcomes[0].frame_id, 6);
+		assert_eq!(outcomes[1], outcome);
+	}
+}
@@ -0,0 +1,205 @@
+use anyhow::{anyhow, bail, ensure};
+use argon_client::{signer::KeystoreSigner, MainchainClient};
+use argon_primitives::{AccountId, Balance, CryptoType, KeystoreParams, ADDRESS_PREFIX};
+use clap::{Args, Parser, ValueEnum};
+use comfy_table::{presets::UTF8_FULL, Table};
+use directories::BaseDirs;
+use dotenv::dotenv;
+use polkadot_sdk::*;
+use sp_core::{
+	crypto::{key_types::ACCOUNT, Ss58Codec},
+	sr25519, Pair as PairT,
+};
+use sp_runtime::traits::IdentifyAccount;
+
+use crate::{agent::BidAgent, db::BidderDb, strategy::BidPolicy};
+
+mod agent;
+mod db;
+mod strategy;
+
+#[derive(Parser, Debug)]
+#[command(author, version = env!("IMPL_VERSION"), about, arg_required_else_help = true, long_about = None)]
+struct Cli {
+	#[command(subcommand)]
+	pub subcommand: Subcommand,
+
+	/// Start in dev mode (using //Ferdie as the funding account and miner seed)
+	#[clap(global = true, long)]
+	dev: bool,
+
+	/// What mainchain RPC websocket url do you want to reach out use to sync blocks?
+	#[clap(global = true, short, long, env, default_value = "ws://127.0.0.1:9944")]
+	trusted_rpc_url: String,
+
+	/// The sqlite database to record bids and frame outcomes in. Defaults to
+	/// `<local data dir>/argon/bidder/bidder.db`
+	#[clap(global = true, long, env = "BIDDER_DB_PATH")]
+	db_path: Option<String>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	keystore_params: KeystoreParams,
+
+	/// The funding account to bid from (Required if not in dev mode)
+	#[clap(global = true, long, env)]
+	signer_address: Option<String>,
+
+	/// What type of crypto to use for the signer (Required if not in dev mode)
+	#[clap(global = true, long, env)]
+	signer_crypto: Option<BidderCryptoType>,
+}
+
+#[derive(Debug, Clone, clap::Subcommand)]
+enum Subcommand {
+	/// Bid for mining seats each frame, re-bidding whenever outbid
+	Run(RunArgs),
+	/// Show the outcome of recent frames
+	History {
+		/// How many frames to show
+		#[clap(long, default_value_t = 10)]
+		limit: u32,
+	},
+}
+
+#[derive(Debug, Clone, Args)]
+struct RunArgs {
+	/// How many seats to try to win each frame
+	#[clap(long, env = "BIDDER_TARGET_SEATS")]
+	target_seats: u32,
+
+	/// The lowest bid per seat (microgons)
+	#[clap(long, env = "BIDDER_MIN_BID", default_value_t = 0)]
+	min_bid: Balance,
+
+	/// The highest bid per seat (microgons)
+	#[clap(long, env = "BIDDER_MAX_BID")]
+	max_bid: Balance,
+
+	/// The most to spend on all seats in a frame (microgons)
+	#[clap(long, env = "BIDDER_BUDGET")]
+	budget: Balance,
+
+	/// The secret uri that seat mining accounts and session keys are derived from
+	/// (`<suri>//<frame id>//<seat>`, with `//seal` and `//grandpa` for the session keys).
+	/// The session keys must be inserted into your mining node's keystore.
+	#[clap(long, env = "BIDDER_MINER_SURI", verbatim_doc_comment)]
+	miner_suri: Option<String>,
+
+	/// Plan and record bids without submitting them
+	#[clap(long)]
+	dry_run: bool,
+}
+
+#[derive(ValueEnum, Debug, Clone)]
+enum BidderCryptoType {
+	Sr25519,
+	Ed25519,
+}
+impl From<BidderCryptoType> for CryptoType {
+	fn from(crypto: BidderCryptoType) -> CryptoType {
+		match crypto {
+			BidderCryptoType::Sr25519 => CryptoType::Sr25519,
+			BidderCryptoType::Ed25519 => CryptoType::Ed25519,
+		}
+	}
+}
+
+const DEV_SURI: &str = "//Ferdie";
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+	let _ = tracing_subscriber::FmtSubscriber::builder()
+		.with_env_filter(
+			tracing_subscriber::EnvFilter::try_from_default_env()
+				.unwrap_or(tracing_subscriber::EnvFilter::from("info")),
+		)
+		.try_init();
+	color_backtrace::install();
+	dotenv().ok();
+
+	let Cli {
+		subcommand,
+		dev,
+		trusted_rpc_url,
+		db_path,
+		keystore_params,
+		signer_address,
+		signer_crypto,
+	} = Cli::parse();
+
+	let db_path = match db_path {
+		Some(path) => path,
+		None => BaseDirs::new()
+			.ok_or(anyhow!("No home directory"))?
+			.data_local_dir()
+			.join("argon/bidder/bidder.db")
+			.to_string_lossy()
+			.to_string(),
+	};
+	let db = BidderDb::open(&db_path).await?;
+
+	let args = match subcommand {
+		Subcommand::History { limit } => {
+			let mut table = Table::new();
+			table.load_preset(UTF8_FULL).set_header(vec![
+				"Frame",
+				"Seats Won",
+				"Argons Bid",
+				"Total Bids",
+				"Min Bid",
+				"Max Bid",
+				"Dry Run",
+			]);
+			for outcome in db.frame_outcomes(limit).await? {
+				table.add_row(vec![
+					outcome.frame_id.to_string(),
+					format!("{} / {}", outcome.seats_won, outcome.target_seats),
+					outcome.argons_bid.to_string(),
+					outcome.bids_count.to_string(),
+					outcome.bid_amount_min.to_string(),
+					outcome.bid_amount_max.to_string(),
+					outcome.dry_run.to_string(),
+				]);
+			}
+			println!("{table}");
+			return Ok(());
+		},
+		Subcommand::Run(args) => args,
+	};
+
+	let mut signer_address = signer_address;
+	let mut signer_crypto = signer_crypto;
+	let mut miner_suri = args.miner_suri;
+	let keystore = if dev && signer_address.is_none() {
+		let pair = sr25519::Pair::from_string(DEV_SURI, None)?;
+		let account_id = pair.public().into_account();
+		signer_address = Some(account_id.to_ss58check_with_version(ADDRESS_PREFIX.into()));
+		signer_crypto = Some(BidderCryptoType::Sr25519);
+		miner_suri.get_or_insert_with(|| DEV_SURI.to_string());
+		keystore_params.open_in_memory(DEV_SURI, CryptoType::Sr25519, ACCOUNT)?
+	} else {
+		keystore_params.open()?
+	};
+
+	let (signer_account, signer_crypto) = match (signer_address, signer_crypto) {
+		(Some(signer_address), Some(signer_crypto)) => {
+			let (signer_account, format) = AccountId::from_ss58check_with_version(&signer_address)?;
+			ensure!(format.prefix() == ADDRESS_PREFIX, "Invalid address format");
+			(signer_account, signer_crypto)
+		},
+		_ => bail!("Signer address and crypto type must be provided"),
+	};
+	let Some(miner_suri) = miner_suri else { bail!("A miner suri must be provided") };
+	ensure!(args.min_bid <= args.max_bid, "The minimum bid is above the maximum bid");
+
+	let signer = KeystoreSigner::new(keystore, signer_account, signer_crypto.into());
+	let client = MainchainClient::from_url(&trusted_rpc_url).await?;
+	let policy = BidPolicy {
+		target_seats: args.target_seats,
+		min_bid: args.min_bid,
+		max_bid: args.max_bid,
+		budget: args.budget,
+	};
+	BidAgent::new(client, signer, db, policy, miner_suri, args.dry_run)?.run().await
+}
@@ -0,0 +1,267 @@
+use argon_primitives::Balance;
+
+/// The limits an operator sets for a single mining frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BidPolicy {
+	/// The number of seats to try to win each frame
+	pub target_seats: u32,
+	/// The lowest bid to place per seat
+	pub min_bid: Balance,
+	/// The highest bid to place per seat
+	pub max_bid: Balance,
+	/// The total argons that can be spent on bids for a frame
+	pub budget: Balance,
+}
+
+/// A bid in the next cohort, highest first (as stored in `BidsForNextSlotCohort`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CohortBid<AccountId> {
+	pub account_id: AccountId,
+	pub bid: Balance,
+}
+
+/// The chain state needed to plan bids
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BiddingState<AccountId> {
+	pub cohort: Vec<CohortBid<AccountId>>,
+	pub cohort_size: u32,
+	pub bid_increment: Balance,
+	pub argonots_per_seat: Balance,
+	/// Argonots the funding account can still put on hold
+	pub available_argonots: Balance,
+}
+
+/// A bid to submit for one of our seats
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedBid<AccountId> {
+	pub seat: u32,
+	pub account_id: AccountId,
+	pub bid: Balance,
+	/// Our previous bid for this seat if we are raising it
+	pub previous_bid: Option<Balance>,
+}
+
+/// Plans the bids needed to hold as many of the target seats as the policy allows.
+///
+/// `our_accounts` are the mining accounts for each seat (in seat order). Seats are only (re)bid
+/// when they are not already in the cohort at or above the winning price. Bids can never be
+/// lowered, so a seat we already hold at a higher bid is left alone. When fewer seats are planned
+/// than we hold, the bids of our later seats stay in the cohort, so they count towards the budget
+/// and take up cohort positions like any other bid.
+pub fn plan_bids<AccountId: PartialEq + Clone>(
+	policy: &BidPolicy,
+	state: &BiddingState<AccountId>,
+	our_accounts: &[AccountId],
+) -> Vec<PlannedBid<AccountId>> {
+	let seats = policy.target_seats.min(our_accounts.len() as u32).min(state.cohort_size);
+	let existing_bid = |account_id: &AccountId| {
+		state.cohort.iter().find(|x| x.account_id == *account_id).map(|x| x.bid)
+	};
+
+	for seat_count in (1..=seats).rev() {
+		let accounts = &our_accounts[..seat_count as usize];
+		// every bid besides the seats being planned, including our own later seats
+		let others = state
+			.cohort
+			.iter()
+			.filter(|x| !accounts.contains(&x.account_id))
+			.collect::<Vec<_>>();
+		let other_bids = others.iter().map(|x| x.bid).collect::<Vec<_>>();
+		let Some(price) = winning_price(policy, state, &other_bids, seat_count) else {
+			continue;
+		};
+		// our later seats that are still in the cohort once the planned seats are placed
+		let open_positions = (state.cohort_size - seat_count) as usize;
+		let held_total = others
+			.iter()
+			.take(open_positions)
+			.filter(|x| our_accounts.contains(&x.account_id))
+			.map(|x| x.bid)
+			.sum::<Balance>();
+		let total = accounts
+			.iter()
+			.map(|a| existing_bid(a).unwrap_or_default().max(price))
+			.sum::<Balance>()
+			.saturating_add(held_total);
+		if total > policy.budget {
+			continue;
+		}
+		let new_seats = accounts.iter().filter(|a| existing_bid(a).is_none()).count() as u128;
+		if new_seats.saturating_mul(state.argonots_per_seat) > state.available_argonots {
+			continue;
+		}
+
+		return accounts
+			.iter()
+			.enumerate()
+			.filter_map(|(seat, account_id)| {
+				let previous_bid = existing_bid(account_id);
+				if previous_bid.is_some_and(|existing| existing >= price) {
+					return None;
+				}
+				Some(PlannedBid {
+					seat: seat as u32,
+					account_id: account_id.clone(),
+					bid: price,
+					previous_bid,
+				})
+			})
+			.collect();
+	}
+	vec![]
+}
+
+/// Adds a bid to a cohort the same way the mining slot pallet does (used to simulate our bids in
+/// dry-run mode). A bid replaces the account's previous bid, is placed behind equal bids and the
+/// lowest bid is dropped once the cohort is full.
+pub fn insert_bid<AccountId: PartialEq>(
+	cohort: &mut Vec<CohortBid<AccountId>>,
+	bid: CohortBid<AccountId>,
+	cohort_size: u32,
+) -> bool {
+	let others = cohort.iter().filter(|x| x.account_id != bid.account_id);
+	let position = others.clone().position(|x| x.bid < bid.bid).unwrap_or(others.count());
+	if position >= cohort_size as usize {
+		return false;
+	}
+	cohort.retain(|x| x.account_id != bid.account_id);
+	cohort.insert(position, bid);
+	cohort.truncate(cohort_size as usize);
+	true
+}
+
+/// The lowest bid that keeps `seat_count` of our bids in the cohort, if it is within the policy
+fn winning_price(
+	policy: &BidPolicy,
+	state: &BiddingState<impl Sized>,
+	competing: &[Balance],
+	seat_count: u32,
+) -> Option<Balance> {
+	let increment = state.bid_increment.max(1);
+	// ties are placed behind existing bids, so we must beat the highest bid we need to displace
+	let open_positions = (state.cohort_size - seat_count) as usize;
+	let price = match competing.get(open_positions) {
+		Some(bid_to_beat) => (bid_to_beat / increment).saturating_add(1).saturating_mul(increment),
+		None => 0,
+	}
+	.max(policy.min_bid.div_ceil(increment).saturating_mul(increment));
+	(price <= policy.max_bid).then_some(price)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	fn policy() -> BidPolicy {
+		BidPolicy { target_seats: 2, min_bid: 10_000, max_bid: 1_000_000, budget: 2_000_000 }
+	}
+
+	fn state(cohort: Vec<(u8, Balance)>) -> BiddingState<u8> {
+		BiddingState {
+			cohort: cohort
+				.into_iter()
+				.map(|(account_id, bid)| CohortBid { account_id, bid })
+				.collect(),
+			cohort_size: 3,
+			bid_increment: 10_000,
+			argonots_per_seat: 100,
+			available_argonots: 1_000,
+		}
+	}
+
+	#[test]
+	fn bids_the_minimum_into_an_open_cohort() {
+		let planned = plan_bids(&policy(), &state(vec![(9, 500_000)]), &[1, 2]);
+		assert_eq!(
+			planned,
+			vec![
+				PlannedBid { seat: 0, account_id: 1, bid: 10_000, previous_bid: None },
+				PlannedBid { seat: 1, account_id: 2, bid: 10_000, previous_bid: None },
+			]
+		);
+	}
+
+	#[test]
+	fn rebids_when_outbid() {
+		// we were pushed down to the last seat and one seat was taken
+		let cohort = vec![(8, 500_000), (9, 205_000), (1, 200_000)];
+		let planned = plan_bids(&policy(), &state(cohort), &[1, 2]);
+		assert_eq!(
+			planned,
+			vec![
+				PlannedBid { seat: 0, account_id: 1, bid: 210_000, previous_bid: Some(200_000) },
+				PlannedBid { seat: 1, account_id: 2, bid: 210_000, previous_bid: None },
+			]
+		);
+	}
+
+	#[test]
+	fn does_not_rebid_a_winning_seat() {
+		let cohort = vec![(1, 300_000), (2, 300_000), (9, 200_000)];
+		assert_eq!(plan_bids(&policy(), &state(cohort), &[1, 2]), vec![]);
+	}
+
+	#[test]
+	fn drops_seats_over_the_max_bid_or_budget() {
+		let cohort = vec![(7, 990_000), (8, 500_000), (9, 300_000)];
+		// two seats would need to beat 500_000, one seat only needs to beat 300_000
+		let mut policy = policy();
+		policy.budget = 800_000;
+		assert_eq!(
+			plan_bids(&policy, &state(cohort.clone()), &[1, 2]),
+			vec![PlannedBid { seat: 0, account_id: 1, bid: 310_000, previous_bid: None }]
+		);
+
+		policy.max_bid = 250_000;
+		assert_eq!(plan_bids(&policy, &state(cohort), &[1, 2]), vec![]);
+	}
+
+	#[test]
+	fn counts_later_seats_we_hold_when_falling_back() {
+		// we held both seats, then seat 0 was outbid
+		let cohort = vec![(9, 500_000), (2, 150_000), (8, 120_000)];
+		let mut policy = policy();
+		policy.budget = 250_000;
+		// two seats cost 130_000 + 150_000. One seat has to beat 120_000 since seat 1 keeps its
+		// cohort position, and seat 1's bid still counts towards the budget.
+		assert_eq!(plan_bids(&policy, &state(cohort), &[1, 2]), vec![]);
+	}
+
+	#[test]
+	fn falls_back_to_one_seat_when_the_price_rises() {
+		// we held both seats, then two higher bids came in
+		let cohort = vec![(9, 500_000), (8, 300_000), (2, 150_000)];
+		let mut policy = policy();
+		policy.budget = 400_000;
+		// two seats would need 310_000 each. One seat pushes out our own seat 1, so it doesn't
+		// count towards the budget.
+		assert_eq!(
+			plan_bids(&policy, &state(cohort), &[1, 2]),
+			vec![PlannedBid { seat: 0, account_id: 1, bid: 310_000, previous_bid: None }]
+		);
+	}
+
+	#[test]
+	fn simulates_cohort_inserts() {
+		let mut cohort = state(vec![(8, 300_000), (9, 200_000)]).cohort;
+		assert!(insert_bid(&mut cohort, CohortBid { account_id: 1, bid: 200_000 }, 3));
+		assert_eq!(cohort.iter().map(|x| x.account_id).collect::<Vec<_>>(), vec![8, 9, 1]);
+
+		assert!(!insert_bid(&mut cohort, CohortBid { account_id: 2, bid: 200_000 }, 3));
+		assert!(insert_bid(&mut cohort, CohortBid { account_id: 1, bid: 400_000 }, 3));
+		assert_eq!(cohort.iter().map(|x| x.account_id).collect::<Vec<_>>(), vec![1, 8, 9]);
+
+		assert!(insert_bid(&mut cohort, CohortBid { account_id: 2, bid: 250_000 }, 3));
+		assert_eq!(cohort.iter().map(|x| x.account_id).collect::<Vec<_>>(), vec![1, 8, 2]);
+	}
+
+	#[test]
+	fn requires_argonots_for_new_seats() {
+		let mut state = state(vec![]);
+		state.available_argonots = 150;
+		assert_eq!(
+			plan_bids(&policy(), &state, &[1, 2]),
+			vec![PlannedBid { seat: 0, account_id: 1, bid: 10_000, previous_bid: None }]
+		);
+	}
+}
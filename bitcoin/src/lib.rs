@@ -6,13 +6,15 @@ pub use cosign_releaser::CosignReleaser;
 pub use cosign_script::{Amount, CosignScript, CosignScriptArgs, ReleaseStep};
 pub use errors::Error;
 #[cfg(feature = "std")]
-pub use utxo_spend_filter::{BlockFilter, UtxoSpendFilter};
+pub use p2p_filter_source::P2pBlockFilterSource;
+#[cfg(feature = "std")]
+pub use utxo_spend_filter::{BlockFilter, BlockFilterSource, UtxoSpendFilter};
 pub use xpriv::*;
 
 pub mod primitives {
 	pub use argon_primitives::bitcoin::*;
 	pub use bitcoin::{
-		bip32::{DerivationPath, Xpriv, Xpub},
+		bip32::{ChildNumber, DerivationPath, Xpriv, Xpub},
 		CompressedPublicKey, FeeRate, Network, PrivateKey, Psbt, ScriptBuf, Txid,
 	};
 }
@@ -23,6 +25,8 @@ mod cosign_script;
 #[cfg(feature = "std")]
 pub mod client;
 mod errors;
+#[cfg(feature = "std")]
+mod p2p_filter_source;
 pub mod psbt_utils;
 #[cfg(feature = "std")]
 mod utxo_spend_filter;
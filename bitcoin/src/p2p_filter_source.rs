@@ -0,0 +1,416 @@
+use crate::{BlockFilter, BlockFilterSource};
+use anyhow::{anyhow, bail, ensure};
+use argon_primitives::bitcoin::{BitcoinBlock, BitcoinHeight, BitcoinNetwork, H256Le};
+use bitcoin::{
+	consensus::{encode, Decodable},
+	io::FromStd,
+	p2p::{
+		address::Address,
+		message::{NetworkMessage, RawNetworkMessage},
+		message_blockdata::{GetHeadersMessage, Inventory},
+		message_filter::{CFilter, GetCFilters},
+		message_network::VersionMessage,
+		Magic, ServiceFlags,
+	},
+	Block, BlockHash, Network,
+};
+use log::{debug, trace};
+use parking_lot::Mutex;
+use std::{
+	io::{BufReader, Write},
+	net::{SocketAddr, TcpStream, ToSocketAddrs},
+	time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+/// The BIP158 "basic" filter type
+const BASIC_FILTER_TYPE: u8 = 0;
+/// The maximum number of filters a peer will return for a single `getcfilters` request (BIP157)
+const MAX_FILTERS_PER_REQUEST: u64 = 1000;
+/// The most unrelated messages (eg, inventory announcements) to accept while waiting for a response
+const MAX_UNRELATED_MESSAGES: usize = 1000;
+const USER_AGENT: &str = "/argon-utxo-tracker/";
+
+/// A block filter source that follows BIP157 compact block filters from a single bitcoin peer
+/// (eg, a bitcoind started with `-blockfilterindex=1 -peerblockfilters=1`). Only blocks that
+/// match a filter are downloaded. There is no local filter file source since matching blocks still
+/// need to be downloaded from a peer.
+///
+/// NOTE: filters are not cross-checked against other peers, so this should be a trusted peer.
+pub struct P2pBlockFilterSource {
+	peer: SocketAddr,
+	network: BitcoinNetwork,
+	timeout: Duration,
+	connection: Mutex<Option<PeerConnection>>,
+}
+
+impl P2pBlockFilterSource {
+	pub fn new(peer: &str, network: BitcoinNetwork) -> anyhow::Result<Self> {
+		let peer = peer
+			.to_socket_addrs()?
+			.next()
+			.ok_or_else(|| anyhow!("Unable to resolve bitcoin peer address {peer}"))?;
+		Ok(Self { peer, network, timeout: Duration::from_secs(30), connection: Mutex::new(None) })
+	}
+
+	fn with_connection<R>(
+		&self,
+		f: impl FnOnce(&mut PeerConnection) -> anyhow::Result<R>,
+	) -> anyhow::Result<R> {
+		let mut connection = self.connection.lock();
+		if connection.is_none() {
+			*connection = Some(PeerConnection::connect(self.peer, self.network, self.timeout)?);
+		}
+		let Some(peer) = connection.as_mut() else { bail!("Bitcoin peer is not connected") };
+		let result = f(peer);
+		if result.is_err() {
+			// reconnect on the next request
+			*connection = None;
+		}
+		result
+	}
+}
+
+impl BlockFilterSource for P2pBlockFilterSource {
+	fn get_network(&self) -> anyhow::Result<BitcoinNetwork> {
+		// the handshake will fail if the peer is on a different network
+		self.with_connection(|_| Ok(self.network))
+	}
+
+	fn get_filters(
+		&self,
+		stop_block: &BitcoinBlock,
+		start_height: BitcoinHeight,
+	) -> anyhow::Result<Vec<BlockFilter>> {
+		let stop_height = stop_block.block_height;
+		ensure!(start_height <= stop_height, "Start height is after the stop block");
+		// request one extra block so we know the previous hash of the first requested block
+		let first_height = start_height.saturating_sub(1);
+
+		// each request is anchored by its stop hash, so batches are requested from the stop block
+		// backwards
+		let mut batches = vec![];
+		let mut batch_stop_height = stop_height;
+		let mut batch_stop_hash: BlockHash = stop_block.block_hash.clone().try_into()?;
+		loop {
+			let batch_start =
+				first_height.max((batch_stop_height + 1).saturating_sub(MAX_FILTERS_PER_REQUEST));
+			let cfilters = self.with_connection(|peer| {
+				peer.get_cfilters(batch_start, batch_stop_height, batch_stop_hash)
+			})?;
+			let first_hash = cfilters[0].block_hash;
+			batches.push((batch_start, cfilters));
+			if batch_start == first_height {
+				break;
+			}
+			batch_stop_hash =
+				self.with_connection(|peer| peer.get_previous_block_hash(first_hash))?;
+			batch_stop_height = batch_start - 1;
+		}
+
+		let mut filters = Vec::with_capacity((stop_height - start_height + 1) as usize);
+		let mut previous_block_hash = None;
+		for (batch_start, cfilters) in batches.into_iter().rev() {
+			for (i, cfilter) in cfilters.into_iter().enumerate() {
+				let block_height = batch_start + i as u64;
+				let block_hash: H256Le = cfilter.block_hash.into();
+				if block_height >= start_height {
+					filters.push(BlockFilter {
+						block_hash: block_hash.clone(),
+						previous_block_hash: previous_block_hash.clone(),
+						block_height,
+						filter: cfilter.filter,
+					});
+				}
+				previous_block_hash = Some(block_hash);
+			}
+		}
+		Ok(filters)
+	}
+
+	fn get_block(&self, block_hash: &BlockHash) -> anyhow::Result<Block> {
+		let block = self.with_connection(|peer| {
+			peer.send(NetworkMessage::GetData(vec![Inventory::WitnessBlock(*block_hash)]))?;
+			let mut response = peer.response(MAX_UNRELATED_MESSAGES + 1);
+			loop {
+				match response.receive(peer)? {
+					NetworkMessage::Block(block) if block.block_hash() == *block_hash =>
+						return Ok(block),
+					NetworkMessage::NotFound(_) =>
+						bail!("Bitcoin peer does not have block {block_hash}"),
+					_ => continue,
+				}
+			}
+		})?;
+		ensure!(block.check_merkle_root(), "Block {block_hash} has an invalid merkle root");
+		ensure!(
+			block.check_witness_commitment(),
+			"Block {block_hash} has an invalid witness commitment"
+		);
+		Ok(block)
+	}
+}
+
+struct PeerConnection {
+	magic: Magic,
+	timeout: Duration,
+	writer: TcpStream,
+	reader: FromStd<BufReader<TcpStream>>,
+}
+
+impl PeerConnection {
+	fn connect(
+		peer: SocketAddr,
+		network: BitcoinNetwork,
+		timeout: Duration,
+	) -> anyhow::Result<Self> {
+		let stream = TcpStream::connect_timeout(&peer, timeout)?;
+		stream.set_read_timeout(Some(timeout))?;
+		stream.set_write_timeout(Some(timeout))?;
+		let mut connection = Self {
+			magic: Magic::from(Network::from(network)),
+			timeout,
+			reader: FromStd::new(BufReader::new(stream.try_clone()?)),
+			writer: stream,
+		};
+		connection.handshake(peer)?;
+		debug!("Connected to bitcoin peer {peer} for compact block filters");
+		Ok(connection)
+	}
+
+	fn handshake(&mut self, peer: SocketAddr) -> anyhow::Result<()> {
+		let local = self.writer.local_addr()?;
+		let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+		// the nonce is only used by peers to detect connections to themselves
+		let nonce = now.as_nanos() as u64 ^ std::process::id() as u64;
+		self.send(NetworkMessage::Version(VersionMessage::new(
+			ServiceFlags::NONE,
+			now.as_secs() as i64,
+			Address::new(&peer, ServiceFlags::NONE),
+			Address::new(&local, ServiceFlags::NONE),
+			nonce,
+			USER_AGENT.to_string(),
+			0,
+		)))?;
+
+		let mut has_version = false;
+		let mut has_verack = false;
+		while !has_version || !has_verack {
+			match self.receive()? {
+				NetworkMessage::Version(version) => {
+					ensure!(
+						version.services.has(ServiceFlags::COMPACT_FILTERS),
+						"Bitcoin peer {peer} does not serve compact block filters (enable -peerblockfilters)"
+					);
+					ensure!(
+						version.services.has(ServiceFlags::WITNESS),
+						"Bitcoin peer {peer} does not serve witness blocks"
+					);
+					has_version = true;
+					self.send(NetworkMessage::Verack)?;
+				},
+				NetworkMessage::Verack => has_verack = true,
+				_ => {},
+			}
+		}
+		Ok(())
+	}
+
+	/// Requests the filters from `start_height` up to the block at `stop_height` (at most
+	/// `MAX_FILTERS_PER_REQUEST`)
+	fn get_cfilters(
+		&mut self,
+		start_height: BitcoinHeight,
+		stop_height: BitcoinHeight,
+		stop_hash: BlockHash,
+	) -> anyhow::Result<Vec<CFilter>> {
+		let expected = (stop_height - start_height + 1) as usize;
+		self.send(NetworkMessage::GetCFilters(GetCFilters {
+			filter_type: BASIC_FILTER_TYPE,
+			start_height: start_height as u32,
+			stop_hash,
+		}))?;
+		let mut cfilters = Vec::with_capacity(expected);
+		let mut response = self.response(expected + MAX_UNRELATED_MESSAGES);
+		while cfilters.len() < expected {
+			if let NetworkMessage::CFilter(cfilter) = response.receive(self)? &&
+				cfilter.filter_type == BASIC_FILTER_TYPE
+			{
+				cfilters.push(cfilter);
+			}
+		}
+		ensure!(
+			cfilters.last().map(|a| a.block_hash) == Some(stop_hash),
+			"Bitcoin peer returned filters that do not end with the requested block"
+		);
+		Ok(cfilters)
+	}
+
+	/// Looks up the parent of a block. A `getheaders` request without a locator returns only the
+	/// header of the stop block.
+	fn get_previous_block_hash(&mut self, block_hash: BlockHash) -> anyhow::Result<BlockHash> {
+		self.send(NetworkMessage::GetHeaders(GetHeadersMessage::new(vec![], block_hash)))?;
+		let mut response = self.response(MAX_UNRELATED_MESSAGES + 1);
+		loop {
+			if let NetworkMessage::Headers(headers) = response.receive(self)? {
+				let Some(header) = headers.into_iter().find(|a| a.block_hash() == block_hash)
+				else {
+					bail!("Bitcoin peer does not have block {block_hash}");
+				};
+				return Ok(header.prev_blockhash);
+			}
+		}
+	}
+
+	/// Starts waiting for the response to a request. The peer has to answer within the timeout and
+	/// at most `max_messages` messages.
+	fn response(&self, max_messages: usize) -> PendingResponse {
+		PendingResponse {
+			deadline: Instant::now() + self.timeout,
+			remaining_messages: max_messages,
+		}
+	}
+
+	fn send(&mut self, message: NetworkMessage) -> anyhow::Result<()> {
+		trace!("Sending bitcoin p2p message {}", message.cmd());
+		let raw = RawNetworkMessage::new(self.magic, message);
+		self.writer.write_all(&encode::serialize(&raw))?;
+		self.writer.flush()?;
+		Ok(())
+	}
+
+	/// Receives the next message from the peer, answering any pings along the way
+	fn receive(&mut self) -> anyhow::Result<NetworkMessage> {
+		loop {
+			let raw = RawNetworkMessage::consensus_decode(&mut self.reader)?;
+			ensure!(*raw.magic() == self.magic, "Bitcoin peer sent a message for another network");
+			match raw.into_payload() {
+				NetworkMessage::Ping(nonce) => self.send(NetworkMessage::Pong(nonce))?,
+				message => {
+					trace!("Received bitcoin p2p message {}", message.cmd());
+					return Ok(message);
+				},
+			}
+		}
+	}
+}
+
+/// Bounds how long and how many messages a peer can take to answer a request, since a peer that
+/// keeps sending unrelated messages would otherwise never trip the socket read timeout
+struct PendingResponse {
+	deadline: Instant,
+	remaining_messages: usize,
+}
+
+impl PendingResponse {
+	fn receive(&mut self, peer: &mut PeerConnection) -> anyhow::Result<NetworkMessage> {
+		ensure!(Instant::now() < self.deadline, "Bitcoin peer did not respond in time");
+		ensure!(
+			self.remaining_messages > 0,
+			"Bitcoin peer sent too many messages without responding"
+		);
+		self.remaining_messages -= 1;
+		peer.receive()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::UtxoSpendFilter;
+	use argon_primitives::bitcoin::BitcoinSyncStatus;
+	use argon_testing::{add_blocks, add_wallet_address};
+	use bitcoin::hashes::Hash;
+	use bitcoincore_rpc::RpcApi;
+
+	#[test]
+	fn it_syncs_filters_from_a_peer() {
+		let (bitcoind, _rpc_url, _network) = argon_testing::start_bitcoind().expect("start");
+		let block_address = add_wallet_address(&bitcoind);
+		add_blocks(&bitcoind, 20, &block_address);
+
+		let p2p_addr = bitcoind.params.p2p_socket.expect("p2p socket");
+		let source = P2pBlockFilterSource::new(&p2p_addr.to_string(), BitcoinNetwork::Regtest)
+			.expect("source");
+		assert_eq!(source.get_network().unwrap(), BitcoinNetwork::Regtest);
+
+		let height = bitcoind.client.get_block_count().unwrap();
+		let best = bitcoind.client.get_best_block_hash().unwrap();
+		let block = source.get_block(&best).expect("block");
+		assert_eq!(block.block_hash(), best);
+
+		let filter = UtxoSpendFilter::with_source(source);
+		filter
+			.sync_to_block(&BitcoinSyncStatus {
+				confirmed_block: BitcoinBlock::new(height, H256Le(best.to_byte_array())),
+				synched_block: None,
+				oldest_allowed_block_height: height - 5,
+			})
+			.expect("sync");
+		let filters = filter.get_stored_filters();
+		assert_eq!(filters.len(), 6);
+		assert_eq!(filters[0].block_height, height - 5);
+		for (i, entry) in filters.iter().enumerate() {
+			let rpc_filter = bitcoind
+				.client
+				.get_block_filter(&entry.block_hash.clone().try_into().unwrap())
+				.unwrap();
+			assert_eq!(entry.filter, rpc_filter.filter);
+			if i > 0 {
+				assert_eq!(entry.previous_block_hash, Some(filters[i - 1].block_hash.clone()));
+			}
+		}
+	}
+
+	#[test]
+	fn it_stops_waiting_for_filters_after_too_many_unrelated_messages() {
+		let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+		let addr = listener.local_addr().unwrap();
+		let magic = Magic::from(Network::Regtest);
+		let spammer = std::thread::spawn(move || {
+			let (mut stream, _) = listener.accept().unwrap();
+			let inv =
+				encode::serialize(&RawNetworkMessage::new(magic, NetworkMessage::Inv(vec![])));
+			// stops once the connection is dropped
+			while stream.write_all(&inv).is_ok() {}
+		});
+		let stream = TcpStream::connect(addr).unwrap();
+		let mut peer = PeerConnection {
+			magic,
+			timeout: Duration::from_secs(30),
+			reader: FromStd::new(BufReader::new(stream.try_clone().unwrap())),
+			writer: stream,
+		};
+		let err = peer.get_cfilters(0, 0, BlockHash::all_zeros()).unwrap_err();
+		assert!(err.to_string().contains("too many messages"), "{err}");
+		drop(peer);
+		spammer.join().unwrap();
+	}
+
+	#[test]
+	fn it_requests_filters_in_batches() {
+		let (bitcoind, _rpc_url, _network) = argon_testing::start_bitcoind().expect("start");
+		let block_address = add_wallet_address(&bitcoind);
+		add_blocks(&bitcoind, 2_200, &block_address);
+
+		let p2p_addr = bitcoind.params.p2p_socket.expect("p2p socket");
+		let source = P2pBlockFilterSource::new(&p2p_addr.to_string(), BitcoinNetwork::Regtest)
+			.expect("source");
+
+		let height = bitcoind.client.get_block_count().unwrap();
+		let best = bitcoind.client.get_best_block_hash().unwrap();
+		let start_height = height - 2_100;
+		let filters = source
+			.get_filters(&BitcoinBlock::new(height, H256Le(best.to_byte_array())), start_height)
+			.expect("filters");
+		assert_eq!(filters.len(), 2_101);
+		let start_parent = bitcoind.client.get_block_hash(start_height - 1).unwrap();
+		assert_eq!(filters[0].previous_block_hash, Some(start_parent.into()));
+		for (i, entry) in filters.iter().enumerate() {
+			assert_eq!(entry.block_height, start_height + i as u64);
+			if i > 0 {
+				assert_eq!(entry.previous_block_hash, Some(filters[i - 1].block_hash.clone()));
+			}
+		}
+		assert_eq!(filters.last().map(|a| a.block_hash.clone()), Some(best.into()));
+	}
+}
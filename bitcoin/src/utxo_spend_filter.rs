@@ -33,8 +33,83 @@ impl BlockFilter {
 	}
 }
 
+/// A source of bitcoin block filters (BIP158) and the blocks they describe.
+pub trait BlockFilterSource: Send {
+	/// The bitcoin network this source is connected to
+	fn get_network(&self) -> anyhow::Result<BitcoinNetwork>;
+
+	/// Retrieves the filters from `start_height` up to and including the `stop_block`, in
+	/// ascending order. Each filter must be linked to the previous one by `previous_block_hash`.
+	/// A source may return fewer filters than requested, but must always end with the stop block.
+	fn get_filters(
+		&self,
+		stop_block: &BitcoinBlock,
+		start_height: BitcoinHeight,
+	) -> anyhow::Result<Vec<BlockFilter>>;
+
+	/// Retrieves a full block (including witness data)
+	fn get_block(&self, block_hash: &bitcoin::BlockHash) -> anyhow::Result<bitcoin::Block>;
+
+	/// The hash of the block at a height in this source's best chain. Returns `None` if the
+	/// source hasn't reached the height or can't look blocks up by height.
+	fn get_best_chain_block_hash(
+		&self,
+		_block_height: BitcoinHeight,
+	) -> anyhow::Result<Option<H256Le>> {
+		Ok(None)
+	}
+}
+
+impl BlockFilterSource for Client {
+	fn get_network(&self) -> anyhow::Result<BitcoinNetwork> {
+		Ok(self.get_blockchain_info()?.chain.into())
+	}
+
+	fn get_filters(
+		&self,
+		stop_block: &BitcoinBlock,
+		start_height: BitcoinHeight,
+	) -> anyhow::Result<Vec<BlockFilter>> {
+		let mut filters = vec![];
+		let mut next_hash = Some(stop_block.block_hash.clone());
+		while let Some(block_hash) = next_hash {
+			let hash = bitcoin::BlockHash::from_slice(&block_hash.0)?;
+			let header = self.get_block_header_info(&hash)?;
+			let filter = self.get_block_filter(&hash)?;
+			let entry = BlockFilter {
+				block_height: header.height as u64,
+				block_hash,
+				previous_block_hash: header.previous_block_hash.map(Into::into),
+				filter: filter.filter,
+			};
+			next_hash = if entry.block_height > start_height {
+				entry.previous_block_hash.clone()
+			} else {
+				None
+			};
+			filters.push(entry);
+		}
+		filters.reverse();
+		Ok(filters)
+	}
+
+	fn get_block(&self, block_hash: &bitcoin::BlockHash) -> anyhow::Result<bitcoin::Block> {
+		Ok(RpcApi::get_block(self, block_hash)?)
+	}
+
+	fn get_best_chain_block_hash(
+		&self,
+		block_height: BitcoinHeight,
+	) -> anyhow::Result<Option<H256Le>> {
+		if block_height > RpcApi::get_block_count(self)? {
+			return Ok(None);
+		}
+		Ok(Some(RpcApi::get_block_hash(self, block_height)?.into()))
+	}
+}
+
 pub struct UtxoSpendFilter {
-	client: Client,
+	source: Box<dyn BlockFilterSource>,
 	synched_filters: Arc<Mutex<Vec<BlockFilter>>>,
 }
 
@@ -47,7 +122,13 @@ impl UtxoSpendFilter {
 		};
 		let client = Client::new(&rpc_url, auth)?;
 
-		Ok(Self { client, synched_filters: Default::default() })
+		Ok(Self::with_source(client))
+	}
+
+	/// Creates a filter backed by any block filter source (eg, a bitcoin peer serving compact
+	/// block filters)
+	pub fn with_source(source: impl BlockFilterSource + 'static) -> Self {
+		Self { source: Box::new(source), synched_filters: Default::default() }
 	}
 
 	pub fn get_stored_filters(&self) -> Vec<BlockFilter> {
@@ -55,31 +136,37 @@ impl UtxoSpendFilter {
 	}
 
 	pub fn get_network(&self) -> anyhow::Result<BitcoinNetwork> {
-		Ok(self.client.get_blockchain_info()?.chain.into())
+		self.source.get_network()
 	}
 
-	pub fn load_filters(&self, filters: Vec<BlockFilter>) {
-		*self.synched_filters.lock() = filters;
+	/// Checks a block against the best chain of the filter source. Returns `None` if the source
+	/// can't tell (eg, it hasn't synced to the block's height yet).
+	pub fn is_best_chain_block(&self, block: &BitcoinBlock) -> anyhow::Result<Option<bool>> {
+		Ok(self
+			.source
+			.get_best_chain_block_hash(block.block_height)?
+			.map(|block_hash| block_hash == block.block_hash))
 	}
 
-	fn get_header_and_filter(&self, block_hash: &H256Le) -> anyhow::Result<BlockFilter> {
-		let hash = bitcoin::BlockHash::from_slice(&block_hash.0)?;
-		let header = self.client.get_block_header_info(&hash)?;
-		let filter = self.client.get_block_filter(&hash)?;
-		Ok(BlockFilter {
-			block_height: header.height as u64,
-			block_hash: block_hash.clone(),
-			previous_block_hash: header.previous_block_hash.map(Into::into),
-			filter: filter.filter,
-		})
+	pub fn load_filters(&self, filters: Vec<BlockFilter>) {
+		*self.synched_filters.lock() = filters;
 	}
 
 	pub fn sync_to_block(&self, sync_status: &BitcoinSyncStatus) -> anyhow::Result<()> {
 		let mut stored_filters = self.synched_filters.lock();
-		let latest_block_hash = &sync_status.confirmed_block.block_hash;
-		if stored_filters.last().map(|a| a.block_hash.clone()) != Some(latest_block_hash.clone()) {
-			let entry = self.get_header_and_filter(latest_block_hash)?;
-			stored_filters.push(entry);
+		let latest_block = &sync_status.confirmed_block;
+		if stored_filters.last().map(|a| a.block_hash.clone()) !=
+			Some(latest_block.block_hash.clone())
+		{
+			// fill in any blocks since our last stored filter. If we've reorged, only the latest
+			// is retrieved and the gap will be backfilled after pruning
+			let start_height = stored_filters
+				.last()
+				.map(|a| a.block_height + 1)
+				.filter(|a| *a <= latest_block.block_height)
+				.unwrap_or(latest_block.block_height);
+			let entries = self.source.get_filters(latest_block, start_height)?;
+			stored_filters.extend(entries);
 		}
 
 		let mut keep_sync_back_to = sync_status.oldest_allowed_block_height;
@@ -91,16 +178,20 @@ impl UtxoSpendFilter {
 		}
 
 		Self::prune_filters(keep_sync_back_to, &mut stored_filters);
-		while stored_filters.first().map(|x| x.block_height) > Some(keep_sync_back_to) {
-			let Some(first) = stored_filters.first() else {
+		while let Some(first) = stored_filters.first() &&
+			first.block_height > keep_sync_back_to
+		{
+			let Some(prev_hash) = first.previous_block_hash.clone() else {
 				break;
 			};
-			if let Some(prev_hash) = &first.previous_block_hash {
-				let entry = self.get_header_and_filter(prev_hash)?;
-				stored_filters.insert(0, entry);
-			} else {
+			let first_height = first.block_height;
+			let stop_block = BitcoinBlock::new(first_height - 1, prev_hash);
+			let entries = self.source.get_filters(&stop_block, keep_sync_back_to)?;
+			// guard against a source that isn't making progress
+			if entries.first().is_none_or(|a| a.block_height >= first_height) {
 				break;
 			}
+			stored_filters.splice(0..0, entries);
 		}
 		Ok(())
 	}
@@ -141,7 +232,7 @@ impl UtxoSpendFilter {
 				continue;
 			}
 
-			let block = self.client.get_block(&block_hash)?;
+			let block = self.source.get_block(&block_hash)?;
 			let height = filter.block_height;
 			for tx in block.txdata {
 				for (idx, output) in tx.output.iter().enumerate() {
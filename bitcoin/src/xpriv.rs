@@ -1,7 +1,9 @@
 use anyhow::anyhow;
-use argon_primitives::bitcoin::BitcoinNetwork;
+use argon_primitives::bitcoin::{
+	BitcoinNetwork, VaultLockXPubSource, XPubChildNumber, XPubFingerprint,
+};
 use bitcoin::{
-	bip32::{DerivationPath, Xpriv, Xpub},
+	bip32::{ChildNumber, DerivationPath, Xpriv, Xpub},
 	key::Secp256k1,
 	secp256k1::PublicKey,
 };
@@ -36,3 +38,108 @@ pub fn derive_pubkey(xpriv: &Xpriv, hd_path: &str) -> anyhow::Result<PublicKey>
 
 	Ok(pubkey)
 }
+
+/// The result of re-deriving a bitcoin lock's vault keys from a locally held xpriv
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VaultLockKeyAudit {
+	/// Both the cosign and claim pubkeys were reproduced
+	Verified,
+	/// None of the provided hd paths produce the xpub this lock was derived from
+	UnknownXpub,
+	/// The xpub was found, but one or both of the derived pubkeys do not match the lock
+	Mismatch { cosign_matches: bool, claim_matches: bool },
+}
+
+/// Finds which of the given hd paths derives the vault xpub with the given fingerprint (this is
+/// the `parent_fingerprint` recorded on each lock's `vault_xpub_sources`).
+pub fn find_vault_xpub(
+	xpriv: &Xpriv,
+	hd_paths: &[DerivationPath],
+	fingerprint: XPubFingerprint,
+) -> anyhow::Result<Option<(DerivationPath, Xpub)>> {
+	let secp = Secp256k1::new();
+	for hd_path in hd_paths {
+		let child = xpriv.derive_priv(&secp, hd_path).map_err(|e| anyhow!(e))?;
+		let xpub = Xpub::from_priv(&secp, &child);
+		if xpub.fingerprint().to_bytes() == fingerprint {
+			return Ok(Some((hd_path.clone(), xpub)));
+		}
+	}
+	Ok(None)
+}
+
+/// Verifies that the vault xpub reproduces both vault pubkeys of a lock
+pub fn audit_vault_lock_keys(
+	vault_xpub: &Xpub,
+	source: &VaultLockXPubSource,
+) -> anyhow::Result<VaultLockKeyAudit> {
+	let derive = |index: XPubChildNumber| -> anyhow::Result<[u8; 33]> {
+		let child_number = ChildNumber::from_normal_idx(index).map_err(|e| anyhow!(e))?;
+		let child = vault_xpub
+			.derive_pub(&Secp256k1::new(), &[child_number])
+			.map_err(|e| anyhow!(e))?;
+		Ok(child.public_key.serialize())
+	};
+	let cosign_matches = derive(source.cosign_child_number)? == source.vault_pubkey.0;
+	let claim_matches = derive(source.claim_child_number)? == source.vault_claim_pubkey.0;
+	if cosign_matches && claim_matches {
+		return Ok(VaultLockKeyAudit::Verified);
+	}
+	Ok(VaultLockKeyAudit::Mismatch { cosign_matches, claim_matches })
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use argon_primitives::bitcoin::CompressedBitcoinPubkey;
+
+	fn source(xpub: &Xpub, cosign: u32, claim: u32) -> VaultLockXPubSource {
+		let derive = |index: u32| {
+			let child = xpub
+				.derive_pub(&Secp256k1::new(), &[ChildNumber::from_normal_idx(index).unwrap()])
+				.unwrap();
+			CompressedBitcoinPubkey(child.public_key.serialize())
+		};
+		VaultLockXPubSource {
+			utxo_id: 1,
+			vault_pubkey: derive(cosign),
+			vault_claim_pubkey: derive(claim),
+			cosign_child_number: cosign,
+			claim_child_number: claim,
+			is_funded: true,
+			vault_claim_height: 100,
+		}
+	}
+
+	#[test]
+	fn it_audits_lock_keys_against_an_xpriv() {
+		let xpriv = xpriv_from_seed(&[1u8; 32], BitcoinNetwork::Regtest).unwrap();
+		let original_path = DerivationPath::from_str("m/84'/0'/0'").unwrap();
+		let rotated_path = DerivationPath::from_str("m/84'/0'/1'").unwrap();
+		let vault_xpub = derive_xpub(&xpriv, "m/84'/0'/1'").unwrap();
+
+		let (hd_path, found) = find_vault_xpub(
+			&xpriv,
+			&[original_path.clone(), rotated_path.clone()],
+			vault_xpub.fingerprint().to_bytes(),
+		)
+		.unwrap()
+		.expect("should find the rotated xpub");
+		assert_eq!(hd_path, rotated_path);
+		assert_eq!(found, vault_xpub);
+		assert_eq!(
+			find_vault_xpub(&xpriv, &[original_path], vault_xpub.fingerprint().to_bytes()).unwrap(),
+			None
+		);
+
+		let lock = source(&vault_xpub, 2, 3);
+		assert_eq!(audit_vault_lock_keys(&vault_xpub, &lock).unwrap(), VaultLockKeyAudit::Verified);
+
+		let mut lock = source(&vault_xpub, 2, 3);
+		lock.claim_child_number = 4;
+		assert_eq!(
+			audit_vault_lock_keys(&vault_xpub, &lock).unwrap(),
+			VaultLockKeyAudit::Mismatch { cosign_matches: true, claim_matches: false }
+		);
+	}
+}
@@ -0,0 +1,135 @@
+use crate::{manage::CallSigner, xpriv::VaultXprivArgs};
+use anyhow::{anyhow, bail};
+use argon_bitcoin::{
+	find_vault_xpub,
+	primitives::{
+		BitcoinNetwork, BitcoinScriptPubkey, BitcoinSignature, ChildNumber, DerivationPath, H256Le,
+		Txid, UtxoId, Xpriv,
+	},
+	Amount, CosignReleaser, CosignScriptArgs, ReleaseStep,
+};
+use argon_client::{
+	api::{storage, tx},
+	FetchAt, MainchainClient,
+};
+use argon_primitives::VaultId;
+use clap::Args;
+
+#[derive(Debug, Args)]
+pub struct CosignArgs {
+	/// The vault whose pending release requests should be cosigned
+	#[clap(long)]
+	vault_id: VaultId,
+
+	/// Only cosign this lock (defaults to every pending cosign of the vault)
+	#[clap(long)]
+	utxo_id: Option<UtxoId>,
+
+	#[clap(flatten)]
+	keys: VaultXprivArgs,
+}
+
+pub async fn run(
+	client: &MainchainClient,
+	signer: &CallSigner,
+	args: CosignArgs,
+) -> anyhow::Result<()> {
+	let xpriv = args.keys.load_xpriv()?;
+	let pending_utxo_ids = client
+		.fetch_storage(&storage().vaults().pending_cosign_by_vault_id(args.vault_id), FetchAt::Best)
+		.await?
+		.map(|a| a.0)
+		.unwrap_or_default();
+
+	let utxo_ids = match args.utxo_id {
+		Some(utxo_id) if !pending_utxo_ids.contains(&utxo_id) => {
+			bail!("Lock {utxo_id} has no pending cosign for vault {}", args.vault_id)
+		},
+		Some(utxo_id) => vec![utxo_id],
+		None => pending_utxo_ids,
+	};
+	if utxo_ids.is_empty() {
+		println!("Vault {} has no pending cosigns", args.vault_id);
+		return Ok(());
+	}
+
+	let network: BitcoinNetwork = client
+		.fetch_storage(&storage().bitcoin_utxos().bitcoin_network(), FetchAt::Best)
+		.await?
+		.ok_or_else(|| anyhow!("No bitcoin network found"))?
+		.into();
+
+	for utxo_id in utxo_ids {
+		let signature = sign_release(client, &xpriv, &args.keys.hd_paths, network, utxo_id).await?;
+		println!("Cosigning release of lock {utxo_id}");
+		signer
+			.submit(client, tx().bitcoin_locks().cosign_release(utxo_id, signature.into()))
+			.await?;
+	}
+	Ok(())
+}
+
+/// Rebuilds the release transaction the runtime will verify and signs it with the vault key the
+/// lock was derived from.
+async fn sign_release(
+	client: &MainchainClient,
+	xpriv: &Xpriv,
+	hd_paths: &[DerivationPath],
+	network: BitcoinNetwork,
+	utxo_id: UtxoId,
+) -> anyhow::Result<BitcoinSignature> {
+	let lock = client
+		.fetch_storage(&storage().bitcoin_locks().locks_by_utxo_id(utxo_id), FetchAt::Best)
+		.await?
+		.ok_or_else(|| anyhow!("No lock found for utxo {utxo_id}"))?;
+	let request = client
+		.fetch_storage(
+			&storage().bitcoin_locks().lock_release_requests_by_utxo_id(utxo_id),
+			FetchAt::Best,
+		)
+		.await?
+		.ok_or_else(|| anyhow!("No release request found for utxo {utxo_id}"))?;
+	let utxo_ref = client
+		.fetch_storage(
+			&storage().bitcoin_locks().utxo_id_to_funding_utxo_ref(utxo_id),
+			FetchAt::Best,
+		)
+		.await?
+		.ok_or_else(|| anyhow!("No funding utxo found for lock {utxo_id}"))?;
+
+	let (fingerprint, cosign_child_number, _) = lock.vault_xpub_sources;
+	let Some((hd_path, _)) = find_vault_xpub(xpriv, hd_paths, fingerprint)? else {
+		bail!(
+			"None of the hd paths derive the vault xpub (0x{}) used by lock {utxo_id}",
+			hex::encode(fingerprint)
+		);
+	};
+
+	let to_script_pubkey: BitcoinScriptPubkey = request
+		.to_script_pubkey
+		.try_into()
+		.map_err(|_| anyhow!("Unable to decode destination pubkey"))?;
+	let txid: Txid = H256Le::from(utxo_ref.txid).into();
+	let mut releaser = CosignReleaser::new(
+		CosignScriptArgs {
+			vault_pubkey: lock.vault_pubkey.into(),
+			vault_claim_pubkey: lock.vault_claim_pubkey.into(),
+			owner_pubkey: lock.owner_pubkey.into(),
+			vault_claim_height: lock.vault_claim_height,
+			open_claim_height: lock.open_claim_height,
+			created_at_height: lock.created_at_height,
+		},
+		lock.utxo_satoshis.unwrap_or(lock.satoshis),
+		txid,
+		utxo_ref.output_index,
+		ReleaseStep::VaultCosign,
+		Amount::from_sat(request.bitcoin_network_fee),
+		to_script_pubkey.into(),
+		network.into(),
+	)?;
+	let (signature, _) =
+		releaser.sign_derived(*xpriv, hd_path.child(ChildNumber::from(cosign_child_number)))?;
+	signature
+		.try_into()
+		.map_err(|_| anyhow!("Unable to translate signature to bytes"))
+}
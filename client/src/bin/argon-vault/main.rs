@@ -0,0 +1,120 @@
+use anyhow::bail;
+use argon_client::MainchainClient;
+use argon_primitives::{CryptoType, KeystoreParams, VaultId};
+use clap::Parser;
+use dotenv::dotenv;
+
+use crate::manage::CallSigner;
+
+mod cosign;
+mod manage;
+mod status;
+mod xpriv;
+mod xpub_audit;
+
+/// Vault operator tooling for the Argon mainchain
+#[derive(Parser, Debug)]
+#[command(author, version, about, arg_required_else_help = true, long_about = None)]
+struct Cli {
+	#[command(subcommand)]
+	subcommand: Subcommand,
+
+	/// The mainchain RPC websocket url to query
+	#[clap(global = true, short, long, env, default_value = "ws://127.0.0.1:9944")]
+	trusted_rpc_url: String,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	keystore_params: KeystoreParams,
+
+	/// The vault operator address in the keystore used to sign calls. Without it, calls are
+	/// printed as polkadot.js deeplinks for offline signing.
+	#[clap(global = true, long, env)]
+	signer_address: Option<String>,
+
+	/// What type of crypto the signer uses
+	#[clap(global = true, long, env, default_value_t = CryptoType::Sr25519)]
+	signer_crypto: CryptoType,
+
+	/// Print a polkadot.js deeplink for each call instead of signing with the keystore
+	#[clap(global = true, long)]
+	deeplink: bool,
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum Subcommand {
+	/// Verify that every outstanding lock of a vault was derived from keys you control.
+	///
+	/// Locks are grouped by the fingerprint of the vault xpub they were created with (a vault
+	/// will have several after `replace_bitcoin_xpub`). Each lock's cosign and claim pubkeys are
+	/// re-derived from the provided xpriv and any that cannot be reproduced are flagged.
+	#[clap(verbatim_doc_comment)]
+	XpubAudit(xpub_audit::XpubAuditArgs),
+	/// Show securitization use, pending cosigns, uncollected revenue and treasury bond space.
+	Status(status::StatusArgs),
+	/// Create a vault operated by the signer.
+	Create(manage::CreateArgs),
+	/// Change the securitization (and optionally the ratio) of a vault.
+	ModifyFunding(manage::ModifyFundingArgs),
+	/// Change the terms of a vault. Unspecified terms keep their latest value.
+	ModifyTerms(manage::ModifyTermsArgs),
+	/// Collect the vault's uncollected revenue.
+	Collect {
+		/// The vault to collect revenue for
+		#[clap(long)]
+		vault_id: VaultId,
+	},
+	/// Set the total argonots committed to the signer's vault.
+	SetCommittedArgonots(manage::SetCommittedArgonotsArgs),
+	/// Reserve treasury bond space of a vault for future bond purchases.
+	SetReservedBondSpace(manage::SetReservedBondSpaceArgs),
+	/// Cosign pending bitcoin release requests with the vault xpriv.
+	Cosign(cosign::CosignArgs),
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+	let _ = tracing_subscriber::FmtSubscriber::builder()
+		.with_env_filter(
+			tracing_subscriber::EnvFilter::try_from_default_env()
+				.unwrap_or(tracing_subscriber::EnvFilter::from("warn")),
+		)
+		.try_init();
+	dotenv().ok();
+
+	let Cli {
+		subcommand,
+		trusted_rpc_url,
+		keystore_params,
+		signer_address,
+		signer_crypto,
+		deeplink,
+	} = Cli::parse();
+	let client = MainchainClient::from_url(&trusted_rpc_url).await?;
+	let signer = || CallSigner::new(&keystore_params, signer_address, signer_crypto, deeplink);
+
+	match subcommand {
+		Subcommand::XpubAudit(args) => {
+			let report = xpub_audit::run(&client, args).await?;
+			println!("{report}");
+			if report.unverified_locks() > 0 {
+				bail!(
+					"{} lock(s) have vault keys that cannot be reproduced from this xpriv",
+					report.unverified_locks()
+				);
+			}
+		},
+		Subcommand::Status(args) => println!("{}", status::run(&client, args).await?),
+		Subcommand::Create(args) => manage::create(&client, &signer()?, args).await?,
+		Subcommand::ModifyFunding(args) =>
+			manage::modify_funding(&client, &signer()?, args).await?,
+		Subcommand::ModifyTerms(args) => manage::modify_terms(&client, &signer()?, args).await?,
+		Subcommand::Collect { vault_id } => manage::collect(&client, &signer()?, vault_id).await?,
+		Subcommand::SetCommittedArgonots(args) =>
+			manage::set_committed_argonots(&client, &signer()?, args).await?,
+		Subcommand::SetReservedBondSpace(args) =>
+			manage::set_reserved_bond_space(&client, &signer()?, args).await?,
+		Subcommand::Cosign(args) => cosign::run(&client, &signer()?, args).await?,
+	}
+	Ok(())
+}
@@ -0,0 +1,327 @@
+use anyhow::{anyhow, bail, ensure};
+use argon_bitcoin::primitives::Xpub;
+use argon_client::{
+	api::{
+		runtime_types::argon_primitives::vault::{Vault, VaultTerms},
+		storage, tx, vaults,
+	},
+	conversion::{from_api_fixed_u128, from_api_per_mill, to_api_fixed_u128, to_api_per_mill},
+	signer::KeystoreSigner,
+	types::AccountId32,
+	FetchAt, MainchainClient,
+};
+use argon_primitives::{
+	argon_utils::format_argons, AccountId, CryptoType, KeystoreParams, VaultId, ADDRESS_PREFIX,
+	MICROGONS_PER_ARGON,
+};
+use clap::Args;
+use polkadot_sdk::*;
+use sp_arithmetic::{FixedU128, Permill};
+use sp_core::crypto::Ss58Codec;
+use std::str::FromStr;
+use subxt::tx::{Payload, Signer};
+
+/// How management calls are signed. Without a keystore signer, calls are printed as polkadot.js
+/// deeplinks so they can be signed on another device.
+pub enum CallSigner {
+	Deeplink,
+	Keystore(KeystoreSigner),
+}
+
+impl CallSigner {
+	pub fn new(
+		keystore_params: &KeystoreParams,
+		signer_address: Option<String>,
+		signer_crypto: CryptoType,
+		deeplink: bool,
+	) -> anyhow::Result<Self> {
+		let Some(signer_address) = signer_address.filter(|_| !deeplink) else {
+			return Ok(Self::Deeplink);
+		};
+		let (account_id, format) = AccountId::from_ss58check_with_version(&signer_address)?;
+		ensure!(format.prefix() == ADDRESS_PREFIX, "Invalid address format");
+		let keystore = keystore_params.open()?;
+		Ok(Self::Keystore(KeystoreSigner::new(keystore, account_id, signer_crypto)))
+	}
+
+	pub async fn submit(&self, client: &MainchainClient, call: impl Payload) -> anyhow::Result<()> {
+		match self {
+			Self::Deeplink => {
+				println!(
+					"Sign and submit this call with polkadot.js:\n{}",
+					client.create_polkadotjs_deeplink(&call)?
+				);
+			},
+			Self::Keystore(signer) => {
+				let account = signer.account_id().to_ss58check_with_version(ADDRESS_PREFIX.into());
+				println!("Submitting as {account}...");
+				let result = client.submit_tx(&call, signer, None, false).await?;
+				println!(
+					"Included in block {:?} (extrinsic {:?})",
+					result.block_hash(),
+					result.extrinsic_hash()
+				);
+			},
+		}
+		Ok(())
+	}
+}
+
+#[derive(Debug, Args)]
+pub struct CreateArgs {
+	/// Argons to allocate as securitization for bitcoin locks (eg, 1000.5)
+	#[clap(long, value_parser = parse_amount)]
+	securitization: u128,
+
+	/// Argons of securitization per argon of bitcoin locked (must be at least 1)
+	#[clap(long, default_value_t = 1.0)]
+	securitization_ratio: f64,
+
+	/// The annual percent rate charged on bitcoin locks (eg, 10 for 10%)
+	#[clap(long)]
+	bitcoin_annual_percent_rate: f64,
+
+	/// A flat fee in argons charged for each bitcoin lock
+	#[clap(long, value_parser = parse_amount, default_value = "0")]
+	bitcoin_base_fee: u128,
+
+	/// The percent of treasury bond earnings the vault shares with bond holders (eg, 50)
+	#[clap(long)]
+	treasury_profit_sharing: f64,
+
+	/// The vault's bitcoin xpub (base58 encoded) used to derive cosign keys for each lock
+	#[clap(long, value_parser = Xpub::from_str)]
+	bitcoin_xpub: Xpub,
+
+	/// An account (ss58) allowed to act on behalf of the vault operator
+	#[clap(long, value_parser = AccountId32::from_str)]
+	delegate_account: Option<AccountId32>,
+}
+
+#[derive(Debug, Args)]
+pub struct ModifyFundingArgs {
+	/// The vault to modify
+	#[clap(long)]
+	vault_id: VaultId,
+
+	/// The new total securitization in argons (eg, 1000.5)
+	#[clap(long, value_parser = parse_amount)]
+	securitization: u128,
+
+	/// The new securitization ratio. It may not go below the current ratio. Defaults to the
+	/// current ratio.
+	#[clap(long)]
+	securitization_ratio: Option<f64>,
+}
+
+#[derive(Debug, Args)]
+pub struct ModifyTermsArgs {
+	/// The vault to modify
+	#[clap(long)]
+	vault_id: VaultId,
+
+	/// The annual percent rate charged on bitcoin locks (eg, 10 for 10%)
+	#[clap(long)]
+	bitcoin_annual_percent_rate: Option<f64>,
+
+	/// A flat fee in argons charged for each bitcoin lock
+	#[clap(long, value_parser = parse_amount)]
+	bitcoin_base_fee: Option<u128>,
+
+	/// The percent of treasury bond earnings the vault shares with bond holders (eg, 50)
+	#[clap(long)]
+	treasury_profit_sharing: Option<f64>,
+}
+
+#[derive(Debug, Args)]
+pub struct SetCommittedArgonotsArgs {
+	/// The total argonots to commit to the operator's vault (eg, 100.5)
+	#[clap(long, value_parser = parse_amount)]
+	argonots: u128,
+}
+
+#[derive(Debug, Args)]
+pub struct SetReservedBondSpaceArgs {
+	/// The vault to reserve bond space in
+	#[clap(long)]
+	vault_id: VaultId,
+
+	/// The number of bonds (₳1 each) to hold back for future bond purchases
+	#[clap(long)]
+	bonds: u32,
+}
+
+pub async fn create(
+	client: &MainchainClient,
+	signer: &CallSigner,
+	args: CreateArgs,
+) -> anyhow::Result<()> {
+	let vault_config = vaults::calls::types::create::VaultConfig {
+		terms: VaultTerms {
+			bitcoin_annual_percent_rate: to_api_fixed_u128(percent_to_fixed(
+				args.bitcoin_annual_percent_rate,
+			)?),
+			bitcoin_base_fee: args.bitcoin_base_fee,
+			treasury_profit_sharing: to_api_per_mill(percent_to_permill(
+				args.treasury_profit_sharing,
+			)?),
+		},
+		delegate_account_id: args.delegate_account,
+		securitization: args.securitization,
+		bitcoin_xpubkey: args.bitcoin_xpub.encode().into(),
+		securitization_ratio: to_api_fixed_u128(ratio_to_fixed(args.securitization_ratio)?),
+	};
+	signer.submit(client, tx().vaults().create(vault_config)).await
+}
+
+pub async fn modify_funding(
+	client: &MainchainClient,
+	signer: &CallSigner,
+	args: ModifyFundingArgs,
+) -> anyhow::Result<()> {
+	let securitization_ratio = match args.securitization_ratio {
+		Some(ratio) => to_api_fixed_u128(ratio_to_fixed(ratio)?),
+		None => get_vault(client, args.vault_id).await?.securitization_ratio,
+	};
+	println!(
+		"Setting vault {} securitization to {} (ratio {})",
+		args.vault_id,
+		format_argons(args.securitization),
+		from_api_fixed_u128(securitization_ratio.clone()).to_float()
+	);
+	signer
+		.submit(
+			client,
+			tx().vaults()
+				.modify_funding(args.vault_id, args.securitization, securitization_ratio),
+		)
+		.await
+}
+
+pub async fn modify_terms(
+	client: &MainchainClient,
+	signer: &CallSigner,
+	args: ModifyTermsArgs,
+) -> anyhow::Result<()> {
+	let vault = get_vault(client, args.vault_id).await?;
+	// build from the latest scheduled terms so unspecified values aren't reverted
+	let mut terms = vault.pending_terms.map(|(_, terms)| terms).unwrap_or(vault.terms);
+	if let Some(apr) = args.bitcoin_annual_percent_rate {
+		terms.bitcoin_annual_percent_rate = to_api_fixed_u128(percent_to_fixed(apr)?);
+	}
+	if let Some(base_fee) = args.bitcoin_base_fee {
+		terms.bitcoin_base_fee = base_fee;
+	}
+	if let Some(sharing) = args.treasury_profit_sharing {
+		terms.treasury_profit_sharing = to_api_per_mill(percent_to_permill(sharing)?);
+	}
+	println!(
+		"New terms for vault {}: {}% APR, {} base fee, {}% treasury profit sharing",
+		args.vault_id,
+		from_api_fixed_u128(terms.bitcoin_annual_percent_rate.clone()).to_float() * 100.0,
+		format_argons(terms.bitcoin_base_fee),
+		from_api_per_mill(terms.treasury_profit_sharing.clone()).deconstruct() as f64 / 10_000.0,
+	);
+	signer.submit(client, tx().vaults().modify_terms(args.vault_id, terms)).await
+}
+
+pub async fn collect(
+	client: &MainchainClient,
+	signer: &CallSigner,
+	vault_id: VaultId,
+) -> anyhow::Result<()> {
+	signer.submit(client, tx().vaults().collect(vault_id)).await
+}
+
+pub async fn set_committed_argonots(
+	client: &MainchainClient,
+	signer: &CallSigner,
+	args: SetCommittedArgonotsArgs,
+) -> anyhow::Result<()> {
+	signer.submit(client, tx().vaults().set_committed_argonots(args.argonots)).await
+}
+
+pub async fn set_reserved_bond_space(
+	client: &MainchainClient,
+	signer: &CallSigner,
+	args: SetReservedBondSpaceArgs,
+) -> anyhow::Result<()> {
+	signer
+		.submit(client, tx().treasury().set_reserved_bond_space(args.vault_id, args.bonds))
+		.await
+}
+
+pub(crate) async fn get_vault(
+	client: &MainchainClient,
+	vault_id: VaultId,
+) -> anyhow::Result<Vault<AccountId32, u128>> {
+	client
+		.fetch_storage(&storage().vaults().vaults_by_id(vault_id), FetchAt::Best)
+		.await?
+		.ok_or_else(|| anyhow!("Vault {vault_id} not found"))
+}
+
+/// Parses a decimal argon (or argonot) amount into its 6-decimal base units. Commas and a
+/// leading `₳` are allowed.
+pub(crate) fn parse_amount(value: &str) -> anyhow::Result<u128> {
+	let value = value.trim().trim_start_matches('₳').replace(',', "");
+	let (whole, fraction) = value.split_once('.').unwrap_or((&value, ""));
+	ensure!(!whole.is_empty() || !fraction.is_empty(), "An amount is required");
+	ensure!(fraction.len() <= 6, "Amounts have at most 6 decimal places");
+	let parse = |digits: &str| -> anyhow::Result<u128> {
+		if digits.is_empty() {
+			return Ok(0);
+		}
+		ensure!(digits.chars().all(|c| c.is_ascii_digit()), "Invalid amount {value}");
+		Ok(digits.parse::<u128>()?)
+	};
+	let fraction = parse(fraction)? * 10u128.pow(6 - fraction.len() as u32);
+	parse(whole)?
+		.checked_mul(MICROGONS_PER_ARGON)
+		.and_then(|a| a.checked_add(fraction))
+		.ok_or_else(|| anyhow!("Amount {value} is too large"))
+}
+
+fn percent_to_fixed(percent: f64) -> anyhow::Result<FixedU128> {
+	if !(0.0..=1_000.0).contains(&percent) {
+		bail!("Invalid percent {percent}");
+	}
+	Ok(FixedU128::from_rational((percent * 10_000.0).round() as u128, 1_000_000))
+}
+
+fn percent_to_permill(percent: f64) -> anyhow::Result<Permill> {
+	if !(0.0..=100.0).contains(&percent) {
+		bail!("Invalid percent {percent}. Must be between 0 and 100");
+	}
+	Ok(Permill::from_parts((percent * 10_000.0).round() as u32))
+}
+
+fn ratio_to_fixed(ratio: f64) -> anyhow::Result<FixedU128> {
+	ensure!(ratio >= 1.0, "The securitization ratio must be at least 1");
+	Ok(FixedU128::from_rational((ratio * 1_000_000.0).round() as u128, 1_000_000))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parses_argon_amounts() {
+		assert_eq!(parse_amount("1").unwrap(), 1_000_000);
+		assert_eq!(parse_amount("₳1,000.5").unwrap(), 1_000_500_000);
+		assert_eq!(parse_amount("0.000001").unwrap(), 1);
+		assert_eq!(parse_amount(".25").unwrap(), 250_000);
+		assert!(parse_amount("1.0000001").is_err());
+		assert!(parse_amount("1e6").is_err());
+		assert!(parse_amount("").is_err());
+	}
+
+	#[test]
+	fn converts_percents() {
+		assert_eq!(percent_to_permill(50.0).unwrap(), Permill::from_percent(50));
+		assert_eq!(percent_to_permill(12.5).unwrap(), Permill::from_parts(125_000));
+		assert!(percent_to_permill(101.0).is_err());
+		assert_eq!(percent_to_fixed(10.0).unwrap(), FixedU128::from_rational(1, 10));
+		assert!(ratio_to_fixed(0.5).is_err());
+	}
+}
@@ -0,0 +1,380 @@
+use crate::manage::get_vault;
+use anyhow::anyhow;
+use argon_client::{
+	api::{apis, storage},
+	FetchAt, MainchainClient,
+};
+use argon_primitives::{
+	argon_utils::format_argons, bitcoin::UtxoId, FrameId, VaultId, MICROGONS_PER_ARGON,
+};
+use clap::Args;
+use comfy_table::{presets::UTF8_FULL, Cell, Color, ContentArrangement, Table};
+use polkadot_sdk::*;
+use sp_arithmetic::{FixedPointNumber, FixedU128};
+use std::fmt;
+use subxt::ext::scale_decode::DecodeAsType;
+
+#[derive(Debug, Args)]
+pub struct StatusArgs {
+	/// The vault to show
+	#[clap(long)]
+	vault_id: VaultId,
+}
+
+pub struct PendingCosign {
+	pub utxo_id: UtxoId,
+	pub cosign_due_frame: FrameId,
+	pub bitcoin_network_fee: u64,
+	pub redemption_amount: u128,
+}
+
+pub struct VaultStatus {
+	pub vault_id: VaultId,
+	pub current_frame_id: FrameId,
+	pub is_closed: bool,
+	pub securitization: u128,
+	pub securitization_locked: u128,
+	pub flexible_securitization_locked: u128,
+	pub reserved_securitization_space: u128,
+	pub securitization_pending_activation: u128,
+	pub locked_satoshis: u64,
+	pub securitized_satoshis: u64,
+	pub flexible_securitized_satoshis: u64,
+	pub committed_micronots: u128,
+	pub encumbered_micronots: u128,
+	pub pending_cosigns: Vec<PendingCosign>,
+	/// Frames with revenue that has not been collected yet, as (frame id, uncollected revenue)
+	pub uncollected_revenue: Vec<(FrameId, u128)>,
+	pub bond_capacity: u32,
+	pub regular_bonds: u32,
+	pub flexible_bonds: u32,
+	pub reserved_bond_space: u32,
+}
+
+/// A vault's `Treasury::BondLotsByVault` entry. The storage is newer than the bundled metadata, so
+/// it's decoded against the live metadata.
+#[derive(Default, DecodeAsType)]
+#[decode_as_type(crate_path = ":: subxt :: ext :: subxt_core :: ext :: scale_decode")]
+struct VaultBondState {
+	regular_bond_lots: Vec<BondLotSummary>,
+	flexible_bonds: u32,
+	reserved_bond_space: u32,
+}
+
+#[derive(DecodeAsType)]
+#[decode_as_type(crate_path = ":: subxt :: ext :: subxt_core :: ext :: scale_decode")]
+struct BondLotSummary {
+	bonds: u32,
+}
+
+impl VaultStatus {
+	/// Securitization that can still back new (non-flexible) bitcoin locks
+	pub fn available_securitization(&self) -> u128 {
+		let regular_locked =
+			self.securitization_locked.saturating_sub(self.flexible_securitization_locked);
+		self.securitization
+			.saturating_sub(regular_locked)
+			.saturating_sub(self.reserved_securitization_space)
+	}
+
+	/// Mirrors `argon_primitives::vault::Vault::effective_securitized_satoshis`, which the treasury
+	/// sizes the vault's bond capacity with. Flexible bitcoin only counts as far as regular locks
+	/// haven't displaced it.
+	pub fn effective_securitized_satoshis(&self) -> u64 {
+		if self.flexible_securitization_locked == 0 {
+			return self.securitized_satoshis;
+		}
+		let activated_securitization = self
+			.securitization_locked
+			.saturating_sub(self.securitization_pending_activation);
+		let confirmed_regular_securitization_locked =
+			activated_securitization.saturating_sub(self.flexible_securitization_locked);
+		let flexible_securitization_available =
+			self.securitization.saturating_sub(confirmed_regular_securitization_locked);
+		let undisplaced_flexible =
+			self.flexible_securitization_locked.min(flexible_securitization_available);
+		let earning_flexible_satoshis =
+			FixedU128::from_rational(undisplaced_flexible, self.flexible_securitization_locked)
+				.saturating_mul_int(self.flexible_securitized_satoshis);
+
+		self.securitized_satoshis
+			.saturating_sub(self.flexible_securitized_satoshis)
+			.saturating_add(earning_flexible_satoshis)
+	}
+
+	pub fn total_uncollected_revenue(&self) -> u128 {
+		self.uncollected_revenue.iter().map(|(_, revenue)| *revenue).sum()
+	}
+
+	/// Bond space open to new bond purchases
+	pub fn available_bond_space(&self) -> u32 {
+		self.bond_capacity
+			.saturating_sub(self.regular_bonds)
+			.saturating_sub(self.reserved_bond_space)
+	}
+}
+
+pub async fn run(client: &MainchainClient, args: StatusArgs) -> anyhow::Result<VaultStatus> {
+	let vault_id = args.vault_id;
+	let vault = get_vault(client, vault_id).await?;
+	let current_frame_id = client.current_frame_id().await?;
+
+	let pending_utxo_ids = client
+		.fetch_storage(&storage().vaults().pending_cosign_by_vault_id(vault_id), FetchAt::Best)
+		.await?
+		.map(|a| a.0)
+		.unwrap_or_default();
+	let mut pending_cosigns = Vec::with_capacity(pending_utxo_ids.len());
+	for utxo_id in pending_utxo_ids {
+		let Some(request) = client
+			.fetch_storage(
+				&storage().bitcoin_locks().lock_release_requests_by_utxo_id(utxo_id),
+				FetchAt::Best,
+			)
+			.await?
+		else {
+			continue;
+		};
+		pending_cosigns.push(PendingCosign {
+			utxo_id,
+			cosign_due_frame: request.cosign_due_frame,
+			bitcoin_network_fee: request.bitcoin_network_fee,
+			redemption_amount: request.redemption_amount,
+		});
+	}
+	pending_cosigns.sort_by_key(|a| (a.cosign_due_frame, a.utxo_id));
+
+	let uncollected_revenue = client
+		.fetch_storage(&storage().vaults().revenue_per_frame_by_vault(vault_id), FetchAt::Best)
+		.await?
+		.map(|a| a.0)
+		.unwrap_or_default()
+		.into_iter()
+		.filter(|frame| frame.uncollected_revenue > 0)
+		.map(|frame| (frame.frame_id, frame.uncollected_revenue))
+		.collect::<Vec<_>>();
+
+	let commitment = client
+		.fetch_storage(&storage().vaults().argonot_commitment_by_vault_id(vault_id), FetchAt::Best)
+		.await?;
+
+	let bond_state = match client
+		.fetch_storage(
+			&subxt::dynamic::storage(
+				"Treasury",
+				"BondLotsByVault",
+				vec![subxt::dynamic::Value::u128(vault_id as u128)],
+			),
+			FetchAt::Best,
+		)
+		.await?
+	{
+		Some(state) => state
+			.as_type::<VaultBondState>()
+			.map_err(|e| anyhow!("Could not decode the treasury bonds of vault {vault_id}: {e}"))?,
+		None => VaultBondState::default(),
+	};
+	let mut status = VaultStatus {
+		vault_id,
+		current_frame_id,
+		is_closed: vault.is_closed,
+		securitization: vault.securitization,
+		securitization_locked: vault.securitization_locked,
+		flexible_securitization_locked: vault.flexible_securitization_locked,
+		reserved_securitization_space: vault.reserved_securitization_space,
+		securitization_pending_activation: vault.securitization_pending_activation,
+		locked_satoshis: vault.locked_satoshis,
+		securitized_satoshis: vault.securitized_satoshis,
+		flexible_securitized_satoshis: vault.flexible_securitized_satoshis,
+		committed_micronots: commitment.as_ref().map(|a| a.committed_micronots).unwrap_or_default(),
+		encumbered_micronots: commitment
+			.as_ref()
+			.map(|a| a.encumbered_micronots)
+			.unwrap_or_default(),
+		pending_cosigns,
+		uncollected_revenue,
+		bond_capacity: 0,
+		regular_bonds: bond_state
+			.regular_bond_lots
+			.iter()
+			.fold(0u32, |total, lot| total.saturating_add(lot.bonds)),
+		flexible_bonds: bond_state.flexible_bonds,
+		reserved_bond_space: bond_state.reserved_bond_space,
+	};
+
+	// the treasury caps a vault's bonds at the market value of its securitized bitcoin
+	let securitized_value = client
+		.call(apis().bitcoin_apis().market_rate(status.effective_securitized_satoshis()), None)
+		.await?
+		.unwrap_or_default();
+	status.bond_capacity = (securitized_value / MICROGONS_PER_ARGON).min(u32::MAX as u128) as u32;
+	Ok(status)
+}
+
+impl fmt::Display for VaultStatus {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		writeln!(
+			f,
+			"Vault {} ({}) at frame {}",
+			self.vault_id,
+			if self.is_closed { "closed" } else { "open" },
+			self.current_frame_id
+		)?;
+
+		let utilization = if self.securitization > 0 {
+			self.securitization_locked as f64 * 100.0 / self.securitization as f64
+		} else {
+			0.0
+		};
+		let mut table = Table::new();
+		table
+			.load_preset(UTF8_FULL)
+			.set_content_arrangement(ContentArrangement::Dynamic)
+			.set_header(vec!["Securitization", "Amount"]);
+		table.add_row(vec!["Total".to_string(), format_argons(self.securitization)]);
+		table.add_row(vec![
+			"Locked".to_string(),
+			format!("{} ({utilization:.1}%)", format_argons(self.securitization_locked)),
+		]);
+		table.add_row(vec![
+			"Flexible locked".to_string(),
+			format_argons(self.flexible_securitization_locked),
+		]);
+		table.add_row(vec![
+			"Pending activation".to_string(),
+			format_argons(self.securitization_pending_activation),
+		]);
+		table.add_row(vec![
+			"Reserved space".to_string(),
+			format_argons(self.reserved_securitization_space),
+		]);
+		table
+			.add_row(vec!["Available".to_string(), format_argons(self.available_securitization())]);
+		table.add_row(vec!["Locked bitcoin".to_string(), format!("{} sats", self.locked_satoshis)]);
+		table.add_row(vec![
+			"Committed argonots".to_string(),
+			format!(
+				"{} argonots ({} encumbered)",
+				format_argons(self.committed_micronots).trim_start_matches('₳'),
+				format_argons(self.encumbered_micronots).trim_start_matches('₳')
+			),
+		]);
+		writeln!(f, "{table}")?;
+
+		if self.pending_cosigns.is_empty() {
+			writeln!(f, "No pending cosigns")?;
+		} else {
+			writeln!(f, "Pending cosigns")?;
+			let mut table = Table::new();
+			table
+				.load_preset(UTF8_FULL)
+				.set_content_arrangement(ContentArrangement::Dynamic)
+				.set_header(vec!["Utxo Id", "Due Frame", "Network Fee", "Redemption Amount"]);
+			for cosign in &self.pending_cosigns {
+				let due = if cosign.cosign_due_frame <= self.current_frame_id {
+					Cell::new(format!("{} (due now)", cosign.cosign_due_frame)).fg(Color::Red)
+				} else {
+					Cell::new(cosign.cosign_due_frame)
+				};
+				table.add_row(vec![
+					Cell::new(cosign.utxo_id),
+					due,
+					Cell::new(format!("{} sats", cosign.bitcoin_network_fee)),
+					Cell::new(format_argons(cosign.redemption_amount)),
+				]);
+			}
+			writeln!(f, "{table}")?;
+		}
+
+		writeln!(
+			f,
+			"Uncollected revenue: {}{}",
+			format_argons(self.total_uncollected_revenue()),
+			match self.uncollected_revenue.iter().map(|(frame_id, _)| frame_id).min() {
+				Some(oldest) => format!(" (oldest from frame {oldest})"),
+				None => String::new(),
+			}
+		)?;
+		writeln!(
+			f,
+			"Treasury bond space: {} of {} bonds available ({} in lots, {} flexible, {} reserved)",
+			self.available_bond_space(),
+			self.bond_capacity,
+			self.regular_bonds,
+			self.flexible_bonds,
+			self.reserved_bond_space
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn status() -> VaultStatus {
+		VaultStatus {
+			vault_id: 1,
+			current_frame_id: 10,
+			is_closed: false,
+			securitization: 100_000_000,
+			securitization_locked: 60_000_000,
+			flexible_securitization_locked: 20_000_000,
+			reserved_securitization_space: 5_000_000,
+			securitization_pending_activation: 0,
+			locked_satoshis: 60_000,
+			securitized_satoshis: 60_000,
+			flexible_securitized_satoshis: 20_000,
+			committed_micronots: 1_000_000,
+			encumbered_micronots: 0,
+			pending_cosigns: vec![PendingCosign {
+				utxo_id: 7,
+				cosign_due_frame: 10,
+				bitcoin_network_fee: 500,
+				redemption_amount: 2_000_000,
+			}],
+			uncollected_revenue: vec![(8, 1_000_000), (9, 500_000)],
+			bond_capacity: 50,
+			regular_bonds: 30,
+			flexible_bonds: 10,
+			reserved_bond_space: 5,
+		}
+	}
+
+	#[test]
+	fn computes_available_space() {
+		let status = status();
+		// 40 of regular securitization is locked, 5 is reserved
+		assert_eq!(status.available_securitization(), 55_000_000);
+		assert_eq!(status.available_bond_space(), 15);
+		assert_eq!(status.total_uncollected_revenue(), 1_500_000);
+	}
+
+	#[test]
+	fn counts_only_undisplaced_flexible_satoshis() {
+		let mut status = status();
+		assert_eq!(status.effective_securitized_satoshis(), 60_000);
+
+		// regular locks take 90 of the 100 securitization, leaving room for half the flexible lock
+		status.securitization_locked = 110_000_000;
+		status.securitized_satoshis = 110_000;
+		assert_eq!(status.effective_securitized_satoshis(), 100_000);
+
+		// securitization still pending activation doesn't displace flexible bitcoin yet
+		status.securitization_pending_activation = 10_000_000;
+		assert_eq!(status.effective_securitized_satoshis(), 110_000);
+
+		status.flexible_securitization_locked = 0;
+		assert_eq!(status.effective_securitized_satoshis(), 110_000);
+	}
+
+	#[test]
+	fn displays_the_status() {
+		let output = status().to_string();
+		assert!(output.starts_with("Vault 1 (open) at frame 10"));
+		assert!(output.contains("10 (due now)"));
+		assert!(output.contains("(oldest from frame 8)"));
+		assert!(output.ends_with(
+			"Treasury bond space: 15 of 50 bonds available (30 in lots, 10 flexible, 5 reserved)\n"
+		));
+	}
+}
@@ -0,0 +1,33 @@
+use anyhow::{anyhow, Context};
+use argon_bitcoin::primitives::{DerivationPath, Xpriv};
+use clap::Args;
+use std::{fs, path::PathBuf, str::FromStr};
+
+/// The vault operator's bitcoin master key and the hd paths of the xpubs registered on chain
+#[derive(Debug, Args)]
+pub struct VaultXprivArgs {
+	/// The master xpriv (base58 encoded) of the vault operator
+	#[clap(long, env = "VAULT_XPRIV", conflicts_with = "xpriv_path", hide_env_values = true)]
+	xpriv: Option<String>,
+
+	/// A file containing the master xpriv (base58 encoded) of the vault operator
+	#[clap(long, env = "VAULT_XPRIV_PATH")]
+	xpriv_path: Option<PathBuf>,
+
+	/// The hd path(s) used to derive each xpub that was registered for this vault. Specify once
+	/// per xpub the vault has used (eg, before and after a `replace_bitcoin_xpub`).
+	#[clap(long = "hd-path", required = true, value_parser = DerivationPath::from_str)]
+	pub hd_paths: Vec<DerivationPath>,
+}
+
+impl VaultXprivArgs {
+	pub fn load_xpriv(&self) -> anyhow::Result<Xpriv> {
+		let xpriv = match (&self.xpriv, &self.xpriv_path) {
+			(Some(xpriv), _) => xpriv.clone(),
+			(None, Some(path)) => fs::read_to_string(path)
+				.with_context(|| format!("Unable to read xpriv file {}", path.display()))?,
+			(None, None) => return Err(anyhow!("An xpriv or xpriv-path must be provided")),
+		};
+		Xpriv::from_str(xpriv.trim()).map_err(|e| anyhow!("Invalid xpriv: {e}"))
+	}
+}
@@ -0,0 +1,133 @@
+use crate::xpriv::VaultXprivArgs;
+use argon_bitcoin::{
+	audit_vault_lock_keys, find_vault_xpub,
+	primitives::{DerivationPath, VaultLockXPubSource, XPubFingerprint, Xpriv},
+	VaultLockKeyAudit,
+};
+use argon_client::MainchainClient;
+use argon_primitives::VaultId;
+use clap::Args;
+use comfy_table::{presets::UTF8_FULL, Cell, Color, ContentArrangement, Table};
+use std::{collections::BTreeMap, fmt};
+
+#[derive(Debug, Args)]
+pub struct XpubAuditArgs {
+	/// The vault to audit
+	#[clap(long)]
+	vault_id: VaultId,
+
+	#[clap(flatten)]
+	keys: VaultXprivArgs,
+}
+
+pub struct XpubGroupAudit {
+	pub fingerprint: XPubFingerprint,
+	pub hd_path: Option<DerivationPath>,
+	pub locks: Vec<(VaultLockXPubSource, VaultLockKeyAudit)>,
+}
+
+pub struct XpubAuditReport {
+	pub vault_id: VaultId,
+	pub groups: Vec<XpubGroupAudit>,
+}
+
+impl XpubAuditReport {
+	pub fn unverified_locks(&self) -> usize {
+		self.groups
+			.iter()
+			.flat_map(|g| g.locks.iter())
+			.filter(|(_, audit)| *audit != VaultLockKeyAudit::Verified)
+			.count()
+	}
+}
+
+pub async fn run(client: &MainchainClient, args: XpubAuditArgs) -> anyhow::Result<XpubAuditReport> {
+	let xpriv = args.keys.load_xpriv()?;
+
+	let sources: BTreeMap<XPubFingerprint, Vec<VaultLockXPubSource>> = client
+		.call_raw("BitcoinApis_vault_lock_xpub_sources", args.vault_id, None)
+		.await?;
+
+	audit(args.vault_id, &xpriv, &args.keys.hd_paths, sources)
+}
+
+pub fn audit(
+	vault_id: VaultId,
+	xpriv: &Xpriv,
+	hd_paths: &[DerivationPath],
+	sources: BTreeMap<XPubFingerprint, Vec<VaultLockXPubSource>>,
+) -> anyhow::Result<XpubAuditReport> {
+	let mut groups = Vec::with_capacity(sources.len());
+	for (fingerprint, locks) in sources {
+		let vault_xpub = find_vault_xpub(xpriv, hd_paths, fingerprint)?;
+		let mut audited = Vec::with_capacity(locks.len());
+		for lock in locks {
+			let result = match &vault_xpub {
+				Some((_, xpub)) => audit_vault_lock_keys(xpub, &lock)?,
+				None => VaultLockKeyAudit::UnknownXpub,
+			};
+			audited.push((lock, result));
+		}
+		groups.push(XpubGroupAudit {
+			fingerprint,
+			hd_path: vault_xpub.map(|(path, _)| path),
+			locks: audited,
+		});
+	}
+	Ok(XpubAuditReport { vault_id, groups })
+}
+
+impl fmt::Display for XpubAuditReport {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.groups.is_empty() {
+			return writeln!(f, "Vault {} has no outstanding bitcoin locks", self.vault_id);
+		}
+		for group in &self.groups {
+			let hd_path = group
+				.hd_path
+				.as_ref()
+				.map(|a| a.to_string())
+				.unwrap_or_else(|| "NOT FOUND".to_string());
+			writeln!(
+				f,
+				"Vault {} xpub 0x{} (hd path {hd_path}) - {} lock(s)",
+				self.vault_id,
+				hex::encode(group.fingerprint),
+				group.locks.len()
+			)?;
+			let mut table = Table::new();
+			table
+				.load_preset(UTF8_FULL)
+				.set_content_arrangement(ContentArrangement::Dynamic)
+				.set_header(vec![
+					"Utxo Id",
+					"Funded",
+					"Vault Claim Height",
+					"Cosign Child",
+					"Claim Child",
+					"Status",
+				]);
+			for (lock, audit) in &group.locks {
+				let status = match audit {
+					VaultLockKeyAudit::Verified => Cell::new("verified").fg(Color::Green),
+					VaultLockKeyAudit::UnknownXpub =>
+						Cell::new("xpub not derivable").fg(Color::Red),
+					VaultLockKeyAudit::Mismatch { cosign_matches, claim_matches } => Cell::new(
+						format!("MISMATCH (cosign={cosign_matches}, claim={claim_matches})"),
+					)
+					.fg(Color::Red),
+				};
+				table.add_row(vec![
+					Cell::new(lock.utxo_id),
+					Cell::new(lock.is_funded),
+					Cell::new(lock.vault_claim_height),
+					Cell::new(lock.cosign_child_number),
+					Cell::new(lock.claim_child_number),
+					status,
+				]);
+			}
+			writeln!(f, "{table}")?;
+		}
+		Ok(())
+	}
+}
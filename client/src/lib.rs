@@ -1,6 +1,7 @@
 extern crate core;
 
 use anyhow::anyhow;
+use codec::{Decode, Encode};
 use jsonrpsee::{
 	client_transport::ws::{Url, WsTransportClientBuilder},
 	core::client::ClientBuilder,
@@ -462,6 +463,25 @@ impl MainchainClient {
 		}
 	}
 
+	/// Calls a runtime api by its fully qualified name (eg, `BitcoinApis_get_bitcoin_network`) and
+	/// decodes the raw response. This allows calling runtime apis that are newer than the
+	/// generated metadata.
+	pub async fn call_raw<R: Decode>(
+		&self,
+		method: &str,
+		args: impl Encode,
+		at: Option<H256>,
+	) -> anyhow::Result<R> {
+		let at = match at {
+			Some(at) => at,
+			None => self.latest_finalized_block_hash().await?.hash(),
+		};
+		let bytes = self.methods.state_call(method, Some(&args.encode()), Some(at)).await?;
+		let result = R::decode(&mut bytes.as_slice())
+			.map_err(|e| anyhow!("Unable to decode the response of {method}: {e:?}"))?;
+		Ok(result)
+	}
+
 	pub async fn fetch_storage<Address>(
 		&self,
 		address: &Address,
@@ -140,6 +140,8 @@ export class DomainStore {
   get list(): Promise<Array<DomainLease>>;
   hashDomain(domain: JsDomain): Uint8Array;
   static getHash(domain: string): Uint8Array;
+  static hashWithTopLevelId(name: string, topLevelId: number): Uint8Array;
+  static resolveHash(domain: string, mainchainClient: MainchainClient): Promise<Uint8Array>;
   static parse(domain: string): Domain;
   get(id: number): Promise<DomainLease>;
 }
@@ -207,6 +209,7 @@ export class Localchain {
   get accounts(): AccountStore;
   get balanceChanges(): BalanceChangeStore;
   get domains(): DomainStore;
+  get voteDecisions(): VoteDecisionStore;
   get openChannelHolds(): OpenChannelHoldsStore;
   get balanceSync(): BalanceSync;
   get transactions(): Transactions;
@@ -225,6 +228,7 @@ export class MainchainClient {
     domainName: string,
     topLevel: DomainTopLevel,
   ): Promise<DomainRegistration | null>;
+  getTopLevelDomainId(topLevel: string): Promise<number | null>;
   getDomainZoneRecord(domainName: string, topLevel: DomainTopLevel): Promise<ZoneRecord | null>;
   getNotaryDetails(notaryId: number): Promise<NotaryDetails | null>;
   getAccount(address: string): Promise<AccountInfo>;
@@ -272,6 +276,7 @@ export class NotarizationBuilder {
   claimChannelHold(openChannelHold: OpenChannelHold): Promise<void>;
   addVote(vote: BlockVote): Promise<void>;
   leaseDomain(domain: string, registerToAddress: string): Promise<void>;
+  renewDomain(domain: string, ownerAddress: string): Promise<void>;
   /** Calculates the transfer tax on the given amount */
   getTransferTaxAmount(amount: bigint): bigint;
   /** Calculates the total needed to end up with the given balance */
@@ -386,6 +391,26 @@ export class Transactions {
   acceptArgonRequest(argonFile: string): Promise<NotarizationTracker>;
 }
 
+export class VoteDecisionRow {
+  id: number;
+  accountId: number;
+  tick: number;
+  voteMinimum: string;
+  availableTax: string;
+  blockHash?: Array<number>;
+  blockAuthor?: string;
+  votes: number;
+  powerPerVote: string;
+  winChance?: number;
+  skipReason?: string;
+  notarizationId?: number;
+}
+
+/** An audit log of the decisions made by vote strategies */
+export class VoteDecisionStore {
+  list(limit?: number | undefined | null): Promise<Array<VoteDecisionRow>>;
+}
+
 export interface AccountInfo {
   nonce: number;
   consumers: number;
@@ -725,6 +750,28 @@ export interface VoteCreationOptions {
   minimumVoteAmount?: number;
   /** How many votes to create per tick loop */
   votesPerTick?: number;
+  /** Decide when and how to vote. Each decision is logged to the `vote_decisions` table. */
+  strategy?: VoteStrategy;
+}
+
+/**
+ * Configures when and how accumulated tax is converted into block votes. Without a strategy,
+ * votes go to the best block as soon as there is enough tax to cover the vote minimum.
+ */
+export interface VoteStrategy {
+  /** Only vote for blocks authored by one of these miner addresses */
+  targetMinerAddresses?: Array<string>;
+  /**
+   * The power of each vote as a percent of the vote minimum (minimum 100). Votes with more
+   * power have proportionally stronger seals.
+   */
+  voteMinimumPercent?: number;
+  /** Skip ticks where the vote minimum is above this amount */
+  maxVoteMinimum?: number;
+  /** Spend at most 1/n of the available tax in a tick, spreading it over multiple ticks */
+  spreadOverTicks?: number;
+  /** Skip ticks where the estimated chance of winning the block is below this (0 to 1) */
+  minimumWinChance?: number;
 }
 
 export interface ZoneRecord {
@@ -0,0 +1,21 @@
+-- An audit log of every decision the voting strategy makes about converting tax into block votes.
+CREATE TABLE IF NOT EXISTS vote_decisions
+(
+    id              INTEGER  NOT NULL PRIMARY KEY AUTOINCREMENT,
+    account_id      INT      NOT NULL,
+    tick            INT      NOT NULL,
+    vote_minimum    TEXT     NOT NULL,
+    available_tax   TEXT     NOT NULL,
+    block_hash      BLOB,
+    block_author    TEXT,
+    votes           INT      NOT NULL,
+    power_per_vote  TEXT     NOT NULL,
+    win_chance      REAL,
+    skip_reason     TEXT,
+    notarization_id INT,
+    created_at      DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (account_id) REFERENCES accounts (id),
+    FOREIGN KEY (notarization_id) REFERENCES notarizations (id)
+);
+
+CREATE INDEX IF NOT EXISTS vote_decisions_tick_idx ON vote_decisions (tick);
@@ -8,6 +8,7 @@ use crate::notarization_builder::NotarizationBuilder;
 use crate::notarization_tracker::NotarizationTracker;
 use crate::open_channel_holds::OpenChannelHoldsStore;
 use crate::transactions::{TransactionType, Transactions};
+use crate::vote_strategy::{VoteDecisionStore, VoteInputs, VoteStrategy};
 use crate::LocalAccount;
 use crate::{bail, Error, Result};
 use crate::{ChannelHold, MainchainClient};
@@ -49,6 +50,9 @@ pub struct VoteCreationOptions {
 
   /// How many votes to create per tick loop
   pub votes_per_tick: Option<i64>,
+
+  /// Decide when and how to vote. Each decision is logged to the `vote_decisions` table.
+  pub strategy: Option<VoteStrategy>,
 }
 
 #[cfg_attr(feature = "napi", napi)]
@@ -705,6 +709,55 @@ impl BalanceSync {
       return Ok(None);
     };
 
+    if let Some(strategy) = &options.strategy {
+      let votable_blocks = mainchain_client.get_votable_blocks(current_tick).await?;
+      let decision = strategy.decide(&VoteInputs {
+        tick: current_tick,
+        available_tax: balance_change.balance().await,
+        vote_minimum: best_block_for_vote.vote_minimum,
+        votes_per_tick: options.votes_per_tick.unwrap_or(1) as u32,
+        minimum_vote_amount: options.minimum_vote_amount.map(|x| x as Balance),
+        votable_blocks: &votable_blocks,
+        average_tick_voting_power: mainchain_client.get_average_tick_voting_power().await?,
+      });
+      let mut db = self.db.acquire().await?;
+      let decision_id = VoteDecisionStore::db_insert(&mut db, account.id, &decision).await?;
+      drop(db);
+      if let Some(reason) = &decision.skip_reason {
+        trace!("Skipping vote for account {}: {}", account.id, reason);
+        return Ok(None);
+      }
+      let Some(block_hash) = decision.block_hash else {
+        return Ok(None);
+      };
+      let Some(votes_address) = options.votes_address.as_ref() else {
+        bail!("No votes address provided to create votes with tax");
+      };
+
+      balance_change.send_to_vote(decision.total_tax()).await?;
+      self
+        .add_votes(
+          &notarization,
+          account,
+          votes_address,
+          block_hash,
+          decision.power_per_vote,
+          decision.votes as Balance,
+          current_tick,
+        )
+        .await?;
+      let tracker = notarization.notarize().await?;
+      let mut db = self.db.acquire().await?;
+      VoteDecisionStore::db_record_notarization(&mut db, decision_id, tracker.notarization_id)
+        .await?;
+      info!(
+        "Created vote for account {} with strategy. Used tax: {}.",
+        account.id,
+        decision.total_tax()
+      );
+      return Ok(Some(tracker));
+    }
+
     let votes = options.votes_per_tick.unwrap_or(1) as Balance;
 
     let (total_tax_for_vote, tax_per_vote) = {
@@ -744,8 +797,36 @@ impl BalanceSync {
       bail!("No votes address provided to create votes with tax");
     };
 
-    let vote_address = votes_address.clone();
+    self
+      .add_votes(
+        &notarization,
+        account,
+        votes_address,
+        H256::from_slice(best_block_for_vote.block_hash.as_ref()),
+        tax_per_vote,
+        votes,
+        current_tick,
+      )
+      .await?;
+    let tracker = notarization.notarize().await?;
+    info!(
+      "Created vote for account {}. Used tax: {}.",
+      account.id, total_tax_for_vote
+    );
+    Ok(Some(tracker))
+  }
 
+  #[allow(clippy::too_many_arguments)]
+  async fn add_votes(
+    &self,
+    notarization: &NotarizationBuilder,
+    account: &LocalAccount,
+    votes_address: &str,
+    block_hash: H256,
+    tax_per_vote: Balance,
+    votes: Balance,
+    current_tick: Tick,
+  ) -> Result<()> {
     for _ in 0..votes {
       let tick_counter = {
         let mut tick_counter = self.tick_counter.write().await;
@@ -761,8 +842,8 @@ impl BalanceSync {
         account_id: account.get_account_id32()?,
         power: tax_per_vote,
         index: tick_counter.1,
-        block_hash: H256::from_slice(best_block_for_vote.block_hash.as_ref()),
-        block_rewards_account_id: AccountStore::parse_address(&vote_address)?,
+        block_hash,
+        block_rewards_account_id: AccountStore::parse_address(votes_address)?,
         signature: Signature::from_raw([0; 64]).into(),
         tick: current_tick,
       };
@@ -773,12 +854,7 @@ impl BalanceSync {
       vote.signature = MultiSignature::decode(&mut signature.as_ref())?;
       notarization.add_vote(vote).await?;
     }
-    let tracker = notarization.notarize().await?;
-    info!(
-      "Created vote for account {}. Used tax: {}.",
-      account.id, total_tax_for_vote
-    );
-    Ok(Some(tracker))
+    Ok(())
   }
 
   /// Sends the notarizations to the notary for finalization.
@@ -2,7 +2,7 @@ use crate::keystore::Keystore;
 use crate::overview::LocalchainOverview;
 use crate::{
   overview, AccountStore, CryptoScheme, DomainStore, Localchain, LocalchainConfig, MainchainClient,
-  VoteCreationOptions,
+  VoteCreationOptions, VoteStrategy,
 };
 use anyhow::anyhow;
 use argon_primitives::argon_utils::format_argons;
@@ -61,6 +61,9 @@ enum Commands {
     #[clap(long)]
     minimum_vote_amount: Option<u128>,
 
+    #[clap(flatten)]
+    vote_strategy: VoteStrategyArgs,
+
     /// Password to unlock the embedded keystore
     #[clap(flatten)]
     keystore_password: EmbeddedKeyPassword,
@@ -115,6 +118,30 @@ enum DomainsSubcommand {
     #[arg(short, long, value_name = "SS58_ADDRESS", required = true)]
     owner_address: String,
   },
+  /// Renew a registered domain (leases it again to the current owner to extend the expiration)
+  Renew {
+    /// The domain name
+    #[clap()]
+    domain: String,
+
+    /// Password to unlock the embedded keystore
+    #[clap(flatten)]
+    keystore_password: EmbeddedKeyPassword,
+  },
+  /// Transfer a domain owned by this localchain's account to a new owner on the mainchain
+  Transfer {
+    /// The domain name
+    #[clap()]
+    domain: String,
+
+    /// Password to unlock the embedded keystore
+    #[clap(flatten)]
+    keystore_password: EmbeddedKeyPassword,
+
+    /// The account to transfer the domain to
+    #[arg(short, long, value_name = "SS58_ADDRESS", required = true)]
+    to_address: String,
+  },
 }
 
 #[derive(Subcommand, Debug)]
@@ -213,6 +240,48 @@ struct SendArgonFileArgs {
   to: Option<String>,
 }
 
+/// Options to vote with a strategy. Every decision is logged to the localchain database.
+#[derive(Debug, Args)]
+struct VoteStrategyArgs {
+  /// Only vote for blocks authored by this miner (can be specified multiple times)
+  #[clap(long = "vote-target-miner", value_name = "SS58_ADDRESS")]
+  target_miner_addresses: Vec<String>,
+
+  /// The power of each vote as a percent of the vote minimum
+  #[clap(long)]
+  vote_minimum_percent: Option<i64>,
+
+  /// Skip voting while the vote minimum is above this amount
+  #[clap(long)]
+  max_vote_minimum: Option<i64>,
+
+  /// Spread the available tax over this many ticks
+  #[clap(long)]
+  spread_votes_over_ticks: Option<i64>,
+
+  /// Skip ticks where the estimated chance of winning the block is below this (0 to 1)
+  #[clap(long)]
+  minimum_win_chance: Option<f64>,
+}
+
+impl VoteStrategyArgs {
+  fn into_strategy(self) -> Option<VoteStrategy> {
+    let strategy = VoteStrategy {
+      target_miner_addresses: Some(self.target_miner_addresses).filter(|x| !x.is_empty()),
+      vote_minimum_percent: self.vote_minimum_percent,
+      max_vote_minimum: self.max_vote_minimum,
+      spread_over_ticks: self.spread_votes_over_ticks,
+      minimum_win_chance: self.minimum_win_chance,
+    };
+    let is_configured = strategy.target_miner_addresses.is_some()
+      || strategy.vote_minimum_percent.is_some()
+      || strategy.max_vote_minimum.is_some()
+      || strategy.spread_over_ticks.is_some()
+      || strategy.minimum_win_chance.is_some();
+    is_configured.then_some(strategy)
+  }
+}
+
 #[derive(Debug, Args)]
 struct TransferArgs {
   /// The number of argons to transfer
@@ -255,6 +324,7 @@ where
       vote_address,
       keystore_password,
       minimum_vote_amount,
+      vote_strategy,
     } => {
       let localchain = Localchain::load(LocalchainConfig {
         path,
@@ -269,6 +339,7 @@ where
         votes_address: Some(vote_address),
         minimum_vote_amount: minimum_vote_amount.map(|v| v as i64),
         votes_per_tick: None,
+        strategy: vote_strategy.into_strategy(),
       });
 
       let sync = balance_sync.sync(sync_options.clone()).await?;
@@ -350,8 +421,6 @@ where
         domain,
         owner_address,
       } => {
-        let argon_domain =
-          Domain::parse(domain.clone()).map_err(|_| anyhow!("Not a valid domain"))?;
         let localchain = Localchain::load(LocalchainConfig {
           path,
           mainchain_url,
@@ -359,6 +428,11 @@ where
           keystore_password: Some(keystore_password),
         })
         .await?;
+        let mainchain = localchain
+          .mainchain_client()
+          .await
+          .ok_or(anyhow!("Not connected to the mainchain"))?;
+        let domain_hash = DomainStore::resolve_hash(domain.clone(), &mainchain).await?;
 
         let change = localchain.begin_change();
         change.lease_domain(domain.clone(), owner_address).await?;
@@ -384,7 +458,83 @@ where
           domain,
           tracker.tick,
           tracker.notebook_number,
-          argon_domain.hash()
+          domain_hash
+        );
+      }
+      DomainsSubcommand::Renew {
+        keystore_password,
+        domain,
+      } => {
+        let argon_domain =
+          Domain::parse(domain.clone()).map_err(|_| anyhow!("Not a valid domain"))?;
+        let localchain = Localchain::load(LocalchainConfig {
+          path,
+          mainchain_url,
+          ntp_pool_url: None,
+          keystore_password: Some(keystore_password),
+        })
+        .await?;
+        let mainchain = localchain
+          .mainchain_client()
+          .await
+          .ok_or(anyhow!("Not connected to the mainchain"))?;
+        let Some(registration) = mainchain
+          .get_domain_registration(argon_domain.name.clone(), argon_domain.top_level)
+          .await?
+        else {
+          return Err(anyhow!("{domain} is not registered. Lease it instead."));
+        };
+
+        let change = localchain.begin_change();
+        change
+          .renew_domain(domain.clone(), registration.registered_to_address.clone())
+          .await?;
+        change.sign().await?;
+        let tracker = change.notarize().await?;
+        println!(
+          "{} renewed for {} at tick {} in notebook {}.",
+          domain, registration.registered_to_address, tracker.tick, tracker.notebook_number,
+        );
+      }
+      DomainsSubcommand::Transfer {
+        keystore_password,
+        domain,
+        to_address,
+      } => {
+        let localchain = Localchain::load(LocalchainConfig {
+          path,
+          mainchain_url,
+          ntp_pool_url: None,
+          keystore_password: Some(keystore_password),
+        })
+        .await?;
+        let mainchain = localchain
+          .mainchain_client()
+          .await
+          .ok_or(anyhow!("Not connected to the mainchain"))?;
+        let domain_hash = DomainStore::resolve_hash(domain.clone(), &mainchain).await?;
+        let Some(registration) = mainchain
+          .get_domain_registration_by_hash(domain_hash)
+          .await?
+        else {
+          return Err(anyhow!("{domain} is not registered"));
+        };
+        let owner_address = registration.registered_to_address;
+        let keystore = localchain.keystore();
+        if !keystore.can_sign(owner_address.clone()).await {
+          return Err(anyhow!(
+            "{domain} is owned by {owner_address}, which this localchain can't sign for"
+          ));
+        }
+
+        let in_block = mainchain
+          .transfer_domain(domain.clone(), owner_address, to_address.clone(), &keystore)
+          .await?;
+        println!(
+          "{} transferred to {} in block {:?}. The zone record was cleared for the new owner to set.",
+          domain,
+          to_address,
+          in_block.block_hash()
         );
       }
     },
@@ -1,9 +1,9 @@
-use argon_primitives::{Domain, DomainHash, DomainTopLevel};
+use argon_primitives::{Domain, DomainHash, DomainTopLevel, DomainTopLevelId};
 use chrono::NaiveDateTime;
 use serde_json::json;
 use sqlx::{FromRow, SqliteConnection, SqlitePool};
 
-use crate::{bail, Result};
+use crate::{bail, MainchainClient, Result};
 
 #[derive(FromRow, Clone)]
 #[allow(dead_code)]
@@ -20,6 +20,13 @@ pub struct DomainRow {
 
 impl DomainRow {}
 
+/// A domain with its top level domain resolved to the id it's hashed with
+pub struct ResolvedDomain {
+  pub name: String,
+  pub top_level: String,
+  pub hash: DomainHash,
+}
+
 #[cfg_attr(feature = "napi", napi)]
 pub struct DomainStore {
   db: SqlitePool,
@@ -58,6 +65,64 @@ impl DomainStore {
     Ok(domain.hash())
   }
 
+  /// Hash a domain by the id of its top level domain (needed for top level domains added by
+  /// governance, which have no `DomainTopLevel` variant)
+  pub fn hash_with_top_level_id(name: String, top_level_id: DomainTopLevelId) -> DomainHash {
+    Domain::hash_with_top_level_id(&name.to_lowercase(), top_level_id)
+  }
+
+  /// Resolves a domain (eg, `delta.flights`) to the name and id of its top level domain. Built in
+  /// top level domains are parsed locally, so the mainchain is only asked for top level domains
+  /// added through governance. Fails if the top level domain is unknown or retired.
+  pub async fn resolve(
+    domain: String,
+    mainchain_client: Option<&MainchainClient>,
+  ) -> Result<ResolvedDomain> {
+    let (name, top_level) = match Domain::parse(domain.clone()) {
+      Ok(parsed) => (parsed.name, parsed.top_level.name().to_string()),
+      Err(e) => {
+        let Some((name, top_level)) = domain.split_once('.').filter(|(name, _)| !name.is_empty())
+        else {
+          bail!("Invalid domain {domain} ({e})");
+        };
+        (name.to_lowercase(), top_level.to_lowercase())
+      }
+    };
+    let top_level_id = match DomainTopLevel::from_name(&top_level) {
+      Some(built_in) => built_in.id(),
+      None => {
+        if !DomainTopLevel::is_valid_name(top_level.as_bytes()) {
+          bail!("Invalid top level domain {top_level}");
+        }
+        let Some(mainchain_client) = mainchain_client else {
+          bail!("A mainchain connection is needed to resolve the top level domain {top_level}");
+        };
+        let Some(top_level_id) = mainchain_client
+          .get_top_level_domain_id(top_level.clone())
+          .await?
+        else {
+          bail!("Unknown or retired top level domain {top_level}");
+        };
+        top_level_id
+      }
+    };
+    let hash = Domain::hash_with_top_level_id(&name, top_level_id);
+    Ok(ResolvedDomain {
+      name,
+      top_level,
+      hash,
+    })
+  }
+
+  /// Hash a domain (eg, `delta.flights`), resolving the top level domain in the mainchain
+  /// registry if it isn't built in. Fails if the top level domain is unknown or retired.
+  pub async fn resolve_hash(
+    domain: String,
+    mainchain_client: &MainchainClient,
+  ) -> Result<DomainHash> {
+    Ok(Self::resolve(domain, Some(mainchain_client)).await?.hash)
+  }
+
   pub fn parse(domain: String) -> Result<JsDomain> {
     Ok(Domain::parse(domain).map(Into::into)?)
   }
@@ -82,9 +147,30 @@ impl DomainStore {
     // remove leading and trailing quote from json
     let top_level = top_level.to_string();
     let top_level = top_level.trim_matches('"');
+    Self::db_insert_lease(
+      db,
+      &domain.name,
+      top_level,
+      registered_to_address,
+      notarization_id,
+      registered_at_tick,
+    )
+    .await
+  }
+
+  /// Records a leased domain by the name of its top level domain (which might not be a built in
+  /// `DomainTopLevel`)
+  pub async fn db_insert_lease(
+    db: &mut SqliteConnection,
+    name: &str,
+    top_level: &str,
+    registered_to_address: String,
+    notarization_id: i64,
+    registered_at_tick: i64,
+  ) -> Result<()> {
     let res = sqlx::query!(
       "INSERT INTO domains (name, top_level, registered_to_address, notarization_id, registered_at_tick) VALUES (?, ?, ?, ?, ?)",
-      domain.name,
+      name,
       top_level,
       registered_to_address,
       notarization_id,
@@ -94,7 +180,7 @@ impl DomainStore {
             .await
             ?;
     if res.rows_affected() != 1 {
-      bail!("Error inserting domain {}", domain.name);
+      bail!("Error inserting domain {}", name);
     }
     Ok(())
   }
@@ -104,7 +190,7 @@ impl DomainStore {
 pub mod napi_ext {
   use super::*;
   use crate::error::NapiOk;
-  use crate::{DomainStore, JsDomain};
+  use crate::{DomainStore, JsDomain, MainchainClient};
   use argon_primitives::DomainTopLevel;
   use napi::bindgen_prelude::*;
 
@@ -129,6 +215,24 @@ pub mod napi_ext {
       DomainStore::get_hash(domain).map(|a| a.0.into()).napi_ok()
     }
 
+    #[napi(js_name = "hashWithTopLevelId")]
+    pub fn hash_with_top_level_id_napi(name: String, top_level_id: u8) -> Uint8Array {
+      DomainStore::hash_with_top_level_id(name, top_level_id)
+        .0
+        .into()
+    }
+
+    #[napi(js_name = "resolveHash")]
+    pub async fn resolve_hash_napi(
+      domain: String,
+      mainchain_client: &MainchainClient,
+    ) -> napi::Result<Uint8Array> {
+      DomainStore::resolve_hash(domain, mainchain_client)
+        .await
+        .map(|a| a.0.into())
+        .napi_ok()
+    }
+
     #[napi(js_name = "parse", ts_return_type = "Domain")]
     pub fn parse_napi(domain: String) -> napi::Result<JsDomain> {
       DomainStore::parse(domain).napi_ok()
@@ -186,6 +290,19 @@ mod test {
     Ok(())
   }
 
+  #[test]
+  fn test_hash_with_top_level_id() {
+    let domain = Domain::new("test", DomainTopLevel::Cars);
+    assert_eq!(
+      DomainStore::hash_with_top_level_id("Test".into(), DomainTopLevel::Cars.id()),
+      domain.hash()
+    );
+    assert_eq!(
+      DomainStore::get_hash("test.cars".into()).unwrap(),
+      DomainStore::hash_with_top_level_id("test".into(), DomainTopLevel::Cars.id())
+    );
+  }
+
   #[sqlx::test]
   async fn test_domain_store(pool: SqlitePool) -> anyhow::Result<()> {
     let store = DomainStore::new(pool);
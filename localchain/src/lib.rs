@@ -30,6 +30,7 @@ use sqlx::{migrate::MigrateDatabase, SqlitePool};
 use sqlx::{Executor, Sqlite};
 use tokio::sync::RwLock as AsyncRwLock;
 use tracing_subscriber::{fmt, EnvFilter};
+pub use vote_strategy::*;
 
 use crate::cli::EmbeddedKeyPassword;
 use crate::mainchain_transfer::MainchainTransferStore;
@@ -51,6 +52,7 @@ mod notarization_builder;
 mod notarization_tracker;
 mod notary_client;
 mod open_channel_holds;
+mod vote_strategy;
 
 pub mod embedded_keystore;
 
@@ -359,6 +361,10 @@ impl Localchain {
     domain::DomainStore::new(self.db.clone())
   }
 
+  pub fn vote_decisions(&self) -> vote_strategy::VoteDecisionStore {
+    vote_strategy::VoteDecisionStore::new(self.db.clone())
+  }
+
   pub fn open_channel_holds(&self) -> open_channel_holds::OpenChannelHoldsStore {
     open_channel_holds::OpenChannelHoldsStore::new(
       self.db.clone(),
@@ -388,6 +394,7 @@ impl Localchain {
       self.keystore.clone(),
       self.ticker.clone(),
     )
+    .with_mainchain_client(self.mainchain_client.clone())
   }
 
   pub fn config_logs() {
@@ -720,6 +727,11 @@ pub mod napi_ext {
       self.domains()
     }
 
+    #[napi(js_name = "voteDecisions", getter)]
+    pub fn vote_decisions_napi(&self) -> vote_strategy::VoteDecisionStore {
+      self.vote_decisions()
+    }
+
     #[napi(js_name = "openChannelHolds", getter)]
     pub fn open_channel_holds_napi(&self) -> open_channel_holds::OpenChannelHoldsStore {
       self.open_channel_holds()
@@ -24,11 +24,14 @@ use argon_client::{
 };
 use argon_primitives::host::Host;
 use argon_primitives::tick::{Tick, Ticker};
+use argon_primitives::VotingSchedule;
 use argon_primitives::{
-  Balance, ChainIdentity, Domain, DomainTopLevel, NotaryId, NotebookNumber, TransferToLocalchainId,
+  Balance, ChainIdentity, Domain, DomainHash, DomainTopLevel, DomainTopLevelId, NotaryId,
+  NotebookNumber, TransferToLocalchainId,
 };
 
 use crate::AccountStore;
+use crate::DomainStore;
 use crate::Keystore;
 use crate::{bail, Result};
 
@@ -181,14 +184,76 @@ impl MainchainClient {
     }))
   }
 
+  /// The blocks votes can be cast for at the current tick (best last), with the miner that
+  /// authored each one
+  pub async fn get_votable_blocks(&self, current_tick: Tick) -> Result<Vec<VotableBlock>> {
+    let best_block_hash = self.get_best_block_hash().await?;
+    let grandparent_tick =
+      VotingSchedule::when_creating_votes(current_tick).grandparent_votes_tick();
+    let Some(block_hashes) = self
+      .fetch_storage(
+        &storage().ticks().recent_blocks_at_ticks(grandparent_tick),
+        Some(best_block_hash),
+      )
+      .await?
+    else {
+      return Ok(vec![]);
+    };
+
+    let mut votable_blocks = vec![];
+    for block_hash in block_hashes.0 {
+      let block_hash = H256::from(block_hash.0);
+      let Some(sealer) = self
+        .fetch_storage(
+          &storage().block_seal().last_block_sealer_info(),
+          Some(block_hash),
+        )
+        .await?
+      else {
+        continue;
+      };
+      votable_blocks.push(VotableBlock {
+        block_hash,
+        author_address: AccountStore::to_address(&AccountId32::from(
+          sealer.block_author_account_id.0,
+        )),
+      });
+    }
+    Ok(votable_blocks)
+  }
+
+  /// The average voting power per tick over the vote minimum adjustment period
+  pub async fn get_average_tick_voting_power(&self) -> Result<Option<Balance>> {
+    let past_block_votes = self
+      .fetch_storage(&storage().block_seal_spec().past_block_votes(), None)
+      .await?
+      .map(|x| x.0)
+      .unwrap_or_default();
+    if past_block_votes.is_empty() {
+      return Ok(None);
+    }
+    let total_power = past_block_votes
+      .iter()
+      .fold(0u128, |total, (_, _, power)| total.saturating_add(*power));
+    Ok(Some(total_power / past_block_votes.len() as Balance))
+  }
+
   pub async fn get_domain_registration(
     &self,
     domain_name: String,
     top_level: DomainTopLevel,
   ) -> Result<Option<DomainRegistration>> {
-    let domain_hash: argon_client::types::H256 =
-      Domain::from_string(domain_name, top_level).hash().into();
+    let domain_hash = Domain::from_string(domain_name, top_level).hash();
+    self.get_domain_registration_by_hash(domain_hash).await
+  }
 
+  /// Looks up a registration by domain hash (needed for domains under top level domains added
+  /// through governance, which have no `DomainTopLevel` variant)
+  pub async fn get_domain_registration_by_hash(
+    &self,
+    domain_hash: DomainHash,
+  ) -> Result<Option<DomainRegistration>> {
+    let domain_hash: argon_client::types::H256 = domain_hash.into();
     let best_block_hash = self.get_best_block_hash().await?;
     if let Some(x) = self
       .fetch_storage(
@@ -215,6 +280,50 @@ impl MainchainClient {
     }
   }
 
+  /// The id to hash domains under a top level domain with. Built in top level domains resolve
+  /// locally. Returns None if the top level domain was never added or has been retired.
+  pub async fn get_top_level_domain_id(
+    &self,
+    top_level: String,
+  ) -> Result<Option<DomainTopLevelId>> {
+    let name = top_level.to_lowercase();
+    if let Some(built_in) = DomainTopLevel::from_name(&name) {
+      return Ok(Some(built_in.id()));
+    }
+    let best_block_hash = self.get_best_block_hash().await?;
+    // read dynamically since top level domain storage is newer than the bundled metadata
+    let Some(top_level_id) = self
+      .fetch_storage(
+        &subxt::dynamic::storage(
+          "Domains",
+          "TopLevelDomainIdsByName",
+          vec![subxt::dynamic::Value::from_bytes(name.as_bytes())],
+        ),
+        Some(best_block_hash),
+      )
+      .await?
+    else {
+      return Ok(None);
+    };
+    let top_level_id = top_level_id
+      .as_type::<DomainTopLevelId>()
+      .map_err(|e| anyhow!("Could not decode the top level domain id: {e}"))?;
+    let retired = self
+      .fetch_storage(
+        &subxt::dynamic::storage(
+          "Domains",
+          "RetiredTopLevelDomains",
+          vec![subxt::dynamic::Value::u128(top_level_id as u128)],
+        ),
+        Some(best_block_hash),
+      )
+      .await?;
+    if retired.is_some() {
+      return Ok(None);
+    }
+    Ok(Some(top_level_id))
+  }
+
   pub async fn get_domain_zone_record(
     &self,
     domain_name: String,
@@ -265,6 +374,79 @@ impl MainchainClient {
     }))
   }
 
+  /// Transfers a domain (eg, `delta.flights`) to a new owner on the mainchain. The top level
+  /// domain is resolved the same way as a lease. The current owner must be an account in this
+  /// keystore.
+  pub async fn transfer_domain(
+    &self,
+    domain: String,
+    owner_address: String,
+    new_owner_address: String,
+    keystore: &Keystore,
+  ) -> Result<TxInBlockWithEvents> {
+    let domain_hash = DomainStore::resolve(domain, Some(self)).await?.hash;
+    let owner = AccountId32::from_str(&owner_address).map_err(|e| anyhow!(e))?;
+    let new_owner = AccountId32::from_str(&new_owner_address).map_err(|e| anyhow!(e))?;
+    let current_nonce = self.get_account_nonce(owner_address.clone()).await?;
+    let mortality = 50; // artibrary number of blocks to keep this tx alive
+
+    let client = self.client().await?;
+    let current_block = client.best_block_hash().await?;
+    let current_block_number = client.block_number(current_block).await?;
+    let params = || {
+      ArgonExtrinsicParamsBuilder::<ArgonConfig>::new()
+        .nonce(current_nonce as u64)
+        .mortal_from_unchecked(mortality, current_block_number as u64, current_block)
+        .build()
+    };
+    // built dynamically since this call is newer than the bundled metadata. The call is validated
+    // against the live metadata when it's encoded.
+    let transfer_tx = || {
+      subxt::dynamic::tx(
+        "Domains",
+        "transfer_domain",
+        vec![
+          subxt::dynamic::Value::from_bytes(domain_hash.0),
+          subxt::dynamic::Value::from_bytes(<[u8; 32]>::from(new_owner.clone())),
+        ],
+      )
+    };
+
+    let payload = {
+      let tx_tmp = client
+        .live
+        .tx()
+        .create_partial_offline(&transfer_tx(), params())?;
+      tx_tmp.signer_payload()
+    };
+
+    let signature = keystore.sign(owner_address.clone(), payload).await?;
+    let multi_signature = MultiSignature::decode(&mut signature.as_ref())?;
+
+    // have to recreate this because the internal types are not send. inefficient, but small penalty
+    let submittable = {
+      client
+        .live
+        .tx()
+        .create_partial_offline(&transfer_tx(), params())?
+        .sign_with_account_and_signature(&owner, &multi_signature)
+    };
+
+    let tx_progress = submittable.submit_and_watch().await?;
+    let in_block = InnerMainchainClient::wait_for_ext_in_block(tx_progress, false)
+      .await
+      .map_err(|e| anyhow!("Error submitting domain transfer to block: {e:?}"))?;
+
+    let transferred = in_block
+      .events
+      .iter()
+      .any(|event| event.pallet_name() == "Domains" && event.variant_name() == "DomainTransferred");
+    if !transferred {
+      bail!("No domain transfer event found for {:?}", domain_hash);
+    }
+    Ok(in_block)
+  }
+
   pub async fn get_notary_details(&self, notary_id: u32) -> Result<Option<NotaryDetails>> {
     let notaries = self
       .fetch_storage(&storage().notaries().active_notaries(), None)
@@ -715,6 +897,14 @@ pub mod napi_ext {
         .napi_ok()
     }
 
+    #[napi(js_name = "getTopLevelDomainId")]
+    pub async fn get_top_level_domain_id_napi(
+      &self,
+      top_level: String,
+    ) -> napi::Result<Option<u8>> {
+      self.get_top_level_domain_id(top_level).await.napi_ok()
+    }
+
     #[napi(js_name = "getDomainZoneRecord")]
     pub async fn get_domain_zone_record_napi(
       &self,
@@ -892,3 +1082,9 @@ pub struct BestBlockForVote {
   pub block_hash: H256,
   pub vote_minimum: Balance,
 }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VotableBlock {
+  pub block_hash: H256,
+  pub author_address: String,
+}
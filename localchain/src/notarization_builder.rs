@@ -1,7 +1,7 @@
 use anyhow::anyhow;
 use argon_notary_audit::{verify_changeset_signatures, verify_notarization_allocation};
 use argon_primitives::{
-  prelude::*, AccountType, BalanceChange, BlockVote, Domain, Notarization, Note, NoteType,
+  prelude::*, AccountType, BalanceChange, BlockVote, DomainHash, Notarization, Note, NoteType,
   DOMAIN_LEASE_COST, MAX_BALANCE_CHANGES_PER_NOTARIZATION, MAX_BLOCK_VOTES_PER_NOTARIZATION,
   MAX_DOMAINS_PER_NOTARIZATION, TAX_PERCENT_BASE, TRANSFER_TAX_CAP,
 };
@@ -21,23 +21,34 @@ use crate::accounts::LocalAccount;
 use crate::argon_file::{ArgonFile, ArgonFileType};
 use crate::balance_change_builder::BalanceChangeBuilder;
 use crate::balance_changes::BalanceChangeStore;
-use crate::domain::JsDomain;
 use crate::keystore::Keystore;
 use crate::notarization_tracker::NotarizationTracker;
 use crate::notary_client::NotaryClients;
 use crate::open_channel_holds::OpenChannelHold;
 use crate::transactions::LocalchainTransaction;
 use crate::{bail, Error};
-use crate::{ChannelHold, DomainStore, LocalchainTransfer, NotaryAccountOrigin};
+use crate::{
+  ChannelHold, DomainStore, LocalchainTransfer, MainchainClient, NotaryAccountOrigin,
+  ResolvedDomain,
+};
 use crate::{Result, TickerRef};
 
+/// A domain leased in this notarization, hashed with the id of its top level domain
+#[derive(Clone)]
+struct PendingDomainLease {
+  name: String,
+  top_level: String,
+  domain_hash: DomainHash,
+  register_to_account: AccountId32,
+}
+
 #[cfg_attr(feature = "napi", napi)]
 #[derive(Clone)]
 pub struct NotarizationBuilder {
   imported_balance_changes: Arc<RwLock<Vec<BalanceChange>>>,
   balance_changes_by_account: Arc<RwLock<HashMap<i64, BalanceChangeBuilder>>>,
   votes: Arc<RwLock<BoundedVec<BlockVote, ConstU32<MAX_BLOCK_VOTES_PER_NOTARIZATION>>>>,
-  domains: Arc<RwLock<BoundedVec<(Domain, AccountId32), ConstU32<MAX_DOMAINS_PER_NOTARIZATION>>>>,
+  domains: Arc<RwLock<BoundedVec<PendingDomainLease, ConstU32<MAX_DOMAINS_PER_NOTARIZATION>>>>,
   loaded_accounts: Arc<RwLock<BTreeMap<(String, AccountType), LocalAccount>>>,
   channel_holds: Arc<RwLock<Vec<OpenChannelHold>>>,
   db: SqlitePool,
@@ -48,6 +59,7 @@ pub struct NotarizationBuilder {
   transaction: Arc<RwLock<Option<LocalchainTransaction>>>,
   keystore: Keystore,
   ticker: TickerRef,
+  mainchain_client: Arc<RwLock<Option<MainchainClient>>>,
 }
 
 impl NotarizationBuilder {
@@ -72,6 +84,7 @@ impl NotarizationBuilder {
       transaction: Default::default(),
       keystore,
       ticker,
+      mainchain_client: Default::default(),
     }
   }
 
@@ -463,15 +476,37 @@ impl NotarizationBuilder {
     Ok(())
   }
 
+  /// Used to resolve top level domains in the mainchain registry
+  pub(crate) fn with_mainchain_client(
+    mut self,
+    mainchain_client: Arc<RwLock<Option<MainchainClient>>>,
+  ) -> Self {
+    self.mainchain_client = mainchain_client;
+    self
+  }
+
+  /// Leases a domain (eg, `delta.flights`). Top level domains added through governance are
+  /// resolved in the mainchain registry, so leases under unknown or retired top level domains are
+  /// rejected before paying.
   pub async fn lease_domain(&self, domain: String, register_to_address: String) -> Result<()> {
-    let lease = self.default_deposit_account().await?.lease_domain().await?;
+    let mainchain_client = self.mainchain_client.read().await.clone();
+    let ResolvedDomain {
+      name,
+      top_level,
+      hash: domain_hash,
+    } = DomainStore::resolve(domain, mainchain_client.as_ref()).await?;
+    let register_to_account = AccountStore::parse_address(&register_to_address)?;
 
+    let lease = self.default_deposit_account().await?.lease_domain().await?;
     self.default_tax_account().await?.claim(lease).await?;
 
-    let register_to_account = AccountStore::parse_address(&register_to_address)?;
-    let domain = Domain::parse(domain)?;
     let mut domains = self.domains.write().await;
-    domains.try_push((domain, register_to_account)).map_err(|_| anyhow!(
+    domains.try_push(PendingDomainLease {
+      name,
+      top_level,
+      domain_hash,
+      register_to_account,
+    }).map_err(|_| anyhow!(
       "Max domains reached for this notarization. Move this domain to a new notarization! ({} domains + 1 > {} max)",
       domains.len(),
       MAX_DOMAINS_PER_NOTARIZATION
@@ -479,6 +514,12 @@ impl NotarizationBuilder {
     Ok(())
   }
 
+  /// Renews a domain by leasing it again to its current owner. A lease from the owner extends the
+  /// expiration of the registration, so it can't be canceled by a competing lease in the same tick.
+  pub async fn renew_domain(&self, domain: String, owner_address: String) -> Result<()> {
+    self.lease_domain(domain, owner_address).await
+  }
+
   /// Calculates the transfer tax on the given amount
   pub fn get_transfer_tax_amount(&self, amount: Balance) -> Balance {
     Note::calculate_transfer_tax(amount)
@@ -718,7 +759,7 @@ impl NotarizationBuilder {
       (*(self.domains.read().await))
         .clone()
         .into_iter()
-        .map(|(d, a)| (d.hash(), a))
+        .map(|lease| (lease.domain_hash, lease.register_to_account))
         .collect(),
     );
 
@@ -889,14 +930,12 @@ impl NotarizationBuilder {
 
       tracker.accounts_by_id.insert(*account_id, account);
     }
-    for (domain, account) in &*(self.domains.read().await) {
-      DomainStore::db_insert(
+    for lease in &*(self.domains.read().await) {
+      DomainStore::db_insert_lease(
         &mut tx,
-        JsDomain {
-          name: domain.name.clone(),
-          top_level: domain.top_level,
-        },
-        AccountStore::to_address(account),
+        &lease.name,
+        &lease.top_level,
+        AccountStore::to_address(&lease.register_to_account),
         notarization_id,
         result.tick as i64,
       )
@@ -1136,6 +1175,15 @@ pub mod napi_ext {
         .napi_ok()
     }
 
+    #[napi(js_name = "renewDomain")]
+    pub async fn renew_domain_napi(
+      &self,
+      domain: String,
+      owner_address: String,
+    ) -> napi::Result<()> {
+      self.renew_domain(domain, owner_address).await.napi_ok()
+    }
+
     /// Calculates the transfer tax on the given amount
     #[napi(js_name = "getTransferTaxAmount")]
     pub fn get_transfer_tax_amount_napi(&self, amount: BigInt) -> BigInt {
@@ -1350,6 +1398,43 @@ mod test {
     Ok(())
   }
 
+  #[sqlx::test]
+  async fn test_lease_domain_rejects_malformed_names(pool: SqlitePool) -> anyhow::Result<()> {
+    let mock_notary = create_mock_notary().await?;
+    let notary_clients = mock_notary_clients(&mock_notary, Ferdie).await?;
+
+    let alice_signer = Keystore::new(pool.clone());
+    let alice_address = alice_signer
+      .import_suri(Alice.to_seed(), Sr25519, None)
+      .await?;
+    let ticker = Ticker::start(Duration::from_secs(1), 2);
+
+    let alice_builder = NotarizationBuilder::new(
+      pool,
+      notary_clients.clone(),
+      alice_signer,
+      TickerRef::new(ticker),
+    );
+
+    for domain in ["delta", ".flights", "delta.", "delta.Fl!ghts"] {
+      assert!(
+        alice_builder
+          .lease_domain(domain.to_string(), alice_address.clone())
+          .await
+          .is_err(),
+        "{domain} should be rejected"
+      );
+    }
+    // added top level domains can't be resolved without a mainchain connection
+    assert!(alice_builder
+      .lease_domain("delta.web3".to_string(), alice_address.clone())
+      .await
+      .is_err());
+    assert!(alice_builder.domains.read().await.is_empty());
+
+    Ok(())
+  }
+
   #[sqlx::test]
   async fn test_exchange(bob_pool: SqlitePool) -> anyhow::Result<()> {
     let mock_notary = create_mock_notary().await?;
@@ -0,0 +1,404 @@
+use argon_primitives::tick::Tick;
+use argon_primitives::Balance;
+use chrono::NaiveDateTime;
+use polkadot_sdk::*;
+use sp_core::H256;
+use sqlx::{FromRow, SqliteConnection, SqlitePool};
+
+use crate::{bail, Result, VotableBlock};
+
+/// Configures when and how accumulated tax is converted into block votes. Without a strategy,
+/// votes go to the best block as soon as there is enough tax to cover the vote minimum.
+#[cfg_attr(feature = "napi", napi(object))]
+#[derive(Clone, Debug, Default)]
+pub struct VoteStrategy {
+  /// Only vote for blocks authored by one of these miner addresses
+  pub target_miner_addresses: Option<Vec<String>>,
+  /// The power of each vote as a percent of the vote minimum (minimum 100). Votes with more
+  /// power have proportionally stronger seals.
+  pub vote_minimum_percent: Option<i64>,
+  /// Skip ticks where the vote minimum is above this amount
+  pub max_vote_minimum: Option<i64>,
+  /// Spend at most 1/n of the available tax in a tick, spreading it over multiple ticks
+  pub spread_over_ticks: Option<i64>,
+  /// Skip ticks where the estimated chance of winning the block is below this (0 to 1)
+  pub minimum_win_chance: Option<f64>,
+}
+
+/// The state of the chain and account a vote decision is made from
+pub struct VoteInputs<'a> {
+  pub tick: Tick,
+  pub available_tax: Balance,
+  pub vote_minimum: Balance,
+  pub votes_per_tick: u32,
+  /// A floor on the power of each vote
+  pub minimum_vote_amount: Option<Balance>,
+  /// The blocks that can be voted on, best last
+  pub votable_blocks: &'a [VotableBlock],
+  /// The average voting power the chain sees in a tick
+  pub average_tick_voting_power: Option<Balance>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct VoteDecision {
+  pub tick: Tick,
+  pub vote_minimum: Balance,
+  pub available_tax: Balance,
+  pub block_hash: Option<H256>,
+  pub block_author: Option<String>,
+  pub votes: u32,
+  pub power_per_vote: Balance,
+  pub win_chance: Option<f64>,
+  /// Why no votes were created. None if voting.
+  pub skip_reason: Option<String>,
+}
+
+impl VoteDecision {
+  pub fn is_vote(&self) -> bool {
+    self.skip_reason.is_none() && self.votes > 0
+  }
+
+  pub fn total_tax(&self) -> Balance {
+    self.power_per_vote.saturating_mul(self.votes as Balance)
+  }
+
+  fn skip(mut self, reason: String) -> Self {
+    self.votes = 0;
+    self.skip_reason = Some(reason);
+    self
+  }
+}
+
+impl VoteStrategy {
+  pub fn decide(&self, inputs: &VoteInputs) -> VoteDecision {
+    let decision = VoteDecision {
+      tick: inputs.tick,
+      vote_minimum: inputs.vote_minimum,
+      available_tax: inputs.available_tax,
+      block_hash: None,
+      block_author: None,
+      votes: 0,
+      power_per_vote: 0,
+      win_chance: None,
+      skip_reason: None,
+    };
+
+    let block = match &self.target_miner_addresses {
+      Some(miners) => inputs
+        .votable_blocks
+        .iter()
+        .rev()
+        .find(|block| miners.contains(&block.author_address)),
+      None => inputs.votable_blocks.last(),
+    };
+    let Some(block) = block else {
+      let reason = match self.target_miner_addresses {
+        Some(_) => "No votable block from a target miner",
+        None => "No votable blocks",
+      };
+      return decision.skip(reason.to_string());
+    };
+    let mut decision = VoteDecision {
+      block_hash: Some(block.block_hash),
+      block_author: Some(block.author_address.clone()),
+      ..decision
+    };
+
+    if let Some(max_vote_minimum) = self.max_vote_minimum
+      && inputs.vote_minimum > max_vote_minimum as Balance
+    {
+      return decision.skip(format!(
+        "Vote minimum {} is above the max of {}",
+        inputs.vote_minimum, max_vote_minimum
+      ));
+    }
+
+    let percent = self.vote_minimum_percent.unwrap_or(100).max(100) as Balance;
+    decision.power_per_vote = (inputs.vote_minimum.saturating_mul(percent) / 100)
+      .max(inputs.minimum_vote_amount.unwrap_or_default())
+      .max(1);
+
+    let tick_budget = inputs.available_tax / self.spread_over_ticks.unwrap_or(1).max(1) as Balance;
+    let affordable_votes = tick_budget / decision.power_per_vote;
+    decision.votes = (inputs.votes_per_tick.max(1) as Balance).min(affordable_votes) as u32;
+    if decision.votes == 0 {
+      let needed = decision.power_per_vote;
+      return decision.skip(format!(
+        "Tick budget {tick_budget} is less than the {needed} needed for a vote"
+      ));
+    }
+
+    // Seal strength is a uniform proof divided by power, so the odds of holding the strongest
+    // seal are roughly our share of the voting power in the tick.
+    if let Some(competing_power) = inputs.average_tick_voting_power {
+      let power = decision.total_tax() as f64;
+      let win_chance = power / (power + competing_power as f64);
+      decision.win_chance = Some(win_chance);
+      if let Some(minimum_win_chance) = self.minimum_win_chance
+        && win_chance < minimum_win_chance
+      {
+        return decision.skip(format!(
+          "Win chance {win_chance:.6} is below the minimum of {minimum_win_chance}"
+        ));
+      }
+    }
+
+    decision
+  }
+}
+
+#[derive(FromRow, Clone)]
+#[allow(dead_code)]
+#[cfg_attr(feature = "napi", napi)]
+pub struct VoteDecisionRow {
+  pub id: i64,
+  pub account_id: i64,
+  pub tick: i64,
+  pub vote_minimum: String,
+  pub available_tax: String,
+  pub block_hash: Option<Vec<u8>>,
+  pub block_author: Option<String>,
+  pub votes: i64,
+  pub power_per_vote: String,
+  pub win_chance: Option<f64>,
+  pub skip_reason: Option<String>,
+  pub notarization_id: Option<i64>,
+  created_at: NaiveDateTime,
+}
+
+/// An audit log of the decisions made by vote strategies
+#[cfg_attr(feature = "napi", napi)]
+pub struct VoteDecisionStore {
+  db: SqlitePool,
+}
+
+impl VoteDecisionStore {
+  pub fn new(db: SqlitePool) -> Self {
+    Self { db }
+  }
+
+  /// The most recent decisions, newest first
+  pub async fn list(&self, limit: Option<i64>) -> Result<Vec<VoteDecisionRow>> {
+    let mut db = self.db.acquire().await?;
+    let limit = limit.unwrap_or(100);
+    Ok(
+      sqlx::query_as!(
+        VoteDecisionRow,
+        "SELECT * FROM vote_decisions ORDER BY id DESC LIMIT ?",
+        limit
+      )
+      .fetch_all(&mut *db)
+      .await?,
+    )
+  }
+
+  pub async fn db_insert(
+    db: &mut SqliteConnection,
+    account_id: i64,
+    decision: &VoteDecision,
+  ) -> Result<i64> {
+    let tick = decision.tick as i64;
+    let vote_minimum = decision.vote_minimum.to_string();
+    let available_tax = decision.available_tax.to_string();
+    let block_hash = decision.block_hash.map(|h| h.0.to_vec());
+    let votes = decision.votes as i64;
+    let power_per_vote = decision.power_per_vote.to_string();
+    let res = sqlx::query!(
+      "INSERT INTO vote_decisions (account_id, tick, vote_minimum, available_tax, block_hash, block_author, votes, power_per_vote, win_chance, skip_reason) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+      account_id,
+      tick,
+      vote_minimum,
+      available_tax,
+      block_hash,
+      decision.block_author,
+      votes,
+      power_per_vote,
+      decision.win_chance,
+      decision.skip_reason,
+    )
+    .execute(db)
+    .await?;
+    if res.rows_affected() != 1 {
+      bail!("Error recording vote decision");
+    }
+    Ok(res.last_insert_rowid())
+  }
+
+  pub async fn db_record_notarization(
+    db: &mut SqliteConnection,
+    id: i64,
+    notarization_id: i64,
+  ) -> Result<()> {
+    let res = sqlx::query!(
+      "UPDATE vote_decisions SET notarization_id = ? WHERE id = ?",
+      notarization_id,
+      id
+    )
+    .execute(db)
+    .await?;
+    if res.rows_affected() != 1 {
+      bail!("Error recording the notarization of vote decision {id}");
+    }
+    Ok(())
+  }
+}
+
+#[cfg(feature = "napi")]
+pub mod napi_ext {
+  use super::*;
+  use crate::error::NapiOk;
+
+  #[napi]
+  impl VoteDecisionStore {
+    #[napi(js_name = "list")]
+    pub async fn list_napi(&self, limit: Option<i64>) -> napi::Result<Vec<VoteDecisionRow>> {
+      self.list(limit).await.napi_ok()
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::AccountStore;
+  use argon_primitives::AccountType;
+  use sp_keyring::Sr25519Keyring::{Alice, Bob, Ferdie};
+
+  fn blocks() -> Vec<VotableBlock> {
+    vec![
+      VotableBlock {
+        block_hash: H256::repeat_byte(1),
+        author_address: AccountStore::to_address(&Alice.to_account_id()),
+      },
+      VotableBlock {
+        block_hash: H256::repeat_byte(2),
+        author_address: AccountStore::to_address(&Bob.to_account_id()),
+      },
+    ]
+  }
+
+  fn inputs(votable_blocks: &[VotableBlock]) -> VoteInputs<'_> {
+    VoteInputs {
+      tick: 10,
+      available_tax: 10_000,
+      vote_minimum: 1_000,
+      votes_per_tick: 4,
+      minimum_vote_amount: None,
+      votable_blocks,
+      average_tick_voting_power: Some(96_000),
+    }
+  }
+
+  #[test]
+  fn test_votes_for_the_best_block_by_default() {
+    let blocks = blocks();
+    let decision = VoteStrategy::default().decide(&inputs(&blocks));
+    assert!(decision.is_vote());
+    assert_eq!(decision.block_hash, Some(H256::repeat_byte(2)));
+    assert_eq!(decision.votes, 4);
+    assert_eq!(decision.power_per_vote, 1_000);
+    assert_eq!(decision.win_chance, Some(0.04));
+  }
+
+  #[test]
+  fn test_targets_miners() {
+    let blocks = blocks();
+    let strategy = VoteStrategy {
+      target_miner_addresses: Some(vec![AccountStore::to_address(&Alice.to_account_id())]),
+      ..Default::default()
+    };
+    let decision = strategy.decide(&inputs(&blocks));
+    assert_eq!(decision.block_hash, Some(H256::repeat_byte(1)));
+
+    let strategy = VoteStrategy {
+      target_miner_addresses: Some(vec![AccountStore::to_address(&Ferdie.to_account_id())]),
+      ..Default::default()
+    };
+    let decision = strategy.decide(&inputs(&blocks));
+    assert!(!decision.is_vote());
+    assert_eq!(decision.block_hash, None);
+  }
+
+  #[test]
+  fn test_applies_thresholds_and_spreads_tax() {
+    let blocks = blocks();
+    let strategy = VoteStrategy {
+      vote_minimum_percent: Some(200),
+      spread_over_ticks: Some(2),
+      ..Default::default()
+    };
+    let decision = strategy.decide(&inputs(&blocks));
+    assert_eq!(decision.power_per_vote, 2_000);
+    // half of the 10_000 tax is available this tick
+    assert_eq!(decision.votes, 2);
+
+    let strategy = VoteStrategy {
+      max_vote_minimum: Some(500),
+      ..Default::default()
+    };
+    let decision = strategy.decide(&inputs(&blocks));
+    assert!(!decision.is_vote());
+    assert!(decision.skip_reason.unwrap().contains("above the max"));
+
+    let strategy = VoteStrategy {
+      spread_over_ticks: Some(20),
+      ..Default::default()
+    };
+    assert!(!strategy.decide(&inputs(&blocks)).is_vote());
+  }
+
+  #[test]
+  fn test_skips_low_win_chances() {
+    let blocks = blocks();
+    let strategy = VoteStrategy {
+      minimum_win_chance: Some(0.05),
+      ..Default::default()
+    };
+    let decision = strategy.decide(&inputs(&blocks));
+    assert!(!decision.is_vote());
+    assert_eq!(decision.win_chance, Some(0.04));
+  }
+
+  #[sqlx::test]
+  async fn test_logs_decisions(pool: SqlitePool) -> anyhow::Result<()> {
+    let store = VoteDecisionStore::new(pool);
+    let mut db = store.db.acquire().await?;
+    let account = AccountStore::db_insert(
+      &mut db,
+      AccountStore::to_address(&Bob.to_account_id()),
+      AccountType::Tax,
+      1,
+      None,
+    )
+    .await?;
+    sqlx::query!(
+      "INSERT into notarizations (json, notary_id, notebook_number, tick) VALUES (?, ?, ?, ?)",
+      "{}",
+      1,
+      1,
+      1
+    )
+    .execute(&mut *db)
+    .await?;
+
+    let blocks = blocks();
+    let vote = VoteStrategy::default().decide(&inputs(&blocks));
+    let vote_id = VoteDecisionStore::db_insert(&mut db, account.id, &vote).await?;
+    VoteDecisionStore::db_record_notarization(&mut db, vote_id, 1).await?;
+    let skip = VoteStrategy {
+      max_vote_minimum: Some(1),
+      ..Default::default()
+    }
+    .decide(&inputs(&blocks));
+    VoteDecisionStore::db_insert(&mut db, account.id, &skip).await?;
+
+    let decisions = store.list(None).await?;
+    assert_eq!(decisions.len(), 2);
+    assert!(decisions[0].skip_reason.is_some());
+    assert_eq!(decisions[1].votes, 4);
+    assert_eq!(decisions[1].power_per_vote, "1000");
+    assert_eq!(decisions[1].block_hash, Some(vec![2; 32]));
+    assert_eq!(decisions[1].notarization_id, Some(1));
+    Ok(())
+  }
+}
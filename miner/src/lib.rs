@@ -0,0 +1,9 @@
+//! A standalone RandomX miner for argon compute blocks.
+//!
+//! Nodes started with `--compute-miner-listen` publish the compute puzzle of the block they're
+//! solving to external miners over a local TCP connection. Messages are newline delimited JSON
+//! (see [`protocol`]). The node sends a [`protocol::MiningJob`] whenever the block to solve
+//! changes, and miners submit the nonces that meet the job's threshold. The node verifies each
+//! nonce before sealing the block, so one node can coordinate the hashes of many machines.
+pub mod protocol;
+pub mod solver;
@@ -0,0 +1,186 @@
+use anyhow::bail;
+use argon_miner::{
+	protocol::{write_message, MinerMessage, MiningJob, NodeMessage, PROTOCOL_VERSION},
+	solver::Solver,
+};
+use argon_randomx::RandomXError;
+use clap::{Parser, ValueEnum};
+use dotenv::dotenv;
+use std::{
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc, Mutex,
+	},
+	time::Duration,
+};
+use tokio::{
+	io::{AsyncBufReadExt, BufReader},
+	net::TcpStream,
+	sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender},
+};
+use tracing::{info, warn};
+
+#[derive(Parser, Debug)]
+#[command(author, version = env!("IMPL_VERSION"), about, long_about = None)]
+struct Cli {
+	/// The external miner address of a node (started with `--compute-miner-listen`)
+	#[clap(short, long, env = "ARGON_MINER_NODE", default_value = "127.0.0.1:9950")]
+	node: String,
+
+	/// How many mining threads to run (defaults to one per cpu)
+	#[clap(short, long, env = "ARGON_MINER_THREADS")]
+	threads: Option<u32>,
+
+	/// Flags to control the randomx compute challenge. Can be specified multiple times.
+	/// - LargePages: use large memory pages for the randomx dataset (default inactive)
+	/// - Secure: use secure memory for the randomx dataset (default inactive)
+	#[arg(long, verbatim_doc_comment)]
+	compute_flags: Vec<RandomxFlag>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum RandomxFlag {
+	LargePages,
+	Secure,
+}
+
+/// The job the mining threads are working on. The version changes whenever the job does.
+#[derive(Default)]
+struct CurrentJob {
+	version: AtomicU64,
+	job: Mutex<Option<MiningJob>>,
+}
+
+impl CurrentJob {
+	fn set(&self, job: Option<MiningJob>) {
+		*self.job.lock().expect("job lock poisoned") = job;
+		self.version.fetch_add(1, Ordering::SeqCst);
+	}
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+	let _ = tracing_subscriber::FmtSubscriber::builder()
+		.with_env_filter(
+			tracing_subscriber::EnvFilter::try_from_default_env()
+				.unwrap_or(tracing_subscriber::EnvFilter::from("info")),
+		)
+		.try_init();
+	color_backtrace::install();
+	dotenv().ok();
+
+	let Cli { node, threads, compute_flags } = Cli::parse();
+	let threads = threads.unwrap_or(num_cpus::get() as u32).max(1);
+
+	let _ = argon_randomx::full_vm::set_global_config(argon_randomx::Config {
+		large_pages: compute_flags.contains(&RandomxFlag::LargePages),
+		secure: compute_flags.contains(&RandomxFlag::Secure),
+	});
+
+	let current_job = Arc::new(CurrentJob::default());
+	let hashes = Arc::new(AtomicU64::new(0));
+	let (found_tx, mut found_rx) = unbounded_channel();
+	for thread in 0..threads {
+		let current_job = current_job.clone();
+		let hashes = hashes.clone();
+		let found_tx = found_tx.clone();
+		std::thread::Builder::new()
+			.name(format!("argon-miner-{thread}"))
+			.spawn(move || mine(&current_job, &hashes, &found_tx))?;
+	}
+
+	loop {
+		if let Err(e) = connect(&node, threads, &current_job, &hashes, &mut found_rx).await {
+			warn!("Disconnected from node {node}: {e:?}");
+		}
+		current_job.set(None);
+		info!("Reconnecting to {node} in 5 seconds");
+		tokio::time::sleep(Duration::from_secs(5)).await;
+	}
+}
+
+async fn connect(
+	node: &str,
+	threads: u32,
+	current_job: &CurrentJob,
+	hashes: &AtomicU64,
+	found_rx: &mut UnboundedReceiver<MinerMessage>,
+) -> anyhow::Result<()> {
+	let stream = TcpStream::connect(node).await?;
+	info!("Connected to node {node} with {threads} mining threads");
+	let (reader, mut writer) = stream.into_split();
+	let mut lines = BufReader::new(reader).lines();
+	write_message(
+		&mut writer,
+		&MinerMessage::Hello {
+			protocol_version: PROTOCOL_VERSION,
+			agent: format!("argon-miner/{}", env!("IMPL_VERSION")),
+			threads,
+		},
+	)
+	.await?;
+
+	let report_interval = Duration::from_secs(10);
+	let mut report = tokio::time::interval(report_interval);
+	loop {
+		tokio::select! {
+			line = lines.next_line() => {
+				let Some(line) = line? else {
+					bail!("The node closed the connection");
+				};
+				match serde_json::from_str::<NodeMessage>(&line)? {
+					NodeMessage::Work(job) => {
+						info!(job_id = job.job_id, difficulty = job.difficulty, "New mining job");
+						current_job.set(Some(job));
+					},
+					NodeMessage::Idle => current_job.set(None),
+					NodeMessage::Accepted { job_id } => info!(job_id, "🔨 Solution accepted"),
+					NodeMessage::Rejected { job_id, reason } => {
+						warn!(job_id, reason, "Solution rejected")
+					},
+				}
+			},
+			Some(found) = found_rx.recv() => write_message(&mut writer, &found).await?,
+			_ = report.tick() => {
+				let count = hashes.swap(0, Ordering::Relaxed);
+				if count > 0 {
+					info!(hashes_per_second = count / report_interval.as_secs(), "Hash rate");
+					write_message(&mut writer, &MinerMessage::Hashes { count }).await?;
+				}
+			},
+		}
+	}
+}
+
+/// A mining thread. Solves the current job until it's solved or replaced.
+fn mine(current_job: &CurrentJob, hashes: &AtomicU64, found_tx: &UnboundedSender<MinerMessage>) {
+	let mut version = None;
+	let mut solver = None;
+	loop {
+		let current_version = current_job.version.load(Ordering::SeqCst);
+		if version != Some(current_version) {
+			version = Some(current_version);
+			solver = current_job.job.lock().expect("job lock poisoned").as_ref().map(Solver::new);
+		}
+		let Some(active) = solver.as_mut() else {
+			std::thread::sleep(Duration::from_millis(200));
+			continue;
+		};
+
+		hashes.fetch_add(1, Ordering::Relaxed);
+		match active.check_next() {
+			Ok(Some(nonce)) => {
+				let _ = found_tx.send(MinerMessage::Submit { job_id: active.job_id, nonce });
+				// the node seals the block with the first valid nonce
+				solver = None;
+			},
+			Ok(None) => {},
+			Err(e) => {
+				warn!("Mining failed: {e:?}");
+				if matches!(e, RandomXError::CreationError(_)) {
+					std::thread::sleep(Duration::from_secs(10));
+				}
+			},
+		}
+	}
+}
@@ -0,0 +1,99 @@
+use polkadot_sdk::*;
+use serde::{Deserialize, Serialize};
+use sp_core::{H256, U256};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+/// Bumped when a message changes incompatibly
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// The compute puzzle for a block.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MiningJob {
+	/// Changes every time the node starts solving a different block
+	pub job_id: u64,
+	/// The randomx key block hash
+	pub key_block_hash: H256,
+	/// The hash of the block being solved (without a seal)
+	#[serde(with = "sp_core::bytes")]
+	pub pre_hash: Vec<u8>,
+	/// The compute difficulty of the block
+	pub difficulty: u128,
+	/// A nonce is valid if the randomx hash of `(pre_hash, nonce)` is at or below the threshold
+	pub threshold: U256,
+}
+
+impl MiningJob {
+	pub fn new(job_id: u64, key_block_hash: H256, pre_hash: Vec<u8>, difficulty: u128) -> Self {
+		Self {
+			job_id,
+			key_block_hash,
+			pre_hash,
+			difficulty,
+			threshold: crate::solver::threshold(difficulty),
+		}
+	}
+}
+
+/// Messages sent from the node to a miner
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum NodeMessage {
+	/// A block to solve. Replaces any previous job.
+	Work(MiningJob),
+	/// There's no block to solve right now. Miners should stop hashing.
+	Idle,
+	/// A nonce was valid and was submitted to seal the block
+	#[serde(rename_all = "camelCase")]
+	Accepted { job_id: u64 },
+	/// A nonce was stale or invalid
+	#[serde(rename_all = "camelCase")]
+	Rejected { job_id: u64, reason: String },
+}
+
+/// Messages sent from a miner to the node
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum MinerMessage {
+	/// The first message on a connection
+	#[serde(rename_all = "camelCase")]
+	Hello { protocol_version: u32, agent: String, threads: u32 },
+	/// A nonce that meets the threshold of a job
+	#[serde(rename_all = "camelCase")]
+	Submit { job_id: u64, nonce: U256 },
+	/// The number of hashes checked since the last report
+	#[serde(rename_all = "camelCase")]
+	Hashes { count: u64 },
+}
+
+/// Write a message as a single line of JSON
+pub async fn write_message<W, T>(writer: &mut W, message: &T) -> std::io::Result<()>
+where
+	W: AsyncWrite + Unpin,
+	T: Serialize,
+{
+	let mut line = serde_json::to_vec(message).map_err(std::io::Error::other)?;
+	line.push(b'\n');
+	writer.write_all(&line).await?;
+	writer.flush().await
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn uses_tagged_json_lines() {
+		let job = MiningJob::new(2, H256::repeat_byte(1), vec![1, 2, 3], 1);
+		let json = serde_json::to_value(NodeMessage::Work(job.clone())).unwrap();
+		assert_eq!(json["type"], "work");
+		assert_eq!(json["jobId"], 2);
+		assert_eq!(json["preHash"], "0x010203");
+		assert_eq!(json["threshold"], serde_json::to_value(U256::MAX).unwrap());
+		assert_eq!(serde_json::from_value::<NodeMessage>(json).unwrap(), NodeMessage::Work(job));
+
+		let submit: MinerMessage =
+			serde_json::from_str(r#"{"type":"submit","jobId":2,"nonce":"0x10"}"#).unwrap();
+		assert_eq!(submit, MinerMessage::Submit { job_id: 2, nonce: U256::from(16) });
+	}
+}
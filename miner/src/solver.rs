@@ -0,0 +1,80 @@
+use crate::protocol::MiningJob;
+use argon_randomx::{calculate_mining_hash, RandomXError};
+use codec::Encode;
+use polkadot_sdk::*;
+use rand::RngExt;
+use sp_core::{H256, U256};
+
+/// The highest hash that solves a puzzle of the given difficulty
+pub fn threshold(difficulty: u128) -> U256 {
+	U256::MAX / U256::from(difficulty).max(U256::one())
+}
+
+pub fn meets_threshold(hash: &[u8; 32], threshold: U256) -> bool {
+	U256::from_big_endian(hash) <= threshold
+}
+
+/// Checks nonces for a job, starting from a random nonce so miners don't overlap.
+///
+/// The hashed payload is the SCALE encoding of `(pre_hash, nonce)`, the same as the node's
+/// `BlockComputeNonce`.
+pub struct Solver {
+	pub job_id: u64,
+	pub nonce: U256,
+	payload: Vec<u8>,
+	threshold: U256,
+	key_block_hash: H256,
+}
+
+impl Solver {
+	pub fn new(job: &MiningJob) -> Self {
+		let mut bytes = [0u8; 32];
+		rand::rng().fill(&mut bytes);
+		let nonce = U256::from_big_endian(&bytes[..]);
+		Self {
+			job_id: job.job_id,
+			nonce,
+			payload: (&job.pre_hash, nonce).encode(),
+			threshold: job.threshold,
+			key_block_hash: job.key_block_hash,
+		}
+	}
+
+	/// Check the next nonce. Returns the nonce if it solves the job.
+	pub fn check_next(&mut self) -> Result<Option<U256>, RandomXError> {
+		self.nonce = self.nonce.checked_add(U256::one()).unwrap_or_default();
+		let nonce_bytes = self.nonce.encode();
+		let start = self.payload.len() - nonce_bytes.len();
+		self.payload.splice(start.., nonce_bytes);
+
+		let hash = calculate_mining_hash(&self.key_block_hash, &self.payload)?;
+		if meets_threshold(hash.as_fixed_bytes(), self.threshold) {
+			return Ok(Some(self.nonce));
+		}
+		Ok(None)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn encodes_the_nonce_after_the_pre_hash() {
+		let job = MiningJob::new(1, H256::repeat_byte(1), vec![2u8; 32], 1);
+		let mut solver = Solver::new(&job);
+		let solved = solver.check_next().expect("randomx hash");
+		// every hash meets a difficulty of 1
+		assert_eq!(solved, Some(solver.nonce));
+		assert_eq!(solver.payload, (job.pre_hash, solver.nonce).encode());
+	}
+
+	#[test]
+	fn scales_the_threshold_by_difficulty() {
+		assert_eq!(threshold(0), U256::MAX);
+		assert_eq!(threshold(1), U256::MAX);
+		assert_eq!(threshold(2), U256::MAX / 2);
+		assert!(meets_threshold(&[0u8; 32], threshold(u128::MAX)));
+		assert!(!meets_threshold(&[255u8; 32], threshold(2)));
+	}
+}
@@ -3,15 +3,15 @@
 mod metrics;
 
 use anyhow::ensure;
-use argon_bitcoin::{BlockFilter, UtxoSpendFilter};
+use argon_bitcoin::{BlockFilter, P2pBlockFilterSource, UtxoSpendFilter};
 use argon_primitives::{
-	bitcoin::{BitcoinSyncStatus, Satoshis, UtxoAddress, UtxoRef},
+	bitcoin::{BitcoinNetwork, BitcoinSyncStatus, Satoshis, UtxoAddress, UtxoRef},
 	inherents::BitcoinUtxoSync,
 	prelude::sp_api::ApiExt,
 	Balance, BitcoinApis,
 };
 use codec::{Decode, Encode};
-use log::info;
+use log::{info, warn};
 pub use metrics::BitcoinMetrics;
 use parking_lot::Mutex;
 use polkadot_sdk::*;
@@ -69,9 +69,48 @@ where
 	Ok(Some(result))
 }
 
+/// Checks the runtime's confirmed bitcoin tip against the local bitcoin node. Does nothing unless
+/// the tracker was created with tip verification. A tip that can't be checked yet (eg, the local
+/// node is behind) is logged and allowed.
+pub fn verify_bitcoin_tip<C, B>(
+	tracker: &Arc<UtxoTracker>,
+	client: &Arc<C>,
+	block_hash: &B::Hash,
+) -> anyhow::Result<()>
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B> + 'static,
+	C::Api: BitcoinApis<B, Balance>,
+{
+	if !tracker.verify_confirmed_tip {
+		return Ok(());
+	}
+	let Some(sync_status) = client.runtime_api().get_sync_status(*block_hash)? else {
+		return Ok(());
+	};
+	let confirmed_block = sync_status.confirmed_block;
+	match tracker.filter.lock().is_best_chain_block(&confirmed_block)? {
+		Some(true) => Ok(()),
+		Some(false) => Err(anyhow::anyhow!(
+			"The confirmed bitcoin tip {:?} at height {} is not in the local bitcoin node's best chain",
+			confirmed_block.block_hash,
+			confirmed_block.block_height
+		)),
+		None => {
+			warn!(
+				target: "node::bitcoin_utxo_tracker",
+				"Unable to verify the confirmed bitcoin tip at height {} against the local bitcoin node",
+				confirmed_block.block_height
+			);
+			Ok(())
+		},
+	}
+}
+
 pub struct UtxoTracker {
 	pub(crate) filter: Arc<Mutex<UtxoSpendFilter>>,
 	metrics: Option<BitcoinMetrics>,
+	verify_confirmed_tip: bool,
 }
 
 impl UtxoTracker {
@@ -81,14 +120,33 @@ impl UtxoTracker {
 		registry: Option<&Registry>,
 	) -> anyhow::Result<Self> {
 		let filter = UtxoSpendFilter::new(rpc_url, auth)?;
+		Ok(Self::with_filter(filter, registry))
+	}
+
+	/// Tracks utxos using compact block filters served by a bitcoin peer (BIP157) instead of a
+	/// full node's rpc
+	pub fn from_p2p_peer(
+		peer: &str,
+		network: BitcoinNetwork,
+		registry: Option<&Registry>,
+	) -> anyhow::Result<Self> {
+		let source = P2pBlockFilterSource::new(peer, network)?;
+		Ok(Self::with_filter(UtxoSpendFilter::with_source(source), registry))
+	}
+
+	fn with_filter(filter: UtxoSpendFilter, registry: Option<&Registry>) -> Self {
 		let metrics = registry.and_then(|a| BitcoinMetrics::new(a).ok());
-		Ok(Self { filter: Arc::new(Mutex::new(filter)), metrics })
+		Self { filter: Arc::new(Mutex::new(filter)), metrics, verify_confirmed_tip: false }
 	}
 
-	pub fn ensure_correct_network(
-		&self,
-		network: argon_primitives::bitcoin::BitcoinNetwork,
-	) -> anyhow::Result<()> {
+	/// Cross-check the runtime's confirmed bitcoin tip against the bitcoin source before creating
+	/// bitcoin inherents for a new block (see [`verify_bitcoin_tip`])
+	pub fn with_tip_verification(mut self, verify_confirmed_tip: bool) -> Self {
+		self.verify_confirmed_tip = verify_confirmed_tip;
+		self
+	}
+
+	pub fn ensure_correct_network(&self, network: BitcoinNetwork) -> anyhow::Result<()> {
 		let filter = self.filter.lock();
 		let connected_network = filter.get_network()?;
 		ensure!(
@@ -248,6 +306,25 @@ mod test {
 		drop(bitcoind);
 	}
 
+	#[test]
+	fn can_verify_the_confirmed_tip_against_bitcoind() {
+		let (bitcoind, tracker, _block_address, _network) = start_bitcoind();
+		let block_height = bitcoind.client.get_block_count().unwrap();
+		let best = bitcoind.client.get_best_block_hash().unwrap();
+		let filter = tracker.filter.lock();
+
+		let confirmed = BitcoinBlock { block_hash: H256Le(best.to_byte_array()), block_height };
+		assert_eq!(filter.is_best_chain_block(&confirmed).unwrap(), Some(true));
+
+		let forked = BitcoinBlock { block_hash: H256Le([1; 32]), block_height };
+		assert_eq!(filter.is_best_chain_block(&forked).unwrap(), Some(false));
+
+		let ahead = BitcoinBlock { block_hash: H256Le([1; 32]), block_height: block_height + 1 };
+		assert_eq!(filter.is_best_chain_block(&ahead).unwrap(), None);
+		drop(filter);
+		drop(bitcoind);
+	}
+
 	lazy_static! {
 		static ref BITCOIND_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
 	}
@@ -1,16 +1,18 @@
 use crate::{
 	aux_client::ArgonAux, error::Error, metrics::ConsensusMetrics,
-	notary_client::get_notebook_header_data,
+	notary_client::get_notebook_header_data, notebook_sealer::SealRejection,
 };
-use argon_bitcoin_utxo_tracker::{get_bitcoin_inherent, UtxoTracker};
+use argon_bitcoin_utxo_tracker::{get_bitcoin_inherent, verify_bitcoin_tip, UtxoTracker};
 use argon_primitives::{
+	fork_power::ForkPower,
 	inherents::{
 		BitcoinInherentDataProvider, BlockSealInherentDataProvider, BlockSealInherentNodeSide,
 		NotebooksInherentDataProvider,
 	},
 	tick::{Tick, TickDigest, Ticker},
 	Balance, BestBlockVoteSeal, BitcoinApis, BlockSealApis, BlockSealAuthorityId, BlockSealDigest,
-	Digestset, NotaryApis, NotebookApis, TickApis, VotingSchedule,
+	BlockVotingPower, Digestset, NotaryApis, NotebookApis, NotebookAuditResult, TickApis,
+	VotingSchedule,
 };
 use argon_runtime::{NotaryRecordT, NotebookVerifyError};
 use codec::{Codec, MaxEncodedLen};
@@ -56,6 +58,26 @@ pub struct BlockCreator<Block: BlockT, BI: Clone, Client: AuxStore, PF, JS: Clon
 	pub(crate) metrics: Arc<Option<ConsensusMetrics<Client>>>,
 }
 
+/// The vote block a node would propose for a notebook tick, assembled without being submitted
+#[derive(Debug, Clone)]
+pub struct BlockProposalDryRun<Hash, AccountId: Codec> {
+	pub notebook_tick: Tick,
+	/// The tick the block would be created in
+	pub block_tick: Tick,
+	/// The block it would be built on
+	pub parent_hash: Option<Hash>,
+	/// The strongest vote seal the local keys can create
+	pub vote_seal: Option<BestBlockVoteSeal<AccountId, BlockSealAuthorityId>>,
+	/// The notebooks that would be included
+	pub notebooks: Vec<NotebookAuditResult<NotebookVerifyError>>,
+	/// The voting power of the included notebooks
+	pub voting_power: BlockVotingPower,
+	/// The fork power the block would have with the chosen seal
+	pub fork_power: Option<ForkPower>,
+	/// Each reason the tick or a candidate block was not used
+	pub rejections: Vec<SealRejection<Hash>>,
+}
+
 pub struct ProposalMeta {
 	pub notebooks: u32,
 	pub tick: Tick,
@@ -176,13 +198,22 @@ where
 				);
 			})?;
 
+		// An unverified bitcoin tip only leaves out the bitcoin sync. The block is still proposed.
 		let bitcoin_utxo_sync =
-			get_bitcoin_inherent(&self.utxo_tracker, &self.client, &parent_hash).unwrap_or_else(
-				|err| {
-					tracing::warn!(?err, "Unable to get bitcoin inherent");
+			match verify_bitcoin_tip(&self.utxo_tracker, &self.client, &parent_hash) {
+				Ok(()) => get_bitcoin_inherent(&self.utxo_tracker, &self.client, &parent_hash)
+					.unwrap_or_else(|err| {
+						tracing::warn!(?err, "Unable to get bitcoin inherent");
+						None
+					}),
+				Err(err) => {
+					tracing::warn!(
+						?err,
+						"Not including a bitcoin inherent for an unverified bitcoin tip"
+					);
 					None
 				},
-			);
+			};
 		BitcoinInherentDataProvider { bitcoin_utxo_sync }
 			.provide_inherent_data(&mut inherent_data)
 			.await
@@ -1,7 +1,8 @@
 use crate::{
 	block_creator::BlockProposal, error::Error, metrics::ConsensusMetrics,
-	notary_client::VotingPowerInfo,
+	notary_client::VotingPowerInfo, notebook_sealer::SealRejection,
 };
+use argon_miner::protocol::MiningJob;
 use argon_primitives::{
 	block_seal::ComputePuzzle,
 	prelude::*,
@@ -12,7 +13,7 @@ use argon_primitives::{
 use argon_randomx::{calculate_hash, calculate_mining_hash, RandomXError};
 use argon_runtime::NotebookVerifyError;
 use codec::{Codec, Encode};
-use frame_support::CloneNoBound;
+use frame_support::{CloneNoBound, DefaultNoBound};
 use futures::prelude::*;
 use log::*;
 use parking_lot::Mutex;
@@ -143,6 +144,21 @@ where
 
 		*self.solving_block.lock() =
 			Some(SolvingBlock { pre_hash, difficulty, proposal, key_block_hash });
+		// solvers (and external miners) need to pick up the new pre-hash
+		self.increment_version();
+	}
+
+	/// The puzzle of the block being solved, as published to external miners
+	pub fn mining_job(&self) -> Option<MiningJob> {
+		let solving_block = self.solving_block.lock();
+		let version = self.version();
+		let build = solving_block.as_ref()?;
+		Some(MiningJob::new(
+			version as u64,
+			build.key_block_hash,
+			build.pre_hash.as_ref().to_vec(),
+			build.difficulty,
+		))
 	}
 
 	pub fn is_valid_solver(&self, solver: &Option<Box<ComputeSolver>>) -> bool {
@@ -223,6 +239,38 @@ where
 	}
 }
 
+/// Lets the block proposal dry run see the compute worker, which only exists once block authoring
+/// has started.
+#[derive(CloneNoBound, DefaultNoBound)]
+pub struct SharedComputeHandle<B: BlockT> {
+	worker: Arc<Mutex<Option<(ComputeHandle<B>, bool)>>>,
+}
+
+impl<B: BlockT> SharedComputeHandle<B> {
+	pub(crate) fn set(&self, compute_handle: ComputeHandle<B>, has_solvers: bool) {
+		*self.worker.lock() = Some((compute_handle, has_solvers));
+	}
+
+	/// Why the compute worker would not solve a block at this tick, if anything
+	pub(crate) fn rejection<Hash>(
+		&self,
+		current_tick: Tick,
+		now_millis: u64,
+	) -> Option<SealRejection<Hash>> {
+		let worker = self.worker.lock();
+		let Some((compute_handle, has_solvers)) = worker.as_ref() else {
+			return Some(SealRejection::ComputeNotRunning);
+		};
+		if !has_solvers {
+			return Some(SealRejection::NoComputeSolvers);
+		}
+		if !compute_handle.ready_to_solve(current_tick, now_millis) {
+			return Some(SealRejection::ComputeNotReadyToSolve { current_tick });
+		}
+		None
+	}
+}
+
 #[derive(Clone, Eq, PartialEq, Encode)]
 pub struct BlockComputeNonce {
 	pub pre_hash: Vec<u8>,
@@ -242,26 +290,18 @@ impl BlockComputeNonce {
 		U256::MAX / U256::from(difficulty).max(U256::one())
 	}
 
+	/// Errors are this node's RandomX failures, so callers must not treat them as an invalid
+	/// nonce from a peer or miner.
 	pub fn is_valid(
 		nonce: &U256,
 		pre_hash: Vec<u8>,
 		key_block_hash: &H256,
 		compute_difficulty: ComputeDifficulty,
-	) -> bool {
-		let hash =
-			Self { nonce: *nonce, pre_hash }.using_encoded(|x| calculate_hash(key_block_hash, x));
-		let hash = match hash {
-			Ok(hash) => hash,
-			Err(e) => {
-				// Local verifier failure must be treated as node-fatal to avoid classifying a
-				// peer's block as invalid due to this node's RandomX/runtime issue.
-				panic!(
-					"Local RandomX error while verifying compute nonce; refusing to mark peer block invalid: {e:?}"
-				);
-			},
-		};
+	) -> Result<bool, RandomXError> {
+		let hash = Self { nonce: *nonce, pre_hash }
+			.using_encoded(|x| calculate_hash(key_block_hash, x))?;
 		let threshold = Self::threshold(compute_difficulty);
-		Self::meets_threshold(hash.as_fixed_bytes(), threshold)
+		Ok(Self::meets_threshold(hash.as_fixed_bytes(), threshold))
 	}
 }
 
@@ -611,14 +651,16 @@ mod tests {
 
 		let key_block_hash = H256::from_slice(&[1u8; 32]);
 
-		assert!(BlockComputeNonce::is_valid(&U256::from(1), bytes.to_vec(), &key_block_hash, 1));
+		assert_eq!(
+			BlockComputeNonce::is_valid(&U256::from(1), bytes.to_vec(), &key_block_hash, 1).ok(),
+			Some(true)
+		);
 
-		assert!(!BlockComputeNonce::is_valid(
-			&U256::from(1),
-			bytes.to_vec(),
-			&key_block_hash,
-			10_000
-		));
+		assert_eq!(
+			BlockComputeNonce::is_valid(&U256::from(1), bytes.to_vec(), &key_block_hash, 10_000)
+				.ok(),
+			Some(false)
+		);
 	}
 
 	#[test]
@@ -636,13 +678,14 @@ mod tests {
 
 			assert_eq!(solver.wip_nonce_hash, solver.wip_nonce.encode());
 			assert_eq!(
-				did_solve,
+				Some(did_solve),
 				BlockComputeNonce::is_valid(
 					&solver.wip_nonce.nonce,
 					pre_hash.clone(),
 					&key_block_hash,
 					1
 				)
+				.ok()
 			);
 		}
 	}
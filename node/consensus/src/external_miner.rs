@@ -0,0 +1,251 @@
+use crate::{
+	compute_worker::{BlockComputeNonce, ComputeHandle},
+	metrics::ConsensusMetrics,
+};
+use argon_miner::protocol::{
+	write_message, MinerMessage, MiningJob, NodeMessage, PROTOCOL_VERSION,
+};
+use log::*;
+use polkadot_sdk::*;
+use sc_client_api::AuxStore;
+use sc_service::{SpawnTaskHandle, TaskManager};
+use sp_runtime::traits::Block as BlockT;
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+use tokio::{
+	io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, BufReader},
+	net::{TcpListener, TcpStream},
+};
+
+/// The longest message (in bytes, including the newline) an external miner can send
+const MAX_MINER_MESSAGE_LENGTH: usize = 4 * 1024;
+
+/// Serves the compute puzzle of the block being solved to external miners (eg, `argon-miner`).
+///
+/// Each connection is sent a [`MiningJob`] whenever the block being solved changes (or `Idle`
+/// when there's nothing to solve). Submitted nonces are verified with
+/// [`BlockComputeNonce::is_valid`] before they're handed to the compute handle, so an invalid
+/// nonce never reaches block import.
+pub fn run_external_miner_server<B, C>(
+	task_manager: &TaskManager,
+	worker: ComputeHandle<B>,
+	listen: SocketAddr,
+	consensus_metrics: Arc<Option<ConsensusMetrics<C>>>,
+) where
+	B: BlockT,
+	C: AuxStore + Send + Sync + 'static,
+{
+	let spawn_handle = task_manager.spawn_handle();
+	let connections = spawn_handle.clone();
+	spawn_handle.spawn("external-miner-server", Some("block-authoring"), async move {
+		let listener = match TcpListener::bind(listen).await {
+			Ok(listener) => listener,
+			Err(e) => {
+				error!("Unable to listen for external miners on {listen}: {e:?}");
+				return;
+			},
+		};
+		info!("Listening for external miners on {listen}");
+		loop {
+			let (stream, peer) = match listener.accept().await {
+				Ok(x) => x,
+				Err(e) => {
+					warn!("Error accepting external miner connection: {e:?}");
+					continue;
+				},
+			};
+			spawn_miner_connection(&connections, stream, peer, worker.clone(), &consensus_metrics);
+		}
+	});
+}
+
+fn spawn_miner_connection<B, C>(
+	spawn_handle: &SpawnTaskHandle,
+	stream: TcpStream,
+	peer: SocketAddr,
+	worker: ComputeHandle<B>,
+	consensus_metrics: &Arc<Option<ConsensusMetrics<C>>>,
+) where
+	B: BlockT,
+	C: AuxStore + Send + Sync + 'static,
+{
+	let consensus_metrics = consensus_metrics.clone();
+	spawn_handle.spawn("external-miner", Some("block-authoring"), async move {
+		match serve_miner(stream, &worker, &consensus_metrics).await {
+			Ok(()) => info!("External miner {peer} disconnected"),
+			Err(e) => warn!("External miner {peer} disconnected with error: {e:?}"),
+		}
+	});
+}
+
+async fn serve_miner<B, C>(
+	stream: TcpStream,
+	worker: &ComputeHandle<B>,
+	consensus_metrics: &Option<ConsensusMetrics<C>>,
+) -> std::io::Result<()>
+where
+	B: BlockT,
+	C: AuxStore + Send + Sync + 'static,
+{
+	let peer = stream.peer_addr()?;
+	let (reader, mut writer) = stream.into_split();
+	let mut reader = BufReader::new(reader);
+	let mut line = Vec::new();
+	// work is sent once the miner says hello
+	let mut is_subscribed = false;
+	let mut sent_job: Option<Option<MiningJob>> = None;
+	let mut poll = tokio::time::interval(Duration::from_millis(250));
+	loop {
+		tokio::select! {
+			next = next_line(&mut reader, &mut line) => {
+				let Some(message_line) = next? else {
+					return Ok(());
+				};
+				let message = match serde_json::from_slice::<MinerMessage>(&message_line) {
+					Ok(message) => message,
+					Err(e) => {
+						warn!("Invalid message from external miner {peer}: {e:?}");
+						continue;
+					},
+				};
+				match message {
+					MinerMessage::Hello { protocol_version, agent, threads } => {
+						if protocol_version != PROTOCOL_VERSION {
+							return Err(std::io::Error::other(format!(
+								"Unsupported miner protocol version {protocol_version}"
+							)));
+						}
+						info!("External miner {peer} connected ({agent} with {threads} threads)");
+						is_subscribed = true;
+						sent_job = None;
+						send_current_job(&mut writer, worker, &mut sent_job).await?;
+					},
+					MinerMessage::Submit { job_id, nonce } => {
+						let response = submit_nonce(worker, job_id, nonce).await;
+						write_message(&mut writer, &response).await?;
+					},
+					MinerMessage::Hashes { count } => {
+						if let Some(metrics) = consensus_metrics.as_ref() {
+							metrics.record_compute_hashes(count);
+						}
+					},
+				}
+			},
+			_ = poll.tick() => {
+				if is_subscribed {
+					send_current_job(&mut writer, worker, &mut sent_job).await?;
+				}
+			},
+		}
+	}
+}
+
+/// Reads the next newline terminated message, failing if it's longer than
+/// [`MAX_MINER_MESSAGE_LENGTH`]. Returns None once the miner disconnects. Partially read messages
+/// are kept in `line`, so this can be cancelled and called again.
+async fn next_line<R: AsyncBufRead + Unpin>(
+	reader: &mut R,
+	line: &mut Vec<u8>,
+) -> std::io::Result<Option<Vec<u8>>> {
+	let limit = (MAX_MINER_MESSAGE_LENGTH + 1).saturating_sub(line.len());
+	reader.take(limit as u64).read_until(b'\n', line).await?;
+	if line.last() != Some(&b'\n') {
+		if line.len() > MAX_MINER_MESSAGE_LENGTH {
+			return Err(std::io::Error::other(format!(
+				"Miner message is longer than {MAX_MINER_MESSAGE_LENGTH} bytes"
+			)));
+		}
+		if line.is_empty() {
+			return Ok(None);
+		}
+	}
+	Ok(Some(std::mem::take(line)))
+}
+
+async fn send_current_job<B: BlockT>(
+	writer: &mut tokio::net::tcp::OwnedWriteHalf,
+	worker: &ComputeHandle<B>,
+	sent_job: &mut Option<Option<MiningJob>>,
+) -> std::io::Result<()> {
+	let job = worker.mining_job();
+	if sent_job.as_ref() == Some(&job) {
+		return Ok(());
+	}
+	let message = match &job {
+		Some(job) => NodeMessage::Work(job.clone()),
+		None => NodeMessage::Idle,
+	};
+	write_message(writer, &message).await?;
+	*sent_job = Some(job);
+	Ok(())
+}
+
+async fn submit_nonce<B: BlockT>(
+	worker: &ComputeHandle<B>,
+	job_id: u64,
+	nonce: sp_core::U256,
+) -> NodeMessage {
+	let Some(job) = worker.mining_job().filter(|x| x.job_id == job_id) else {
+		return NodeMessage::Rejected { job_id, reason: "Stale job".to_string() };
+	};
+	let (pre_hash, key_block_hash, difficulty) =
+		(job.pre_hash.clone(), job.key_block_hash, job.difficulty);
+	// randomx verification is cpu bound
+	let is_valid = match tokio::task::spawn_blocking(move || {
+		BlockComputeNonce::is_valid(&nonce, pre_hash, &key_block_hash, difficulty)
+	})
+	.await
+	{
+		Ok(Ok(is_valid)) => is_valid,
+		Ok(Err(e)) => {
+			error!("Local RandomX error verifying an external miner nonce: {e:?}");
+			return NodeMessage::Rejected { job_id, reason: "Unable to verify nonce".to_string() };
+		},
+		Err(e) => {
+			error!("Error verifying an external miner nonce: {e:?}");
+			return NodeMessage::Rejected { job_id, reason: "Unable to verify nonce".to_string() };
+		},
+	};
+	if !is_valid {
+		return NodeMessage::Rejected {
+			job_id,
+			reason: "Nonce does not meet the difficulty".to_string(),
+		};
+	}
+	worker.submit(BlockComputeNonce { pre_hash: job.pre_hash, nonce }, &job.key_block_hash);
+	NodeMessage::Accepted { job_id }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use argon_miner::solver::Solver;
+	use sp_core::H256;
+
+	#[test]
+	fn external_miner_nonces_verify() {
+		let job = MiningJob::new(1, H256::repeat_byte(1), vec![2u8; 32], 1);
+		let mut solver = Solver::new(&job);
+		let nonce = solver.check_next().expect("randomx hash").expect("difficulty 1 is solved");
+		assert_eq!(
+			BlockComputeNonce::is_valid(&nonce, job.pre_hash.clone(), &job.key_block_hash, 1).ok(),
+			Some(true)
+		);
+		assert_eq!(job.threshold, BlockComputeNonce::threshold(job.difficulty));
+	}
+
+	#[tokio::test]
+	async fn rejects_miner_messages_over_the_max_length() {
+		let mut line = Vec::new();
+		let mut reader = BufReader::new(&b"{\"type\":\"hashes\",\"count\":1}\n"[..]);
+		let message = next_line(&mut reader, &mut line).await.unwrap().expect("a message");
+		assert!(matches!(
+			serde_json::from_slice::<MinerMessage>(&message),
+			Ok(MinerMessage::Hashes { count: 1 })
+		));
+		assert!(next_line(&mut reader, &mut line).await.unwrap().is_none());
+
+		let long = vec![b'a'; MAX_MINER_MESSAGE_LENGTH * 2];
+		let mut reader = BufReader::new(&long[..]);
+		assert!(next_line(&mut reader, &mut line).await.is_err());
+	}
+}
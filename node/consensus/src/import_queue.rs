@@ -742,12 +742,21 @@ where
 				let compute_difficulty = compute_puzzle.difficulty;
 
 				tracing::info!(?key_block_hash, ?compute_difficulty, ?nonce, block_hash=?post_hash, "Verifying compute nonce");
-				if !BlockComputeNonce::is_valid(
+				let is_valid = BlockComputeNonce::is_valid(
 					nonce,
 					pre_hash.as_ref().to_vec(),
 					&key_block_hash,
 					compute_difficulty,
-				) {
+				)
+				.unwrap_or_else(|e| {
+					// Local verifier failure must be treated as node-fatal. Any verification error
+					// is reported as an invalid block and penalizes the peer, but this is this
+					// node's RandomX/runtime issue.
+					panic!(
+						"Local RandomX error while verifying compute nonce; refusing to mark peer block invalid: {e:?}"
+					)
+				});
+				if !is_valid {
 					tracing::warn!(?key_block_hash, ?compute_difficulty, ?nonce, block_hash=?post_hash, pre_hash=?pre_hash, "Invalid compute nonce!");
 					return Err(Error::InvalidComputeNonce.into());
 				}
@@ -2,6 +2,7 @@ use crate::{
 	aux_client::ArgonAux,
 	block_creator::BlockCreator,
 	compute_worker::{run_compute_solver_threads, ComputeHandle},
+	external_miner::run_external_miner_server,
 	notary_client::VotingPowerInfo,
 	notebook_sealer::NotebookSealer,
 };
@@ -27,7 +28,7 @@ use sp_blockchain::HeaderBackend;
 use sp_consensus::{BlockOrigin, Environment, SelectChain, SyncOracle};
 use sp_keystore::{Keystore, KeystorePtr};
 use sp_runtime::traits::{Block as BlockT, Header};
-use std::{collections::HashSet, sync::Arc, time::Duration};
+use std::{collections::HashSet, net::SocketAddr, sync::Arc, time::Duration};
 use tokio::{sync::Mutex, time, time::Instant};
 use tracing::{debug, info, trace, warn};
 
@@ -40,14 +41,16 @@ pub(crate) mod mock_notary;
 
 pub mod aux_client;
 mod aux_data;
-pub(crate) mod block_creator;
+pub mod block_creator;
 pub(crate) mod compute_worker;
 pub mod error;
+pub(crate) mod external_miner;
 pub mod grandpa_hard_forks;
 pub mod import_queue;
 pub(crate) mod metrics;
 pub(crate) mod notary_client;
-pub(crate) mod notebook_sealer;
+pub mod notebook_archive_mirror;
+pub mod notebook_sealer;
 pub mod state_anchor;
 
 pub use notary_client::{run_notary_sync, NotaryClient, NotebookDownloader};
@@ -58,6 +61,7 @@ pub use state_anchor::{
 };
 
 use crate::{compute_worker::ComputeState, notebook_sealer::create_vote_seal};
+pub use compute_worker::SharedComputeHandle;
 pub use import_queue::create_import_queue;
 
 pub struct BlockBuilderParams<
@@ -99,6 +103,10 @@ pub struct BlockBuilderParams<
 	pub select_chain: SC,
 	/// How many mining threads to activate
 	pub compute_threads: u32,
+	/// The address to serve compute puzzles to external miners on
+	pub compute_miner_listen: Option<SocketAddr>,
+	/// Shares the compute worker with the block proposal dry run
+	pub compute_handle: SharedComputeHandle<Block>,
 
 	/// A notary client to verify notebooks
 	pub notary_client: Arc<NotaryClient<Block, Client, A>>,
@@ -154,6 +162,8 @@ pub fn run_block_builder_task<Block, BI, C, PF, A, SC, SO, JS, B>(
 		select_chain,
 		justification_sync_link,
 		compute_threads,
+		compute_miner_listen,
+		compute_handle: shared_compute_handle,
 	} = params;
 
 	let consensus_metrics = notary_client.metrics.clone();
@@ -171,6 +181,10 @@ pub fn run_block_builder_task<Block, BI, C, PF, A, SC, SO, JS, B>(
 	};
 
 	let compute_handle = ComputeHandle::new(compute_block_tx);
+	if compute_author.is_some() {
+		shared_compute_handle
+			.set(compute_handle.clone(), compute_threads > 0 || compute_miner_listen.is_some());
+	}
 
 	if compute_threads > 0 {
 		run_compute_solver_threads(
@@ -181,6 +195,15 @@ pub fn run_block_builder_task<Block, BI, C, PF, A, SC, SO, JS, B>(
 		)
 	}
 
+	if let Some(listen) = compute_miner_listen {
+		run_external_miner_server(
+			task_manager,
+			compute_handle.clone(),
+			listen,
+			consensus_metrics.clone(),
+		);
+	}
+
 	let notebook_sealer = NotebookSealer::new(
 		client.clone(),
 		ticker,
@@ -245,7 +268,7 @@ pub fn run_block_builder_task<Block, BI, C, PF, A, SC, SO, JS, B>(
 		}
 	};
 
-	let is_compute_enabled = compute_threads > 0;
+	let is_compute_enabled = compute_threads > 0 || compute_miner_listen.is_some();
 	let consensus_metrics_finder = consensus_metrics.clone();
 
 	let block_finder_task = async move {
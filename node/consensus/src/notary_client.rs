@@ -2,6 +2,7 @@ use crate::{
 	aux_client::ArgonAux,
 	error::Error,
 	metrics::ConsensusMetrics,
+	notebook_archive_mirror::NotebookArchiveMirror,
 	state_anchor::{
 		resolve_best_or_finalized_state_hash, resolve_stateful_hash,
 		ResolveBestOrFinalizedStateHashError, StateAnchorClient, DEFAULT_STATE_LOOKBACK_DEPTH,
@@ -985,6 +986,11 @@ where
 		);
 
 		let audit_result = self.audit_notebook(&best_hash, &notebook_details).await?;
+		if audit_result.audit_first_failure.is_none() {
+			self.notebook_downloader
+				.mirror(DownloadKind::Header, notary_id, notebook_number, &raw_header.0)
+				.await;
+		}
 		let runtime_tick = self.client.current_tick(best_hash)?;
 		let voting_power = self.aux_client.store_notebook_result(
 			audit_result,
@@ -1181,6 +1187,9 @@ where
 			Ok(votes) => {
 				let vote_count = votes.raw_votes.len();
 				self.aux_client.store_votes(tick, votes)?;
+				self.notebook_downloader
+					.mirror(DownloadKind::Notebook, notary_id, notebook_number, &full_notebook.0)
+					.await;
 
 				tracing::info!(
 					notary_id,
@@ -1378,6 +1387,8 @@ pub struct NotebookDownloader {
 	pub trust_mode: DownloadTrustMode,
 	pub header_max_bytes: Option<u64>,
 	pub notebook_max_bytes: Option<u64>,
+	/// Where audited notebooks and headers are persisted so they can be served to other nodes
+	pub archive_mirror: Option<Arc<NotebookArchiveMirror>>,
 }
 
 const DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(60);
@@ -1398,7 +1409,35 @@ impl NotebookDownloader {
 			.map(|host| ArchiveHost::new(host.as_ref().to_string()))
 			.collect::<Result<Vec<_>, _>>()
 			.map_err(|e| Error::NotaryArchiveError(e.to_string()))?;
-		Ok(Self { archive_hosts, trust_mode, header_max_bytes, notebook_max_bytes })
+		Ok(Self {
+			archive_hosts,
+			trust_mode,
+			header_max_bytes,
+			notebook_max_bytes,
+			archive_mirror: None,
+		})
+	}
+
+	pub fn with_archive_mirror(mut self, archive_mirror: Arc<NotebookArchiveMirror>) -> Self {
+		self.archive_mirror = Some(archive_mirror);
+		self
+	}
+
+	/// Persists a notebook or header to the archive mirror (if enabled). Failures are only logged
+	/// since the mirror is a courtesy to other nodes.
+	pub async fn mirror(
+		&self,
+		kind: DownloadKind,
+		notary_id: NotaryId,
+		notebook_number: NotebookNumber,
+		bytes: &[u8],
+	) {
+		let Some(archive_mirror) = self.archive_mirror.as_ref() else {
+			return;
+		};
+		if let Err(e) = archive_mirror.store(kind, notary_id, notebook_number, bytes).await {
+			warn!("Unable to mirror notary {notary_id}, notebook {notebook_number}: {e:?}");
+		}
 	}
 
 	pub fn is_strict(&self) -> bool {
@@ -0,0 +1,184 @@
+use crate::error::Error;
+use argon_notary_apis::{get_download_path_suffix, DownloadKind};
+use argon_primitives::{NotaryId, NotebookNumber};
+use axum::{
+	body::Bytes,
+	extract::{Path, State},
+	http::{header, StatusCode},
+	response::IntoResponse,
+	routing::get,
+	Router,
+};
+use log::*;
+use polkadot_sdk::*;
+use sc_service::TaskManager;
+use std::{net::SocketAddr, path::PathBuf, str::FromStr, sync::Arc};
+
+/// Persists audited notebooks and signed headers to disk using the same layout as a notary
+/// archive (`/notary/{notary_id}/{header|notebook}/{notebook_number}.scale`), so the directory
+/// can be served to other nodes as a `--notebook-archive-hosts` entry.
+#[derive(Clone, Debug)]
+pub struct NotebookArchiveMirror {
+	pub path: PathBuf,
+}
+
+impl NotebookArchiveMirror {
+	pub fn new(path: impl Into<PathBuf>) -> Result<Self, Error> {
+		let path = path.into();
+		std::fs::create_dir_all(&path).map_err(|e| {
+			Error::NotaryArchiveError(format!(
+				"Unable to create notebook archive mirror at {}: {e:?}",
+				path.display()
+			))
+		})?;
+		Ok(Self { path })
+	}
+
+	pub fn file_path(
+		&self,
+		kind: DownloadKind,
+		notary_id: NotaryId,
+		notebook_number: NotebookNumber,
+	) -> PathBuf {
+		let suffix = get_download_path_suffix(kind, notary_id, notebook_number);
+		self.path.join(suffix.trim_start_matches('/'))
+	}
+
+	/// Writes the bytes to the mirror. The file is written to a temporary path and renamed so a
+	/// partially written file is never served.
+	pub async fn store(
+		&self,
+		kind: DownloadKind,
+		notary_id: NotaryId,
+		notebook_number: NotebookNumber,
+		bytes: &[u8],
+	) -> Result<(), Error> {
+		let path = self.file_path(kind, notary_id, notebook_number);
+		let write = async {
+			if tokio::fs::try_exists(&path).await? {
+				return Ok(());
+			}
+			if let Some(parent) = path.parent() {
+				tokio::fs::create_dir_all(parent).await?;
+			}
+			let tmp_path = path.with_extension("scale.tmp");
+			tokio::fs::write(&tmp_path, bytes).await?;
+			tokio::fs::rename(&tmp_path, &path).await
+		};
+		write.await.map_err(|e: std::io::Error| {
+			Error::NotaryArchiveError(format!(
+				"Unable to write {} to notebook archive mirror: {e:?}",
+				path.display()
+			))
+		})
+	}
+
+	pub async fn get(
+		&self,
+		kind: DownloadKind,
+		notary_id: NotaryId,
+		notebook_number: NotebookNumber,
+	) -> Option<Vec<u8>> {
+		tokio::fs::read(self.file_path(kind, notary_id, notebook_number)).await.ok()
+	}
+
+	pub fn router(self: Arc<Self>) -> Router {
+		Router::new()
+			.route("/notary/{notary_id}/{kind}/{file}", get(Self::serve_file))
+			.with_state(self)
+	}
+
+	async fn serve_file(
+		State(mirror): State<Arc<Self>>,
+		Path((notary_id, kind, file)): Path<(NotaryId, String, String)>,
+	) -> impl IntoResponse {
+		let kind = match kind.as_str() {
+			"header" => DownloadKind::Header,
+			"notebook" => DownloadKind::Notebook,
+			_ => return (StatusCode::NOT_FOUND, Bytes::from("Not found")).into_response(),
+		};
+		let Some(notebook_number) =
+			file.strip_suffix(".scale").and_then(|n| NotebookNumber::from_str(n).ok())
+		else {
+			return (StatusCode::NOT_FOUND, Bytes::from("Not found")).into_response();
+		};
+		match mirror.get(kind, notary_id, notebook_number).await {
+			Some(bytes) => (
+				StatusCode::OK,
+				[(header::CONTENT_TYPE, "application/octet-stream")],
+				Bytes::from(bytes),
+			)
+				.into_response(),
+			None => (StatusCode::NOT_FOUND, Bytes::from("Not found")).into_response(),
+		}
+	}
+}
+
+/// Serves a notebook archive mirror over http so other nodes can use this node as an archive host
+pub fn run_notebook_archive_mirror_server(
+	task_manager: &TaskManager,
+	mirror: Arc<NotebookArchiveMirror>,
+	listen: SocketAddr,
+) {
+	task_manager
+		.spawn_handle()
+		.spawn("notebook-archive-mirror", Some("notary-sync"), async move {
+			let listener = match tokio::net::TcpListener::bind(listen).await {
+				Ok(listener) => listener,
+				Err(e) => {
+					error!("Unable to serve the notebook archive mirror on {listen}: {e:?}");
+					return;
+				},
+			};
+			info!("Serving notebook archive mirror {} on {listen}", mirror.path.display());
+			if let Err(e) = axum::serve(listener, mirror.router()).await {
+				error!("Notebook archive mirror server stopped: {e:?}");
+			}
+		});
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use argon_notary_apis::ArchiveHost;
+	use std::time::Duration;
+
+	#[tokio::test]
+	async fn it_serves_stored_files_in_the_archive_layout() {
+		let path =
+			std::env::temp_dir().join(format!("argon-archive-mirror-{}", rand::random::<u64>()));
+		let mirror = Arc::new(NotebookArchiveMirror::new(&path).unwrap());
+		mirror.store(DownloadKind::Header, 1, 5, &[1, 2, 3]).await.unwrap();
+		mirror.store(DownloadKind::Notebook, 1, 5, &[4, 5, 6]).await.unwrap();
+		assert!(path.join("notary/1/header/5.scale").exists());
+		assert!(path.join("notary/1/notebook/5.scale").exists());
+
+		// a stored file is never overwritten
+		mirror.store(DownloadKind::Header, 1, 5, &[9]).await.unwrap();
+		assert_eq!(mirror.get(DownloadKind::Header, 1, 5).await, Some(vec![1, 2, 3]));
+
+		let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+		let host = format!("http://{}", listener.local_addr().unwrap());
+		let server = tokio::spawn(async move {
+			axum::serve(listener, mirror.router()).await.unwrap();
+		});
+
+		let archive = ArchiveHost::new(host).unwrap();
+		let timeout = Duration::from_secs(5);
+		let header = ArchiveHost::download_header_bytes(archive.get_header_url(1, 5), timeout)
+			.await
+			.unwrap();
+		assert_eq!(header.0, vec![1, 2, 3]);
+		let notebook =
+			ArchiveHost::download_notebook_bytes(archive.get_notebook_url(1, 5), timeout)
+				.await
+				.unwrap();
+		assert_eq!(notebook.0, vec![4, 5, 6]);
+		assert!(ArchiveHost::download_header_bytes(archive.get_header_url(1, 6), timeout)
+			.await
+			.is_err());
+
+		server.abort();
+		let _ = std::fs::remove_dir_all(path);
+	}
+}
@@ -1,5 +1,10 @@
 use crate::{
-	aux_client::ArgonAux, block_creator::CreateTaxVoteBlock, error::Error, NotebookTickChecker,
+	aux_client::ArgonAux,
+	block_creator::{BlockProposalDryRun, CreateTaxVoteBlock},
+	compute_worker::SharedComputeHandle,
+	error::Error,
+	notary_client::get_notebook_header_data,
+	NotebookTickChecker,
 };
 use argon_primitives::{
 	block_seal::{BLOCK_SEAL_CRYPTO_ID, BLOCK_SEAL_KEY_TYPE},
@@ -8,9 +13,9 @@ use argon_primitives::{
 	notary::NotaryNotebookRawVotes,
 	tick::{Tick, Ticker},
 	BestBlockVoteSeal, BlockCreatorApis, BlockSealApis, BlockSealAuthorityId, BlockSealDigest,
-	BlockVote, TickApis, VotingSchedule,
+	BlockVote, NotaryApis, NotebookApis, TickApis, VotingSchedule,
 };
-use argon_runtime::NotebookVerifyError;
+use argon_runtime::{NotaryRecordT, NotebookVerifyError};
 use codec::Codec;
 use log::*;
 use polkadot_sdk::*;
@@ -30,15 +35,18 @@ pub struct NotebookSealer<B: BlockT, C: AuxStore, SC, AC: Clone + Codec> {
 	ticker: Ticker,
 	select_chain: Arc<SC>,
 	keystore: KeystorePtr,
-	sender: TracingUnboundedSender<CreateTaxVoteBlock<B, AC>>,
+	/// Where found vote blocks are sent to be created. Without one, the sealer can only inspect.
+	sender: Option<TracingUnboundedSender<CreateTaxVoteBlock<B, AC>>>,
 	aux_client: ArgonAux<B, C>,
+	/// The compute worker, used to report whether a compute block would be solved instead
+	compute_handle: SharedComputeHandle<B>,
 	_phantom: PhantomData<B>,
 }
 
 impl<B, C, SC, AC> Clone for NotebookSealer<B, C, SC, AC>
 where
 	B: BlockT,
-	C: AuxStore + Clone,
+	C: AuxStore,
 	AC: Codec + Clone,
 {
 	fn clone(&self) -> Self {
@@ -49,6 +57,7 @@ where
 			keystore: self.keystore.clone(),
 			sender: self.sender.clone(),
 			aux_client: self.aux_client.clone(),
+			compute_handle: self.compute_handle.clone(),
 			_phantom: PhantomData,
 		}
 	}
@@ -60,6 +69,49 @@ pub struct CheckForNotebookResult {
 	pub recheck_notebook_tick_time: Option<Instant>,
 }
 
+/// The outcome of looking for a vote seal for a notebook tick
+#[derive(Debug, Clone)]
+pub struct VoteSealSearch<Hash, AC: Codec> {
+	pub voting_schedule: VotingSchedule,
+	/// The block a vote block would be built on
+	pub parent_hash: Option<Hash>,
+	/// The strongest seal the local keys can create
+	pub vote_seal: Option<BestBlockVoteSeal<AC, BlockSealAuthorityId>>,
+	/// Each reason the tick or a candidate block was not used
+	pub rejections: Vec<SealRejection<Hash>>,
+}
+
+/// Why a notebook tick or a candidate parent block can't be used to create a vote block
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SealRejection<Hash> {
+	/// The clock hasn't moved past the notebook tick
+	ClockBeforeNotebookTick { current_tick: Tick },
+	/// The notebook tick is too old for its votes to still be active
+	NotebookTickTooOld { current_tick: Tick },
+	/// There are no block seal keys in the keystore
+	NoBlockSealKeys,
+	/// No votes were received for the eligible votes tick
+	NoVotes { votes_tick: Tick },
+	/// A block at the block tick has already been finalized
+	BlockTickFinalized { finalized_tick: Tick },
+	/// A leaf has no ancestor at the parent tick
+	NoAncestorAtParentTick { leaf: Hash },
+	/// The block has no votes eligible to seal a child
+	NoEligibleVotes { block_hash: Hash },
+	/// Another candidate parent has more fork power
+	WeakerForkPower { block_hash: Hash, best_block_hash: Hash },
+	/// No candidate parent block could be found
+	NoParentBlock,
+	/// None of the local keys can beat the best seal already built on the parent
+	NoStrongerSeal { block_hash: Hash, seal_strength: U256, miner_nonce_score: Option<U256> },
+	/// This node isn't authoring compute blocks
+	ComputeNotRunning,
+	/// There are no compute threads or external miners to solve a compute block
+	NoComputeSolvers,
+	/// The compute worker is still waiting for a vote block before it solves
+	ComputeNotReadyToSolve { current_tick: Tick },
+}
+
 impl<B, C, SC, AC> NotebookSealer<B, C, SC, AC>
 where
 	B: BlockT,
@@ -84,7 +136,29 @@ where
 			select_chain: Arc::new(select_chain),
 			aux_client,
 			keystore,
-			sender,
+			sender: Some(sender),
+			compute_handle: SharedComputeHandle::default(),
+			_phantom: PhantomData,
+		}
+	}
+
+	/// A sealer that can look for vote seals (eg, for a dry run), but never creates blocks
+	pub fn new_inspector(
+		client: Arc<C>,
+		ticker: Ticker,
+		select_chain: SC,
+		keystore: KeystorePtr,
+		aux_client: ArgonAux<B, C>,
+		compute_handle: SharedComputeHandle<B>,
+	) -> Self {
+		Self {
+			client,
+			ticker,
+			select_chain: Arc::new(select_chain),
+			aux_client,
+			keystore,
+			sender: None,
+			compute_handle,
 			_phantom: PhantomData,
 		}
 	}
@@ -93,26 +167,74 @@ where
 		&self,
 		notebook_tick: Tick,
 	) -> Result<CheckForNotebookResult, Error> {
-		let current_clocktime_tick = self.ticker.current();
 		let mut result = CheckForNotebookResult::default();
+		let Some(sender) = self.sender.as_ref() else {
+			return Ok(result);
+		};
+		let search = self.find_vote_seal(notebook_tick).await?;
+		for rejection in &search.rejections {
+			tracing::trace!(notebook_tick, ?rejection, "Vote block candidate rejected");
+		}
+		let (Some(block_hash), Some(vote_seal)) = (search.parent_hash, search.vote_seal) else {
+			return Ok(result);
+		};
 
-		if current_clocktime_tick <= notebook_tick {
-			tracing::trace!(
-				current_clocktime_tick,
-				notebook_tick,
-				"Clock-time current tick is before notebook tick",
-			);
+		tracing::trace!(build_on_block = ?block_hash, strength = ?vote_seal.seal_strength, miner_nonce_score = ?vote_seal.miner_nonce_score,
+			"Found vote-eligible block");
+		let block_tick = search.voting_schedule.block_tick();
+		if let Some(recheck_at) = NotebookTickChecker::should_delay_block_attempt(
+			block_tick,
+			&self.ticker,
+			vote_seal.miner_nonce_score,
+		) {
+			result.recheck_notebook_tick_time = Some(recheck_at);
 			return Ok(result);
 		}
+		sender
+			.unbounded_send(CreateTaxVoteBlock::<B, AC> {
+				current_tick: block_tick,
+				timestamp_millis: self.ticker.now_adjusted_to_ntp(),
+				vote: vote_seal,
+				parent_hash: block_hash,
+			})
+			.map_err(|e| {
+				Error::StringError(format!("Failed to send CreateTaxVoteBlockV2 message: {e:?}"))
+			})?;
+		result.found_block = true;
+		Ok(result)
+	}
+
+	/// Looks for the best block to build on for a notebook tick, and the strongest vote seal the
+	/// local keys can create on top of it. Each reason a tick or candidate block is not used is
+	/// recorded in the result.
+	pub async fn find_vote_seal(
+		&self,
+		notebook_tick: Tick,
+	) -> Result<VoteSealSearch<B::Hash, AC>, Error> {
+		let current_clocktime_tick = self.ticker.current();
+		let voting_schedule = VotingSchedule::on_notebook_tick_state(notebook_tick);
+		let mut search = VoteSealSearch {
+			voting_schedule,
+			parent_hash: None,
+			vote_seal: None,
+			rejections: vec![],
+		};
+
+		if current_clocktime_tick <= notebook_tick {
+			search.rejections.push(SealRejection::ClockBeforeNotebookTick {
+				current_tick: current_clocktime_tick,
+			});
+			return Ok(search);
+		}
 
 		// Votes only work when they're for active ticks, so no point in doing this for old ticks
 		const OLDEST_TICK_TO_SOLVE_FOR: Tick = 5;
 
 		if notebook_tick < current_clocktime_tick.saturating_sub(OLDEST_TICK_TO_SOLVE_FOR) {
-			trace!(
-				"Notebook tick {notebook_tick} is too old to be considered for block creation with votes"
-			);
-			return Ok(result);
+			search
+				.rejections
+				.push(SealRejection::NotebookTickTooOld { current_tick: current_clocktime_tick });
+			return Ok(search);
 		}
 
 		let keys: Vec<BlockSealAuthorityId> = self
@@ -122,18 +244,17 @@ where
 			.map(Into::into)
 			.collect::<Vec<_>>();
 		if keys.is_empty() {
-			trace!("No block vote keys to sign block with");
-			return Ok(result);
+			search.rejections.push(SealRejection::NoBlockSealKeys);
+			return Ok(search);
 		}
 
-		let voting_schedule = VotingSchedule::on_notebook_tick_state(notebook_tick);
 		let votes_tick = voting_schedule.eligible_votes_tick();
 		// get the active votes, which were from 2 notebooks previous
 		let block_votes = self.aux_client.get_votes(votes_tick)?.get();
 		let votes_count = block_votes.iter().fold(0u32, |acc, x| acc + x.raw_votes.len() as u32);
 		if votes_count == 0 {
-			trace!("No block votes at tick {votes_tick}");
-			return Ok(result);
+			search.rejections.push(SealRejection::NoVotes { votes_tick });
+			return Ok(search);
 		}
 
 		let info = self.client.info();
@@ -152,21 +273,17 @@ where
 				.ok_or_else(|| Error::UnableToDecodeDigest("finalized tick".to_string()))?
 		};
 		if voting_schedule.block_tick() <= finalized_tick {
-			tracing::trace!(
-				notebook_tick,
-				finalized_tick,
-				include_in_block_tick = ?voting_schedule.block_tick(),
-				"Block tick for received notebook has already been finalized, no need to seal block",
-			);
-			return Ok(result);
+			search.rejections.push(SealRejection::BlockTickFinalized { finalized_tick });
+			return Ok(search);
 		}
 
 		let Some((block_hash, seal_strength, miner_nonce_score)) =
-			self.get_best_block_at_parent(&voting_schedule).await?
+			self.get_best_block_at_parent(&voting_schedule, &mut search.rejections).await?
 		else {
-			trace!("No parent block to build on for tick {votes_tick}");
-			return Ok(result);
+			search.rejections.push(SealRejection::NoParentBlock);
+			return Ok(search);
 		};
+		search.parent_hash = Some(block_hash);
 		tracing::trace!(
 			votes_tick,
 			votes_count,
@@ -176,7 +293,7 @@ where
 			"Building vote seal on block",
 		);
 
-		let seal = self
+		search.vote_seal = self
 			.check_v2_seals(
 				&block_votes,
 				keys,
@@ -186,40 +303,14 @@ where
 				votes_tick,
 			)
 			.await?;
-
-		if let Some(vote_seal) = seal {
-			tracing::trace!(build_on_block = ?block_hash, strength = ?vote_seal.seal_strength, miner_nonce_score = ?vote_seal.miner_nonce_score,
-				"Found vote-eligible block");
-			let block_tick = voting_schedule.block_tick();
-			if let Some(recheck_at) = NotebookTickChecker::should_delay_block_attempt(
-				block_tick,
-				&self.ticker,
-				vote_seal.miner_nonce_score,
-			) {
-				result.recheck_notebook_tick_time = Some(recheck_at);
-				return Ok(result);
-			}
-			self.sender
-				.unbounded_send(CreateTaxVoteBlock::<B, AC> {
-					current_tick: block_tick,
-					timestamp_millis: self.ticker.now_adjusted_to_ntp(),
-					vote: vote_seal,
-					parent_hash: block_hash,
-				})
-				.map_err(|e| {
-					Error::StringError(format!(
-						"Failed to send CreateTaxVoteBlockV2 message: {e:?}"
-					))
-				})?;
-			result.found_block = true;
-			return Ok(result);
+		if search.vote_seal.is_none() {
+			search.rejections.push(SealRejection::NoStrongerSeal {
+				block_hash,
+				seal_strength,
+				miner_nonce_score,
+			});
 		}
-
-		tracing::trace!(
-			block_hash = ?block_hash, notebook_tick, votes_tick, best_seal_strength = ?seal_strength, miner_nonce_score = ?miner_nonce_score,
-			"Could not find any stronger seals for block",
-		);
-		Ok(result)
+		Ok(search)
 	}
 
 	async fn check_v2_seals(
@@ -262,6 +353,7 @@ where
 	async fn get_best_block_at_parent(
 		&self,
 		voting_schedule: &VotingSchedule,
+		rejections: &mut Vec<SealRejection<B::Hash>>,
 	) -> Result<Option<(B::Hash, U256, Option<U256>)>, Error> {
 		let leaves = self.select_chain.leaves().await?;
 		// Blocks are always created with a tick at least notebook tick +1, so the parent will be at
@@ -270,15 +362,20 @@ where
 		let mut best_parent: Option<(B::Hash, ForkPower)> = None;
 		for leaf in &leaves {
 			let Some(parent_hash) = self.get_block_ancestor_with_tick(*leaf, parent_tick) else {
+				rejections.push(SealRejection::NoAncestorAtParentTick { leaf: *leaf });
 				continue;
 			};
 			// **only** consider it if there really are votes to seal here
 			if !self.client.runtime_api().has_eligible_votes(parent_hash).unwrap_or(false) {
+				rejections.push(SealRejection::NoEligibleVotes { block_hash: parent_hash });
 				continue;
 			}
 
 			let forkpower = self.get_fork_power(parent_hash)?;
 			if let Some((best_parent_hash, best_forkpower)) = &best_parent {
+				if *best_parent_hash == parent_hash {
+					continue;
+				}
 				// Prefer strictly better fork power; use hash as deterministic tie-break.
 				if !is_better_fork_candidate(
 					&parent_hash,
@@ -286,13 +383,20 @@ where
 					best_parent_hash,
 					best_forkpower,
 				) {
+					rejections.push(SealRejection::WeakerForkPower {
+						block_hash: parent_hash,
+						best_block_hash: *best_parent_hash,
+					});
 					continue;
 				}
+				rejections.push(SealRejection::WeakerForkPower {
+					block_hash: *best_parent_hash,
+					best_block_hash: parent_hash,
+				});
 			}
 			best_parent = Some((parent_hash, forkpower));
 		}
 		let Some((best_parent_hash, _)) = best_parent else {
-			tracing::trace!(parent_tick, "Could not find any parent block with votes to seal",);
 			return Ok(None);
 		};
 
@@ -362,6 +466,73 @@ where
 	}
 }
 
+impl<B, C, SC, AC> NotebookSealer<B, C, SC, AC>
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B> + HeaderBackend<B> + AuxStore + 'static,
+	C::Api: BlockSealApis<B, AC, BlockSealAuthorityId>
+		+ TickApis<B>
+		+ BlockCreatorApis<B, AC, NotebookVerifyError>
+		+ NotebookApis<B, NotebookVerifyError>
+		+ NotaryApis<B, NotaryRecordT>,
+	SC: SelectChain<B> + 'static,
+	AC: Codec + Clone,
+{
+	/// Assembles the vote block this node would propose for a notebook tick (defaults to the
+	/// latest notebook tick), without signing or submitting anything.
+	///
+	/// Also reports why the compute worker would not solve a block right now, if it wouldn't.
+	pub async fn dry_run(
+		&self,
+		notebook_tick: Option<Tick>,
+	) -> Result<BlockProposalDryRun<B::Hash, AC>, Error> {
+		let notebook_tick =
+			notebook_tick.unwrap_or_else(|| self.ticker.current().saturating_sub(1));
+		let VoteSealSearch { voting_schedule, parent_hash, vote_seal, mut rejections } =
+			self.find_vote_seal(notebook_tick).await?;
+		let now_millis = self.ticker.now_adjusted_to_ntp();
+		rejections.extend(
+			self.compute_handle.rejection(self.ticker.tick_for_time(now_millis), now_millis),
+		);
+		let mut dry_run = BlockProposalDryRun {
+			notebook_tick,
+			block_tick: voting_schedule.block_tick(),
+			parent_hash,
+			vote_seal,
+			notebooks: vec![],
+			voting_power: 0,
+			fork_power: None,
+			rejections,
+		};
+		let Some(parent_hash) = parent_hash else {
+			return Ok(dry_run);
+		};
+
+		let voting_schedule = VotingSchedule::when_creating_block(dry_run.block_tick);
+		let notebook_header_data = get_notebook_header_data::<B, C, AC>(
+			&self.client,
+			&self.aux_client,
+			&parent_hash,
+			&voting_schedule,
+		)
+		.await?;
+		dry_run.voting_power = notebook_header_data.vote_digest.voting_power;
+		dry_run.notebooks = notebook_header_data.notebook_digest.notebooks.into_inner();
+		if let Some(vote_seal) = dry_run.vote_seal.as_ref() {
+			// mirrors the fork power the runtime records for a vote block
+			let mut fork_power = self.get_fork_power(parent_hash)?;
+			fork_power.add_vote(
+				dry_run.voting_power,
+				dry_run.notebooks.len() as u32,
+				vote_seal.seal_strength,
+				vote_seal.miner_nonce_score.map(|(distance, _)| distance),
+			);
+			dry_run.fork_power = Some(fork_power);
+		}
+		Ok(dry_run)
+	}
+}
+
 fn is_better_fork_candidate<Hash: Ord>(
 	candidate_hash: &Hash,
 	candidate_fork_power: &ForkPower,
@@ -404,13 +575,20 @@ pub fn create_vote_seal<Hash: AsRef<[u8]>>(
 #[cfg(test)]
 mod tests {
 	use frame_support::assert_ok;
+	use sc_utils::mpsc::tracing_unbounded;
+	use sp_api::{ApiRef, ProvideRuntimeApi};
 	use sp_core::H256;
 	use sp_keyring::Ed25519Keyring;
 	use sp_keystore::{testing::MemoryKeystore, Keystore};
+	use sp_runtime::{generic, traits::NumberFor};
 
-	use argon_primitives::block_seal::BLOCK_SEAL_KEY_TYPE;
+	use argon_primitives::{block_seal::BLOCK_SEAL_KEY_TYPE, AccountId};
 
-	use crate::mock_notary::setup_logs;
+	use crate::{
+		compute_worker::ComputeHandle,
+		mock_importer::{Block, MemChain},
+		mock_notary::setup_logs,
+	};
 
 	use super::*;
 
@@ -422,6 +600,139 @@ mod tests {
 		keystore.into()
 	}
 
+	/// A chain without runtime state. The dry runs below are rejected before any runtime api is
+	/// called, so the apis return empty defaults.
+	#[derive(Clone)]
+	struct DryRunClient(MemChain);
+
+	struct MockApi;
+
+	sp_api::mock_impl_runtime_apis! {
+		impl BlockSealApis<Block, AccountId, BlockSealAuthorityId> for MockApi {
+			fn has_eligible_votes() -> bool {
+				false
+			}
+		}
+		impl TickApis<Block> for MockApi {
+			fn current_tick() -> Tick {
+				0
+			}
+		}
+		impl BlockCreatorApis<Block, AccountId, NotebookVerifyError> for MockApi {
+			fn digest_notebooks(
+				_digests: &sp_runtime::Digest,
+			) -> Result<
+				Vec<argon_primitives::NotebookAuditResult<NotebookVerifyError>>,
+				sp_runtime::DispatchError,
+			> {
+				Ok(vec![])
+			}
+		}
+		impl NotebookApis<Block, NotebookVerifyError> for MockApi {
+			fn latest_notebook_by_notary(
+			) -> std::collections::BTreeMap<argon_primitives::NotaryId, (argon_primitives::NotebookNumber, Tick)> {
+				Default::default()
+			}
+		}
+		impl NotaryApis<Block, NotaryRecordT> for MockApi {
+			fn notaries() -> Vec<NotaryRecordT> {
+				vec![]
+			}
+		}
+	}
+
+	impl ProvideRuntimeApi<Block> for DryRunClient {
+		type Api = MockApi;
+
+		fn runtime_api(&self) -> ApiRef<'_, Self::Api> {
+			MockApi.into()
+		}
+	}
+
+	impl HeaderBackend<Block> for DryRunClient {
+		fn header(
+			&self,
+			hash: <Block as BlockT>::Hash,
+		) -> sp_blockchain::Result<Option<<Block as BlockT>::Header>> {
+			self.0.header(hash)
+		}
+		fn info(&self) -> sp_blockchain::Info<Block> {
+			self.0.info()
+		}
+		fn status(
+			&self,
+			hash: <Block as BlockT>::Hash,
+		) -> sp_blockchain::Result<sp_blockchain::BlockStatus> {
+			self.0.status(hash)
+		}
+		fn number(
+			&self,
+			hash: <Block as BlockT>::Hash,
+		) -> sp_blockchain::Result<Option<NumberFor<Block>>> {
+			self.0.number(hash)
+		}
+		fn hash(
+			&self,
+			number: NumberFor<Block>,
+		) -> sp_blockchain::Result<Option<<Block as BlockT>::Hash>> {
+			self.0.hash(number)
+		}
+	}
+
+	impl AuxStore for DryRunClient {
+		fn insert_aux<
+			'a,
+			'b: 'a,
+			'c: 'a,
+			I: IntoIterator<Item = &'a (&'c [u8], &'c [u8])>,
+			D: IntoIterator<Item = &'a &'b [u8]>,
+		>(
+			&self,
+			insert: I,
+			delete: D,
+		) -> sp_blockchain::Result<()> {
+			self.0.insert_aux(insert, delete)
+		}
+		fn get_aux(&self, key: &[u8]) -> sp_blockchain::Result<Option<Vec<u8>>> {
+			self.0.get_aux(key)
+		}
+	}
+
+	#[derive(Clone)]
+	struct NoLeaves;
+
+	#[async_trait::async_trait]
+	impl SelectChain<Block> for NoLeaves {
+		async fn leaves(&self) -> Result<Vec<<Block as BlockT>::Hash>, ConsensusError> {
+			Ok(vec![])
+		}
+		async fn best_chain(&self) -> Result<<Block as BlockT>::Header, ConsensusError> {
+			Err(ConsensusError::ChainLookup("No best chain in the dry run".into()))
+		}
+	}
+
+	fn create_inspector(
+		keystore: KeystorePtr,
+		compute_handle: SharedComputeHandle<Block>,
+	) -> NotebookSealer<Block, DryRunClient, NoLeaves, AccountId> {
+		let genesis = generic::Header::new(
+			0,
+			Default::default(),
+			Default::default(),
+			Default::default(),
+			Default::default(),
+		);
+		let client = Arc::new(DryRunClient(MemChain::new(genesis)));
+		NotebookSealer::new_inspector(
+			client.clone(),
+			Ticker::new(2000, 2),
+			NoLeaves,
+			keystore,
+			ArgonAux::new(client),
+			compute_handle,
+		)
+	}
+
 	#[test]
 	fn it_can_sign_a_vote() {
 		setup_logs();
@@ -475,4 +786,46 @@ mod tests {
 		assert!(is_better_fork_candidate(&lower_hash, &fork_power, &higher_hash, &fork_power));
 		assert!(!is_better_fork_candidate(&higher_hash, &fork_power, &lower_hash, &fork_power));
 	}
+
+	#[tokio::test]
+	async fn it_reports_missing_seal_keys_and_compute_worker_in_a_dry_run() {
+		setup_logs();
+		let keystore: KeystorePtr = MemoryKeystore::new().into();
+		let sealer = create_inspector(keystore, SharedComputeHandle::default());
+
+		let dry_run = sealer.dry_run(None).await.expect("dry run");
+		assert_eq!(dry_run.parent_hash, None);
+		assert!(dry_run.vote_seal.is_none());
+		assert_eq!(
+			dry_run.rejections,
+			vec![SealRejection::NoBlockSealKeys, SealRejection::ComputeNotRunning]
+		);
+	}
+
+	#[tokio::test]
+	async fn it_reports_missing_votes_and_compute_readiness_in_a_dry_run() {
+		setup_logs();
+		let compute_handle = SharedComputeHandle::default();
+		let sealer =
+			create_inspector(create_keystore(Ed25519Keyring::Alice), compute_handle.clone());
+		let notebook_tick = sealer.ticker.current() - 1;
+		let votes_tick =
+			VotingSchedule::on_notebook_tick_state(notebook_tick).eligible_votes_tick();
+
+		let (tx, _rx) = tracing_unbounded("node::consensus::compute_block_stream", 10);
+		compute_handle.set(ComputeHandle::new(tx), false);
+		let dry_run = sealer.dry_run(Some(notebook_tick)).await.expect("dry run");
+		assert_eq!(
+			dry_run.rejections,
+			vec![SealRejection::NoVotes { votes_tick }, SealRejection::NoComputeSolvers]
+		);
+
+		// no block is being solved yet, so the worker isn't ready
+		let (tx, _rx) = tracing_unbounded("node::consensus::compute_block_stream", 10);
+		compute_handle.set(ComputeHandle::new(tx), true);
+		let dry_run = sealer.dry_run(Some(notebook_tick)).await.expect("dry run");
+		assert_eq!(dry_run.rejections.len(), 2);
+		assert_eq!(dry_run.rejections[0], SealRejection::NoVotes { votes_tick });
+		assert!(matches!(dry_run.rejections[1], SealRejection::ComputeNotReadyToSolve { .. }));
+	}
 }
@@ -2,6 +2,7 @@ use argon_primitives::AccountId;
 use clap::{Parser, ValueEnum};
 use polkadot_sdk::*;
 use sc_cli::RunCmd;
+use std::{net::SocketAddr, path::PathBuf};
 
 #[derive(Debug, clap::Parser)]
 pub struct Cli {
@@ -15,6 +16,17 @@ pub struct Cli {
 	/// full node. Include optional auth inline
 	#[arg(long, global = true)]
 	pub bitcoin_rpc_url: Option<String>,
+
+	/// A bitcoin peer (host:port) serving BIP157 compact block filters. Can be used instead of a
+	/// bitcoin rpc url so a full node's rpc is not required. Should be a trusted peer started with
+	/// `-blockfilterindex=1 -peerblockfilters=1`.
+	#[arg(long, global = true, conflicts_with = "bitcoin_rpc_url")]
+	pub bitcoin_p2p_peer: Option<String>,
+
+	/// Leave bitcoin utxo syncs out of authored blocks unless the runtime's confirmed bitcoin tip
+	/// is in the best chain of the bitcoin node (only supported with a bitcoin rpc url).
+	#[arg(long, global = true, conflicts_with = "bitcoin_p2p_peer")]
+	pub bitcoin_verify_tip: bool,
 }
 
 #[derive(Debug, Clone, Parser)]
@@ -41,6 +53,13 @@ pub struct ArgonRunCmd {
 	#[arg(long, verbatim_doc_comment)]
 	pub compute_flags: Vec<RandomxFlag>,
 
+	/// Serve the compute puzzle to external miners (eg, `argon-miner`) on this address
+	/// (eg, 127.0.0.1:9950). Submitted nonces are verified before a block is sealed.
+	///
+	/// NOTE: the protocol is unauthenticated. Only expose it to machines you trust.
+	#[arg(long, value_name = "SOCKET_ADDR", verbatim_doc_comment)]
+	pub compute_miner_listen: Option<SocketAddr>,
+
 	/// The archive hosts to download notary notebooks from if a notary is unavailable. You are
 	/// free to use a hosted service, or download a registry to your local machine.
 	///
@@ -48,6 +67,21 @@ pub struct ArgonRunCmd {
 	#[arg(long, verbatim_doc_comment)]
 	pub notebook_archive_hosts: Vec<String>,
 
+	/// Persist audited notebooks and signed headers to this directory, using the same layout as
+	/// a notary archive (eg, `notary/1/notebook/100.scale`).
+	#[arg(long, value_name = "PATH", verbatim_doc_comment)]
+	pub notebook_archive_mirror_path: Option<PathBuf>,
+
+	/// Serve the notebook archive mirror over http on this address (eg, 0.0.0.0:9960). Other
+	/// nodes can add this node to their `--notebook-archive-hosts`.
+	#[arg(
+		long,
+		value_name = "SOCKET_ADDR",
+		requires = "notebook_archive_mirror_path",
+		verbatim_doc_comment
+	)]
+	pub notebook_archive_mirror_listen: Option<SocketAddr>,
+
 	/// Maximum header download size (in MB). Downloads above this limit are rejected.
 	#[arg(long, env = "ARGON_NOTEBOOK_HEADER_MAX_MB", default_value_t = 2)]
 	pub notebook_header_max_mb: u64,
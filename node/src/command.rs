@@ -13,7 +13,7 @@ use sc_cli::{Error, Result as CliResult, SubstrateCli};
 use sc_network::{config::NetworkBackendType, Litep2pNetworkBackend, NetworkWorker};
 use sp_core::crypto::AccountId32;
 use sp_keyring::Sr25519Keyring::Alice;
-use std::cmp::max;
+use std::{cmp::max, net::SocketAddr, path::PathBuf};
 use url::Url;
 
 type CanaryRuntimeApi = argon_canary_runtime::RuntimeApi;
@@ -186,9 +186,14 @@ where
 
 pub struct MiningConfig {
 	compute_threads: Option<u32>,
+	pub compute_miner_listen: Option<SocketAddr>,
 	pub compute_author: Option<AccountId32>,
 	bitcoin_rpc_url: Option<String>,
+	pub bitcoin_p2p_peer: Option<String>,
+	pub bitcoin_verify_tip: bool,
 	pub notebook_archive_hosts: Vec<String>,
+	pub notebook_archive_mirror_path: Option<PathBuf>,
+	pub notebook_archive_mirror_listen: Option<SocketAddr>,
 	pub notebook_download_trust_mode: DownloadTrustMode,
 	pub notebook_header_max_bytes: Option<u64>,
 	pub notebook_body_max_bytes: Option<u64>,
@@ -223,9 +228,14 @@ impl MiningConfig {
 
 		Self {
 			compute_threads,
+			compute_miner_listen: cli.run.compute_miner_listen,
 			compute_author,
 			bitcoin_rpc_url,
+			bitcoin_p2p_peer: cli.bitcoin_p2p_peer.clone(),
+			bitcoin_verify_tip: cli.bitcoin_verify_tip,
 			notebook_archive_hosts: cli.run.notebook_archive_hosts.clone(),
+			notebook_archive_mirror_path: cli.run.notebook_archive_mirror_path.clone(),
+			notebook_archive_mirror_listen: cli.run.notebook_archive_mirror_listen,
 			notebook_download_trust_mode,
 			notebook_header_max_bytes,
 			notebook_body_max_bytes,
@@ -258,13 +268,16 @@ impl MiningConfig {
 		} else {
 			max(num_cpus::get() - 1, 1)
 		};
-		if compute_threads > 0 {
+		if compute_threads > 0 || self.compute_miner_listen.is_some() {
 			if self.compute_author.is_none() {
 				panic!(
 					"Compute fallback mining is enabled without a compute author. Unable to activate!"
 				);
 			}
 			log::info!("Compute fallback mining is enabled with {compute_threads} threads");
+			if let Some(listen) = self.compute_miner_listen {
+				log::info!("Compute puzzles will be served to external miners on {listen}");
+			}
 		} else {
 			log::info!("Compute fallback mining is disabled");
 		}
@@ -274,7 +287,7 @@ impl MiningConfig {
 	pub fn bitcoin_rpc_url_with_auth(&self) -> CliResult<(Url, Option<(String, String)>)> {
 		let Some(bitcoin_rpc_url) = &self.bitcoin_rpc_url else {
 			return Err(Error::Input(
-				"Bitcoin RPC URL is required for block validation".to_string(),
+				"Bitcoin RPC URL or P2P peer is required for block validation".to_string(),
 			));
 		};
 
@@ -329,6 +342,24 @@ mod tests {
 		assert_eq!(config.notebook_body_max_bytes, Some(4 * 1024 * 1024));
 	}
 
+	#[test]
+	fn bitcoin_p2p_peer_replaces_the_rpc_url() {
+		let cli =
+			parse_cli(&["argon-node", "--chain", "dev", "--bitcoin-p2p-peer", "127.0.0.1:18444"]);
+		let config = MiningConfig::new(&cli);
+		assert_eq!(config.bitcoin_p2p_peer.as_deref(), Some("127.0.0.1:18444"));
+		assert!(config.bitcoin_rpc_url_with_auth().is_err());
+
+		assert!(Cli::try_parse_from([
+			"argon-node",
+			"--bitcoin-p2p-peer",
+			"127.0.0.1:18444",
+			"--bitcoin-rpc-url",
+			"http://127.0.0.1:18443",
+		])
+		.is_err());
+	}
+
 	#[test]
 	fn notebook_trust_mode_is_dev_for_dev_chains() {
 		for chain in ["dev", "dev-docker", "local", "meta"] {
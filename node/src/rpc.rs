@@ -8,9 +8,14 @@ use polkadot_sdk::*;
 use std::sync::Arc;
 
 use crate::runtime_api::opaque::{Block, Hash};
-use argon_primitives::{AccountId, Balance, BlockNumber, BlockSealAuthorityId, MiningApis, Nonce};
+use argon_node_consensus::{aux_client::ArgonAux, notebook_sealer::NotebookSealer};
+use argon_primitives::{
+	AccountId, Balance, BitcoinApis, BlockCreatorApis, BlockNumber, BlockSealApis,
+	BlockSealAuthorityId, MiningApis, Nonce, NotaryApis, NotebookApis, TickApis, TreasuryApis,
+};
+use argon_runtime::{NotaryRecordT, NotebookVerifyError};
 use jsonrpsee::{types::ErrorObjectOwned, RpcModule};
-use sc_client_api::{AuxStore, BlockBackend, ProofProvider};
+use sc_client_api::{AuxStore, BlockBackend, BlockchainEvents, ProofProvider};
 use sc_consensus_grandpa::FinalityProofProvider;
 use sc_network_sync::{SyncState, SyncStatus, SyncingService, WarpSyncPhase};
 use sc_transaction_pool_api::TransactionPool;
@@ -18,8 +23,11 @@ use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
 
+mod argon;
+mod block_proposals;
+
 /// Full client dependencies.
-pub struct FullDeps<C, P, B> {
+pub struct FullDeps<C: AuxStore, P, B> {
 	/// The client instance to use.
 	pub client: Arc<C>,
 	/// Transaction pool instance.
@@ -28,6 +36,10 @@ pub struct FullDeps<C, P, B> {
 	pub sync_service: Arc<SyncingService<Block>>,
 	/// Best block when the node's synchronization service started.
 	pub starting_block: BlockNumber,
+	/// Consensus aux data (notebook audits and tick voting power).
+	pub aux_client: ArgonAux<Block, C>,
+	/// A notebook sealer that can only inspect, used to dry run block proposals.
+	pub notebook_sealer: NotebookSealer<Block, C, sc_consensus::LongestChain<B, Block>, AccountId>,
 	/// GRANDPA specific dependencies.
 	pub grandpa: GrandpaDeps<B>,
 }
@@ -56,22 +68,40 @@ where
 		+ ProofProvider<Block>
 		+ BlockBackend<Block>
 		+ HeaderMetadata<Block, Error = BlockChainError>
+		+ BlockchainEvents<Block>
 		+ AuxStore,
 	C: Send + Sync + 'static,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: BlockBuilder<Block>,
 	C::Api: MiningApis<Block, AccountId, BlockSealAuthorityId>,
+	C::Api: BitcoinApis<Block, Balance>,
+	C::Api: NotaryApis<Block, NotaryRecordT>,
+	C::Api: TickApis<Block>,
+	C::Api: BlockSealApis<Block, AccountId, BlockSealAuthorityId>,
+	C::Api: BlockCreatorApis<Block, AccountId, NotebookVerifyError>,
+	C::Api: NotebookApis<Block, NotebookVerifyError>,
+	C::Api: TreasuryApis<Block, Balance>,
 	P: TransactionPool + 'static,
 	B: sc_client_api::Backend<Block> + Send + Sync + 'static,
 	B::State: sc_client_api::StateBackend<sp_runtime::traits::HashingFor<Block>>,
 {
+	use argon::{Argon, ArgonApiServer};
+	use block_proposals::{BlockProposals, BlockProposalsApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use sc_consensus_grandpa_rpc::{Grandpa, GrandpaApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 
 	let mut module = RpcModule::new(());
-	let FullDeps { client, pool, sync_service, starting_block, grandpa } = deps;
+	let FullDeps {
+		client,
+		pool,
+		sync_service,
+		starting_block,
+		aux_client,
+		notebook_sealer,
+		grandpa,
+	} = deps;
 	let GrandpaDeps {
 		shared_voter_state,
 		shared_authority_set,
@@ -83,6 +113,10 @@ where
 	module.merge(System::new(client.clone(), pool).into_rpc())?;
 	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
 
+	module
+		.merge(Argon::new(client.clone(), aux_client, subscription_executor.clone()).into_rpc())?;
+	module.merge(BlockProposals::new(notebook_sealer).into_rpc())?;
+
 	module.merge(
 		Grandpa::new(
 			subscription_executor,
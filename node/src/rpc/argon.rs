@@ -0,0 +1,832 @@
+//! Typed `argon_*` RPC methods that wrap the Argon runtime apis and the consensus aux data so
+//! that dashboards don't need to decode raw storage.
+
+use futures::{future, StreamExt};
+use jsonrpsee::{
+	core::RpcResult, proc_macros::rpc, types::ErrorObjectOwned, PendingSubscriptionSink,
+};
+use polkadot_sdk::*;
+use serde::{Deserialize, Serialize};
+use std::{cmp::Ordering, sync::Arc};
+
+use crate::runtime_api::opaque::{Block, Hash};
+use argon_node_consensus::aux_client::{ArgonAux, NotebookAuditAndRawHeader};
+use argon_primitives::{
+	bitcoin::{BitcoinBlock, BitcoinSyncStatus, Satoshis},
+	block_seal::{BlockPayout, BlockRewardType},
+	fork_power::{ForkChoiceReason, ForkPower},
+	notary::{NotaryNotebookRawVotes, NotaryState},
+	tick::Tick,
+	vault::{TreasuryBondYieldProjection, TreasuryFrameApy, TreasuryProgramYields},
+	AccountId, Balance, BitcoinApis, BlockNumber, BlockSealAuthorityId, BlockVote,
+	BlockVotingPower, MiningApis, NotaryApis, NotaryId, NotebookNumber, TickApis, TreasuryApis,
+	VaultId,
+};
+use argon_runtime::{NotaryRecordT, NotebookVerifyError};
+use codec::{Decode, Encode};
+use sc_client_api::{AuxStore, BlockchainEvents};
+use sc_rpc::{
+	utils::{pipe_from_stream, spawn_subscription_task},
+	SubscriptionTaskExecutor,
+};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::U256;
+use sp_runtime::traits::Header as HeaderT;
+
+const RUNTIME_ERROR: i32 = 1;
+const AUX_ERROR: i32 = 2;
+const UNKNOWN_BLOCK_ERROR: i32 = 3;
+
+/// Argon specific rpc methods.
+#[rpc(server)]
+pub trait ArgonApi {
+	/// The payouts made in the given block (defaults to the best block).
+	#[method(name = "argon_blockPayouts")]
+	fn block_payouts(&self, at: Option<Hash>) -> RpcResult<Vec<BlockPayoutResponse>>;
+
+	/// The bitcoin block sync status recorded in the runtime.
+	#[method(name = "argon_bitcoinSyncStatus")]
+	fn bitcoin_sync_status(&self, at: Option<Hash>)
+		-> RpcResult<Option<BitcoinSyncStatusResponse>>;
+
+	/// The market rate and redemption price of the given amount of satoshis.
+	#[method(name = "argon_redemptionQuote")]
+	fn redemption_quote(
+		&self,
+		satoshis: Satoshis,
+		max_microgons_at_target: Option<Balance>,
+		at: Option<Hash>,
+	) -> RpcResult<RedemptionQuoteResponse>;
+
+	/// Each notary with its runtime state and the latest notebook audited by this node.
+	#[method(name = "argon_notaryStates")]
+	fn notary_states(&self, at: Option<Hash>) -> RpcResult<Vec<NotaryStateResponse>>;
+
+	/// The notebook audit results this node has recorded for a notary, optionally limited to an
+	/// inclusive tick range.
+	#[method(name = "argon_notaryAuditHistory")]
+	fn notary_audit_history(
+		&self,
+		notary_id: NotaryId,
+		start_tick: Option<Tick>,
+		end_tick: Option<Tick>,
+	) -> RpcResult<Vec<AuditedNotebookResponse>>;
+
+	/// The raw block votes this node has received in notebooks for a tick.
+	#[method(name = "argon_votesAtTick")]
+	fn votes_at_tick(&self, tick: Tick) -> RpcResult<Vec<NotebookVotesResponse>>;
+
+	/// The fork power inputs of a block compared to another block (defaults to the best block).
+	#[method(name = "argon_blockForkPower")]
+	fn block_fork_power(
+		&self,
+		block_hash: Hash,
+		compare_to: Option<Hash>,
+	) -> RpcResult<BlockForkPowerResponse>;
+
+	/// The recent per-frame APYs of the Argonot bond program and each vault's bond program.
+	#[method(name = "argon_treasuryBondYields")]
+	fn treasury_bond_yields(&self, at: Option<Hash>) -> RpcResult<Vec<BondProgramYieldsResponse>>;
+
+	/// Projects the next-frame yield of buying a number of bonds in a vault's bond program (or the
+	/// Argonot bond program if no vault is given).
+	#[method(name = "argon_treasuryBondYieldProjection")]
+	fn treasury_bond_yield_projection(
+		&self,
+		vault_id: Option<VaultId>,
+		bonds: u32,
+		at: Option<Hash>,
+	) -> RpcResult<BondYieldProjectionResponse>;
+
+	/// Notifies each time the best block moves to a new tick.
+	#[subscription(
+		name = "argon_subscribeTicks" => "argon_tick",
+		unsubscribe = "argon_unsubscribeTicks",
+		item = TickResponse
+	)]
+	fn subscribe_ticks(&self);
+}
+
+/// A single payout made in a block.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockPayoutResponse {
+	/// The account receiving the payout.
+	pub account_id: AccountId,
+	/// Ownership tokens paid (microgons).
+	pub ownership: Balance,
+	/// Argons paid (microgons).
+	pub argons: Balance,
+	/// One of `miner`, `voter`, `profitShare` or `miningPoolShare`.
+	pub reward_type: String,
+	/// The hex encoded block seal authority for miner rewards.
+	pub block_seal_authority: Option<String>,
+}
+
+impl From<BlockPayout<AccountId, Balance>> for BlockPayoutResponse {
+	fn from(payout: BlockPayout<AccountId, Balance>) -> Self {
+		Self {
+			account_id: payout.account_id,
+			ownership: payout.ownership,
+			argons: payout.argons,
+			reward_type: match payout.reward_type {
+				BlockRewardType::Miner => "miner",
+				BlockRewardType::Voter => "voter",
+				BlockRewardType::ProfitShare => "profitShare",
+				BlockRewardType::MiningPoolShare => "miningPoolShare",
+			}
+			.to_string(),
+			block_seal_authority: payout
+				.block_seal_authority
+				.map(|a: BlockSealAuthorityId| format!("0x{}", hex::encode(a.encode()))),
+		}
+	}
+}
+
+/// A bitcoin block height and hash.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BitcoinBlockResponse {
+	/// The bitcoin block height.
+	pub block_height: u64,
+	/// The bitcoin block hash in the usual (reversed) display order.
+	pub block_hash: String,
+}
+
+impl From<BitcoinBlock> for BitcoinBlockResponse {
+	fn from(block: BitcoinBlock) -> Self {
+		let mut hash = block.block_hash.0;
+		hash.reverse();
+		Self { block_height: block.block_height, block_hash: hex::encode(hash) }
+	}
+}
+
+/// The bitcoin sync status of the runtime.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BitcoinSyncStatusResponse {
+	/// The latest confirmed bitcoin block.
+	pub confirmed_block: BitcoinBlockResponse,
+	/// The last bitcoin block the runtime has processed.
+	pub synched_block: Option<BitcoinBlockResponse>,
+	/// The oldest bitcoin block height that can still be processed.
+	pub oldest_allowed_block_height: u64,
+}
+
+impl From<BitcoinSyncStatus> for BitcoinSyncStatusResponse {
+	fn from(status: BitcoinSyncStatus) -> Self {
+		Self {
+			confirmed_block: status.confirmed_block.into(),
+			synched_block: status.synched_block.map(Into::into),
+			oldest_allowed_block_height: status.oldest_allowed_block_height,
+		}
+	}
+}
+
+/// Prices (in microgons) for an amount of satoshis.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedemptionQuoteResponse {
+	/// The quoted amount of satoshis.
+	pub satoshis: Satoshis,
+	/// The current market rate of the satoshis.
+	pub market_rate: Option<Balance>,
+	/// The redemption price of the satoshis at the market rate.
+	pub redemption_rate: Option<Balance>,
+	/// The redemption amount capped at the given price at target (only on runtimes that support
+	/// it).
+	pub redemption_amount: Option<Balance>,
+}
+
+/// The latest notebook this node has audited for a notary.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditedNotebookResponse {
+	/// The notebook number.
+	pub notebook_number: NotebookNumber,
+	/// The notebook tick.
+	pub tick: Tick,
+	/// The first audit failure, if any.
+	pub audit_first_failure: Option<NotebookVerifyError>,
+}
+
+impl From<&NotebookAuditAndRawHeader<NotebookVerifyError>> for AuditedNotebookResponse {
+	fn from(notebook: &NotebookAuditAndRawHeader<NotebookVerifyError>) -> Self {
+		Self {
+			notebook_number: notebook.notebook_number,
+			tick: notebook.tick,
+			audit_first_failure: notebook.audit_first_failure.clone(),
+		}
+	}
+}
+
+/// A notary with its state.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotaryStateResponse {
+	/// The notary id.
+	pub notary_id: NotaryId,
+	/// The operator of the notary.
+	pub operator_account_id: AccountId,
+	/// The notary name.
+	pub name: String,
+	/// The notary hosts.
+	pub hosts: Vec<String>,
+	/// The block the notary was activated.
+	pub activated_block: BlockNumber,
+	/// The tick the notary meta was last updated.
+	pub meta_updated_tick: Tick,
+	/// One of `active`, `locked` or `reactivated`.
+	pub state: String,
+	/// The audit failure that locked the notary.
+	pub failed_audit_reason: Option<NotebookVerifyError>,
+	/// The tick the notary was locked.
+	pub locked_at_tick: Option<Tick>,
+	/// The locked or reprocessed notebook number.
+	pub notebook_number: Option<NotebookNumber>,
+	/// The latest notebook this node has audited for the notary.
+	pub latest_audited_notebook: Option<AuditedNotebookResponse>,
+}
+
+impl NotaryStateResponse {
+	fn new(
+		record: NotaryRecordT,
+		latest_audited_notebook: Option<AuditedNotebookResponse>,
+	) -> Self {
+		let (state, failed_audit_reason, locked_at_tick, notebook_number) = match record.state {
+			NotaryState::Active => ("active", None, None, None),
+			NotaryState::Locked { failed_audit_reason, at_tick, notebook_number } =>
+				("locked", Some(failed_audit_reason), Some(at_tick), Some(notebook_number)),
+			NotaryState::Reactivated { reprocess_notebook_number } =>
+				("reactivated", None, None, Some(reprocess_notebook_number)),
+		};
+		Self {
+			notary_id: record.notary_id,
+			operator_account_id: record.operator_account_id,
+			name: String::from_utf8_lossy(&record.meta.name.0).to_string(),
+			hosts: record
+				.meta
+				.hosts
+				.iter()
+				.map(|host| String::from_utf8_lossy(&host.0).to_string())
+				.collect(),
+			activated_block: record.activated_block,
+			meta_updated_tick: record.meta_updated_tick,
+			state: state.to_string(),
+			failed_audit_reason,
+			locked_at_tick,
+			notebook_number,
+			latest_audited_notebook,
+		}
+	}
+}
+
+/// The votes included in a single notebook.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotebookVotesResponse {
+	/// The notary id.
+	pub notary_id: NotaryId,
+	/// The notebook number.
+	pub notebook_number: NotebookNumber,
+	/// The votes in the notebook.
+	pub votes: Vec<RawVoteResponse>,
+}
+
+/// A single block vote.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RawVoteResponse {
+	/// The voting power of the vote.
+	pub power: BlockVotingPower,
+	/// The decoded vote, if it could be decoded.
+	pub vote: Option<BlockVote>,
+	/// The hex encoded vote.
+	pub raw_vote: String,
+}
+
+impl From<NotaryNotebookRawVotes> for NotebookVotesResponse {
+	fn from(votes: NotaryNotebookRawVotes) -> Self {
+		Self {
+			notary_id: votes.notary_id,
+			notebook_number: votes.notebook_number,
+			votes: votes
+				.raw_votes
+				.into_iter()
+				.map(|(raw_vote, power)| RawVoteResponse {
+					power,
+					vote: BlockVote::decode(&mut raw_vote.as_slice()).ok(),
+					raw_vote: format!("0x{}", hex::encode(raw_vote)),
+				})
+				.collect(),
+		}
+	}
+}
+
+/// The fork power recorded in a block header.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ForkPowerResponse {
+	/// The block hash.
+	pub block_hash: Hash,
+	/// The block number.
+	pub block_number: BlockNumber,
+	/// True if the block was created with a vote.
+	pub is_latest_vote: bool,
+	/// The notebooks included in the fork.
+	pub notebooks: u64,
+	/// The accumulated voting power of the fork.
+	pub voting_power: U256,
+	/// The seal strength of the latest vote.
+	pub seal_strength: U256,
+	/// The accumulated compute difficulty of the fork.
+	pub total_compute_difficulty: U256,
+	/// The number of vote created blocks in the fork.
+	pub vote_created_blocks: u128,
+	/// The xor distance of the miner to the vote.
+	pub miner_nonce_score: Option<U256>,
+}
+
+impl ForkPowerResponse {
+	fn new(block_hash: Hash, block_number: BlockNumber, power: ForkPower) -> Self {
+		Self {
+			block_hash,
+			block_number,
+			is_latest_vote: power.is_latest_vote,
+			notebooks: power.notebooks,
+			voting_power: power.voting_power,
+			seal_strength: power.seal_strength,
+			total_compute_difficulty: power.total_compute_difficulty,
+			vote_created_blocks: power.vote_created_blocks,
+			miner_nonce_score: power.miner_nonce_score,
+		}
+	}
+}
+
+/// The fork power of a block compared to another block.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockForkPowerResponse {
+	/// The fork power of the requested block.
+	pub block: ForkPowerResponse,
+	/// The fork power of the block it is compared to.
+	pub compared_to: ForkPowerResponse,
+	/// One of `better`, `worse` or `equal` for the requested block.
+	pub comparison: String,
+	/// The fork power input that decided the comparison (`blockHash` if all inputs are equal and
+	/// the lower block hash wins).
+	pub decided_by: String,
+}
+
+impl BlockForkPowerResponse {
+	fn new(
+		block: ForkPowerResponse,
+		compared_to: ForkPowerResponse,
+		power: &ForkPower,
+		other: &ForkPower,
+	) -> Self {
+		let (mut ordering, reason) = power.compare(other);
+		let decided_by = match reason {
+			ForkChoiceReason::VoteCreatedBlocks => "voteCreatedBlocks",
+			ForkChoiceReason::VotingPower => "votingPower",
+			ForkChoiceReason::SealStrength => "sealStrength",
+			ForkChoiceReason::MinerNonceScore => "minerNonceScore",
+			ForkChoiceReason::TotalComputeDifficulty => "totalComputeDifficulty",
+			ForkChoiceReason::Equal if block.block_hash != compared_to.block_hash => {
+				// equal forks are broken by the lower block hash
+				ordering = compared_to.block_hash.cmp(&block.block_hash);
+				"blockHash"
+			},
+			ForkChoiceReason::Equal => "equal",
+		};
+		let comparison = match ordering {
+			Ordering::Greater => "better",
+			Ordering::Less => "worse",
+			Ordering::Equal => "equal",
+		};
+		Self {
+			block,
+			compared_to,
+			comparison: comparison.to_string(),
+			decided_by: decided_by.to_string(),
+		}
+	}
+}
+
+/// The recent frame yields of a treasury bond program.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BondProgramYieldsResponse {
+	/// The vault of a vault bond program, or null for the Argonot bond program.
+	pub vault_id: Option<VaultId>,
+	/// The share of gross earnings paid to bond holders (the vault's current profit sharing,
+	/// which past frames may not have used).
+	pub sharing_percent: f64,
+	/// The yield of each recent frame, newest first.
+	pub frames: Vec<FrameYieldResponse>,
+}
+
+impl From<TreasuryProgramYields<Balance>> for BondProgramYieldsResponse {
+	fn from(yields: TreasuryProgramYields<Balance>) -> Self {
+		Self {
+			vault_id: yields.vault_id,
+			sharing_percent: yields.sharing_percent.deconstruct() as f64 / 1_000_000.0,
+			frames: yields.frames.into_iter().map(Into::into).collect(),
+		}
+	}
+}
+
+/// A bond holder's yield in one frame.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FrameYieldResponse {
+	/// The frame id.
+	pub frame_id: u64,
+	/// The bonds that shared the frame earnings.
+	pub bonds: u32,
+	/// The value of the bonds (microgons).
+	pub capital: Balance,
+	/// The earnings paid to bond holders (microgons).
+	pub earnings: Balance,
+	/// The frame yield annualized without compounding (0.05 is 5%).
+	pub apy: f64,
+}
+
+impl From<TreasuryFrameApy<Balance>> for FrameYieldResponse {
+	fn from(frame: TreasuryFrameApy<Balance>) -> Self {
+		Self {
+			frame_id: frame.frame_id,
+			bonds: frame.bonds,
+			capital: frame.capital,
+			earnings: frame.earnings,
+			apy: frame.apy.to_float(),
+		}
+	}
+}
+
+/// The projected next-frame yield of a bond purchase.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BondYieldProjectionResponse {
+	/// The vault of a vault bond program, or null for the Argonot bond program.
+	pub vault_id: Option<VaultId>,
+	/// The bonds to buy.
+	pub bonds: u32,
+	/// The accepted bond lot the purchase would bump.
+	pub bumped_bond_lot_id: Option<u64>,
+	/// The bonds in the bumped bond lot.
+	pub bumped_bonds: u32,
+	/// False if the vault would not be among the vaults sharing the next frame's bid pool.
+	pub is_participating: bool,
+	/// The value of the purchased bonds (microgons).
+	pub capital: Balance,
+	/// The projected earnings for one frame (microgons).
+	pub earnings: Balance,
+	/// The projected frame yield annualized without compounding (0.05 is 5%).
+	pub apy: f64,
+}
+
+impl From<TreasuryBondYieldProjection<Balance>> for BondYieldProjectionResponse {
+	fn from(projection: TreasuryBondYieldProjection<Balance>) -> Self {
+		Self {
+			vault_id: projection.vault_id,
+			bonds: projection.bonds,
+			bumped_bond_lot_id: projection.bumped_bond_lot_id,
+			bumped_bonds: projection.bumped_bonds,
+			is_participating: projection.is_participating,
+			capital: projection.capital,
+			earnings: projection.earnings,
+			apy: projection.apy.to_float(),
+		}
+	}
+}
+
+/// The tick of a new best block.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TickResponse {
+	/// The runtime tick.
+	pub tick: Tick,
+	/// The best block hash.
+	pub block_hash: Hash,
+	/// The best block number.
+	pub block_number: BlockNumber,
+	/// The block voting power of the notebooks this node has received for the tick.
+	pub voting_power: u128,
+	/// The number of notebooks this node has received for the tick.
+	pub notebooks: u32,
+}
+
+/// Implements the [`ArgonApiServer`] rpc trait.
+pub struct Argon<C: AuxStore> {
+	client: Arc<C>,
+	aux_client: ArgonAux<Block, C>,
+	executor: SubscriptionTaskExecutor,
+}
+
+impl<C: AuxStore> Argon<C> {
+	/// Create a new instance of the Argon rpc handler.
+	pub fn new(
+		client: Arc<C>,
+		aux_client: ArgonAux<Block, C>,
+		executor: SubscriptionTaskExecutor,
+	) -> Self {
+		Self { client, aux_client, executor }
+	}
+}
+
+impl<C> Argon<C>
+where
+	C: HeaderBackend<Block> + AuxStore,
+{
+	fn at(&self, at: Option<Hash>) -> Hash {
+		at.unwrap_or_else(|| self.client.info().best_hash)
+	}
+
+	fn fork_power(&self, block_hash: Hash) -> RpcResult<(ForkPowerResponse, ForkPower)> {
+		let header = self.client.header(block_hash).map_err(runtime_error)?.ok_or_else(|| {
+			ErrorObjectOwned::owned(
+				UNKNOWN_BLOCK_ERROR,
+				"Unknown block",
+				Some(format!("{block_hash:?}")),
+			)
+		})?;
+		let power = ForkPower::try_from(header.digest()).map_err(runtime_error)?;
+		Ok((ForkPowerResponse::new(block_hash, *header.number(), power.clone()), power))
+	}
+}
+
+fn runtime_error(error: impl std::fmt::Debug) -> ErrorObjectOwned {
+	ErrorObjectOwned::owned(RUNTIME_ERROR, "Runtime api error", Some(format!("{error:?}")))
+}
+
+fn aux_error(error: impl ToString) -> ErrorObjectOwned {
+	ErrorObjectOwned::owned(AUX_ERROR, "Unable to read consensus aux data", Some(error.to_string()))
+}
+
+impl<C> ArgonApiServer for Argon<C>
+where
+	C: ProvideRuntimeApi<Block>
+		+ HeaderBackend<Block>
+		+ BlockchainEvents<Block>
+		+ AuxStore
+		+ Send
+		+ Sync
+		+ 'static,
+	C::Api: MiningApis<Block, AccountId, BlockSealAuthorityId>
+		+ BitcoinApis<Block, Balance>
+		+ NotaryApis<Block, NotaryRecordT>
+		+ TickApis<Block>
+		+ TreasuryApis<Block, Balance>,
+{
+	fn block_payouts(&self, at: Option<Hash>) -> RpcResult<Vec<BlockPayoutResponse>> {
+		let payouts = self
+			.client
+			.runtime_api()
+			.get_block_payouts(self.at(at))
+			.map_err(runtime_error)?;
+		Ok(payouts.into_iter().map(Into::into).collect())
+	}
+
+	fn bitcoin_sync_status(
+		&self,
+		at: Option<Hash>,
+	) -> RpcResult<Option<BitcoinSyncStatusResponse>> {
+		let status =
+			self.client.runtime_api().get_sync_status(self.at(at)).map_err(runtime_error)?;
+		Ok(status.map(Into::into))
+	}
+
+	fn redemption_quote(
+		&self,
+		satoshis: Satoshis,
+		max_microgons_at_target: Option<Balance>,
+		at: Option<Hash>,
+	) -> RpcResult<RedemptionQuoteResponse> {
+		let at = self.at(at);
+		let api = self.client.runtime_api();
+		let api_version = api
+			.api_version::<dyn BitcoinApis<Block, Balance>>(at)
+			.map_err(runtime_error)?
+			.unwrap_or_default();
+		let redemption_amount = if api_version >= 3 {
+			api.calculate_redemption_amount(at, satoshis, max_microgons_at_target)
+				.map_err(runtime_error)?
+		} else {
+			None
+		};
+		Ok(RedemptionQuoteResponse {
+			satoshis,
+			market_rate: api.market_rate(at, satoshis).map_err(runtime_error)?,
+			redemption_rate: api.redemption_rate(at, satoshis).map_err(runtime_error)?,
+			redemption_amount,
+		})
+	}
+
+	fn notary_states(&self, at: Option<Hash>) -> RpcResult<Vec<NotaryStateResponse>> {
+		let notaries = self.client.runtime_api().notaries(self.at(at)).map_err(runtime_error)?;
+		notaries
+			.into_iter()
+			.map(|record| {
+				let history = self
+					.aux_client
+					.get_notary_audit_history(record.notary_id)
+					.map_err(aux_error)?;
+				let latest = history.get().last_key_value().map(|(_, notebook)| notebook.into());
+				Ok(NotaryStateResponse::new(record, latest))
+			})
+			.collect()
+	}
+
+	fn notary_audit_history(
+		&self,
+		notary_id: NotaryId,
+		start_tick: Option<Tick>,
+		end_tick: Option<Tick>,
+	) -> RpcResult<Vec<AuditedNotebookResponse>> {
+		let history = self.aux_client.get_notary_audit_history(notary_id).map_err(aux_error)?;
+		let start_tick = start_tick.unwrap_or_default();
+		let end_tick = end_tick.unwrap_or(Tick::MAX);
+		Ok(history
+			.get()
+			.values()
+			.filter(|notebook| notebook.tick >= start_tick && notebook.tick <= end_tick)
+			.map(Into::into)
+			.collect())
+	}
+
+	fn votes_at_tick(&self, tick: Tick) -> RpcResult<Vec<NotebookVotesResponse>> {
+		let votes = self.aux_client.get_votes(tick).map_err(aux_error)?;
+		Ok(votes.get().into_iter().map(Into::into).collect())
+	}
+
+	fn block_fork_power(
+		&self,
+		block_hash: Hash,
+		compare_to: Option<Hash>,
+	) -> RpcResult<BlockForkPowerResponse> {
+		let compare_to = self.at(compare_to);
+		let (block, power) = self.fork_power(block_hash)?;
+		let (compared_to, other_power) = self.fork_power(compare_to)?;
+		Ok(BlockForkPowerResponse::new(block, compared_to, &power, &other_power))
+	}
+
+	fn treasury_bond_yields(&self, at: Option<Hash>) -> RpcResult<Vec<BondProgramYieldsResponse>> {
+		let yields = self
+			.client
+			.runtime_api()
+			.bond_program_yields(self.at(at))
+			.map_err(runtime_error)?;
+		Ok(yields.into_iter().map(Into::into).collect())
+	}
+
+	fn treasury_bond_yield_projection(
+		&self,
+		vault_id: Option<VaultId>,
+		bonds: u32,
+		at: Option<Hash>,
+	) -> RpcResult<BondYieldProjectionResponse> {
+		let projection = self
+			.client
+			.runtime_api()
+			.project_bond_yield(self.at(at), vault_id, bonds)
+			.map_err(runtime_error)?
+			.map_err(runtime_error)?;
+		Ok(projection.into())
+	}
+
+	fn subscribe_ticks(&self, pending: PendingSubscriptionSink) {
+		let client = self.client.clone();
+		let aux_client = self.aux_client.clone();
+		let mut last_tick = None;
+		let stream = self
+			.client
+			.import_notification_stream()
+			.filter(|notification| future::ready(notification.is_new_best))
+			.filter_map(move |notification| {
+				let tick = client.runtime_api().current_tick(notification.hash).ok();
+				let response = match tick {
+					Some(tick) if last_tick != Some(tick) => {
+						last_tick = Some(tick);
+						let (_, voting_power, notebooks) = aux_client
+							.get_tick_voting_power(tick)
+							.ok()
+							.flatten()
+							.unwrap_or((tick, 0, 0));
+						Some(TickResponse {
+							tick,
+							block_hash: notification.hash,
+							block_number: *notification.header.number(),
+							voting_power,
+							notebooks,
+						})
+					},
+					_ => None,
+				};
+				future::ready(response)
+			});
+
+		spawn_subscription_task(&self.executor, pipe_from_stream(pending, stream));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use argon_primitives::bitcoin::H256Le;
+	use serde_json::json;
+	use sp_core::crypto::AccountId32;
+
+	#[test]
+	fn converts_bitcoin_sync_status() {
+		let mut hash = [0u8; 32];
+		hash[31] = 1;
+		let response = BitcoinSyncStatusResponse::from(BitcoinSyncStatus {
+			confirmed_block: BitcoinBlock::new(100, H256Le(hash)),
+			synched_block: None,
+			oldest_allowed_block_height: 90,
+		});
+		assert_eq!(
+			serde_json::to_value(response).unwrap(),
+			json!({
+				"confirmedBlock": {
+					"blockHeight": 100,
+					"blockHash": format!("01{}", "00".repeat(31)),
+				},
+				"synchedBlock": null,
+				"oldestAllowedBlockHeight": 90,
+			})
+		);
+	}
+
+	#[test]
+	fn explains_fork_power_comparison() {
+		let mut power = ForkPower::default();
+		power.add_vote(10, 1, U256::from(5), None);
+		let mut other = power.clone();
+		let block = ForkPowerResponse::new(Hash::repeat_byte(1), 10, power.clone());
+		let compared_to = ForkPowerResponse::new(Hash::repeat_byte(2), 10, other.clone());
+
+		let response =
+			BlockForkPowerResponse::new(block.clone(), compared_to.clone(), &power, &other);
+		assert_eq!(response.comparison, "better");
+		assert_eq!(response.decided_by, "blockHash");
+
+		other.seal_strength = U256::from(4);
+		let response = BlockForkPowerResponse::new(block, compared_to, &power, &other);
+		assert_eq!(response.comparison, "worse");
+		assert_eq!(response.decided_by, "sealStrength");
+	}
+
+	#[test]
+	fn converts_bond_program_yields() {
+		let response = BondProgramYieldsResponse::from(TreasuryProgramYields {
+			vault_id: Some(2),
+			sharing_percent: sp_runtime::Permill::from_percent(20),
+			frames: vec![TreasuryFrameApy {
+				frame_id: 5,
+				bonds: 100,
+				capital: 100_000_000,
+				earnings: 50_000,
+				apy: sp_runtime::FixedU128::from_rational(1825, 10_000),
+			}],
+		});
+		assert_eq!(
+			serde_json::to_value(response).unwrap(),
+			json!({
+				"vaultId": 2,
+				"sharingPercent": 0.2,
+				"frames": [{
+					"frameId": 5,
+					"bonds": 100,
+					"capital": 100_000_000,
+					"earnings": 50_000,
+					"apy": 0.1825,
+				}],
+			})
+		);
+	}
+
+	#[test]
+	fn converts_block_payouts() {
+		let account_id = AccountId32::new([1u8; 32]);
+		let response = BlockPayoutResponse::from(BlockPayout {
+			account_id: account_id.clone(),
+			ownership: 1_000,
+			argons: 2_000,
+			reward_type: BlockRewardType::ProfitShare,
+			block_seal_authority: None,
+		});
+		assert_eq!(
+			serde_json::to_value(response).unwrap(),
+			json!({
+				"accountId": account_id.to_string(),
+				"ownership": 1_000,
+				"argons": 2_000,
+				"rewardType": "profitShare",
+				"blockSealAuthority": null,
+			})
+		);
+	}
+}
@@ -0,0 +1,289 @@
+//! Lets operators see the vote block this node would author for a tick, and why candidates were
+//! rejected, without creating anything.
+
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::ErrorObjectOwned,
+	Extensions,
+};
+use polkadot_sdk::*;
+use serde::{Deserialize, Serialize};
+
+use crate::runtime_api::opaque::{Block, Hash};
+use argon_node_consensus::{
+	block_creator::BlockProposalDryRun,
+	notebook_sealer::{NotebookSealer, SealRejection},
+};
+use argon_primitives::{
+	fork_power::ForkPower, tick::Tick, AccountId, BlockCreatorApis, BlockSealApis,
+	BlockSealAuthorityId, BlockVotingPower, NotaryApis, NotaryId, NotebookApis,
+	NotebookAuditResult, NotebookNumber, TickApis,
+};
+use argon_runtime::{NotaryRecordT, NotebookVerifyError};
+use codec::Encode;
+use sc_client_api::AuxStore;
+use sc_rpc_api::check_if_safe;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_consensus::SelectChain;
+use sp_core::U256;
+
+const DRY_RUN_ERROR: i32 = 4;
+
+/// Block creator inspection rpc methods.
+#[rpc(server)]
+pub trait BlockProposalsApi {
+	/// Assembles the vote block this node would propose for a notebook tick (defaults to the
+	/// latest notebook tick). Nothing is signed or submitted, but it uses the local block seal
+	/// keys, so it's an unsafe method.
+	#[method(name = "argon_blockProposalDryRun", with_extensions)]
+	async fn block_proposal_dry_run(
+		&self,
+		notebook_tick: Option<Tick>,
+	) -> RpcResult<BlockProposalDryRunResponse>;
+}
+
+/// The vote block this node would propose.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockProposalDryRunResponse {
+	/// The notebook tick the votes were sealed for.
+	pub notebook_tick: Tick,
+	/// The tick the block would be created in.
+	pub block_tick: Tick,
+	/// The block it would be built on.
+	pub parent_hash: Option<Hash>,
+	/// The strongest vote seal the local keys can create.
+	pub seal: Option<VoteSealResponse>,
+	/// The notebooks that would be included.
+	pub notebooks: Vec<ProposedNotebookResponse>,
+	/// The voting power of the included notebooks.
+	pub voting_power: BlockVotingPower,
+	/// The fork power the block would have with the chosen seal.
+	pub fork_power: Option<ProposedForkPowerResponse>,
+	/// Each reason the tick or a candidate block was not used.
+	pub rejections: Vec<SealRejectionResponse>,
+}
+
+/// A vote seal chosen for a block.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VoteSealResponse {
+	/// The seal strength (lower is stronger).
+	pub seal_strength: U256,
+	/// The xor distance of the miner to the vote.
+	pub miner_nonce_score: Option<U256>,
+	/// The notary of the sealing vote.
+	pub notary_id: NotaryId,
+	/// The notebook the sealing vote was included in.
+	pub source_notebook_number: NotebookNumber,
+	/// The miner account that would author the block.
+	pub author: AccountId,
+	/// The hex encoded block seal authority that would sign the block.
+	pub block_seal_authority: String,
+}
+
+/// A notebook that would be included in the block.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposedNotebookResponse {
+	/// The notary id.
+	pub notary_id: NotaryId,
+	/// The notebook number.
+	pub notebook_number: NotebookNumber,
+	/// The notebook tick.
+	pub tick: Tick,
+	/// The first audit failure, if any.
+	pub audit_first_failure: Option<NotebookVerifyError>,
+}
+
+impl From<NotebookAuditResult<NotebookVerifyError>> for ProposedNotebookResponse {
+	fn from(notebook: NotebookAuditResult<NotebookVerifyError>) -> Self {
+		Self {
+			notary_id: notebook.notary_id,
+			notebook_number: notebook.notebook_number,
+			tick: notebook.tick,
+			audit_first_failure: notebook.audit_first_failure,
+		}
+	}
+}
+
+/// The fork power a proposed block would have.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposedForkPowerResponse {
+	/// The notebooks included in the fork.
+	pub notebooks: u64,
+	/// The accumulated voting power of the fork.
+	pub voting_power: U256,
+	/// The seal strength of the latest vote.
+	pub seal_strength: U256,
+	/// The accumulated compute difficulty of the fork.
+	pub total_compute_difficulty: U256,
+	/// The number of vote created blocks in the fork.
+	pub vote_created_blocks: u128,
+	/// The xor distance of the miner to the vote.
+	pub miner_nonce_score: Option<U256>,
+}
+
+impl From<ForkPower> for ProposedForkPowerResponse {
+	fn from(power: ForkPower) -> Self {
+		Self {
+			notebooks: power.notebooks,
+			voting_power: power.voting_power,
+			seal_strength: power.seal_strength,
+			total_compute_difficulty: power.total_compute_difficulty,
+			vote_created_blocks: power.vote_created_blocks,
+			miner_nonce_score: power.miner_nonce_score,
+		}
+	}
+}
+
+/// Why a tick or a candidate block was not used to create a vote block, or why the compute worker
+/// would not solve a block instead.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "reason", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum SealRejectionResponse {
+	/// The clock hasn't moved past the notebook tick.
+	ClockBeforeNotebookTick { current_tick: Tick },
+	/// The notebook tick is too old for its votes to still be active.
+	NotebookTickTooOld { current_tick: Tick },
+	/// There are no block seal keys in the keystore.
+	NoBlockSealKeys,
+	/// No votes were received for the eligible votes tick.
+	NoVotes { votes_tick: Tick },
+	/// A block at the block tick has already been finalized.
+	BlockTickFinalized { finalized_tick: Tick },
+	/// A leaf has no ancestor at the parent tick.
+	NoAncestorAtParentTick { leaf: Hash },
+	/// The block has no votes eligible to seal a child.
+	NoEligibleVotes { block_hash: Hash },
+	/// Another candidate parent has more fork power.
+	WeakerForkPower { block_hash: Hash, best_block_hash: Hash },
+	/// No candidate parent block could be found.
+	NoParentBlock,
+	/// None of the local keys can beat the best seal already built on the parent.
+	NoStrongerSeal { block_hash: Hash, seal_strength: U256, miner_nonce_score: Option<U256> },
+	/// This node isn't authoring compute blocks.
+	ComputeNotRunning,
+	/// There are no compute threads or external miners to solve a compute block.
+	NoComputeSolvers,
+	/// The compute worker is still waiting for a vote block before it solves.
+	ComputeNotReadyToSolve { current_tick: Tick },
+}
+
+impl From<SealRejection<Hash>> for SealRejectionResponse {
+	fn from(rejection: SealRejection<Hash>) -> Self {
+		match rejection {
+			SealRejection::ClockBeforeNotebookTick { current_tick } =>
+				Self::ClockBeforeNotebookTick { current_tick },
+			SealRejection::NotebookTickTooOld { current_tick } =>
+				Self::NotebookTickTooOld { current_tick },
+			SealRejection::NoBlockSealKeys => Self::NoBlockSealKeys,
+			SealRejection::NoVotes { votes_tick } => Self::NoVotes { votes_tick },
+			SealRejection::BlockTickFinalized { finalized_tick } =>
+				Self::BlockTickFinalized { finalized_tick },
+			SealRejection::NoAncestorAtParentTick { leaf } => Self::NoAncestorAtParentTick { leaf },
+			SealRejection::NoEligibleVotes { block_hash } => Self::NoEligibleVotes { block_hash },
+			SealRejection::WeakerForkPower { block_hash, best_block_hash } =>
+				Self::WeakerForkPower { block_hash, best_block_hash },
+			SealRejection::NoParentBlock => Self::NoParentBlock,
+			SealRejection::NoStrongerSeal { block_hash, seal_strength, miner_nonce_score } =>
+				Self::NoStrongerSeal { block_hash, seal_strength, miner_nonce_score },
+			SealRejection::ComputeNotRunning => Self::ComputeNotRunning,
+			SealRejection::NoComputeSolvers => Self::NoComputeSolvers,
+			SealRejection::ComputeNotReadyToSolve { current_tick } =>
+				Self::ComputeNotReadyToSolve { current_tick },
+		}
+	}
+}
+
+impl From<BlockProposalDryRun<Hash, AccountId>> for BlockProposalDryRunResponse {
+	fn from(dry_run: BlockProposalDryRun<Hash, AccountId>) -> Self {
+		Self {
+			notebook_tick: dry_run.notebook_tick,
+			block_tick: dry_run.block_tick,
+			parent_hash: dry_run.parent_hash,
+			seal: dry_run.vote_seal.map(|seal| VoteSealResponse {
+				seal_strength: seal.seal_strength,
+				miner_nonce_score: seal.miner_nonce_score.map(|(distance, _)| distance),
+				notary_id: seal.notary_id,
+				source_notebook_number: seal.source_notebook_number,
+				author: seal.closest_miner.0,
+				block_seal_authority: format!("0x{}", hex::encode(seal.closest_miner.1.encode())),
+			}),
+			notebooks: dry_run.notebooks.into_iter().map(Into::into).collect(),
+			voting_power: dry_run.voting_power,
+			fork_power: dry_run.fork_power.map(Into::into),
+			rejections: dry_run.rejections.into_iter().map(Into::into).collect(),
+		}
+	}
+}
+
+/// Implements the [`BlockProposalsApiServer`] rpc trait.
+pub struct BlockProposals<C: AuxStore, SC> {
+	notebook_sealer: NotebookSealer<Block, C, SC, AccountId>,
+}
+
+impl<C: AuxStore, SC> BlockProposals<C, SC> {
+	/// Create a new instance with a sealer that can't submit blocks.
+	pub fn new(notebook_sealer: NotebookSealer<Block, C, SC, AccountId>) -> Self {
+		Self { notebook_sealer }
+	}
+}
+
+#[async_trait]
+impl<C, SC> BlockProposalsApiServer for BlockProposals<C, SC>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + AuxStore + Send + Sync + 'static,
+	C::Api: BlockSealApis<Block, AccountId, BlockSealAuthorityId>
+		+ BlockCreatorApis<Block, AccountId, NotebookVerifyError>
+		+ NotebookApis<Block, NotebookVerifyError>
+		+ NotaryApis<Block, NotaryRecordT>
+		+ TickApis<Block>,
+	SC: SelectChain<Block> + Send + Sync + 'static,
+{
+	async fn block_proposal_dry_run(
+		&self,
+		ext: &Extensions,
+		notebook_tick: Option<Tick>,
+	) -> RpcResult<BlockProposalDryRunResponse> {
+		check_if_safe(ext)?;
+		let dry_run = self.notebook_sealer.dry_run(notebook_tick).await.map_err(|e| {
+			ErrorObjectOwned::owned(
+				DRY_RUN_ERROR,
+				"Unable to assemble block proposal",
+				Some(format!("{e:?}")),
+			)
+		})?;
+		Ok(dry_run.into())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn serializes_rejection_reasons() {
+		let hash = Hash::repeat_byte(1);
+		let rejections = vec![
+			SealRejection::NoBlockSealKeys,
+			SealRejection::WeakerForkPower { block_hash: hash, best_block_hash: hash },
+			SealRejection::ComputeNotReadyToSolve { current_tick: 5 },
+		]
+		.into_iter()
+		.map(SealRejectionResponse::from)
+		.collect::<Vec<_>>();
+		assert_eq!(
+			serde_json::to_value(rejections).unwrap(),
+			json!([
+				{ "reason": "noBlockSealKeys" },
+				{ "reason": "weakerForkPower", "blockHash": hash, "bestBlockHash": hash },
+				{ "reason": "computeNotReadyToSolve", "currentTick": 5 },
+			])
+		);
+	}
+}
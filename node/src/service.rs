@@ -7,12 +7,14 @@ use crate::{
 	runtime_api::{opaque::Block, BaseHostRuntimeApis},
 };
 use argon_bitcoin_utxo_tracker::UtxoTracker;
-#[cfg(any(not(debug_assertions), test))]
-use argon_node_consensus::read_chain_spec_bitcoin_network;
 use argon_node_consensus::{
-	aux_client::ArgonAux, create_import_queue, grandpa_hard_forks,
-	read_chain_spec_grandpa_authorities, read_chain_spec_ticker, run_block_builder_task,
-	run_notary_sync, BlockBuilderParams, NotaryClient, NotebookDownloader,
+	aux_client::ArgonAux,
+	create_import_queue, grandpa_hard_forks,
+	notebook_archive_mirror::{run_notebook_archive_mirror_server, NotebookArchiveMirror},
+	notebook_sealer::NotebookSealer,
+	read_chain_spec_bitcoin_network, read_chain_spec_grandpa_authorities, read_chain_spec_ticker,
+	run_block_builder_task, run_notary_sync, BlockBuilderParams, NotaryClient, NotebookDownloader,
+	SharedComputeHandle,
 };
 use argon_primitives::{
 	digests::ArgonDigests,
@@ -162,14 +164,21 @@ where
 			telemetry.as_ref().map(|x| x.handle()),
 		)?;
 
-	let (bitcoin_url, bitcoin_auth) = mining_config
-		.bitcoin_rpc_url_with_auth()
-		.map_err(|e| ServiceError::Other(format!("Failed to parse bitcoin rpc url {e:?}")))?;
-	let utxo_tracker = UtxoTracker::new(
-		bitcoin_url.origin().unicode_serialization(),
-		bitcoin_auth,
-		config.prometheus_registry(),
-	)
+	let utxo_tracker = if let Some(bitcoin_peer) = &mining_config.bitcoin_p2p_peer {
+		let bitcoin_network = read_chain_spec_bitcoin_network(config.chain_spec.as_ref())
+			.map_err(|e| ServiceError::Other(e.to_string()))?;
+		UtxoTracker::from_p2p_peer(bitcoin_peer, bitcoin_network, config.prometheus_registry())
+	} else {
+		let (bitcoin_url, bitcoin_auth) = mining_config
+			.bitcoin_rpc_url_with_auth()
+			.map_err(|e| ServiceError::Other(format!("Failed to parse bitcoin rpc url {e:?}")))?;
+		UtxoTracker::new(
+			bitcoin_url.origin().unicode_serialization(),
+			bitcoin_auth,
+			config.prometheus_registry(),
+		)
+		.map(|tracker| tracker.with_tip_verification(mining_config.bitcoin_verify_tip))
+	}
 	.map_err(|e| ServiceError::Other(format!("Failed to initialize bitcoin monitoring {e:?}")))?;
 
 	let utxo_tracker = Arc::new(utxo_tracker);
@@ -185,13 +194,22 @@ where
 		ticker
 	};
 	let idle_delay = if ticker.tick_duration_millis <= 10_000 { 100 } else { 1000 };
-	let notebook_downloader = NotebookDownloader::new(
+	let mut notebook_downloader = NotebookDownloader::new(
 		mining_config.notebook_archive_hosts.clone(),
 		mining_config.notebook_download_trust_mode,
 		mining_config.notebook_header_max_bytes,
 		mining_config.notebook_body_max_bytes,
 	)
 	.map_err(|e| ServiceError::Other(format!("Failed to initialize notebook downloader {e:?}")))?;
+	if let Some(path) = mining_config.notebook_archive_mirror_path.as_ref() {
+		let archive_mirror = Arc::new(NotebookArchiveMirror::new(path).map_err(|e| {
+			ServiceError::Other(format!("Failed to initialize notebook archive mirror {e:?}"))
+		})?);
+		if let Some(listen) = mining_config.notebook_archive_mirror_listen {
+			run_notebook_archive_mirror_server(&task_manager, archive_mirror.clone(), listen);
+		}
+		notebook_downloader = notebook_downloader.with_archive_mirror(archive_mirror);
+	}
 	let notary_client = run_notary_sync(
 		&task_manager,
 		client.clone(),
@@ -336,6 +354,7 @@ where
 		})?;
 	}
 	let shared_voter_state = sc_consensus_grandpa::SharedVoterState::empty();
+	let compute_handle = SharedComputeHandle::default();
 
 	let rpc_builder = {
 		let client = client.clone();
@@ -349,12 +368,23 @@ where
 			Some(shared_authority_set.clone()),
 		);
 		let shared_voter_state = shared_voter_state.clone();
+		let aux_client = aux_client.clone();
+		let notebook_sealer = NotebookSealer::new_inspector(
+			client.clone(),
+			ticker,
+			select_chain.clone(),
+			keystore_container.keystore(),
+			aux_client.clone(),
+			compute_handle.clone(),
+		);
 		Box::new(move |subscription_executor: SubscriptionTaskExecutor| {
 			let deps = rpc::FullDeps {
 				client: client.clone(),
 				pool: transaction_pool.clone(),
 				sync_service: sync_service.clone(),
 				starting_block,
+				aux_client: aux_client.clone(),
+				notebook_sealer: notebook_sealer.clone(),
 				grandpa: GrandpaDeps {
 					shared_voter_state: shared_voter_state.clone(),
 					shared_authority_set: shared_authority_set.clone(),
@@ -412,6 +442,8 @@ where
 				justification_sync_link: sync_service.clone(),
 				compute_author,
 				compute_threads,
+				compute_miner_listen: mining_config.compute_miner_listen,
+				compute_handle,
 			},
 			&task_manager,
 		);
@@ -1,6 +1,9 @@
-use crate::uniswap_oracle::{
-	ethereum_rpc_urls_from_env, PriceAndLiquidity, UniswapOracle, USDC_ADDRESS,
-	USDC_ADDRESS_SEPOLIA,
+use crate::{
+	dex_pools::DexPoolSource,
+	uniswap_oracle::{
+		ethereum_rpc_urls_from_env, PriceAndLiquidity, UniswapOracle, USDC_ADDRESS,
+		USDC_ADDRESS_SEPOLIA,
+	},
 };
 use anyhow::Result;
 use argon_client::api::runtime_types::pallet_price_index::PriceIndex;
@@ -32,10 +35,10 @@ impl ArgonPriceLookup {
 		ticker: &Ticker,
 		last_price: Option<PriceIndex>,
 		ethereum_rpc_urls: Vec<String>,
-		usd_token: Token,
 		lookup_token: Token,
+		pools: Vec<DexPoolSource>,
 	) -> Result<Self> {
-		let uniswap_oracle = UniswapOracle::new(ethereum_rpc_urls, usd_token, lookup_token).await?;
+		let uniswap_oracle = UniswapOracle::new(ethereum_rpc_urls, lookup_token, pools).await?;
 
 		Ok(Self {
 			uniswap_oracle,
@@ -59,11 +62,11 @@ impl ArgonPriceLookup {
 			env::var("ARGON_TOKEN_ADDRESS").expect("ARGON_TOKEN_ADDRESS must be set");
 		let network = if use_sepolia { ChainId::SEPOLIA } else { ChainId::MAINNET };
 		let ethereum_rpc_urls = ethereum_rpc_urls_from_env()?;
+		let pools = DexPoolSource::list_from_env("ARGON_DEX_POOLS")?;
 
-		let usdc_token = get_usdc_token(network);
 		let lookup_token =
 			token!(network as u64, argon_token_address, 18, ARGON_TOKEN_SYMBOL, "Argon");
-		Self::new(ticker, last_price, ethereum_rpc_urls, usdc_token, lookup_token).await
+		Self::new(ticker, last_price, ethereum_rpc_urls, lookup_token, pools).await
 	}
 
 	/// Calculates the expected cost of an Argon in USD based on the starting and current U.S. CPI.
@@ -78,9 +81,7 @@ impl ArgonPriceLookup {
 		max_argon_change_per_tick_away_from_target: FixedU128,
 		usd_token_price: FixedU128,
 	) -> Result<PriceAndLiquidity> {
-		let mut price = self.uniswap_oracle.get_current_price().await?;
-		// ARGON/USDC * USDC/USD = ARGON/USD
-		price.price = price.price * usd_token_price;
+		let mut price = self.uniswap_oracle.get_current_price(usd_token_price).await?;
 
 		price.price =
 			self.clamp_price(price.price, tick, max_argon_change_per_tick_away_from_target);
@@ -1,5 +1,5 @@
 use crate::{
-	argon_price::get_usdc_token,
+	dex_pools::DexPoolSource,
 	uniswap_oracle::{ethereum_rpc_urls_from_env, UniswapOracle},
 };
 use anyhow::Result;
@@ -17,11 +17,11 @@ pub struct ArgonotPriceLookup {
 impl ArgonotPriceLookup {
 	pub async fn new(
 		ethereum_rpc_urls: Vec<String>,
-		usd_token: Token,
 		lookup_token: Token,
+		pools: Vec<DexPoolSource>,
 		last_price: FixedU128,
 	) -> Result<Self> {
-		let uniswap_oracle = UniswapOracle::new(ethereum_rpc_urls, usd_token, lookup_token).await?;
+		let uniswap_oracle = UniswapOracle::new(ethereum_rpc_urls, lookup_token, pools).await?;
 
 		Ok(Self { last_price, uniswap_oracle })
 	}
@@ -32,16 +32,14 @@ impl ArgonotPriceLookup {
 			env::var("ARGONOT_TOKEN_ADDRESS").expect("ARGONOT_TOKEN_ADDRESS must be set");
 		let network = if use_sepolia { ChainId::SEPOLIA } else { ChainId::MAINNET };
 		let ethereum_rpc_urls = ethereum_rpc_urls_from_env()?;
+		let pools = DexPoolSource::list_from_env("ARGONOT_DEX_POOLS")?;
 
-		let usdc_token = get_usdc_token(network);
 		let lookup_token = token!(network as u64, argonot_token_address, 18);
-		Self::new(ethereum_rpc_urls, usdc_token, lookup_token, last_price).await
+		Self::new(ethereum_rpc_urls, lookup_token, pools, last_price).await
 	}
 
 	pub async fn get_latest_price(&mut self, usd_token_price: FixedU128) -> Result<FixedU128> {
-		let price = self.uniswap_oracle.get_current_price().await?;
-		// ARGONOT/USDC * USDC/USD = ARGONOT/USD
-		let price = price.price * usd_token_price;
+		let price = self.uniswap_oracle.get_current_price(usd_token_price).await?.price;
 		self.last_price = price;
 		Ok(price)
 	}
@@ -1,9 +1,9 @@
-use crate::utils::parse_f64;
+use crate::{response_recording::ResponseRecorder, utils::parse_f64};
 use anyhow::{anyhow, Result};
 use clap::ValueEnum;
 use polkadot_sdk::*;
 use reqwest::Client;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use sp_runtime::{traits::One, FixedU128};
 use std::{collections::HashMap, time::Duration};
 use tokio::{join, task::JoinSet, time::Instant};
@@ -20,6 +20,9 @@ pub struct CoinUsdPriceLookup {
 	pub client: Client,
 	pub last_refresh: Option<(Instant, PriceLookups)>,
 	pub providers: Vec<PriceProviderKind>,
+	pub recorder: ResponseRecorder,
+	/// The raw prices (or failure) of each provider in the last refresh
+	pub last_provider_prices: Vec<ProviderPrice>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
@@ -41,7 +44,18 @@ pub const ALL_PRICE_PROVIDERS: &[PriceProviderKind] = &[
 
 impl CoinUsdPriceLookup {
 	pub fn new_with_providers(providers: Vec<PriceProviderKind>) -> Self {
-		Self { client: Client::new(), last_refresh: None, providers }
+		Self {
+			client: Client::new(),
+			last_refresh: None,
+			providers,
+			recorder: Default::default(),
+			last_provider_prices: vec![],
+		}
+	}
+
+	pub fn with_recorder(mut self, recorder: ResponseRecorder) -> Self {
+		self.recorder = recorder;
+		self
 	}
 
 	fn median_fixed(values: &mut [FixedU128]) -> Option<FixedU128> {
@@ -91,10 +105,14 @@ impl CoinUsdPriceLookup {
 		let mut join_set: JoinSet<(PriceProviderKind, Result<PriceLookupMaybe>)> = JoinSet::new();
 		for provider in self.providers.iter().copied() {
 			let client = self.client.clone();
+			let recorder = self.recorder.clone();
 			join_set.spawn(async move {
-				let result =
-					CoinUsdPriceLookup::get_price_from_provider_with_client(&client, provider)
-						.await;
+				let result = recorder
+					.capture(
+						&format!("coin-price:{provider:?}"),
+						CoinUsdPriceLookup::get_price_from_provider_with_client(&client, provider),
+					)
+					.await;
 				(provider, result)
 			});
 		}
@@ -102,10 +120,18 @@ impl CoinUsdPriceLookup {
 		let mut bitcoin_values: Vec<FixedU128> = Vec::new();
 		let mut ethereum_values: Vec<FixedU128> = Vec::new();
 		let mut usdc_values: Vec<FixedU128> = Vec::new();
+		let mut provider_prices: Vec<ProviderPrice> = Vec::new();
 
 		while let Some(joined) = join_set.join_next().await {
 			match joined {
-				Ok((_provider, Ok(price))) => {
+				Ok((provider, Ok(price))) => {
+					provider_prices.push(ProviderPrice {
+						provider,
+						bitcoin: Some(price.bitcoin),
+						ethereum: price.ethereum,
+						usdc: price.usdc,
+						error: None,
+					});
 					bitcoin_values.push(price.bitcoin);
 					if let Some(ethereum) = price.ethereum {
 						ethereum_values.push(ethereum);
@@ -118,6 +144,13 @@ impl CoinUsdPriceLookup {
 					// Avoid the literal substring "error" in our own message; upstream errors may
 					// still contain it.
 					println!("Price lookup failed on {provider:?}: {err}");
+					provider_prices.push(ProviderPrice {
+						provider,
+						bitcoin: None,
+						ethereum: None,
+						usdc: None,
+						error: Some(err.to_string()),
+					});
 				},
 				Err(join_err) => {
 					println!("Price lookup task failed: {join_err}");
@@ -125,6 +158,11 @@ impl CoinUsdPriceLookup {
 			}
 		}
 
+		provider_prices.sort_by_key(|price| {
+			self.providers.iter().position(|provider| *provider == price.provider)
+		});
+		self.last_provider_prices = provider_prices;
+
 		let median_btc = Self::median_fixed(&mut bitcoin_values)
 			.ok_or_else(|| anyhow!("No bitcoin prices available from any provider"))?;
 		let median_ethereum = Self::median_fixed(&mut ethereum_values);
@@ -273,6 +311,16 @@ pub struct PriceLookups {
 	pub usdc: FixedU128,
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProviderPrice {
+	pub provider: PriceProviderKind,
+	pub bitcoin: Option<FixedU128>,
+	pub ethereum: Option<FixedU128>,
+	pub usdc: Option<FixedU128>,
+	pub error: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
 struct PriceLookupMaybe {
 	bitcoin: FixedU128,
 	ethereum: Option<FixedU128>,
@@ -0,0 +1,190 @@
+use crate::argon_price::get_usdc_token;
+use alloy_primitives::{address, Address};
+use anyhow::{anyhow, bail, Context, Result};
+use argon_primitives::Balance;
+use polkadot_sdk::*;
+use sp_runtime::{traits::One, FixedU128};
+use std::{env, fmt, str::FromStr};
+use uniswap_sdk_core::{prelude::*, token};
+use uniswap_v3_sdk::prelude::FeeAmount;
+
+pub const USDT_ADDRESS: Address = address!("dAC17F958D2ee523a2206206994597C13D831ec7");
+pub const DAI_ADDRESS: Address = address!("6B175474E89094C44Da98b954EedeAC495271d0F");
+
+/// The stablecoin a pool prices the looked up token in
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum QuoteToken {
+	Usdc,
+	Usdt,
+	Dai,
+}
+
+impl QuoteToken {
+	pub fn token(&self, chain_id: u64) -> Result<Token> {
+		if chain_id == ChainId::SEPOLIA as u64 {
+			return match self {
+				Self::Usdc => Ok(get_usdc_token(ChainId::SEPOLIA)),
+				_ => bail!("{self} pools are only supported on mainnet"),
+			};
+		}
+		Ok(match self {
+			Self::Usdc => get_usdc_token(ChainId::MAINNET),
+			Self::Usdt => token!(chain_id, USDT_ADDRESS, 6, "USDT", "Tether USD"),
+			Self::Dai => token!(chain_id, DAI_ADDRESS, 18, "DAI", "Dai Stablecoin"),
+		})
+	}
+
+	/// The usd price of the quote token. Only USDC is looked up from the coin price providers, so
+	/// other stablecoins are assumed to hold their peg.
+	pub fn usd_price(&self, usdc_usd_price: FixedU128) -> FixedU128 {
+		match self {
+			Self::Usdc => usdc_usd_price,
+			Self::Usdt | Self::Dai => FixedU128::one(),
+		}
+	}
+}
+
+impl fmt::Display for QuoteToken {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Usdc => write!(f, "usdc"),
+			Self::Usdt => write!(f, "usdt"),
+			Self::Dai => write!(f, "dai"),
+		}
+	}
+}
+
+impl FromStr for QuoteToken {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self> {
+		match s.to_ascii_lowercase().as_str() {
+			"usdc" => Ok(Self::Usdc),
+			"usdt" => Ok(Self::Usdt),
+			"dai" => Ok(Self::Dai),
+			_ => bail!("Unknown quote token {s}. Expected usdc, usdt or dai"),
+		}
+	}
+}
+
+/// A pool the oracle reads a time weighted price and liquidity from. All pools must expose the
+/// Uniswap v3 `observe` oracle interface.
+///
+/// Parsed from `uniswap-v3:<quote>:<fee>` (a pool found by the Uniswap v3 factory) or
+/// `pool:<quote>:<address>` (a pool at a fixed address, eg, a v3 fork or the oracle hook of a
+/// Uniswap v4 pool).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DexPoolSource {
+	UniswapV3 { quote: QuoteToken, fee: FeeAmount },
+	Observable { quote: QuoteToken, address: Address },
+}
+
+impl DexPoolSource {
+	/// The pools used when none are configured: the low and medium Uniswap v3 USDC tiers. The high
+	/// tier is left out since it's usually used for volatile assets, not a stablecoin.
+	pub fn defaults() -> Vec<Self> {
+		vec![
+			Self::UniswapV3 { quote: QuoteToken::Usdc, fee: FeeAmount::LOW },
+			Self::UniswapV3 { quote: QuoteToken::Usdc, fee: FeeAmount::MEDIUM },
+		]
+	}
+
+	pub fn quote(&self) -> QuoteToken {
+		match self {
+			Self::UniswapV3 { quote, .. } | Self::Observable { quote, .. } => *quote,
+		}
+	}
+
+	/// Reads a comma separated list of pools from an environment variable, falling back to the
+	/// defaults when it isn't set
+	pub fn list_from_env(var: &str) -> Result<Vec<Self>> {
+		let Ok(value) = env::var(var) else {
+			return Ok(Self::defaults());
+		};
+		let pools = value
+			.split(',')
+			.map(str::trim)
+			.filter(|pool| !pool.is_empty())
+			.map(|pool| pool.parse::<Self>().with_context(|| format!("Invalid pool in {var}")))
+			.collect::<Result<Vec<_>>>()?;
+		if pools.is_empty() {
+			return Ok(Self::defaults());
+		}
+		Ok(pools)
+	}
+}
+
+impl fmt::Display for DexPoolSource {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::UniswapV3 { quote, fee } => write!(f, "uniswap-v3:{quote}:{}", *fee as u32),
+			Self::Observable { quote, address } => write!(f, "pool:{quote}:{address}"),
+		}
+	}
+}
+
+impl FromStr for DexPoolSource {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self> {
+		let parts = s.split(':').collect::<Vec<_>>();
+		let [kind, quote, pool] = parts[..] else {
+			bail!("Expected `uniswap-v3:<quote>:<fee>` or `pool:<quote>:<address>`, got {s}");
+		};
+		let quote = quote.parse()?;
+		match kind {
+			"uniswap-v3" => {
+				let fee = match pool.parse::<u32>()? {
+					100 => FeeAmount::LOWEST,
+					500 => FeeAmount::LOW,
+					3000 => FeeAmount::MEDIUM,
+					10000 => FeeAmount::HIGH,
+					fee => bail!("Unsupported Uniswap v3 fee tier {fee}"),
+				};
+				Ok(Self::UniswapV3 { quote, fee })
+			},
+			"pool" => Ok(Self::Observable {
+				quote,
+				address: pool.parse().map_err(|e| anyhow!("Invalid pool address {pool}: {e}"))?,
+			}),
+			_ => bail!("Unknown pool kind {kind}"),
+		}
+	}
+}
+
+/// What a single pool contributed to an aggregated price
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoolPriceBreakdown {
+	pub source: DexPoolSource,
+	/// The usd price of the looked up token in this pool
+	pub usd_price: Option<FixedU128>,
+	/// The time weighted average liquidity used to weight the price
+	pub liquidity: Balance,
+	/// Why the pool was left out, if it was
+	pub error: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn parses_pool_sources() {
+		assert_eq!(
+			"uniswap-v3:usdc:500".parse::<DexPoolSource>().unwrap(),
+			DexPoolSource::UniswapV3 { quote: QuoteToken::Usdc, fee: FeeAmount::LOW }
+		);
+		let pool = "pool:dai:0x6B175474E89094C44Da98b954EedeAC495271d0F"
+			.parse::<DexPoolSource>()
+			.unwrap();
+		assert_eq!(
+			pool,
+			DexPoolSource::Observable { quote: QuoteToken::Dai, address: DAI_ADDRESS }
+		);
+		assert_eq!(pool.to_string().parse::<DexPoolSource>().unwrap(), pool);
+
+		assert!("uniswap-v3:usdc:42".parse::<DexPoolSource>().is_err());
+		assert!("uniswap-v3:eur:500".parse::<DexPoolSource>().is_err());
+		assert!("curve:usdc:500".parse::<DexPoolSource>().is_err());
+	}
+}
@@ -2,10 +2,11 @@ use alloy_provider::{network::Ethereum, Provider, RootProvider};
 use anyhow::{anyhow, Result};
 use tracing::warn;
 
-use crate::uniswap_oracle::ethereum_rpc_urls_from_env;
+use crate::{response_recording::ResponseRecorder, uniswap_oracle::ethereum_rpc_urls_from_env};
 
 pub struct EthereumGasPriceLookup {
 	providers: Vec<RootProvider<Ethereum>>,
+	recorder: ResponseRecorder,
 }
 
 impl EthereumGasPriceLookup {
@@ -14,10 +15,19 @@ impl EthereumGasPriceLookup {
 		for url in ethereum_rpc_urls_from_env()? {
 			providers.push(RootProvider::connect(&url).await?);
 		}
-		Ok(Self { providers })
+		Ok(Self { providers, recorder: Default::default() })
+	}
+
+	pub fn with_recorder(mut self, recorder: ResponseRecorder) -> Self {
+		self.recorder = recorder;
+		self
 	}
 
 	pub async fn get_gas_price(&self) -> Result<u128> {
+		self.recorder.capture("ethereum-gas-price", self.lookup_gas_price()).await
+	}
+
+	async fn lookup_gas_price(&self) -> Result<u128> {
 		let mut last_error: Option<anyhow::Error> = None;
 		for provider in &self.providers {
 			match provider.get_gas_price().await {
@@ -32,3 +42,25 @@ impl EthereumGasPriceLookup {
 		Err(last_error.unwrap_or_else(|| anyhow!("No Ethereum RPC providers configured")))
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[tokio::test]
+	async fn replays_recorded_gas_prices() {
+		let path = std::env::temp_dir()
+			.join(format!("argon-oracle-gas-price-replay-{}.jsonl", std::process::id()));
+		std::fs::write(
+			&path,
+			"{\"source\":\"ethereum-gas-price\",\"offsetMillis\":0,\"response\":1500000000}\n",
+		)
+		.unwrap();
+
+		let lookup = EthereumGasPriceLookup { providers: vec![], recorder: Default::default() }
+			.with_recorder(ResponseRecorder::replay_from(&path).unwrap());
+		assert_eq!(lookup.get_gas_price().await.unwrap(), 1_500_000_000);
+		assert!(lookup.get_gas_price().await.is_err());
+		let _ = std::fs::remove_file(&path);
+	}
+}
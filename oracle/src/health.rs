@@ -0,0 +1,511 @@
+use crate::{
+	coin_usd_prices::{PriceLookups, ProviderPrice},
+	dex_pools::PoolPriceBreakdown,
+	price_index::PriceIndex,
+};
+use anyhow::Result;
+use argon_primitives::tick::{Tick, Ticker};
+use axum::{
+	extract::State,
+	http::{header, StatusCode},
+	response::{IntoResponse, Response},
+	routing::get,
+	Json, Router,
+};
+use parking_lot::Mutex;
+use polkadot_sdk::*;
+use prometheus::{Encoder, Gauge, GaugeVec, IntCounterVec, IntGauge, Opts, Registry, TextEncoder};
+use serde::Serialize;
+use sp_core::H256;
+use sp_runtime::{FixedI128, FixedPointNumber, FixedU128};
+use std::{net::SocketAddr, sync::Arc};
+use tracing::info;
+
+/// The latest state of the price index loop, so drift can be spotted before the chain treats the
+/// oracle's prices as stale
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OracleHealth {
+	pub current_tick: Option<Tick>,
+	/// The last raw values returned by each coin price provider
+	pub coin_prices: Vec<ProviderPriceHealth>,
+	/// The median of the coin price providers used in the price index
+	pub median_prices: Option<CoinPricesHealth>,
+	pub argon_pools: Vec<PoolPriceHealth>,
+	pub argonot_pools: Vec<PoolPriceHealth>,
+	pub us_cpi_ratio: Option<f64>,
+	pub last_submission: Option<SubmissionHealth>,
+	/// The tick of the last price index from this oracle included in a block
+	pub last_included_tick: Option<Tick>,
+	/// The tick of the price index the chain is using (`PriceIndex::Current`), which might have
+	/// been submitted by another operator
+	pub on_chain_price_tick: Option<Tick>,
+	/// How old the chain's current price index can get before the chain stops using it
+	/// (`MaxDowntimeTicksBeforeReset`)
+	pub max_downtime_ticks: Option<Tick>,
+	/// How many more ticks the chain's current price index stays usable. Negative once the chain
+	/// no longer uses it.
+	pub price_age_headroom_ticks: Option<i64>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderPriceHealth {
+	pub provider: String,
+	pub bitcoin: Option<f64>,
+	pub ethereum: Option<f64>,
+	pub usdc: Option<f64>,
+	pub error: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinPricesHealth {
+	pub bitcoin: f64,
+	pub ethereum: Option<f64>,
+	pub usdc: f64,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolPriceHealth {
+	pub pool: String,
+	pub usd_price: Option<f64>,
+	pub liquidity: u128,
+	pub error: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SubmissionStatus {
+	Submitted,
+	InBlock,
+	Failed,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmissionHealth {
+	pub tick: Tick,
+	pub argon_usd_target_price: f64,
+	pub argon_usd_price: f64,
+	pub argon_time_weighted_average_liquidity: u128,
+	pub argonot_usd_price: f64,
+	pub btc_usd_price: f64,
+	pub ethereum_usd_price: Option<f64>,
+	pub ethereum_gas_price_wei: Option<u128>,
+	pub status: SubmissionStatus,
+	pub block_hash: Option<H256>,
+	pub error: Option<String>,
+}
+
+/// Prometheus metrics for the price index loop
+struct OracleMetrics {
+	provider_price: GaugeVec,
+	provider_errors_total: IntCounterVec,
+	median_price: GaugeVec,
+	pool_price: GaugeVec,
+	pool_liquidity: GaugeVec,
+	us_cpi_ratio: Gauge,
+	submitted_price: GaugeVec,
+	submissions_total: IntCounterVec,
+	last_included_tick: IntGauge,
+	on_chain_price_tick: IntGauge,
+	price_age_headroom_ticks: IntGauge,
+}
+
+impl OracleMetrics {
+	fn new(registry: &Registry) -> Result<Self> {
+		let metrics = Self {
+			provider_price: GaugeVec::new(
+				Opts::new(
+					"oracle_provider_price",
+					"Last usd price returned by a coin price provider",
+				),
+				&["provider", "asset"],
+			)?,
+			provider_errors_total: IntCounterVec::new(
+				Opts::new("oracle_provider_errors_total", "Number of failed coin price lookups"),
+				&["provider"],
+			)?,
+			median_price: GaugeVec::new(
+				Opts::new("oracle_median_price", "Median usd price across coin price providers"),
+				&["asset"],
+			)?,
+			pool_price: GaugeVec::new(
+				Opts::new("oracle_pool_price", "Time weighted usd price of a token in a dex pool"),
+				&["token", "pool"],
+			)?,
+			pool_liquidity: GaugeVec::new(
+				Opts::new("oracle_pool_liquidity", "Time weighted liquidity of a dex pool"),
+				&["token", "pool"],
+			)?,
+			us_cpi_ratio: Gauge::new("oracle_us_cpi_ratio", "Ratio of the US CPI to the baseline")?,
+			submitted_price: GaugeVec::new(
+				Opts::new("oracle_submitted_price", "Prices in the last submitted price index"),
+				&["price"],
+			)?,
+			submissions_total: IntCounterVec::new(
+				Opts::new("oracle_submissions_total", "Number of price index submissions"),
+				&["status"],
+			)?,
+			last_included_tick: IntGauge::new(
+				"oracle_last_included_tick",
+				"Tick of the last price index from this oracle included in a block",
+			)?,
+			on_chain_price_tick: IntGauge::new(
+				"oracle_on_chain_price_tick",
+				"Tick of the price index the chain is using",
+			)?,
+			price_age_headroom_ticks: IntGauge::new(
+				"oracle_price_age_headroom_ticks",
+				"Ticks until the chain's current price index is too old to use",
+			)?,
+		};
+		registry.register(Box::new(metrics.provider_price.clone()))?;
+		registry.register(Box::new(metrics.provider_errors_total.clone()))?;
+		registry.register(Box::new(metrics.median_price.clone()))?;
+		registry.register(Box::new(metrics.pool_price.clone()))?;
+		registry.register(Box::new(metrics.pool_liquidity.clone()))?;
+		registry.register(Box::new(metrics.us_cpi_ratio.clone()))?;
+		registry.register(Box::new(metrics.submitted_price.clone()))?;
+		registry.register(Box::new(metrics.submissions_total.clone()))?;
+		registry.register(Box::new(metrics.last_included_tick.clone()))?;
+		registry.register(Box::new(metrics.on_chain_price_tick.clone()))?;
+		registry.register(Box::new(metrics.price_age_headroom_ticks.clone()))?;
+		Ok(metrics)
+	}
+}
+
+/// Tracks the price index loop and serves it as json (`/health`) and Prometheus metrics
+/// (`/metrics`)
+#[derive(Clone)]
+pub struct OracleMonitor {
+	health: Arc<Mutex<OracleHealth>>,
+	ticker: Arc<Mutex<Option<Ticker>>>,
+	metrics: Arc<OracleMetrics>,
+	registry: Registry,
+}
+
+impl OracleMonitor {
+	pub fn new() -> Result<Self> {
+		let registry = Registry::new();
+		let metrics = OracleMetrics::new(&registry)?;
+		Ok(Self {
+			health: Default::default(),
+			ticker: Default::default(),
+			metrics: Arc::new(metrics),
+			registry,
+		})
+	}
+
+	pub fn set_chain_info(
+		&self,
+		ticker: Ticker,
+		max_downtime_ticks: Tick,
+		on_chain_price_tick: Option<Tick>,
+	) {
+		*self.ticker.lock() = Some(ticker);
+		self.health.lock().max_downtime_ticks = Some(max_downtime_ticks);
+		self.record_on_chain_price_tick(on_chain_price_tick);
+	}
+
+	/// Records the tick of `PriceIndex::Current`, which the price age headroom is measured from
+	pub fn record_on_chain_price_tick(&self, tick: Option<Tick>) {
+		self.health.lock().on_chain_price_tick = tick;
+		if let Some(tick) = tick {
+			self.metrics.on_chain_price_tick.set(tick as i64);
+		}
+	}
+
+	pub fn record_coin_prices(&self, providers: &[ProviderPrice], median: Option<&PriceLookups>) {
+		let mut health = self.health.lock();
+		health.coin_prices = providers
+			.iter()
+			.map(|price| {
+				let provider = format!("{:?}", price.provider).to_lowercase();
+				if price.error.is_some() {
+					self.metrics
+						.provider_errors_total
+						.with_label_values(&[provider.as_str()])
+						.inc();
+				}
+				for (asset, value) in
+					[("bitcoin", price.bitcoin), ("ethereum", price.ethereum), ("usdc", price.usdc)]
+				{
+					if let Some(value) = value {
+						self.metrics
+							.provider_price
+							.with_label_values(&[provider.as_str(), asset])
+							.set(value.to_float());
+					}
+				}
+				ProviderPriceHealth {
+					provider,
+					bitcoin: price.bitcoin.map(|x| x.to_float()),
+					ethereum: price.ethereum.map(|x| x.to_float()),
+					usdc: price.usdc.map(|x| x.to_float()),
+					error: price.error.clone(),
+				}
+			})
+			.collect();
+		if let Some(median) = median {
+			self.metrics
+				.median_price
+				.with_label_values(&["bitcoin"])
+				.set(median.bitcoin.to_float());
+			self.metrics
+				.median_price
+				.with_label_values(&["usdc"])
+				.set(median.usdc.to_float());
+			if let Some(ethereum) = median.ethereum {
+				self.metrics
+					.median_price
+					.with_label_values(&["ethereum"])
+					.set(ethereum.to_float());
+			}
+			health.median_prices = Some(CoinPricesHealth {
+				bitcoin: median.bitcoin.to_float(),
+				ethereum: median.ethereum.map(|x| x.to_float()),
+				usdc: median.usdc.to_float(),
+			});
+		}
+	}
+
+	pub fn record_pools(&self, token: &str, breakdown: &[PoolPriceBreakdown]) {
+		let pools = breakdown
+			.iter()
+			.map(|pool| {
+				let name = pool.source.to_string();
+				if let Some(price) = pool.usd_price {
+					self.metrics
+						.pool_price
+						.with_label_values(&[token, name.as_str()])
+						.set(price.to_float());
+				}
+				self.metrics
+					.pool_liquidity
+					.with_label_values(&[token, name.as_str()])
+					.set(pool.liquidity as f64);
+				PoolPriceHealth {
+					pool: name,
+					usd_price: pool.usd_price.map(|x| x.to_float()),
+					liquidity: pool.liquidity,
+					error: pool.error.clone(),
+				}
+			})
+			.collect();
+		let mut health = self.health.lock();
+		match token {
+			"argonot" => health.argonot_pools = pools,
+			_ => health.argon_pools = pools,
+		}
+	}
+
+	pub fn record_us_cpi_ratio(&self, ratio: FixedI128) {
+		let ratio = ratio.to_float();
+		self.metrics.us_cpi_ratio.set(ratio);
+		self.health.lock().us_cpi_ratio = Some(ratio);
+	}
+
+	pub fn record_submission(&self, tick: Tick, price: &PriceIndex) {
+		for (name, value) in [
+			("argon_usd_target", price.argon_usd_target_price),
+			("argon_usd", price.argon_usd_price),
+			("argonot_usd", price.argonot_usd_price),
+			("btc_usd", price.btc_usd_price),
+		] {
+			self.metrics.submitted_price.with_label_values(&[name]).set(value.to_float());
+		}
+		self.metrics.submissions_total.with_label_values(&["submitted"]).inc();
+		self.health.lock().last_submission = Some(SubmissionHealth {
+			tick,
+			argon_usd_target_price: price.argon_usd_target_price.to_float(),
+			argon_usd_price: price.argon_usd_price.to_float(),
+			argon_time_weighted_average_liquidity: price.argon_time_weighted_average_liquidity,
+			argonot_usd_price: price.argonot_usd_price.to_float(),
+			btc_usd_price: price.btc_usd_price.to_float(),
+			ethereum_usd_price: price.ethereum.as_ref().map(|x| x.ethereum_usd_price.to_float()),
+			ethereum_gas_price_wei: price.ethereum.as_ref().map(|x| x.ethereum_gas_price_wei),
+			status: SubmissionStatus::Submitted,
+			block_hash: None,
+			error: None,
+		});
+	}
+
+	/// Records whether the submission for the tick made it into a block
+	pub fn record_inclusion(&self, tick: Tick, result: Result<H256, String>) {
+		let mut health = self.health.lock();
+		let status = if result.is_ok() { "in_block" } else { "failed" };
+		self.metrics.submissions_total.with_label_values(&[status]).inc();
+		if result.is_ok() && health.last_included_tick.is_none_or(|included| included < tick) {
+			health.last_included_tick = Some(tick);
+			self.metrics.last_included_tick.set(tick as i64);
+		}
+		if let Some(submission) = health.last_submission.as_mut() &&
+			submission.tick == tick
+		{
+			match result {
+				Ok(block_hash) => {
+					submission.status = SubmissionStatus::InBlock;
+					submission.block_hash = Some(block_hash);
+				},
+				Err(error) => {
+					submission.status = SubmissionStatus::Failed;
+					submission.error = Some(error);
+				},
+			}
+		}
+	}
+
+	pub fn health(&self) -> OracleHealth {
+		let current_tick = self.ticker.lock().map(|ticker| ticker.current());
+		let mut health = self.health.lock().clone();
+		health.current_tick = current_tick;
+		health.price_age_headroom_ticks =
+			match (current_tick, health.on_chain_price_tick, health.max_downtime_ticks) {
+				(Some(current), Some(price_tick), Some(max_downtime)) =>
+					Some((price_tick + max_downtime) as i64 - current as i64),
+				_ => None,
+			};
+		if let Some(headroom) = health.price_age_headroom_ticks {
+			self.metrics.price_age_headroom_ticks.set(headroom);
+		}
+		health
+	}
+
+	pub fn metrics_text(&self) -> Result<String> {
+		// refresh the headroom, which changes with time rather than with the loop
+		let _ = self.health();
+		let mut buffer = vec![];
+		TextEncoder::new().encode(&self.registry.gather(), &mut buffer)?;
+		Ok(String::from_utf8(buffer)?)
+	}
+
+	pub fn router(&self) -> Router {
+		Router::new()
+			.route("/health", get(serve_health))
+			.route("/metrics", get(serve_metrics))
+			.with_state(self.clone())
+	}
+
+	pub async fn serve(self, bind: SocketAddr) -> Result<()> {
+		let listener = tokio::net::TcpListener::bind(bind).await?;
+		info!("Oracle health listening on {}", listener.local_addr()?);
+		axum::serve(listener, self.router()).await?;
+		Ok(())
+	}
+}
+
+/// Responds with the oracle health, using a 503 once the chain's current price is too old to use
+async fn serve_health(State(monitor): State<OracleMonitor>) -> Response {
+	let health = monitor.health();
+	(health_status(&health), Json(health)).into_response()
+}
+
+/// The chain stops using its current price once it's more than `MaxDowntimeTicksBeforeReset` old
+fn health_status(health: &OracleHealth) -> StatusCode {
+	if health.price_age_headroom_ticks.is_some_and(|headroom| headroom < 0) {
+		StatusCode::SERVICE_UNAVAILABLE
+	} else {
+		StatusCode::OK
+	}
+}
+
+async fn serve_metrics(State(monitor): State<OracleMonitor>) -> Response {
+	match monitor.metrics_text() {
+		Ok(text) =>
+			(StatusCode::OK, [(header::CONTENT_TYPE, TextEncoder::new().format_type())], text)
+				.into_response(),
+		Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::coin_usd_prices::PriceProviderKind;
+	use sp_runtime::traits::One;
+	use std::time::Duration;
+
+	#[tokio::test]
+	async fn serves_health_and_metrics() {
+		let monitor = OracleMonitor::new().unwrap();
+		let ticker = Ticker::start(Duration::from_secs(60 * 60), 2);
+		let current_tick = ticker.current();
+		monitor.set_chain_info(ticker, 10, Some(current_tick - 4));
+		monitor.record_coin_prices(
+			&[
+				ProviderPrice {
+					provider: PriceProviderKind::Kraken,
+					bitcoin: Some(FixedU128::from_u32(62_000)),
+					ethereum: None,
+					usdc: Some(FixedU128::one()),
+					error: None,
+				},
+				ProviderPrice {
+					provider: PriceProviderKind::Coinbase,
+					bitcoin: None,
+					ethereum: None,
+					usdc: None,
+					error: Some("rate limited".to_string()),
+				},
+			],
+			Some(&PriceLookups {
+				bitcoin: FixedU128::from_u32(62_000),
+				ethereum: None,
+				usdc: FixedU128::one(),
+			}),
+		);
+		monitor.record_us_cpi_ratio(FixedI128::from_rational(1, 100));
+
+		let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+		let url = format!("http://{}", listener.local_addr().unwrap());
+		let router = monitor.router();
+		let server = tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+
+		let response = reqwest::get(format!("{url}/health")).await.unwrap();
+		assert_eq!(response.status(), reqwest::StatusCode::OK);
+		let health = response.json::<serde_json::Value>().await.unwrap();
+		assert_eq!(health["priceAgeHeadroomTicks"], 6);
+		assert_eq!(health["coinPrices"][0]["provider"], "kraken");
+		assert_eq!(health["coinPrices"][1]["error"], "rate limited");
+		assert_eq!(health["usCpiRatio"], 0.01);
+
+		let metrics = reqwest::get(format!("{url}/metrics")).await.unwrap().text().await.unwrap();
+		assert!(
+			metrics.contains(r#"oracle_provider_price{asset="bitcoin",provider="kraken"} 62000"#)
+		);
+		assert!(metrics.contains(r#"oracle_provider_errors_total{provider="coinbase"} 1"#));
+		assert!(metrics.contains("oracle_price_age_headroom_ticks 6"));
+
+		// an inclusion from this oracle doesn't move the headroom until the chain uses the price
+		monitor.record_inclusion(current_tick - 1, Ok(H256::zero()));
+		assert_eq!(monitor.health().last_included_tick, Some(current_tick - 1));
+		assert_eq!(monitor.health().price_age_headroom_ticks, Some(6));
+		monitor.record_on_chain_price_tick(Some(current_tick - 2));
+		assert_eq!(monitor.health().price_age_headroom_ticks, Some(8));
+
+		// once the chain's current price is too old, the health check fails
+		monitor.record_on_chain_price_tick(Some(current_tick - 11));
+		let response = reqwest::get(format!("{url}/health")).await.unwrap();
+		assert_eq!(response.status(), reqwest::StatusCode::SERVICE_UNAVAILABLE);
+		server.abort();
+	}
+
+	#[test]
+	fn fails_health_once_the_chain_stops_using_the_price() {
+		let monitor = OracleMonitor::new().unwrap();
+		let ticker = Ticker::start(Duration::from_secs(60 * 60), 2);
+		let current_tick = ticker.current();
+		monitor.set_chain_info(ticker, 60, Some(current_tick - 60));
+		let health = monitor.health();
+		assert_eq!(health.price_age_headroom_ticks, Some(0));
+		assert_eq!(health_status(&health), StatusCode::OK);
+
+		monitor.record_on_chain_price_tick(Some(current_tick - 61));
+		let health = monitor.health();
+		assert_eq!(health.price_age_headroom_ticks, Some(-1));
+		assert_eq!(health_status(&health), StatusCode::SERVICE_UNAVAILABLE);
+	}
+}
@@ -9,22 +9,27 @@ use sp_core::{
 	sr25519, Pair as PairT,
 };
 use sp_runtime::traits::IdentifyAccount;
-use std::env;
+use std::{env, net::SocketAddr, path::PathBuf};
 use tracing::{error, info};
 use url::Url;
 
 use crate::{
 	bitcoin_tip::bitcoin_loop,
 	coin_usd_prices::{PriceProviderKind, ALL_PRICE_PROVIDERS},
+	health::OracleMonitor,
 	price_index::{price_index_loop, price_index_loop_from_file},
+	response_recording::ResponseRecorder,
 };
 
 mod argon_price;
 mod argonot_price;
 mod bitcoin_tip;
 mod coin_usd_prices;
+mod dex_pools;
 mod ethereum_gas_price;
+mod health;
 mod price_index;
+mod response_recording;
 mod uniswap_oracle;
 mod us_cpi;
 mod us_cpi_schedule;
@@ -68,6 +73,20 @@ pub enum Subcommand {
 		/// Which coin price providers to use for USD prices
 		#[clap(long, env, value_enum, value_delimiter = ',', default_values_t = ALL_PRICE_PROVIDERS)]
 		coin_price_providers: Vec<PriceProviderKind>,
+
+		/// Record every price source response (coin prices, Uniswap pools, Ethereum gas prices and
+		/// CPI) to this file, replacing any previous recording in it
+		#[clap(long, env = "ORACLE_RECORD_PATH", conflicts_with_all = ["from_file_path", "replay_from"])]
+		record_to: Option<PathBuf>,
+
+		/// Replay price source responses from a recording instead of calling the live sources.
+		/// Responses are fed back at their original timing. Only allowed on development chains.
+		#[clap(long, env = "ORACLE_REPLAY_PATH", conflicts_with = "from_file_path")]
+		replay_from: Option<PathBuf>,
+
+		/// Serve `/health` (json) and `/metrics` (Prometheus) on this address, eg 127.0.0.1:9955
+		#[clap(long, env = "ORACLE_HEALTH_BIND")]
+		health_bind: Option<SocketAddr>,
 	},
 	Bitcoin {
 		/// The Bitcoin full node to follow for longest chain. Should be a hosted/trusted
@@ -95,7 +114,13 @@ pub enum Subcommand {
 
 impl Default for Subcommand {
 	fn default() -> Self {
-		Subcommand::PriceIndex { from_file_path: None, coin_price_providers: vec![] }
+		Subcommand::PriceIndex {
+			from_file_path: None,
+			coin_price_providers: vec![],
+			record_to: None,
+			replay_from: None,
+			health_bind: None,
+		}
 	}
 }
 
@@ -198,11 +223,32 @@ async fn main() -> anyhow::Result<()> {
 	let signer = KeystoreSigner::new(keystore, signer_account, signer_crypto.into());
 
 	match subcommand {
-		Subcommand::PriceIndex { from_file_path, coin_price_providers } =>
+		Subcommand::PriceIndex {
+			from_file_path,
+			coin_price_providers,
+			record_to,
+			replay_from,
+			health_bind,
+		} =>
 			if let Some(path) = from_file_path {
 				price_index_loop_from_file(trusted_rpc_url, signer, path).await?
 			} else {
-				price_index_loop(trusted_rpc_url, signer, coin_price_providers).await?
+				let recorder = match (record_to, replay_from) {
+					(Some(path), _) => ResponseRecorder::record_to(path)?,
+					(_, Some(path)) => ResponseRecorder::replay_from(path)?,
+					_ => ResponseRecorder::default(),
+				};
+				let monitor = OracleMonitor::new()?;
+				if let Some(bind) = health_bind {
+					let server = monitor.clone();
+					tokio::spawn(async move {
+						if let Err(error) = server.serve(bind).await {
+							error!(?error, "Oracle health server stopped");
+						}
+					});
+				}
+				price_index_loop(trusted_rpc_url, signer, coin_price_providers, recorder, monitor)
+					.await?
 			},
 		Subcommand::Bitcoin { bitcoin_rpc_url } => {
 			let mut bitcoin_url = Url::parse(&bitcoin_rpc_url).map_err(|e| {
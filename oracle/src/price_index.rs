@@ -14,6 +14,8 @@ use crate::{
 	argon_price, argonot_price, coin_usd_prices,
 	coin_usd_prices::PriceProviderKind,
 	ethereum_gas_price::EthereumGasPriceLookup,
+	health::OracleMonitor,
+	response_recording::ResponseRecorder,
 	uniswap_oracle::{PriceAndLiquidity, UniswapOracleError},
 	us_cpi::UsCpiRetriever,
 	utils::MIN_TRANSACTION_WATCH_TIMEOUT,
@@ -66,6 +68,8 @@ pub async fn price_index_loop(
 	trusted_rpc_url: String,
 	signer: KeystoreSigner,
 	coin_price_providers: Vec<PriceProviderKind>,
+	recorder: ResponseRecorder,
+	monitor: OracleMonitor,
 ) -> anyhow::Result<()> {
 	let mut reconnecting_client = ReconnectingClient::new(vec![trusted_rpc_url.clone()]);
 	let mainchain_client = reconnecting_client.get().await?;
@@ -76,6 +80,10 @@ pub async fn price_index_loop(
 	if chain_info.contains("Development") || chain_info.contains("Testnet") {
 		is_test = true;
 	}
+	ensure!(
+		chain_info.contains("Development") || !recorder.is_replaying(),
+		"Replayed price responses can only be used on development chain"
+	);
 
 	let mut ticker = mainchain_client.lookup_ticker().await?;
 	if let Ok(ntp_pool) = env::var("NTP_POOL") &&
@@ -102,6 +110,11 @@ pub async fn price_index_loop(
 		constants_client.at(&constants().price_index().max_argon_target_change_per_tick())?,
 	);
 
+	// the chain stops using its current price once it's older than the max downtime
+	let max_downtime_ticks =
+		constants_client.at(&constants().price_index().max_downtime_ticks_before_reset())?;
+	monitor.set_chain_info(ticker, max_downtime_ticks, last_price.as_ref().map(|a| a.tick));
+
 	let mut last_attempted_tick = last_price.as_ref().map(|a| a.tick).unwrap_or(0);
 	let mut last_target_price = last_price
 		.as_ref()
@@ -122,15 +135,19 @@ pub async fn price_index_loop(
 		.saturating_mul(2)
 		.max(MIN_TRANSACTION_WATCH_TIMEOUT);
 
-	let mut us_cpi = UsCpiRetriever::new(&ticker).await?;
+	let mut us_cpi = UsCpiRetriever::new(&ticker, recorder.clone()).await?;
 	let mut usd_price_lookups =
-		coin_usd_prices::CoinUsdPriceLookup::new_with_providers(coin_price_providers);
+		coin_usd_prices::CoinUsdPriceLookup::new_with_providers(coin_price_providers)
+			.with_recorder(recorder.clone());
 
 	let mut argon_price_lookup =
 		argon_price::ArgonPriceLookup::from_env(&ticker, last_price).await?;
+	argon_price_lookup.uniswap_oracle.set_recorder(recorder.clone());
 	let mut argonot_price_lookup =
 		argonot_price::ArgonotPriceLookup::from_env(last_argonot_price).await?;
-	let ethereum_gas_price_lookup = EthereumGasPriceLookup::from_env().await?;
+	argonot_price_lookup.uniswap_oracle.set_recorder(recorder.clone());
+	let ethereum_gas_price_lookup =
+		EthereumGasPriceLookup::from_env().await?.with_recorder(recorder);
 
 	info!("Oracle Started.");
 
@@ -142,8 +159,13 @@ pub async fn price_index_loop(
 			continue;
 		}
 		last_attempted_tick = tick;
+		record_on_chain_price_tick(&mut reconnecting_client, &monitor).await;
 
 		let (usd_price_lookup, _) = join!(usd_price_lookups.get_latest_prices(), us_cpi.refresh());
+		monitor.record_coin_prices(
+			&usd_price_lookups.last_provider_prices,
+			usd_price_lookup.as_ref().ok(),
+		);
 		let usd_price_lookup = match usd_price_lookup {
 			Ok(x) => x,
 			Err(e) => {
@@ -152,6 +174,7 @@ pub async fn price_index_loop(
 			},
 		};
 		let us_cpi_ratio = us_cpi.get_us_cpi_ratio(tick);
+		monitor.record_us_cpi_ratio(us_cpi_ratio);
 		let target_price = argon_price_lookup.get_target_price(us_cpi_ratio).clamp(
 			last_target_price.saturating_sub(max_argon_target_change_per_tick),
 			last_target_price.saturating_add(max_argon_target_change_per_tick),
@@ -164,6 +187,7 @@ pub async fn price_index_loop(
 			),
 			ethereum_gas_price_lookup.get_gas_price(),
 		);
+		monitor.record_pools("argon", &argon_price_lookup.uniswap_oracle.last_breakdown());
 
 		let argon_usd_price = match price_result {
 			Ok(x) => x,
@@ -190,10 +214,10 @@ pub async fn price_index_loop(
 				},
 		};
 
-		let argonot_price_lookup = match argonot_price_lookup
-			.get_latest_price(usd_price_lookup.usdc)
-			.await
-		{
+		let argonot_price_result =
+			argonot_price_lookup.get_latest_price(usd_price_lookup.usdc).await;
+		monitor.record_pools("argonot", &argonot_price_lookup.uniswap_oracle.last_breakdown());
+		let argonot_price_lookup = match argonot_price_result {
 			Ok(x) => x,
 			Err(e) =>
 				if is_test {
@@ -248,6 +272,7 @@ pub async fn price_index_loop(
 			&signer,
 			tick,
 			transaction_watch_timeout,
+			Some(&monitor),
 			PriceIndex {
 				argon_usd_target_price,
 				argon_usd_price,
@@ -318,6 +343,7 @@ pub async fn price_index_loop_from_file(
 			&signer,
 			tick,
 			transaction_watch_timeout,
+			None,
 			price_data,
 		)
 		.await?;
@@ -333,6 +359,7 @@ async fn submit_price_index(
 	signer: &KeystoreSigner,
 	tick: Tick,
 	transaction_watch_timeout: Duration,
+	monitor: Option<&OracleMonitor>,
 	price: PriceIndex,
 ) -> anyhow::Result<()> {
 	let client = reconnecting_client.get().await?;
@@ -346,6 +373,9 @@ async fn submit_price_index(
 		.ok_or_else(|| anyhow!("PriceIndex.submit is missing from the connected runtime"))?;
 	let supports_ethereum_prices =
 		submit_call.fields.iter().any(|field| field.name.as_deref() == Some("ethereum"));
+	if let Some(monitor) = monitor {
+		monitor.record_submission(tick, &price);
+	}
 	let index = ApiPriceIndex {
 		argon_usd_target_price: to_api_fixed_u128(price.argon_usd_target_price),
 		tick,
@@ -371,15 +401,45 @@ async fn submit_price_index(
 		.await?;
 
 	info!("Submitted price index with progress: {:?}", progress);
-	MainchainClient::wait_for_ext_in_block_with_timeout(progress, false, transaction_watch_timeout)
-		.await
-		.map_err(|error| {
-			tracing::warn!("Error processing price index!! {:?}", error);
-			error
-		})?;
+	let result = MainchainClient::wait_for_ext_in_block_with_timeout(
+		progress,
+		false,
+		transaction_watch_timeout,
+	)
+	.await;
+	if let Some(monitor) = monitor {
+		monitor.record_inclusion(
+			tick,
+			result.as_ref().map(|in_block| in_block.block_hash()).map_err(|e| e.to_string()),
+		);
+		record_on_chain_price_tick(reconnecting_client, monitor).await;
+	}
+	result.map_err(|error| {
+		tracing::warn!("Error processing price index!! {:?}", error);
+		error
+	})?;
 	Ok(())
 }
 
+/// Refreshes the health monitor with the tick of the chain's current price index. Another
+/// operator's price keeps the chain's price fresh even when this oracle's submissions fail.
+async fn record_on_chain_price_tick(
+	reconnecting_client: &mut ReconnectingClient,
+	monitor: &OracleMonitor,
+) {
+	let current = match reconnecting_client.get().await {
+		Ok(client) => client.fetch_storage(&storage().price_index().current(), FetchAt::Best).await,
+		Err(e) => {
+			tracing::warn!("Unable to read the on-chain price index {:?}", e);
+			return;
+		},
+	};
+	match current {
+		Ok(current) => monitor.record_on_chain_price_tick(current.map(|price| price.tick)),
+		Err(e) => tracing::warn!("Unable to read the on-chain price index {:?}", e),
+	}
+}
+
 fn should_use_argon_pool_fallback(error: &anyhow::Error) -> bool {
 	error.chain().any(|cause| {
 		matches!(
@@ -415,7 +475,9 @@ mod tests {
 
 	use crate::{
 		coin_usd_prices::{use_mock_price_lookups, PriceLookups},
+		health::OracleMonitor,
 		price_index_loop,
+		response_recording::ResponseRecorder,
 		uniswap_oracle::{use_mock_uniswap_prices, PriceAndLiquidity, UniswapOracleError},
 		us_cpi::use_mock_cpi_values,
 	};
@@ -474,7 +536,13 @@ mod tests {
 		});
 		use_mock_cpi_values(vec![0.2, 0.1, -0.1, 0.3]).await;
 		let signer = KeystoreSigner::new(keystore.into(), account_id, CryptoType::Sr25519);
-		spawn(price_index_loop(node.client.url.clone(), signer, vec![]));
+		spawn(price_index_loop(
+			node.client.url.clone(),
+			signer,
+			vec![],
+			ResponseRecorder::default(),
+			OracleMonitor::new().unwrap(),
+		));
 		let mut counter = 0;
 		let mut blocks = 0;
 		while let Some(Ok(block)) = block_sub.next().await {
@@ -0,0 +1,252 @@
+use anyhow::{anyhow, Context, Result};
+use parking_lot::Mutex;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+	collections::{HashMap, VecDeque},
+	fs::{self, File, OpenOptions},
+	future::Future,
+	io::Write,
+	path::Path,
+	sync::Arc,
+	time::Duration,
+};
+use tokio::time::{sleep_until, Instant};
+use tracing::{info, warn};
+
+/// Captures the responses of the oracle's external price sources (coin price apis, Uniswap pool
+/// observations, Ethereum gas prices and BLS CPI data) so a live session can be replayed
+/// deterministically. Replays submit prices, so they are only allowed on development chains.
+///
+/// Recordings are written as one json response per line, in the order they completed. During
+/// replay, each source gets its recorded responses back in order, delayed until the same offset
+/// from the start of the session they were originally received at.
+#[derive(Clone, Debug, Default)]
+pub struct ResponseRecorder {
+	mode: Option<Arc<RecorderMode>>,
+}
+
+#[derive(Debug)]
+enum RecorderMode {
+	Record { started: Instant, file: Mutex<File> },
+	Replay { started: Instant, responses: Mutex<HashMap<String, VecDeque<RecordedResponse>>> },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordedResponse {
+	pub source: String,
+	pub offset_millis: u64,
+	#[serde(flatten)]
+	pub result: RecordedResult,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum RecordedResult {
+	Response(serde_json::Value),
+	Error(String),
+}
+
+impl ResponseRecorder {
+	/// Records all responses to the given file. Each session starts a new recording, replacing
+	/// any previous one in the file.
+	pub fn record_to(path: impl AsRef<Path>) -> Result<Self> {
+		let path = path.as_ref();
+		if let Some(dir) = path.parent() &&
+			!dir.as_os_str().is_empty()
+		{
+			fs::create_dir_all(dir)?;
+		}
+		let file = OpenOptions::new()
+			.create(true)
+			.write(true)
+			.truncate(true)
+			.open(path)
+			.with_context(|| format!("Unable to open response recording {path:?}"))?;
+		info!("Recording price source responses to {path:?}");
+		Ok(Self {
+			mode: Some(Arc::new(RecorderMode::Record {
+				started: Instant::now(),
+				file: Mutex::new(file),
+			})),
+		})
+	}
+
+	/// Loads a recording to feed back instead of calling the live sources
+	pub fn replay_from(path: impl AsRef<Path>) -> Result<Self> {
+		let path = path.as_ref();
+		let recording = fs::read_to_string(path)
+			.with_context(|| format!("Unable to read response recording {path:?}"))?;
+		let mut responses = HashMap::<String, VecDeque<RecordedResponse>>::new();
+		for (index, line) in recording.lines().enumerate() {
+			if line.trim().is_empty() {
+				continue;
+			}
+			let response: RecordedResponse = serde_json::from_str(line)
+				.with_context(|| format!("Invalid recorded response on line {}", index + 1))?;
+			responses.entry(response.source.clone()).or_default().push_back(response);
+		}
+		info!("Replaying price source responses from {path:?}");
+		Ok(Self {
+			mode: Some(Arc::new(RecorderMode::Replay {
+				started: Instant::now(),
+				responses: Mutex::new(responses),
+			})),
+		})
+	}
+
+	pub fn is_replaying(&self) -> bool {
+		matches!(self.mode.as_deref(), Some(RecorderMode::Replay { .. }))
+	}
+
+	/// Whether responses are being recorded or replayed, in which case cached state should not
+	/// stand in for a source lookup
+	pub fn is_active(&self) -> bool {
+		self.mode.is_some()
+	}
+
+	/// Runs the lookup for a source, recording its result. When replaying, the lookup is skipped
+	/// and the next recorded result for the source is returned at its original timing.
+	pub async fn capture<T, F>(&self, source: &str, lookup: F) -> Result<T>
+	where
+		T: Serialize + DeserializeOwned,
+		F: Future<Output = Result<T>>,
+	{
+		match self.mode.as_deref() {
+			None => lookup.await,
+			Some(RecorderMode::Record { started, file }) => {
+				let result = lookup.await;
+				// a recording failure shouldn't fail the live lookup
+				if let Err(e) = Self::record(file, source, started.elapsed(), &result) {
+					warn!("Unable to record the {source} response: {e:#}");
+				}
+				result
+			},
+			Some(RecorderMode::Replay { started, responses }) => {
+				let recorded = responses
+					.lock()
+					.get_mut(source)
+					.and_then(|queue| queue.pop_front())
+					.ok_or_else(|| {
+						anyhow!("The replayed recording has no more responses for {source}")
+					})?;
+				sleep_until(*started + Duration::from_millis(recorded.offset_millis)).await;
+				match recorded.result {
+					RecordedResult::Response(value) => Ok(serde_json::from_value(value)?),
+					RecordedResult::Error(e) => Err(anyhow!(e)),
+				}
+			},
+		}
+	}
+
+	fn record<T: Serialize>(
+		file: &Mutex<File>,
+		source: &str,
+		offset: Duration,
+		result: &Result<T>,
+	) -> Result<()> {
+		let recorded = RecordedResponse {
+			source: source.to_string(),
+			offset_millis: offset.as_millis() as u64,
+			result: match result {
+				Ok(value) => RecordedResult::Response(serde_json::to_value(value)?),
+				Err(e) => RecordedResult::Error(format!("{e:#}")),
+			},
+		};
+		let mut line = serde_json::to_string(&recorded)?;
+		line.push('\n');
+		file.lock()
+			.write_all(line.as_bytes())
+			.context("Unable to write recorded response")
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use std::{collections::BTreeMap, env};
+
+	fn recording_path(name: &str) -> std::path::PathBuf {
+		let path =
+			env::temp_dir().join(format!("argon-oracle-{name}-{}.jsonl", std::process::id()));
+		let _ = fs::remove_file(&path);
+		path
+	}
+
+	#[tokio::test]
+	async fn replays_recorded_responses_per_source_with_original_timing() {
+		let path = recording_path("replay-timing");
+		let recorder = ResponseRecorder::record_to(&path).unwrap();
+		let first: u64 = recorder.capture("coin-price:kraken", async { Ok(1) }).await.unwrap();
+		tokio::time::sleep(Duration::from_millis(100)).await;
+		let err = recorder
+			.capture::<u64, _>("coin-price:coinbase", async { Err(anyhow!("rate limited")) })
+			.await;
+		tokio::time::sleep(Duration::from_millis(100)).await;
+		let second: u64 = recorder.capture("coin-price:kraken", async { Ok(2) }).await.unwrap();
+		assert_eq!((first, second), (1, 2));
+		assert!(err.is_err());
+
+		let replay = ResponseRecorder::replay_from(&path).unwrap();
+		let started = Instant::now();
+		let replayed = replay
+			.capture::<u64, _>("coin-price:kraken", async {
+				Err(anyhow!("should not call the source"))
+			})
+			.await
+			.unwrap();
+		assert_eq!(replayed, 1);
+		let error = replay
+			.capture::<u64, _>("coin-price:coinbase", async { Ok(5) })
+			.await
+			.expect_err("should replay the recorded error");
+		assert_eq!(error.to_string(), "rate limited");
+		assert!(started.elapsed() >= Duration::from_millis(100));
+		assert_eq!(
+			replay.capture::<u64, _>("coin-price:kraken", async { Ok(5) }).await.unwrap(),
+			2
+		);
+		assert!(started.elapsed() >= Duration::from_millis(200));
+
+		assert!(replay.capture::<u64, _>("coin-price:kraken", async { Ok(5) }).await.is_err());
+		let _ = fs::remove_file(&path);
+	}
+
+	#[tokio::test]
+	async fn starts_a_new_recording_each_session() {
+		let path = recording_path("new-session");
+		let recorder = ResponseRecorder::record_to(&path).unwrap();
+		let _: u64 = recorder.capture("coin-price:kraken", async { Ok(1) }).await.unwrap();
+		drop(recorder);
+
+		let recorder = ResponseRecorder::record_to(&path).unwrap();
+		let _: u64 = recorder.capture("coin-price:kraken", async { Ok(2) }).await.unwrap();
+		drop(recorder);
+
+		let replay = ResponseRecorder::replay_from(&path).unwrap();
+		assert_eq!(
+			replay.capture::<u64, _>("coin-price:kraken", async { Ok(5) }).await.unwrap(),
+			2
+		);
+		assert!(replay.capture::<u64, _>("coin-price:kraken", async { Ok(5) }).await.is_err());
+		let _ = fs::remove_file(&path);
+	}
+
+	#[tokio::test]
+	async fn returns_the_live_response_when_it_cannot_be_recorded() {
+		let path = recording_path("unrecordable");
+		let recorder = ResponseRecorder::record_to(&path).unwrap();
+		// json maps need string keys, so this can't be recorded
+		let live = BTreeMap::from([((1u32, 2u32), 3u32)]);
+		let response = recorder
+			.capture("coin-price:kraken", {
+				let live = live.clone();
+				async move { Ok(live) }
+			})
+			.await
+			.unwrap();
+		assert_eq!(response, live);
+		assert_eq!(fs::read_to_string(&path).unwrap(), "");
+		let _ = fs::remove_file(&path);
+	}
+}
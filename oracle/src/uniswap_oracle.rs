@@ -1,3 +1,7 @@
+use crate::{
+	dex_pools::{DexPoolSource, PoolPriceBreakdown},
+	response_recording::ResponseRecorder,
+};
 use alloy_contract::Error as ContractError;
 use alloy_eips::{BlockId, BlockNumberOrTag};
 use alloy_primitives::{address, aliases::I56};
@@ -9,6 +13,7 @@ use argon_primitives::{
 };
 use polkadot_sdk::*;
 use sdk_core::prelude::*;
+use serde::{Deserialize, Serialize};
 use sp_runtime::FixedU128;
 use std::{collections::HashMap, env, fmt, sync::Arc};
 use tokio::sync::Mutex;
@@ -37,7 +42,20 @@ type PoolContract = IUniswapV3PoolInstance<RootProvider<Ethereum>>;
 
 struct EthereumRpcProvider {
 	provider: Arc<RootProvider<Ethereum>>,
-	pool_cache_by_fee: Mutex<HashMap<FeeAmount, PoolContract>>,
+	pool_cache_by_address: Mutex<HashMap<Address, PoolContract>>,
+}
+
+/// The time weighted average tick and liquidity (in mainchain units) observed for a pool
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct PoolTwap {
+	tick: i32,
+	liquidity: Balance,
+}
+
+/// A configured pool with its quote token resolved for the oracle's chain
+struct OraclePool {
+	source: DexPoolSource,
+	quote_token: Token,
 }
 
 #[cfg(test)]
@@ -53,9 +71,10 @@ pub(crate) fn use_mock_uniswap_prices(token_address: Address, mut prices: Vec<Pr
 pub struct UniswapOracle {
 	providers: Vec<EthereumRpcProvider>,
 	factory_address: Address,
-	usd_token: Token,
 	lookup_token: Token,
-	fee_tiers: Vec<FeeAmount>,
+	pools: Vec<OraclePool>,
+	last_breakdown: parking_lot::Mutex<Vec<PoolPriceBreakdown>>,
+	recorder: ResponseRecorder,
 }
 
 #[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Default)]
@@ -73,8 +92,8 @@ pub(crate) enum UniswapOracleError {
 impl fmt::Display for UniswapOracleError {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		match self {
-			Self::NoPoolData => write!(f, "No pool data across available fee tiers"),
-			Self::NoActiveLiquidity => write!(f, "No active liquidity across available fee tiers"),
+			Self::NoPoolData => write!(f, "No pool data across configured pools"),
+			Self::NoActiveLiquidity => write!(f, "No active liquidity across configured pools"),
 		}
 	}
 }
@@ -82,66 +101,85 @@ impl fmt::Display for UniswapOracleError {
 impl std::error::Error for UniswapOracleError {}
 
 impl UniswapOracle {
-	pub async fn new(rpc_urls: Vec<String>, usd_token: Token, lookup_token: Token) -> Result<Self> {
+	pub async fn new(
+		rpc_urls: Vec<String>,
+		lookup_token: Token,
+		pools: Vec<DexPoolSource>,
+	) -> Result<Self> {
 		if rpc_urls.is_empty() {
 			return Err(anyhow!("At least one Ethereum RPC URL is required"));
 		}
+		if pools.is_empty() {
+			return Err(anyhow!("At least one pool is required"));
+		}
 
-		let factory_address = if usd_token.chain_id() == ChainId::SEPOLIA as u64 {
+		let chain_id = lookup_token.chain_id();
+		let factory_address = if chain_id == ChainId::SEPOLIA as u64 {
 			SEPOLIA_FACTORY_ADDRESS
 		} else {
 			FACTORY_ADDRESS
 		};
+		let pools = pools
+			.into_iter()
+			.map(|source| Ok(OraclePool { quote_token: source.quote().token(chain_id)?, source }))
+			.collect::<Result<Vec<_>>>()?;
 		let mut providers = Vec::with_capacity(rpc_urls.len());
 		for url in rpc_urls {
 			providers.push(EthereumRpcProvider {
 				provider: Arc::new(RootProvider::connect(&url).await?),
-				pool_cache_by_fee: Default::default(),
+				pool_cache_by_address: Default::default(),
 			});
 		}
 
 		Ok(Self {
 			providers,
 			factory_address,
-			usd_token,
 			lookup_token,
-			// NOTE: taking high tier out since this will be for pricing a stablecoin. High fees are
-			// usually for highly volatile assets
-			fee_tiers: vec![FeeAmount::LOW, FeeAmount::MEDIUM],
+			pools,
+			last_breakdown: Default::default(),
+			recorder: Default::default(),
 		})
 	}
 
-	pub async fn get_current_price(&self) -> Result<PriceAndLiquidity> {
+	/// What each configured pool contributed to the last price lookup
+	pub fn last_breakdown(&self) -> Vec<PoolPriceBreakdown> {
+		self.last_breakdown.lock().clone()
+	}
+
+	/// Records or replays the pool observations used for each price lookup
+	pub fn set_recorder(&mut self, recorder: ResponseRecorder) {
+		self.recorder = recorder;
+	}
+
+	/// Gets the usd price of the lookup token, averaged across all configured pools by their
+	/// liquidity. USDC quoted pools are converted using the given USDC/USD price.
+	pub async fn get_current_price(&self, usdc_usd_price: FixedU128) -> Result<PriceAndLiquidity> {
 		#[cfg(test)]
 		{
 			if let Some(mock_tokens) = MOCK_PRICES.lock().get_mut(&self.lookup_token.address()) &&
-				let Some(price) = mock_tokens.pop()
+				let Some(mut price) = mock_tokens.pop()
 			{
+				price.price = price.price * usdc_usd_price;
 				return Ok(price);
 			}
 		}
 		let (price, liquidity) = self
-			.get_aggregated_twap()
+			.get_aggregated_twap(usdc_usd_price)
 			.await?
 			.ok_or_else(|| anyhow!("Failed to get price, using default"))?;
-		let scaled_numerator = price.adjusted_for_decimals().to_decimal() * FixedU128::accuracy();
-		let float = scaled_numerator.to_u128().map_err(|_| anyhow!("Failed to convert to u128"))?;
 
-		Ok(PriceAndLiquidity {
-			price: FixedU128::from_inner(float),
-			liquidity: Balance::try_from(liquidity)
-				.map_err(|e| anyhow!("Failed to convert liquidity  {e:?}"))?,
-		})
+		Ok(PriceAndLiquidity { price, liquidity })
 	}
 
-	/// Calculate time-weighted average price and liquidity for a given fee tier.
-	async fn get_twap_and_liquidity_basis(
+	/// Calculate time-weighted average tick and liquidity for a given pool.
+	async fn get_pool_twap(
 		&self,
 		provider: &EthereumRpcProvider,
-		fee: FeeAmount,
-	) -> Result<(Price<Token, Token>, BigInt)> {
+		pool: &OraclePool,
+	) -> Result<PoolTwap> {
 		let block_id = BlockId::Number(BlockNumberOrTag::Latest);
-		let pool_contract = self.get_cached_pool_contract(provider, fee).await?;
+		let source = &pool.source;
+		let pool_contract = self.get_cached_pool_contract(provider, pool).await?;
 
 		let mut backup_second_options = vec![60 * 60, 30 * 60, 10 * 60, 5 * 60, 60];
 		let mut time_window_seconds = backup_second_options.remove(0);
@@ -157,13 +195,13 @@ impl UniswapOracle {
 					let is_old_observation = error_msg.contains("execution reverted: OLD");
 					if is_old_observation && !backup_second_options.is_empty() {
 						time_window_seconds = backup_second_options.remove(0);
-						trace!(fee = ?fee, new_time_window = ?time_window_seconds, error = ?e, "Reducing time window and retrying observe");
+						trace!(%source, new_time_window = ?time_window_seconds, error = ?e, "Reducing time window and retrying observe");
 						continue;
 					}
 					if is_old_observation {
-						return Err(anyhow!("All time windows exhausted for fee tier {fee:?}"));
+						return Err(anyhow!("All time windows exhausted for pool {source}"));
 					}
-					error!(fee = ?fee, error = ?e, "Error calling observe on fee tier, returning error");
+					error!(%source, error = ?e, "Error calling observe on pool, returning error");
 					return Err(e).context("Error calling observe");
 				},
 			}
@@ -174,14 +212,11 @@ impl UniswapOracle {
 		let tick_diff = tick_cumulatives[1] - tick_cumulatives[0];
 
 		// Calculate time-weighted average tick (fixed-point division)
-		let tick_twap = {
+		let tick = {
 			let seconds_as_i56 = I56::try_from(time_window_seconds)?;
-			(tick_diff / seconds_as_i56).to_i24()
+			i32::try_from(tick_diff / seconds_as_i56)?
 		};
 
-		// Convert tick to sqrtPriceX96
-		let price = tick_to_price(self.lookup_token.clone(), self.usd_token.clone(), tick_twap)?;
-
 		// The same observations contain cumulative seconds per liquidity. Inverting the change
 		// across the selected window produces Uniswap's harmonic mean liquidity (TWAL).
 		let liquidity_diff = result.secondsPerLiquidityCumulativeX128s[1] -
@@ -193,78 +228,116 @@ impl UniswapOracle {
 			seconds_between_x128 / liquidity_diff.to_big_int()
 		};
 
-		// Uniswap liquidity for an 18-decimal token paired with 6-decimal USDC has 12 decimal
-		// places. Mainchain balances have 6, so remove the remaining 6 decimal places.
-		let liquidity_mainchain_units = average_liquidity / BigInt::from(10u128.pow(6));
+		// Uniswap liquidity is the geometric mean of both token amounts, so it carries the average
+		// of the two tokens' decimals (eg, 12 for an 18-decimal token paired with 6-decimal USDC).
+		// Mainchain balances have 6, so remove the remaining decimal places.
+		let liquidity_decimals = (self.lookup_token.decimals() + pool.quote_token.decimals()) / 2;
+		let liquidity_mainchain_units = average_liquidity /
+			BigInt::from(10u128.pow(liquidity_decimals.saturating_sub(6) as u32));
 
-		Ok((price, liquidity_mainchain_units))
+		Ok(PoolTwap {
+			tick,
+			liquidity: Balance::try_from(liquidity_mainchain_units)
+				.map_err(|e| anyhow!("Failed to convert liquidity  {e:?}"))?,
+		})
 	}
 
-	/// Aggregate TWAPs across fee tiers, weighted by TWAL
-	async fn get_aggregated_twap(&self) -> Result<Option<(Price<Token, Token>, BigInt)>> {
-		let mut total_numerator = BigInt::zero();
-		let mut total_denominator = BigInt::zero();
-		let mut total_liquidity = BigInt::zero();
-		let mut no_pool_data_fee_tiers = 0usize;
-		let mut successful_fee_tiers = 0usize;
-		let mut had_other_errors = false;
-		let mut last_error = None;
-
-		for &fee in &self.fee_tiers {
-			let mut fee_result = None;
-			let mut fee_error = None;
-			for provider in &self.providers {
-				match self.get_twap_and_liquidity_basis(provider, fee).await {
-					Ok(result) => {
-						fee_result = Some(result);
-						break;
-					},
-					Err(e) if has_internal_rpc_error(&e) => {
-						warn!(fee = ?fee, "Ethereum RPC returned an internal error; trying fallback provider");
-						fee_error = Some(e);
-					},
-					Err(e) => {
-						fee_error = Some(e);
-						break;
-					},
-				}
-			}
-
-			if let Some((price, current_liquidity)) = fee_result {
-				successful_fee_tiers += 1;
-				trace!(
-					fee = ?fee,
-					price = %price.to_fixed(3, None),
-					current_liquidity = ?current_liquidity,
-					"Got TWAP and liquidity basis"
-				);
-				total_liquidity += current_liquidity;
-				total_numerator += price.numerator * current_liquidity;
-				total_denominator += price.denominator * current_liquidity;
-				continue;
+	/// Looks up a pool's TWAP, trying each provider in turn when one returns an internal error.
+	/// Returns `None` if the pool has no data.
+	async fn fetch_pool_twap(&self, pool: &OraclePool) -> Result<Option<PoolTwap>> {
+		let mut pool_error = None;
+		for provider in &self.providers {
+			match self.get_pool_twap(provider, pool).await {
+				Ok(twap) => return Ok(Some(twap)),
+				Err(e) if has_internal_rpc_error(&e) => {
+					warn!(source = %pool.source, "Ethereum RPC returned an internal error; trying fallback provider");
+					pool_error = Some(e);
+				},
+				Err(e) => {
+					pool_error = Some(e);
+					break;
+				},
 			}
+		}
+		let Some(e) = pool_error else {
+			return Ok(None);
+		};
+		let oracle_error = e.chain().find_map(|cause| cause.downcast_ref::<UniswapOracleError>());
+		if matches!(oracle_error, Some(UniswapOracleError::NoPoolData)) {
+			return Ok(None);
+		}
+		Err(e)
+	}
 
-			let Some(e) = fee_error else {
-				continue;
+	/// Aggregate usd TWAPs across pools, weighted by TWAL
+	async fn get_aggregated_twap(
+		&self,
+		usdc_usd_price: FixedU128,
+	) -> Result<Option<(FixedU128, Balance)>> {
+		let mut total_weighted_price = BigInt::zero();
+		let mut total_liquidity: Balance = 0;
+		let mut no_pool_data_pools = 0usize;
+		let mut successful_pools = 0usize;
+		let mut last_error = None;
+		let mut breakdown = Vec::with_capacity(self.pools.len());
+
+		for pool in &self.pools {
+			let source = &pool.source;
+			let recorded_source = format!("uniswap:{}:{source}", self.lookup_token.address());
+			let twap = self.recorder.capture(&recorded_source, self.fetch_pool_twap(pool)).await;
+
+			let pool_price = match twap {
+				Ok(Some(twap)) => self.to_usd_price(pool, twap.tick, usdc_usd_price).map(|price| {
+					trace!(%source, price = ?price, liquidity = twap.liquidity, "Got TWAP and liquidity basis");
+					(price, twap.liquidity)
+				}),
+				Ok(None) => {
+					no_pool_data_pools += 1;
+					breakdown.push(PoolPriceBreakdown {
+						source: source.clone(),
+						usd_price: None,
+						liquidity: 0,
+						error: Some("No pool data".to_string()),
+					});
+					continue;
+				},
+				Err(e) => Err(e),
 			};
-			let oracle_error =
-				e.chain().find_map(|cause| cause.downcast_ref::<UniswapOracleError>());
-			if matches!(oracle_error, Some(UniswapOracleError::NoPoolData)) {
-				no_pool_data_fee_tiers += 1;
-				continue;
-			}
-			had_other_errors = true;
-			warn!(fee = ?fee, message = e.to_string(), "Could not get TWAP and liquidity basis for fee tier, skipping");
-			if last_error.is_none() {
-				last_error = Some(e);
+
+			match pool_price {
+				Ok((usd_price, liquidity)) => {
+					successful_pools += 1;
+					breakdown.push(PoolPriceBreakdown {
+						source: source.clone(),
+						usd_price: Some(usd_price),
+						liquidity,
+						error: None,
+					});
+					total_weighted_price +=
+						BigInt::from(usd_price.into_inner()) * BigInt::from(liquidity);
+					total_liquidity = total_liquidity.saturating_add(liquidity);
+				},
+				Err(e) => {
+					warn!(%source, message = e.to_string(), "Could not get TWAP and liquidity basis for pool, skipping");
+					breakdown.push(PoolPriceBreakdown {
+						source: source.clone(),
+						usd_price: None,
+						liquidity: 0,
+						error: Some(e.to_string()),
+					});
+					if last_error.is_none() {
+						last_error = Some(e);
+					}
+				},
 			}
 		}
+		*self.last_breakdown.lock() = breakdown;
 
-		if total_denominator == BigInt::zero() {
-			if no_pool_data_fee_tiers == self.fee_tiers.len() {
+		if total_liquidity == 0 {
+			if no_pool_data_pools == self.pools.len() {
 				return Err(UniswapOracleError::NoPoolData.into());
 			}
-			if !had_other_errors && successful_fee_tiers > 0 && total_liquidity == BigInt::zero() {
+			if last_error.is_none() && successful_pools > 0 {
 				return Err(UniswapOracleError::NoActiveLiquidity.into());
 			}
 			if let Some(error) = last_error {
@@ -273,39 +346,47 @@ impl UniswapOracle {
 			return Ok(None);
 		}
 
-		// Combine prices into a single aggregated Price
-		Ok(Some((
-			Price::new(
-				self.lookup_token.clone(),
-				self.usd_token.clone(),
-				total_denominator,
-				total_numerator,
-			),
-			total_liquidity,
-		)))
+		let price = Balance::try_from(total_weighted_price / BigInt::from(total_liquidity))
+			.map_err(|e| anyhow!("Failed to convert aggregated price {e:?}"))?;
+		Ok(Some((FixedU128::from_inner(price), total_liquidity)))
+	}
+
+	fn to_usd_price(
+		&self,
+		pool: &OraclePool,
+		tick: i32,
+		usdc_usd_price: FixedU128,
+	) -> Result<FixedU128> {
+		let price = tick_to_price(self.lookup_token.clone(), pool.quote_token.clone(), tick)?;
+		let scaled_numerator = price.adjusted_for_decimals().to_decimal() * FixedU128::accuracy();
+		let float = scaled_numerator.to_u128().map_err(|_| anyhow!("Failed to convert to u128"))?;
+		Ok(FixedU128::from_inner(float) * pool.source.quote().usd_price(usdc_usd_price))
 	}
 
 	async fn get_cached_pool_contract(
 		&self,
 		provider: &EthereumRpcProvider,
-		fee: FeeAmount,
+		pool: &OraclePool,
 	) -> Result<PoolContract> {
-		let mut cache = provider.pool_cache_by_fee.lock().await;
-		if let Some(pool) = cache.get(&fee) {
+		let pool_address = match pool.source {
+			DexPoolSource::UniswapV3 { fee, .. } => compute_pool_address(
+				self.factory_address,
+				pool.quote_token.address(),
+				self.lookup_token.address(),
+				fee,
+				None,
+				None,
+			),
+			DexPoolSource::Observable { address, .. } => address,
+		};
+		let mut cache = provider.pool_cache_by_address.lock().await;
+		if let Some(pool) = cache.get(&pool_address) {
 			return Ok(pool.clone());
 		}
 
-		let pool_address = compute_pool_address(
-			self.factory_address,
-			self.usd_token.address(),
-			self.lookup_token.address(),
-			fee,
-			None,
-			None,
-		);
 		let pool_contract =
 			IUniswapV3PoolInstance::new(pool_address, provider.provider.as_ref().clone());
-		cache.insert(fee, pool_contract.clone());
+		cache.insert(pool_address, pool_contract.clone());
 
 		Ok(pool_contract)
 	}
@@ -331,10 +412,12 @@ fn has_internal_rpc_error(error: &anyhow::Error) -> bool {
 mod test {
 	use super::*;
 
+	use crate::dex_pools::QuoteToken;
 	use alloy_primitives::{aliases::U160, Bytes};
 	use alloy_provider::ProviderBuilder;
 	use alloy_sol_types::SolCall;
 	use alloy_transport::mock::Asserter;
+	use sp_runtime::traits::One;
 	use tracing::warn;
 	use uniswap_lens::bindings::iuniswapv3pool::IUniswapV3Pool::{observeCall, observeReturn};
 	use uniswap_sdk_core::token;
@@ -344,19 +427,131 @@ mod test {
 	#[allow(dead_code)]
 	const ARGONOT_ADDRESS: &str = "0x6B93a120829558C18f8CD54a96E8024EF973cE52";
 
+	fn usdc_pools(fees: &[FeeAmount]) -> Vec<OraclePool> {
+		fees.iter()
+			.map(|&fee| OraclePool {
+				source: DexPoolSource::UniswapV3 { quote: QuoteToken::Usdc, fee },
+				quote_token: token!(ChainId::MAINNET as u64, USDC_ADDRESS, 6, "USDC"),
+			})
+			.collect()
+	}
+
+	#[derive(Deserialize)]
+	#[serde(rename_all = "camelCase")]
+	struct ObservationFixture {
+		lookup_token: Address,
+		responses: Vec<RecordedObservation>,
+	}
+
+	#[derive(Deserialize)]
+	struct RecordedObservation {
+		pool: String,
+		result: Bytes,
+	}
+
+	fn fixture_oracle(
+		fixture: &ObservationFixture,
+		providers: Vec<EthereumRpcProvider>,
+	) -> UniswapOracle {
+		let pools = fixture
+			.responses
+			.iter()
+			.map(|response| {
+				let source = response.pool.parse::<DexPoolSource>().unwrap();
+				OraclePool {
+					quote_token: source.quote().token(ChainId::MAINNET as u64).unwrap(),
+					source,
+				}
+			})
+			.collect();
+		UniswapOracle {
+			providers,
+			factory_address: FACTORY_ADDRESS,
+			lookup_token: token!(ChainId::MAINNET as u64, fixture.lookup_token, 18, "ARGON"),
+			pools,
+			last_breakdown: Default::default(),
+			recorder: Default::default(),
+		}
+	}
+
+	fn load_observation_fixture() -> (ObservationFixture, Vec<EthereumRpcProvider>) {
+		let fixture: ObservationFixture =
+			serde_json::from_str(include_str!("../test-fixtures/dex-pool-observations.json"))
+				.unwrap();
+		let asserter = Asserter::new();
+		for response in &fixture.responses {
+			asserter.push_success(&response.result);
+		}
+		let providers = vec![EthereumRpcProvider {
+			provider: Arc::new(ProviderBuilder::default().connect_mocked_client(asserter)),
+			pool_cache_by_address: Default::default(),
+		}];
+		(fixture, providers)
+	}
+
+	#[tokio::test]
+	async fn aggregates_recorded_pool_observations_by_liquidity() {
+		let (fixture, providers) = load_observation_fixture();
+		let oracle = fixture_oracle(&fixture, providers);
+
+		let usdc_usd_price = FixedU128::from_rational(99, 100);
+		let result = oracle.get_current_price(usdc_usd_price).await.unwrap();
+
+		let breakdown = oracle.last_breakdown();
+		assert_eq!(breakdown.len(), 2);
+		assert!(breakdown.iter().all(|pool| pool.error.is_none()));
+		// the usdc pool holds 3 argons of liquidity at ~1 usdc, the dai pool 1 argon at ~0.98 dai
+		assert_eq!(breakdown[0].liquidity, 3_000_000);
+		assert_eq!(breakdown[1].liquidity, 1_000_000);
+		let usdc_pool_price = breakdown[0].usd_price.unwrap();
+		let dai_pool_price = breakdown[1].usd_price.unwrap();
+		assert_eq!((usdc_pool_price.to_float() * 100.0).round(), 99.0);
+		assert_eq!((dai_pool_price.to_float() * 100.0).round(), 98.0);
+
+		assert_eq!(result.liquidity, 4_000_000);
+		assert_eq!(
+			result.price,
+			FixedU128::from_inner(
+				(usdc_pool_price.into_inner() * 3 + dai_pool_price.into_inner()) / 4
+			)
+		);
+	}
+
+	#[tokio::test]
+	async fn replays_recorded_pool_observations() {
+		let path = std::env::temp_dir()
+			.join(format!("argon-oracle-uniswap-replay-{}.jsonl", std::process::id()));
+		let _ = std::fs::remove_file(&path);
+		let usdc_usd_price = FixedU128::from_rational(99, 100);
+
+		let (fixture, providers) = load_observation_fixture();
+		let mut oracle = fixture_oracle(&fixture, providers);
+		oracle.set_recorder(ResponseRecorder::record_to(&path).unwrap());
+		let recorded = oracle.get_current_price(usdc_usd_price).await.unwrap();
+
+		let mut replay_oracle = fixture_oracle(&fixture, vec![]);
+		replay_oracle.set_recorder(ResponseRecorder::replay_from(&path).unwrap());
+		let replayed = replay_oracle.get_current_price(usdc_usd_price).await.unwrap();
+
+		assert_eq!(replayed, recorded);
+		assert_eq!(replay_oracle.last_breakdown(), oracle.last_breakdown());
+		let _ = std::fs::remove_file(&path);
+	}
+
 	#[tokio::test]
 	async fn uses_observation_history_for_time_weighted_average_liquidity() {
 		let asserter = Asserter::new();
 		let provider = EthereumRpcProvider {
 			provider: Arc::new(ProviderBuilder::default().connect_mocked_client(asserter.clone())),
-			pool_cache_by_fee: Default::default(),
+			pool_cache_by_address: Default::default(),
 		};
 		let oracle = UniswapOracle {
 			providers: vec![],
 			factory_address: FACTORY_ADDRESS,
-			usd_token: token!(ChainId::MAINNET as u64, USDC_ADDRESS, 6, "USDC"),
 			lookup_token: token!(ChainId::MAINNET as u64, ARGON_ADDRESS, 18, "ARGON"),
-			fee_tiers: vec![FeeAmount::LOW],
+			pools: usdc_pools(&[FeeAmount::LOW]),
+			last_breakdown: Default::default(),
+			recorder: Default::default(),
 		};
 
 		asserter.push_success(&Bytes::from(observeCall::abi_encode_returns(&observeReturn {
@@ -364,10 +559,9 @@ mod test {
 			secondsPerLiquidityCumulativeX128s: vec![U160::ZERO, U160::from(3_600u128 << 64)],
 		})));
 
-		let (_, time_weighted_average_liquidity) =
-			oracle.get_twap_and_liquidity_basis(&provider, FeeAmount::LOW).await.unwrap();
+		let twap = oracle.get_pool_twap(&provider, &oracle.pools[0]).await.unwrap();
 
-		assert_eq!(time_weighted_average_liquidity, BigInt::from((1u128 << 64) / 1_000_000));
+		assert_eq!(twap.liquidity, (1u128 << 64) / 1_000_000);
 	}
 
 	#[tokio::test]
@@ -380,19 +574,20 @@ mod test {
 					provider: Arc::new(
 						ProviderBuilder::default().connect_mocked_client(primary.clone()),
 					),
-					pool_cache_by_fee: Default::default(),
+					pool_cache_by_address: Default::default(),
 				},
 				EthereumRpcProvider {
 					provider: Arc::new(
 						ProviderBuilder::default().connect_mocked_client(fallback.clone()),
 					),
-					pool_cache_by_fee: Default::default(),
+					pool_cache_by_address: Default::default(),
 				},
 			],
 			factory_address: FACTORY_ADDRESS,
-			usd_token: token!(ChainId::MAINNET as u64, USDC_ADDRESS, 6, "USDC"),
 			lookup_token: token!(ChainId::MAINNET as u64, ARGON_ADDRESS, 18, "ARGON"),
-			fee_tiers: vec![FeeAmount::LOW, FeeAmount::MEDIUM],
+			pools: usdc_pools(&[FeeAmount::LOW, FeeAmount::MEDIUM]),
+			last_breakdown: Default::default(),
+			recorder: Default::default(),
 		};
 
 		primary.push_success(&Bytes::from(observeCall::abi_encode_returns(&observeReturn {
@@ -406,7 +601,7 @@ mod test {
 		})));
 
 		let result = oracle
-			.get_current_price()
+			.get_current_price(FixedU128::one())
 			.await
 			.expect("the fallback provider should return the price");
 
@@ -434,7 +629,7 @@ mod test {
 			providers: vec![
 				EthereumRpcProvider {
 					provider: Arc::new(ProviderBuilder::default().connect_mocked_client(primary)),
-					pool_cache_by_fee: Default::default(),
+					pool_cache_by_address: Default::default(),
 				},
 				EthereumRpcProvider {
 					provider: Arc::new(
@@ -442,16 +637,17 @@ mod test {
 							.await
 							.expect("Failed to connect to fallback provider"),
 					),
-					pool_cache_by_fee: Default::default(),
+					pool_cache_by_address: Default::default(),
 				},
 			],
 			factory_address: FACTORY_ADDRESS,
-			usd_token: token!(ChainId::MAINNET as u64, USDC_ADDRESS, 6, "USDC"),
 			lookup_token: token!(ChainId::MAINNET as u64, ARGONOT_ADDRESS, 18, "ARGONOT"),
-			fee_tiers: vec![FeeAmount::LOW, FeeAmount::MEDIUM],
+			pools: usdc_pools(&[FeeAmount::LOW, FeeAmount::MEDIUM]),
+			last_breakdown: Default::default(),
+			recorder: Default::default(),
 		};
 
-		if let Err(error) = oracle.get_current_price().await {
+		if let Err(error) = oracle.get_current_price(FixedU128::one()).await {
 			let oracle_error =
 				error.chain().find_map(|cause| cause.downcast_ref::<UniswapOracleError>());
 			assert_eq!(
@@ -476,13 +672,13 @@ mod test {
 		for (address, symbol) in [(ARGON_ADDRESS, "ARGON"), (ARGONOT_ADDRESS, "ARGONOT")] {
 			let oracle = UniswapOracle::new(
 				rpc_urls.clone(),
-				token!(ChainId::MAINNET as u64, USDC_ADDRESS, 6, "USDC"),
 				token!(ChainId::MAINNET as u64, address, 18, symbol),
+				DexPoolSource::defaults(),
 			)
 			.await
 			.expect("Failed to create oracle");
 			let price = oracle
-				.get_current_price()
+				.get_current_price(FixedU128::one())
 				.await
 				.inspect_err(|e| {
 					error!(symbol, "Error getting price: {:?}", e);
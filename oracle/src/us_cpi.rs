@@ -1,4 +1,5 @@
 use crate::{
+	response_recording::ResponseRecorder,
 	us_cpi_schedule::{load_cpi_schedule, CpiSchedule},
 	utils::{parse_date, parse_maybe_f64, to_fixed_i128},
 };
@@ -55,6 +56,8 @@ pub struct UsCpiRetriever {
 	pub last_cpi_check: DateTime<Utc>,
 	#[serde(skip)]
 	pub ticker: Ticker,
+	#[serde(skip)]
+	pub recorder: ResponseRecorder,
 }
 
 impl UsCpiRetriever {
@@ -71,6 +74,10 @@ impl UsCpiRetriever {
 	}
 
 	pub fn save_state(&self) -> Result<()> {
+		// a replayed session shouldn't overwrite the cache of the live one
+		if self.recorder.is_replaying() {
+			return Ok(());
+		}
 		let state_file = Self::get_state_file_path()?;
 		if !state_file.exists() {
 			let dir = state_file.parent().ok_or(anyhow!("No parent directory to build"))?;
@@ -101,13 +108,16 @@ impl UsCpiRetriever {
 		}
 	}
 
-	pub async fn new(ticker: &Ticker) -> Result<Self> {
-		if let Ok(retriever) = Self::load_state(ticker) {
+	pub async fn new(ticker: &Ticker, recorder: ResponseRecorder) -> Result<Self> {
+		// the cached state would skip the lookups a recording needs to capture or replay
+		if !recorder.is_active() &&
+			let Ok(retriever) = Self::load_state(ticker)
+		{
 			return Ok(retriever);
 		}
 
 		let schedule = load_cpi_schedule().await?;
-		let cpis = get_raw_cpis().await?;
+		let cpis = get_raw_cpis(&recorder).await?;
 		let current = cpis.first().ok_or(anyhow!("No CPI data"))?;
 		let previous = cpis.get(1).ok_or(anyhow!("No previous CPI data"))?;
 		let current_cpi_release_tick =
@@ -128,6 +138,7 @@ impl UsCpiRetriever {
 			last_cpi_check: Utc::now(),
 			cpi_change_per_tick: FixedI128::from_u32(0),
 			cpi_backoff: None,
+			recorder,
 		};
 		entry.cpi_change_per_tick = entry.calculate_cpi_change_per_tick();
 		entry.save_state()?;
@@ -149,7 +160,7 @@ impl UsCpiRetriever {
 		if self.cpi_backoff.is_none() &&
 			now.signed_duration_since(self.last_cpi_check).num_seconds() as u64 > ONE_HOUR
 		{
-			let next_cpi = get_raw_cpi().await.inspect_err(|e| {
+			let next_cpi = get_raw_cpi(&self.recorder).await.inspect_err(|e| {
 				if e.to_string().contains("REQUEST_NOT_PROCESSED") {
 					info!("Failed to get CPI data. Backing off for 1 hour");
 					self.cpi_backoff = Some(now + Duration::from_secs(ONE_HOUR));
@@ -262,7 +273,7 @@ lazy_static! {
 	static ref MOCK_RAW_CPIS: Arc<Mutex<Option<Vec<RawCpiValue>>>> = Arc::new(Mutex::new(None));
 }
 
-async fn get_raw_cpis() -> Result<Vec<RawCpiValue>> {
+async fn get_raw_cpis(recorder: &ResponseRecorder) -> Result<Vec<RawCpiValue>> {
 	#[cfg(test)]
 	{
 		let mut mock = MOCK_RAW_CPIS.lock().unwrap();
@@ -278,13 +289,18 @@ async fn get_raw_cpis() -> Result<Vec<RawCpiValue>> {
 		request_url.query_pairs_mut().append_pair("registrationkey", &key);
 	}
 
-	let client = Client::new();
-	let resp_price = client
-		.get(request_url)
-		.header("Content-Type", "application/json")
-		.send()
-		.await?
-		.text()
+	let resp_price = recorder
+		.capture("us-cpi:bls", async {
+			let client = Client::new();
+			let response = client
+				.get(request_url)
+				.header("Content-Type", "application/json")
+				.send()
+				.await?
+				.text()
+				.await?;
+			Ok(response)
+		})
 		.await?;
 
 	parse_cpi_results(&resp_price).map_err(|e| {
@@ -345,7 +361,7 @@ fn parse_cpi_results(response_body: &str) -> Result<Vec<RawCpiValue>> {
 	Ok(chronological_cpi_values)
 }
 
-async fn get_raw_cpi() -> Result<RawCpiValue> {
+async fn get_raw_cpi(recorder: &ResponseRecorder) -> Result<RawCpiValue> {
 	#[cfg(test)]
 	{
 		let mut mock = MOCK_RAW_CPIS.lock().unwrap();
@@ -355,7 +371,7 @@ async fn get_raw_cpi() -> Result<RawCpiValue> {
 		}
 	}
 
-	let result = get_raw_cpis().await?;
+	let result = get_raw_cpis(recorder).await?;
 	ensure!(!result.is_empty(), "No CPI data");
 	Ok(result.first().ok_or(anyhow!("No CPI data"))?.clone())
 }
@@ -464,7 +480,7 @@ mod tests {
 	#[tokio::test]
 	#[ignore]
 	async fn test_can_get_raw_cpi() {
-		let cpi = get_raw_cpi().await.unwrap();
+		let cpi = get_raw_cpi(&ResponseRecorder::default()).await.unwrap();
 		println!("CPI: {cpi:?}");
 		assert!(cpi.value >= FixedU128::from_u32(200));
 	}
@@ -491,6 +507,7 @@ mod tests {
 			last_cpi_check: Utc::now(),
 			cpi_backoff: None,
 			ticker,
+			recorder: Default::default(),
 		};
 		retriever.schedule = vec![
 			CpiSchedule {
@@ -574,6 +591,7 @@ mod tests {
 			last_cpi_check: Utc::now(),
 			cpi_backoff: None,
 			ticker,
+			recorder: Default::default(),
 		};
 		let timestamp =
 			parse_date("15 May 2024", vec!["%d %B %Y"]).unwrap().timestamp_millis() as u64;
@@ -101,6 +101,7 @@ mod benchmarks {
 			.ok_or(BenchmarkError::Stop("missing release request"))?;
 		assert_eq!(request.bitcoin_network_fee, bitcoin_network_fee);
 		assert_eq!(request.to_script_pubkey, release_script_pubkey);
+		assert!(LockReleaseRequestBitcoinHeightByUtxoId::<T>::contains_key(context.utxo_id));
 		Ok(())
 	}
 
@@ -124,6 +125,7 @@ mod benchmarks {
 		_(RawOrigin::Signed(operator), context.utxo_id, signature);
 
 		assert!(!LocksByUtxoId::<T>::contains_key(context.utxo_id));
+		assert!(!LockReleaseRequestBitcoinHeightByUtxoId::<T>::contains_key(context.utxo_id));
 		Ok(())
 	}
 
@@ -220,11 +222,16 @@ mod benchmarks {
 			overdue_utxo_ids.push(context.utxo_id);
 		}
 
+		let seeded_utxo_ids = overdue_utxo_ids.clone();
+
 		#[block]
 		{
 			let _ = Pallet::<T>::process_overdue_releases(overdue_utxo_ids);
 		}
 
+		for utxo_id in seeded_utxo_ids {
+			assert!(!LockReleaseRequestBitcoinHeightByUtxoId::<T>::contains_key(utxo_id));
+		}
 		Ok(())
 	}
 
@@ -69,12 +69,13 @@ pub mod weights;
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
+	use alloc::collections::BTreeMap;
 	use argon_bitcoin::{Amount, CosignReleaser, CosignScriptArgs, ReleaseStep};
 	use argon_primitives::{
 		bitcoin::{
 			BitcoinCosignScriptPubkey, BitcoinHeight, BitcoinScriptPubkey, BitcoinSignature,
-			CompressedBitcoinPubkey, Satoshis, UtxoId, UtxoRef, XPubChildNumber, XPubFingerprint,
-			SATOSHIS_PER_BITCOIN,
+			CompressedBitcoinPubkey, Satoshis, UtxoId, UtxoRef, VaultLockXPubSource,
+			XPubChildNumber, XPubFingerprint, SATOSHIS_PER_BITCOIN,
 		},
 		vault::{
 			BitcoinVaultProvider, LockExtension, Securitization, VaultError, VaultLockRequest,
@@ -229,6 +230,12 @@ pub mod pallet {
 	pub type LockReleaseRequestsByUtxoId<T: Config> =
 		StorageMap<_, Twox64Concat, UtxoId, LockReleaseRequest<T::Balance>, OptionQuery>;
 
+	/// The bitcoin height at which each pending release request was made (used to measure vault
+	/// cosign latency)
+	#[pallet::storage]
+	pub type LockReleaseRequestBitcoinHeightByUtxoId<T: Config> =
+		StorageMap<_, Twox64Concat, UtxoId, BitcoinHeight, OptionQuery>;
+
 	/// Mismatched utxos that were sent with invalid amounts to a locked bitcoin
 	#[pallet::storage]
 	pub type OrphanedUtxosByAccount<T: Config> = StorageDoubleMap<
@@ -968,6 +975,10 @@ pub mod pallet {
 					redemption_amount,
 				},
 			);
+			LockReleaseRequestBitcoinHeightByUtxoId::<T>::insert(
+				utxo_id,
+				T::BitcoinBlockHeightChange::get().1,
+			);
 
 			LockCosignDueByFrame::<T>::try_mutate(cosign_due_frame, |a| a.try_insert(utxo_id))
 				.map_err(|_| Error::<T>::ExpirationAtBlockOverflow)?;
@@ -1013,6 +1024,7 @@ pub mod pallet {
 			let owner_account = lock.owner_account.clone();
 
 			ensure!(T::VaultProvider::is_owner(vault_id, &who), Error::<T>::NoPermissions);
+			let requested_at_height = LockReleaseRequestBitcoinHeightByUtxoId::<T>::get(utxo_id);
 			let request = Self::take_release_request(utxo_id)?;
 			let bitcoin_network_fee = request.bitcoin_network_fee;
 			let to_script_pubkey = request.to_script_pubkey.clone();
@@ -1057,6 +1069,13 @@ pub mod pallet {
 				utxo_id,
 				frame_system::Pallet::<T>::block_number(),
 			);
+			if let Some(requested_at_height) = requested_at_height {
+				let current_bitcoin_height = T::BitcoinBlockHeightChange::get().1;
+				T::VaultProvider::record_cosign(
+					vault_id,
+					current_bitcoin_height.saturating_sub(requested_at_height),
+				);
+			}
 
 			Self::deposit_event(Event::BitcoinUtxoCosigned { utxo_id, vault_id, signature });
 
@@ -1715,6 +1734,34 @@ pub mod pallet {
 			MinimumSatoshis::<T>::get()
 		}
 
+		/// Lists the vault keys of every outstanding lock for a vault, grouped by the fingerprint
+		/// of the vault xpub they were derived from.
+		pub fn vault_lock_xpub_sources(
+			vault_id: VaultId,
+		) -> BTreeMap<XPubFingerprint, Vec<VaultLockXPubSource>> {
+			let mut sources = BTreeMap::<XPubFingerprint, Vec<VaultLockXPubSource>>::new();
+			for (utxo_id, _) in UtxoIdsByVaultId::<T>::iter_prefix(vault_id) {
+				let Some(lock) = LocksByUtxoId::<T>::get(utxo_id) else {
+					continue;
+				};
+				let (fingerprint, cosign_child_number, claim_child_number) =
+					lock.vault_xpub_sources;
+				sources.entry(fingerprint).or_default().push(VaultLockXPubSource {
+					utxo_id,
+					vault_pubkey: lock.vault_pubkey,
+					vault_claim_pubkey: lock.vault_claim_pubkey,
+					cosign_child_number,
+					claim_child_number,
+					is_funded: lock.is_funded,
+					vault_claim_height: lock.vault_claim_height,
+				});
+			}
+			for locks in sources.values_mut() {
+				locks.sort_by_key(|a| a.utxo_id);
+			}
+			sources
+		}
+
 		pub(crate) fn process_expiring_locks(expirations: impl IntoIterator<Item = UtxoId>) -> u64 {
 			let mut expiring_count: u64 = 0;
 			for utxo_id in expirations {
@@ -1874,6 +1921,7 @@ pub mod pallet {
 		) -> Result<LockReleaseRequest<T::Balance>, Error<T>> {
 			let request = LockReleaseRequestsByUtxoId::<T>::take(utxo_id)
 				.ok_or(Error::<T>::RedemptionNotLocked)?;
+			LockReleaseRequestBitcoinHeightByUtxoId::<T>::remove(utxo_id);
 
 			LockCosignDueByFrame::<T>::mutate(request.cosign_due_frame, |a| {
 				a.remove(&utxo_id);
@@ -1928,6 +1976,7 @@ pub mod pallet {
 		/// Call made during the on_initialize to implement cosign overdue penalties.
 		pub(crate) fn cosign_bitcoin_overdue(utxo_id: UtxoId) -> DispatchResult {
 			let entry = Self::take_release_request(utxo_id)?;
+			T::VaultProvider::record_missed_cosign(entry.vault_id);
 			let Some(lock) = LocksByUtxoId::<T>::take(utxo_id) else {
 				UtxoIdsByVaultId::<T>::remove(entry.vault_id, utxo_id);
 				UtxoIdToFundingUtxoRef::<T>::remove(utxo_id);
@@ -123,6 +123,8 @@ parameter_types! {
 
 	pub static VaultViewOfCosignPendingLocks: BTreeMap<VaultId,  BTreeSet<UtxoId>> = BTreeMap::new();
 	pub static VaultViewOfOrphanedUtxoCosigns: BTreeMap<VaultId,  BTreeMap<u64, u32>> = BTreeMap::new();
+	pub static RecordedCosignLatencies: Vec<(VaultId, BitcoinHeight)> = Vec::new();
+	pub static RecordedMissedCosigns: Vec<VaultId> = Vec::new();
 	pub const TicksPerBitcoinBlock: u64 = 10;
 	pub const ArgonTicksPerDay: u64 = 1440;
 	pub static CurrentTick: Tick = 1;
@@ -459,6 +461,14 @@ impl BitcoinVaultProvider for StaticVaultProvider {
 		Ok(())
 	}
 
+	fn record_cosign(vault_id: VaultId, cosign_latency: BitcoinHeight) {
+		RecordedCosignLatencies::mutate(|a| a.push((vault_id, cosign_latency)));
+	}
+
+	fn record_missed_cosign(vault_id: VaultId) {
+		RecordedMissedCosigns::mutate(|a| a.push(vault_id));
+	}
+
 	fn get_securitization_ratio(_vault_id: VaultId) -> Result<FixedU128, VaultError> {
 		Ok(DefaultVault::get().securitization_ratio)
 	}
@@ -9,8 +9,9 @@ use crate::{
 	mock::*,
 	pallet::{
 		LastFeeCouponNonceByVaultAndAccount, LockCosignDueByFrame, LockReleaseCosignHeightById,
-		LockReleaseRequestsByUtxoId, LocksByUtxoId, LocksPendingFundingByBitcoinHeight,
-		UtxoIdToFundingUtxoRef, UtxoIdsByOwnerAccount, UtxoIdsByVaultId,
+		LockReleaseRequestBitcoinHeightByUtxoId, LockReleaseRequestsByUtxoId, LocksByUtxoId,
+		LocksPendingFundingByBitcoinHeight, UtxoIdToFundingUtxoRef, UtxoIdsByOwnerAccount,
+		UtxoIdsByVaultId,
 	},
 	Error, Event, FeeCoupon, HoldReason, LockExpirationsByBitcoinHeight, LockOptions,
 	LockReleaseRequest, MicrogonPerBtcHistory, OrphanedUtxoExpirationByFrame,
@@ -113,6 +114,46 @@ fn can_lock_a_bitcoin_utxo_until_expiration() {
 	});
 }
 
+#[test]
+fn lists_vault_lock_xpub_sources_by_fingerprint() {
+	set_bitcoin_height(12);
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		DefaultVault::mutate(|x| {
+			x.securitization = 500_000 * MICROGONS_PER_ARGON;
+		});
+
+		set_argons(2, 2_000_000);
+		let pubkey = CompressedBitcoinPubkey([1; 33]);
+		for _ in 0..3 {
+			assert_ok!(BitcoinLocks::initialize(
+				RuntimeOrigin::signed(2),
+				1,
+				SATOSHIS_PER_BITCOIN,
+				pubkey,
+				None
+			));
+		}
+		// simulate a lock created with a replaced xpub
+		LocksByUtxoId::<Test>::mutate(2, |lock| {
+			lock.as_mut().unwrap().vault_xpub_sources = ([1; 4], 4, 5);
+		});
+
+		let sources = BitcoinLocks::vault_lock_xpub_sources(1);
+		assert_eq!(sources.len(), 2);
+		assert_eq!(sources[&[0; 4]].iter().map(|a| a.utxo_id).collect::<Vec<_>>(), vec![1, 3]);
+		let rotated = &sources[&[1; 4]];
+		assert_eq!(rotated.len(), 1);
+		assert_eq!(rotated[0].utxo_id, 2);
+		assert_eq!(rotated[0].cosign_child_number, 4);
+		assert_eq!(rotated[0].claim_child_number, 5);
+		assert_eq!(rotated[0].vault_pubkey, LocksByUtxoId::<Test>::get(2).unwrap().vault_pubkey);
+		assert!(!rotated[0].is_funded);
+
+		assert!(BitcoinLocks::vault_lock_xpub_sources(2).is_empty());
+	});
+}
+
 #[test]
 fn can_lock_a_bitcoin_utxo_with_a_preset_rate() {
 	set_bitcoin_height(12);
@@ -1510,6 +1551,7 @@ fn penalizes_vault_if_not_release_countersigned() {
 		assert!(LockCosignDueByFrame::<Test>::get(cosign_due).contains(&1));
 		assert!(VaultViewOfCosignPendingLocks::get().contains_key(&1));
 		assert!(VaultViewOfCosignPendingLocks::get().get(&1).unwrap().contains(&1));
+		assert_eq!(LockReleaseRequestBitcoinHeightByUtxoId::<Test>::get(1), Some(1));
 
 		CurrentFrameId::set(cosign_due);
 		System::set_block_number(2);
@@ -1538,6 +1580,9 @@ fn penalizes_vault_if_not_release_countersigned() {
 		assert_eq!(Balances::balance_on_hold(&HoldReason::ReleaseBitcoinLock.into(), &who), 0);
 		assert_eq!(Balances::balance(&who), 2000 + market_price);
 		assert_eq!(DefaultVault::get().securitized_satoshis, 0);
+		assert_eq!(LockReleaseRequestBitcoinHeightByUtxoId::<Test>::get(1), None);
+		assert_eq!(RecordedMissedCosigns::get(), vec![1]);
+		assert!(RecordedCosignLatencies::get().is_empty());
 	});
 }
 
@@ -1653,11 +1698,15 @@ fn clears_released_bitcoins() {
 			UtxoRef { txid: H256Le([0; 32]), output_index: 0 },
 		);
 
+		set_bitcoin_height(4);
 		assert_ok!(BitcoinLocks::cosign_release(
 			RuntimeOrigin::signed(1),
 			1,
 			BitcoinSignature(BoundedVec::truncate_from([0u8; 73].to_vec()))
 		));
+		assert_eq!(RecordedCosignLatencies::get(), vec![(1, 3)]);
+		assert_eq!(LockReleaseRequestBitcoinHeightByUtxoId::<Test>::get(1), None);
+		assert!(RecordedMissedCosigns::get().is_empty());
 		assert_eq!(
 			LastReleaseEvent::get(),
 			Some((1, who, false, redemption_amount, lock.liquidity_promised)),
@@ -98,7 +98,9 @@ where
 	}
 
 	fn cosign_release() -> Weight {
-		Base::cosign_release().saturating_add(LockEventWeight::utxo_released())
+		Base::cosign_release()
+			.saturating_add(LockEventWeight::utxo_released())
+			.saturating_add(VaultProviderWeights::<T>::record_cosign())
 	}
 
 	fn ratchet() -> Weight {
@@ -119,6 +121,9 @@ where
 	fn on_initialize_overdue_releases(n: u32) -> Weight {
 		Base::on_initialize_overdue_releases(n)
 			.saturating_add(LockEventWeight::utxo_released().saturating_mul(n.into()))
+			.saturating_add(
+				VaultProviderWeights::<T>::record_missed_cosign().saturating_mul(n.into()),
+			)
 	}
 
 	fn on_initialize_orphan_expirations(n: u32) -> Weight {
@@ -155,7 +160,9 @@ where
 	}
 
 	fn provider_utxo_detected() -> Weight {
-		Base::provider_utxo_detected().saturating_add(LockEventWeight::utxo_locked())
+		Base::provider_utxo_detected()
+			.saturating_add(LockEventWeight::utxo_locked())
+			.saturating_add(VaultProviderWeights::<T>::record_locked_satoshis())
 	}
 
 	fn provider_spent() -> Weight {
@@ -61,10 +61,26 @@ mod benchmarks {
 
 	#[benchmark]
 	fn set_confirmed_block() -> Result<(), BenchmarkError> {
-		let operator: T::AccountId = account("bitcoin-utxo-operator", 0, 0);
 		let bitcoin_height: BitcoinHeight = 10;
 		let bitcoin_block_hash = benchmark_block_hash(1);
-		OracleOperatorAccount::<T>::put(operator.clone());
+		// worst case: every tip operator is needed to reach the quorum
+		let max_operators = T::MaxTipOracleOperators::get();
+		let mut operators = BoundedBTreeSet::<T::AccountId, T::MaxTipOracleOperators>::new();
+		for i in 0..max_operators {
+			let operator_id: T::AccountId = account("bitcoin-utxo-operator", i, 0);
+			TipAttestations::<T>::insert(
+				&operator_id,
+				BitcoinBlock {
+					block_height: bitcoin_height,
+					block_hash: bitcoin_block_hash.clone(),
+				},
+			);
+			let _ = operators.try_insert(operator_id);
+		}
+		let operator: T::AccountId = account("bitcoin-utxo-operator", 0, 0);
+		TipAttestations::<T>::remove(&operator);
+		TipOracleOperators::<T>::put(operators);
+		TipAttestationQuorum::<T>::put(max_operators);
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(operator), bitcoin_height, bitcoin_block_hash.clone());
@@ -87,6 +103,30 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_tip_operators() -> Result<(), BenchmarkError> {
+		let max_operators = T::MaxTipOracleOperators::get();
+		let mut previous = BoundedBTreeSet::<T::AccountId, T::MaxTipOracleOperators>::new();
+		for i in 0..max_operators {
+			let operator_id: T::AccountId = account("previous-tip-operator", i, 0);
+			TipAttestations::<T>::insert(
+				&operator_id,
+				BitcoinBlock { block_height: 10, block_hash: benchmark_block_hash(1) },
+			);
+			let _ = previous.try_insert(operator_id);
+		}
+		TipOracleOperators::<T>::put(previous);
+		let operators = BoundedVec::<T::AccountId, T::MaxTipOracleOperators>::truncate_from(
+			(0..max_operators).map(|i| account("bitcoin-utxo-operator", i, 0)).collect(),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, operators.clone(), max_operators);
+
+		assert_eq!(TipOracleOperators::<T>::get().len(), operators.len());
+		Ok(())
+	}
+
 	#[benchmark]
 	fn utxo_spent(n: Linear<1, MAX_SYNC_ITEMS>) -> Result<(), BenchmarkError> {
 		let satoshis = benchmark_satoshis::<T>();
@@ -51,6 +51,10 @@ pub mod pallet {
 
 		/// Minimum output size tracked and reported for a watched Lock address.
 		type MinimumSatoshisPerUtxo: Get<Satoshis>;
+
+		/// Maximum number of operators attesting to the confirmed bitcoin tip.
+		#[pallet::constant]
+		type MaxTipOracleOperators: Get<u32>;
 	}
 
 	/// The Lock ID identified by each watched script pubkey.
@@ -97,6 +101,21 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type OracleOperatorAccount<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
 
+	/// Operators attesting to the confirmed bitcoin tip. When empty, the single
+	/// `OracleOperatorAccount` sets the tip on its own.
+	#[pallet::storage]
+	pub type TipOracleOperators<T: Config> =
+		StorageValue<_, BoundedBTreeSet<T::AccountId, T::MaxTipOracleOperators>, ValueQuery>;
+
+	/// How many tip operators must attest to the same block before the confirmed tip advances
+	#[pallet::storage]
+	pub type TipAttestationQuorum<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The latest block attested by each tip operator
+	#[pallet::storage]
+	pub type TipAttestations<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BitcoinBlock, OptionQuery>;
+
 	/// Check if the inherent was included
 	#[pallet::storage]
 	pub type InherentIncluded<T: Config> = StorageValue<_, bool, ValueQuery>;
@@ -127,6 +146,20 @@ pub mod pallet {
 			utxo_id: UtxoId,
 			error: DispatchError,
 		},
+		/// Two tip operators attested to different blocks at the same bitcoin height (emitted when
+		/// the conflicting attestation is first made)
+		ConflictingTipAttestation {
+			bitcoin_height: BitcoinHeight,
+			operator_id: T::AccountId,
+			block_hash: BitcoinBlockHash,
+			conflicting_operator_id: T::AccountId,
+			conflicting_block_hash: BitcoinBlockHash,
+		},
+		/// The tip operators or the attestation quorum changed
+		TipOracleOperatorsChanged {
+			operators: BoundedVec<T::AccountId, T::MaxTipOracleOperators>,
+			quorum: u32,
+		},
 	}
 
 	#[pallet::error]
@@ -157,6 +190,8 @@ pub mod pallet {
 		DuplicateUtxoId,
 		/// Too many outputs have been observed at one Lock address.
 		MaxUtxosPerLockExceeded,
+		/// The tip attestation quorum must be between one and the number of tip operators
+		InvalidTipAttestationQuorum,
 	}
 
 	#[pallet::genesis_config]
@@ -258,18 +293,20 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Sets the most recent confirmed bitcoin block height (only executable by the Oracle
-		/// Operator account)
+		/// Attests to the most recent confirmed bitcoin block (only executable by a tip operator).
+		/// The confirmed tip advances once the quorum of tip operators has attested to the same
+		/// block.
 		///
 		/// # Arguments
 		/// * `bitcoin_height` - the latest bitcoin block height to be confirmed
+		/// * `bitcoin_block_hash` - the hash of the block at that height
 		#[pallet::call_index(1)]
 		#[pallet::weight((T::WeightInfo::set_confirmed_block(), DispatchClass::Operational))]
 		#[pallet::feeless_if(|origin: &OriginFor<T>, _height: &BitcoinHeight, _hash: &BitcoinBlockHash, | -> bool {
 			let Ok(who) = ensure_signed(origin.clone()) else {
 				return false;
 			};
-			Some(who) == OracleOperatorAccount::<T>::get()
+			Pallet::<T>::is_tip_operator(&who)
 		})]
 		pub fn set_confirmed_block(
 			origin: OriginFor<T>,
@@ -277,16 +314,48 @@ pub mod pallet {
 			bitcoin_block_hash: BitcoinBlockHash,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			ensure!(Some(who) == OracleOperatorAccount::<T>::get(), Error::<T>::NoPermissions);
+			ensure!(Self::is_tip_operator(&who), Error::<T>::NoPermissions);
 			if let Some(current) = ConfirmedBitcoinBlockTip::<T>::get() &&
 				bitcoin_height < current.block_height
 			{
 				return Ok(());
 			}
-			ConfirmedBitcoinBlockTip::<T>::put(BitcoinBlock {
-				block_height: bitcoin_height,
-				block_hash: bitcoin_block_hash,
-			});
+			let attested =
+				BitcoinBlock { block_height: bitcoin_height, block_hash: bitcoin_block_hash };
+			// conflicts are only reported when they first appear, not each time an operator repeats
+			// its attestation
+			let is_new_attestation = TipAttestations::<T>::get(&who).as_ref() != Some(&attested);
+			TipAttestations::<T>::insert(&who, attested.clone());
+
+			let (operators, quorum) = Self::tip_operators();
+			let mut attesting = 0u32;
+			for operator_id in operators {
+				if operator_id == who {
+					attesting.saturating_inc();
+					continue;
+				}
+				let Some(other) = TipAttestations::<T>::get(&operator_id) else {
+					continue;
+				};
+				if other.block_height != attested.block_height {
+					continue;
+				}
+				if other.block_hash == attested.block_hash {
+					attesting.saturating_inc();
+				} else if is_new_attestation {
+					Self::deposit_event(Event::ConflictingTipAttestation {
+						bitcoin_height,
+						operator_id: who.clone(),
+						block_hash: attested.block_hash.clone(),
+						conflicting_operator_id: operator_id,
+						conflicting_block_hash: other.block_hash,
+					});
+				}
+			}
+
+			if attesting >= quorum {
+				ConfirmedBitcoinBlockTip::<T>::put(attested);
+			}
 			Ok(())
 		}
 
@@ -301,6 +370,40 @@ pub mod pallet {
 			OracleOperatorAccount::<T>::put(account_id.clone());
 			Ok(())
 		}
+
+		/// Sets the operators attesting to the confirmed bitcoin tip and how many of them must
+		/// agree (only executable by the Root account). An empty set falls back to the single
+		/// oracle operator.
+		///
+		/// # Arguments
+		/// * `operators` - the account ids of the tip operators
+		/// * `quorum` - the number of operators that must attest to the same block
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::set_tip_operators())]
+		pub fn set_tip_operators(
+			origin: OriginFor<T>,
+			operators: BoundedVec<T::AccountId, T::MaxTipOracleOperators>,
+			quorum: u32,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			let mut next = BoundedBTreeSet::<T::AccountId, T::MaxTipOracleOperators>::new();
+			for operator in operators.iter() {
+				let _ = next.try_insert(operator.clone());
+			}
+			ensure!(
+				next.is_empty() || (quorum > 0 && quorum as usize <= next.len()),
+				Error::<T>::InvalidTipAttestationQuorum
+			);
+			for previous in TipOracleOperators::<T>::get() {
+				if !next.contains(&previous) {
+					TipAttestations::<T>::remove(&previous);
+				}
+			}
+			TipOracleOperators::<T>::put(next);
+			TipAttestationQuorum::<T>::put(quorum);
+			Self::deposit_event(Event::TipOracleOperatorsChanged { operators, quorum });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> BitcoinUtxoTracker for Pallet<T> {
@@ -346,6 +449,23 @@ pub mod pallet {
 	}
 
 	impl<T: Config> Pallet<T> {
+		pub fn is_tip_operator(who: &T::AccountId) -> bool {
+			let operators = TipOracleOperators::<T>::get();
+			if operators.is_empty() {
+				return OracleOperatorAccount::<T>::get().as_ref() == Some(who);
+			}
+			operators.contains(who)
+		}
+
+		/// The operators attesting to the tip and the quorum they need to reach
+		fn tip_operators() -> (Vec<T::AccountId>, u32) {
+			let operators = TipOracleOperators::<T>::get();
+			if operators.is_empty() {
+				return (OracleOperatorAccount::<T>::get().into_iter().collect(), 1);
+			}
+			(operators.into_iter().collect(), TipAttestationQuorum::<T>::get())
+		}
+
 		pub fn has_new_bitcoin_tip() -> bool {
 			let Some(current) = ConfirmedBitcoinBlockTip::<T>::get() else {
 				return false;
@@ -25,6 +25,7 @@ parameter_types! {
 	pub static MinimumSatoshisPerUtxo: u64 = 100_000_000; // 1 bitcoin minimum
 
 	pub const MaxUtxosPerLock: u32 = 10;
+	pub const MaxTipOracleOperators: u32 = 5;
 	pub static UtxoDetectedCallback: Option<UtxoDetectedCallbackFn> = None;
 	pub static LastSpent: Option<(UtxoId, UtxoRef)> = None;
 }
@@ -56,6 +57,7 @@ impl pallet_bitcoin_utxos::Config for Test {
 	type MaxUtxosPerLock = MaxUtxosPerLock;
 	type EventHandler = StaticEventHandler;
 	type MinimumSatoshisPerUtxo = MinimumSatoshisPerUtxo;
+	type MaxTipOracleOperators = MaxTipOracleOperators;
 }
 
 pub fn new_test_ext() -> TestState {
@@ -1,7 +1,7 @@
 use crate::{
 	mock::{System, *},
 	pallet::{ConfirmedBitcoinBlockTip, InherentIncluded},
-	Error, Event, UtxoAddressByUtxoId, UtxoRefsByUtxoId,
+	Error, Event, OracleOperatorAccount, TipAttestations, UtxoAddressByUtxoId, UtxoRefsByUtxoId,
 };
 use pallet_prelude::{
 	argon_primitives::{
@@ -158,6 +158,105 @@ fn duplicate_reports_do_not_duplicate_attachments() {
 	});
 }
 
+#[test]
+fn single_oracle_operator_sets_the_tip() {
+	new_test_ext().execute_with(|| {
+		OracleOperatorAccount::<Test>::put(1);
+		assert_noop!(
+			BitcoinUtxos::set_confirmed_block(RuntimeOrigin::signed(2), 10, block(10).block_hash),
+			Error::<Test>::NoPermissions
+		);
+		assert_ok!(BitcoinUtxos::set_confirmed_block(
+			RuntimeOrigin::signed(1),
+			10,
+			block(10).block_hash
+		));
+		assert_eq!(ConfirmedBitcoinBlockTip::<Test>::get(), Some(block(10)));
+
+		// older heights are ignored
+		assert_ok!(BitcoinUtxos::set_confirmed_block(
+			RuntimeOrigin::signed(1),
+			9,
+			block(9).block_hash
+		));
+		assert_eq!(ConfirmedBitcoinBlockTip::<Test>::get(), Some(block(10)));
+	});
+}
+
+#[test]
+fn tip_advances_once_a_quorum_attests() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		OracleOperatorAccount::<Test>::put(1);
+		assert_noop!(
+			BitcoinUtxos::set_tip_operators(
+				RuntimeOrigin::root(),
+				BoundedVec::truncate_from(vec![1, 2, 3]),
+				4
+			),
+			Error::<Test>::InvalidTipAttestationQuorum
+		);
+		let operators = BoundedVec::truncate_from(vec![1, 2, 3]);
+		assert_ok!(BitcoinUtxos::set_tip_operators(RuntimeOrigin::root(), operators.clone(), 2));
+		System::assert_last_event(
+			Event::<Test>::TipOracleOperatorsChanged { operators, quorum: 2 }.into(),
+		);
+
+		assert_ok!(BitcoinUtxos::set_confirmed_block(
+			RuntimeOrigin::signed(1),
+			10,
+			block(10).block_hash
+		));
+		assert_eq!(ConfirmedBitcoinBlockTip::<Test>::get(), None);
+
+		// a different block at the same height is an alert, not a vote for the first
+		let conflicting = H256Le([99; 32]);
+		assert_ok!(BitcoinUtxos::set_confirmed_block(
+			RuntimeOrigin::signed(2),
+			10,
+			conflicting.clone()
+		));
+		assert_eq!(ConfirmedBitcoinBlockTip::<Test>::get(), None);
+		System::assert_last_event(
+			Event::<Test>::ConflictingTipAttestation {
+				bitcoin_height: 10,
+				operator_id: 2,
+				block_hash: conflicting,
+				conflicting_operator_id: 1,
+				conflicting_block_hash: block(10).block_hash,
+			}
+			.into(),
+		);
+		// repeating an attestation doesn't report the same conflict again
+		System::reset_events();
+		assert_ok!(BitcoinUtxos::set_confirmed_block(
+			RuntimeOrigin::signed(2),
+			10,
+			conflicting.clone()
+		));
+		assert_eq!(System::events().len(), 0);
+
+		assert_ok!(BitcoinUtxos::set_confirmed_block(
+			RuntimeOrigin::signed(3),
+			10,
+			block(10).block_hash
+		));
+		assert_eq!(ConfirmedBitcoinBlockTip::<Test>::get(), Some(block(10)));
+
+		// removed operators lose their attestations
+		assert_ok!(BitcoinUtxos::set_tip_operators(
+			RuntimeOrigin::root(),
+			BoundedVec::truncate_from(vec![1, 3]),
+			2
+		));
+		assert!(!TipAttestations::<Test>::contains_key(2));
+		assert_noop!(
+			BitcoinUtxos::set_confirmed_block(RuntimeOrigin::signed(2), 11, block(11).block_hash),
+			Error::<Test>::NoPermissions
+		);
+	});
+}
+
 fn select_funding(_: (UtxoId, UtxoRef, u64)) -> DispatchResult {
 	Ok(())
 }
@@ -21,6 +21,7 @@ pub trait WeightInfo {
 	fn sync_base() -> Weight;
 	fn set_confirmed_block() -> Weight;
 	fn set_operator() -> Weight;
+	fn set_tip_operators() -> Weight;
 
 	// Individual UTXO operation weights (linear benchmarks for sync composition)
 	fn utxo_spent(n: u32) -> Weight;
@@ -56,6 +57,10 @@ where
 		Base::set_operator()
 	}
 
+	fn set_tip_operators() -> Weight {
+		Base::set_tip_operators()
+	}
+
 	fn utxo_spent(n: u32) -> Weight {
 		Base::utxo_spent(n).saturating_add(EventHandlerWeight::spent().saturating_mul(n.into()))
 	}
@@ -84,6 +89,10 @@ impl WeightInfo for () {
 		Weight::zero()
 	}
 
+	fn set_tip_operators() -> Weight {
+		Weight::zero()
+	}
+
 	fn utxo_spent(_n: u32) -> Weight {
 		Weight::zero()
 	}
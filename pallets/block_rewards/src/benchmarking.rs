@@ -1,7 +1,10 @@
 use super::*;
 #[allow(unused)]
 use crate::Pallet as BlockRewardsPallet;
-use argon_primitives::TickProvider;
+use argon_primitives::{
+	block_seal::{BlockPayout, BlockRewardType},
+	TickProvider,
+};
 use frame_system::RawOrigin;
 use pallet_prelude::benchmarking::{
 	set_benchmark_notebook_provider_state, BenchmarkNotebookProviderState,
@@ -33,6 +36,52 @@ mod benchmarks {
 		assert!(BlockVoterRewardsEnabled::<T>::get());
 	}
 
+	// A new pool, so the deposit is held.
+	#[benchmark]
+	fn set_mining_pool_shares(m: Linear<1, MAX_MINING_POOL_MEMBERS>) {
+		let pool_account: T::AccountId = whitelisted_caller();
+		fund_mining_pool_deposit::<T>(&pool_account);
+		let members = (0..m)
+			.map(|i| MiningPoolMember { account_id: account("member", i, 0), shares: 100 })
+			.collect::<Vec<_>>();
+
+		#[extrinsic_call]
+		set_mining_pool_shares(
+			RawOrigin::Signed(pool_account.clone()),
+			BoundedVec::truncate_from(members),
+		);
+
+		assert_eq!(MiningPools::<T>::get(&pool_account).map(|x| x.len() as u32), Some(m));
+		assert_eq!(
+			T::ArgonCurrency::balance_on_hold(&HoldReason::MiningPoolDeposit.into(), &pool_account),
+			T::MiningPoolDeposit::get()
+		);
+	}
+
+	#[benchmark]
+	fn close_mining_pool() {
+		let pool_account: T::AccountId = whitelisted_caller();
+		fund_mining_pool_deposit::<T>(&pool_account);
+		BlockRewardsPallet::<T>::hold_mining_pool_deposit(&pool_account)
+			.expect("deposit should be held");
+		MiningPools::<T>::insert(
+			&pool_account,
+			BoundedVec::truncate_from(vec![MiningPoolMember {
+				account_id: account("member", 0, 0),
+				shares: 1,
+			}]),
+		);
+
+		#[extrinsic_call]
+		close_mining_pool(RawOrigin::Signed(pool_account.clone()));
+
+		assert!(!MiningPools::<T>::contains_key(&pool_account));
+		assert_eq!(
+			T::ArgonCurrency::balance_on_hold(&HoldReason::MiningPoolDeposit.into(), &pool_account),
+			0u128.into()
+		);
+	}
+
 	#[benchmark]
 	fn on_initialize_with_rewards() {
 		let block_number: BlockNumberFor<T> = 1u32.into();
@@ -63,5 +112,41 @@ mod benchmarks {
 		assert_eq!(payouts.len(), 2);
 	}
 
+	// Mining pool members are paid on top of `on_initialize_with_rewards`, so only the split and
+	// the member mints are measured here.
+	#[benchmark]
+	fn split_mining_pool_payout(m: Linear<1, MAX_MINING_POOL_MEMBERS>) {
+		let members = (0..m)
+			.map(|i| MiningPoolMember { account_id: account("member", i, 0), shares: 1 })
+			.collect::<Vec<_>>();
+		let pool_payout = BlockPayout {
+			account_id: whitelisted_caller(),
+			reward_type: BlockRewardType::Miner,
+			block_seal_authority: None,
+			ownership: 100_000_000u128.into(),
+			argons: 100_000_000u128.into(),
+		};
+
+		#[block]
+		{
+			for mut payout in BlockRewardsPallet::<T>::split_pool_payout(pool_payout, &members) {
+				BlockRewardsPallet::<T>::mint::<T::ArgonCurrency>(&mut payout)
+					.expect("member argons should mint");
+				BlockRewardsPallet::<T>::mint::<T::OwnershipCurrency>(&mut payout)
+					.expect("member ownership should mint");
+			}
+		}
+
+		assert_eq!(
+			T::ArgonCurrency::balance(&account("member", 0, 0)),
+			(100_000_000u128 / m as u128).into()
+		);
+	}
+
 	impl_benchmark_test_suite!(BlockRewardsPallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
+
+fn fund_mining_pool_deposit<T: Config>(pool_account: &T::AccountId) {
+	let amount = T::MiningPoolDeposit::get().saturating_add(T::ArgonCurrency::minimum_balance());
+	T::ArgonCurrency::set_balance(pool_account, amount);
+}
@@ -18,11 +18,19 @@ pub mod weights;
 /// (Incremental increase per block, blocks between increments, max value)
 pub type GrowthPath<Balance> = (Balance, Tick, Balance);
 
+/// The most members a compute mining pool can split rewards between
+pub const MAX_MINING_POOL_MEMBERS: u32 = 50;
+
+/// A miner, a voter, plus one payout per mining pool member (and the pool's remainder)
+pub const MAX_PAYOUTS_PER_BLOCK: u32 = MAX_MINING_POOL_MEMBERS + 3;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use core::any::TypeId;
 	use frame_support::traits::fungible::{InspectFreeze, Mutate, MutateFreeze};
-	use sp_runtime::FixedPointNumber;
+	use sp_runtime::{
+		helpers_128bit::multiply_by_rational_with_rounding, FixedPointNumber, Rounding,
+	};
 
 	use super::*;
 	use argon_primitives::{
@@ -37,6 +45,8 @@ pub mod pallet {
 	#[pallet::composite_enum]
 	pub enum HoldReason {
 		MaturationPeriod,
+		/// The deposit for a compute mining pool
+		MiningPoolDeposit,
 	}
 
 	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
@@ -53,6 +63,7 @@ pub mod pallet {
 
 		type ArgonCurrency: MutateFreeze<Self::AccountId, Balance = Self::Balance>
 			+ Mutate<Self::AccountId, Balance = Self::Balance>
+			+ MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason, Balance = Self::Balance>
 			+ InspectFreeze<Self::AccountId, Balance = Self::Balance, Id = Self::RuntimeFreezeReason>;
 		type OwnershipCurrency: MutateFreeze<Self::AccountId, Balance = Self::Balance>
 			+ Mutate<Self::AccountId, Balance = Self::Balance>
@@ -105,6 +116,8 @@ pub mod pallet {
 
 		/// The overarching freeze reason.
 		type RuntimeFreezeReason: From<FreezeReason>;
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
 		type EventHandler: BlockRewardsEventHandler<Self::AccountId, Self::Balance>;
 
 		/// The number of "argons by cohort" entries to keep in history
@@ -118,6 +131,10 @@ pub mod pallet {
 
 		/// A percent reduction in mining argons vs the mint amount
 		type PerBlockArgonReducerPercent: Get<FixedU128>;
+
+		/// The argons held from a mining pool account while the pool exists
+		#[pallet::constant]
+		type MiningPoolDeposit: Get<Self::Balance>;
 	}
 
 	/// Historical payouts by block number
@@ -126,10 +143,22 @@ pub mod pallet {
 		_,
 		Blake2_128Concat,
 		BlockNumberFor<T>,
-		BoundedVec<BlockPayout<T::AccountId, T::Balance>, ConstU32<3>>,
+		BoundedVec<BlockPayout<T::AccountId, T::Balance>, ConstU32<MAX_PAYOUTS_PER_BLOCK>>,
 		ValueQuery,
 	>;
 
+	/// Compute mining pools by the pool account (the compute author of the pool's blocks). The
+	/// miner rewards of compute blocks authored by the pool are split between the members by
+	/// their shares.
+	#[pallet::storage]
+	pub type MiningPools<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<MiningPoolMember<T::AccountId>, ConstU32<MAX_MINING_POOL_MEMBERS>>,
+		OptionQuery,
+	>;
+
 	/// Bool if block rewards are paused
 	#[pallet::storage]
 	pub type BlockRewardsPaused<T: Config> = StorageValue<_, bool, ValueQuery>;
@@ -163,6 +192,16 @@ pub mod pallet {
 			ownership: Option<T::Balance>,
 			error: DispatchError,
 		},
+		/// The member shares of a mining pool were set
+		MiningPoolSharesSet {
+			pool_account: T::AccountId,
+			members: u32,
+			total_shares: u64,
+		},
+		/// A mining pool was closed
+		MiningPoolClosed {
+			pool_account: T::AccountId,
+		},
 	}
 
 	/// A reason for freezing funds.
@@ -174,7 +213,18 @@ pub mod pallet {
 	}
 
 	#[pallet::error]
-	pub enum Error<T> {}
+	pub enum Error<T> {
+		/// A mining pool needs at least one member with shares
+		NoMiningPoolShares,
+		/// A member is listed more than once
+		DuplicateMiningPoolMember,
+		/// The account doesn't have a mining pool
+		MiningPoolNotFound,
+		/// The account can't cover the mining pool deposit
+		InsufficientMiningPoolDeposit,
+		/// The mining pool deposit could not be released
+		UnrecoverableMiningPoolDeposit,
+	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T>
@@ -232,6 +282,21 @@ pub mod pallet {
 				argons: miner_argons,
 			}];
 
+			// compute blocks authored by a mining pool are split between the members
+			if assigned_rewards_account.is_none() &&
+				!T::BlockSealerProvider::is_block_vote_seal() &&
+				let Some(members) = MiningPools::<T>::get(&miner_reward_account)
+			{
+				let pool_payout = rewards.remove(0);
+				// on_initialize only charges the payouts of a single author, and on_finalize can't
+				// return weight
+				frame_system::Pallet::<T>::register_extra_weight_unchecked(
+					T::WeightInfo::split_mining_pool_payout(members.len() as u32),
+					DispatchClass::Mandatory,
+				);
+				rewards.extend(Self::split_pool_payout(pool_payout, &members));
+			}
+
 			if BlockVoterRewardsEnabled::<T>::get() &&
 				let Some(ref block_vote_rewards_account) = authors.block_vote_rewards_account
 			{
@@ -301,6 +366,56 @@ pub mod pallet {
 			<BlockVoterRewardsEnabled<T>>::set(enabled);
 			Ok(())
 		}
+
+		/// Register (or update) a compute mining pool for the signing account. The signer must be
+		/// the compute author of the pool's blocks. Shares can be fixed weights, or share counts
+		/// the operator attests to and updates as members submit work. The miner rewards of each
+		/// compute block the pool authors are split by the shares at the time of the block.
+		///
+		/// Registering a pool holds [`Config::MiningPoolDeposit`] argons from the signer until the
+		/// pool is closed.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::set_mining_pool_shares(members.len() as u32))]
+		pub fn set_mining_pool_shares(
+			origin: OriginFor<T>,
+			members: BoundedVec<MiningPoolMember<T::AccountId>, ConstU32<MAX_MINING_POOL_MEMBERS>>,
+		) -> DispatchResult {
+			let pool_account = ensure_signed(origin)?;
+			let total_shares = members.iter().fold(0u64, |acc, x| acc.saturating_add(x.shares));
+			ensure!(total_shares > 0, Error::<T>::NoMiningPoolShares);
+			for (i, member) in members.iter().enumerate() {
+				ensure!(
+					!members.iter().skip(i + 1).any(|x| x.account_id == member.account_id),
+					Error::<T>::DuplicateMiningPoolMember
+				);
+			}
+
+			if !MiningPools::<T>::contains_key(&pool_account) {
+				Self::hold_mining_pool_deposit(&pool_account)?;
+			}
+
+			let member_count = members.len() as u32;
+			MiningPools::<T>::insert(&pool_account, members);
+			Self::deposit_event(Event::MiningPoolSharesSet {
+				pool_account,
+				members: member_count,
+				total_shares,
+			});
+			Ok(())
+		}
+
+		/// Close the signer's mining pool and release its deposit. Rewards go to the pool account
+		/// again.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::close_mining_pool())]
+		pub fn close_mining_pool(origin: OriginFor<T>) -> DispatchResult {
+			let pool_account = ensure_signed(origin)?;
+			ensure!(MiningPools::<T>::contains_key(&pool_account), Error::<T>::MiningPoolNotFound);
+			MiningPools::<T>::remove(&pool_account);
+			Self::release_mining_pool_deposit(&pool_account)?;
+			Self::deposit_event(Event::MiningPoolClosed { pool_account });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T>
@@ -327,6 +442,75 @@ pub mod pallet {
 			}
 		}
 
+		pub(crate) fn hold_mining_pool_deposit(pool_account: &T::AccountId) -> DispatchResult {
+			let reason = HoldReason::MiningPoolDeposit;
+			if T::ArgonCurrency::balance_on_hold(&reason.into(), pool_account) == 0u128.into() {
+				frame_system::Pallet::<T>::inc_providers(pool_account);
+			}
+			T::ArgonCurrency::hold(&reason.into(), pool_account, T::MiningPoolDeposit::get())
+				.map_err(|_| Error::<T>::InsufficientMiningPoolDeposit)?;
+			Ok(())
+		}
+
+		fn release_mining_pool_deposit(pool_account: &T::AccountId) -> DispatchResult {
+			let reason = HoldReason::MiningPoolDeposit;
+			let deposit = T::ArgonCurrency::balance_on_hold(&reason.into(), pool_account);
+			if deposit == 0u128.into() {
+				return Ok(());
+			}
+			T::ArgonCurrency::release(&reason.into(), pool_account, deposit, Precision::Exact)
+				.map_err(|e| {
+					log::warn!(
+						"Error releasing mining pool deposit for {pool_account:?}. Amount {deposit:?}. {e:?}"
+					);
+					Error::<T>::UnrecoverableMiningPoolDeposit
+				})?;
+			let _ = frame_system::Pallet::<T>::dec_providers(pool_account);
+			Ok(())
+		}
+
+		/// Split a pool's miner payout pro rata by member shares. Rounding remainders stay with
+		/// the pool account (if there are any).
+		pub(crate) fn split_pool_payout(
+			pool_payout: BlockPayout<T::AccountId, T::Balance>,
+			members: &[MiningPoolMember<T::AccountId>],
+		) -> Vec<BlockPayout<T::AccountId, T::Balance>> {
+			let total_shares = members.iter().fold(0u64, |acc, x| acc.saturating_add(x.shares));
+			if total_shares == 0 {
+				return vec![pool_payout];
+			}
+			let pro_rata = |amount: T::Balance, shares: u64| -> T::Balance {
+				multiply_by_rational_with_rounding(
+					amount.into(),
+					shares as u128,
+					total_shares as u128,
+					Rounding::Down,
+				)
+				.unwrap_or_default()
+				.into()
+			};
+
+			let mut remainder = pool_payout.clone();
+			let mut payouts = vec![];
+			for member in members {
+				let argons = pro_rata(pool_payout.argons, member.shares);
+				let ownership = pro_rata(pool_payout.ownership, member.shares);
+				remainder.argons.saturating_reduce(argons);
+				remainder.ownership.saturating_reduce(ownership);
+				payouts.push(BlockPayout {
+					account_id: member.account_id.clone(),
+					ownership,
+					argons,
+					reward_type: BlockRewardType::MiningPoolShare,
+					block_seal_authority: None,
+				});
+			}
+			if remainder.argons > 0u128.into() || remainder.ownership > 0u128.into() {
+				payouts.insert(0, remainder);
+			}
+			payouts
+		}
+
 		pub fn track_fee(amount: T::Balance) {
 			BlockFees::<T>::mutate(|f| f.saturating_accrue(amount))
 		}
@@ -477,6 +661,15 @@ pub mod pallet {
 	}
 }
 
+#[derive(
+	Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Debug,
+)]
+pub struct MiningPoolMember<AccountId> {
+	pub account_id: AccountId,
+	/// The member's weight in the pool (fixed, or a share count attested by the operator)
+	pub shares: u64,
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub(crate) struct RewardAmounts<T: Config> {
 	pub argons: T::Balance,
@@ -85,6 +85,7 @@ parameter_types! {
 	pub static HalvingBeginBlock: u32 = 1000;
 	pub static HalvingBlocks :u32 = 100;
 	pub static MinerPayoutPercent :FixedU128 = FixedU128::from_rational(75, 100);
+	pub static MiningPoolDeposit: Balance = 1_000;
 	pub static ActiveNotaries: Vec<NotaryId> = vec![1];
 	pub static NotebookTick: Tick = 0;
 	pub static ElapsedTicks: Tick = 0;
@@ -252,6 +253,7 @@ impl pallet_block_rewards::Config for Test {
 	type BlockSealerProvider = StaticBlockSealerProvider;
 	type NotaryProvider = TestProvider;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type TickProvider = StaticTickProvider;
 	type NotebookProvider = TestProvider;
 	type EventHandler = (RewardEvents,);
@@ -261,6 +263,7 @@ impl pallet_block_rewards::Config for Test {
 	type PayoutHistoryBlocks = ConstU32<5>;
 	type EpochTicks = ConstU64<14_400>;
 	type PerBlockArgonReducerPercent = BlockRewardsDampener;
+	type MiningPoolDeposit = MiningPoolDeposit;
 }
 
 pub fn new_test_ext() -> TestState {
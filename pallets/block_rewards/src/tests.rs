@@ -1,7 +1,7 @@
 use crate::{
 	mock::{Balances, BlockRewards, Ownership, *},
-	pallet::{ArgonsPerBlock, BlockRewardsByCohort, BlockVoterRewardsEnabled},
-	Event, RewardAmounts,
+	pallet::{ArgonsPerBlock, BlockRewardsByCohort, BlockVoterRewardsEnabled, MiningPools},
+	Error, Event, HoldReason, MiningPoolMember, RewardAmounts,
 };
 use argon_primitives::{
 	block_seal::{BlockPayout, BlockRewardType},
@@ -495,3 +495,152 @@ fn it_should_dampen_block_rewards() {
 		assert_eq!(ArgonsPerBlock::<Test>::get(), 25_000);
 	});
 }
+
+#[test]
+fn it_splits_compute_rewards_between_mining_pool_members() {
+	BlockSealer::set(BlockSealerInfo {
+		block_author_account_id: 1,
+		block_vote_rewards_account: None,
+		block_seal_authority: None,
+	});
+	NotebooksInBlock::set(vec![(1, 1, 1)]);
+	NotebookTick::set(1);
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let member = |account_id, shares| MiningPoolMember { account_id, shares };
+		assert_noop!(
+			BlockRewards::set_mining_pool_shares(
+				RuntimeOrigin::signed(1),
+				BoundedVec::truncate_from(vec![member(10, 1)])
+			),
+			Error::<Test>::InsufficientMiningPoolDeposit
+		);
+		let _ = Balances::mint_into(&1, MiningPoolDeposit::get() + ExistentialDeposit::get());
+		assert_noop!(
+			BlockRewards::set_mining_pool_shares(
+				RuntimeOrigin::signed(1),
+				BoundedVec::truncate_from(vec![member(10, 0)])
+			),
+			Error::<Test>::NoMiningPoolShares
+		);
+		assert_noop!(
+			BlockRewards::set_mining_pool_shares(
+				RuntimeOrigin::signed(1),
+				BoundedVec::truncate_from(vec![member(10, 1), member(10, 2)])
+			),
+			Error::<Test>::DuplicateMiningPoolMember
+		);
+		assert_ok!(BlockRewards::set_mining_pool_shares(
+			RuntimeOrigin::signed(1),
+			BoundedVec::truncate_from(vec![member(10, 2), member(11, 1)])
+		));
+		System::assert_last_event(
+			Event::MiningPoolSharesSet { pool_account: 1, members: 2, total_shares: 3 }.into(),
+		);
+		assert_eq!(
+			Balances::balance_on_hold(&HoldReason::MiningPoolDeposit.into(), &1),
+			MiningPoolDeposit::get()
+		);
+		// updating the shares doesn't take another deposit
+		assert_ok!(BlockRewards::set_mining_pool_shares(
+			RuntimeOrigin::signed(1),
+			BoundedVec::truncate_from(vec![member(10, 2), member(11, 1)])
+		));
+		assert_eq!(
+			Balances::balance_on_hold(&HoldReason::MiningPoolDeposit.into(), &1),
+			MiningPoolDeposit::get()
+		);
+
+		BlockRewards::on_initialize(1);
+		BlockRewards::on_finalize(1);
+		System::assert_last_event(
+			Event::RewardCreated {
+				rewards: vec![
+					BlockPayout {
+						account_id: 10,
+						ownership: 2500,
+						argons: 2500,
+						block_seal_authority: None,
+						reward_type: BlockRewardType::MiningPoolShare,
+					},
+					BlockPayout {
+						account_id: 11,
+						ownership: 1250,
+						argons: 1250,
+						block_seal_authority: None,
+						reward_type: BlockRewardType::MiningPoolShare,
+					},
+				],
+			}
+			.into(),
+		);
+		assert_eq!(Balances::balance(&1), ExistentialDeposit::get());
+		assert_eq!(Balances::balance(&10), 2500);
+		assert_eq!(Ownership::balance(&11), 1250);
+
+		// vote blocks are not split
+		IsBlockVoteSeal::set(true);
+		System::set_block_number(2);
+		BlockRewards::on_initialize(2);
+		BlockRewards::on_finalize(2);
+		assert_eq!(Balances::balance(&1), ExistentialDeposit::get() + 3750);
+		IsBlockVoteSeal::set(false);
+
+		assert_ok!(BlockRewards::close_mining_pool(RuntimeOrigin::signed(1)));
+		assert!(MiningPools::<Test>::get(1).is_none());
+		assert_eq!(Balances::balance_on_hold(&HoldReason::MiningPoolDeposit.into(), &1), 0);
+		assert_eq!(
+			Balances::balance(&1),
+			ExistentialDeposit::get() + 3750 + MiningPoolDeposit::get()
+		);
+		assert_noop!(
+			BlockRewards::close_mining_pool(RuntimeOrigin::signed(1)),
+			Error::<Test>::MiningPoolNotFound
+		);
+	});
+}
+
+#[test]
+fn it_keeps_mining_pool_rounding_with_the_pool() {
+	new_test_ext().execute_with(|| {
+		let payouts = BlockRewards::split_pool_payout(
+			BlockPayout {
+				account_id: 1,
+				ownership: 100,
+				argons: 1000,
+				block_seal_authority: None,
+				reward_type: BlockRewardType::Miner,
+			},
+			&[
+				MiningPoolMember { account_id: 10, shares: 3 },
+				MiningPoolMember { account_id: 11, shares: 4 },
+			],
+		);
+		let amounts = payouts
+			.iter()
+			.map(|x| (x.account_id, x.argons, x.ownership))
+			.collect::<Vec<_>>();
+		assert_eq!(amounts, vec![(1, 1, 1), (10, 428, 42), (11, 571, 57)]);
+		assert_eq!(payouts[0].reward_type, BlockRewardType::Miner);
+
+		// an even split leaves nothing for the pool
+		let payouts = BlockRewards::split_pool_payout(
+			BlockPayout {
+				account_id: 1,
+				ownership: 70,
+				argons: 700,
+				block_seal_authority: None,
+				reward_type: BlockRewardType::Miner,
+			},
+			&[
+				MiningPoolMember { account_id: 10, shares: 3 },
+				MiningPoolMember { account_id: 11, shares: 4 },
+			],
+		);
+		let amounts = payouts
+			.iter()
+			.map(|x| (x.account_id, x.argons, x.ownership))
+			.collect::<Vec<_>>();
+		assert_eq!(amounts, vec![(10, 300, 30), (11, 400, 40)]);
+	});
+}
@@ -12,9 +12,12 @@ use pallet_prelude::*;
 pub trait WeightInfo {
 	fn set_block_rewards_paused() -> Weight;
 	fn set_block_voter_rewards_enabled() -> Weight;
+	fn set_mining_pool_shares(m: u32) -> Weight;
+	fn close_mining_pool() -> Weight;
 	fn on_initialize_with_rewards() -> Weight {
 		Weight::zero()
 	}
+	fn split_mining_pool_payout(m: u32) -> Weight;
 }
 
 type BlockSealerProviderWeights<T> =
@@ -89,6 +92,14 @@ where
 		Base::set_block_voter_rewards_enabled()
 	}
 
+	fn set_mining_pool_shares(m: u32) -> Weight {
+		Base::set_mining_pool_shares(m)
+	}
+
+	fn close_mining_pool() -> Weight {
+		Base::close_mining_pool()
+	}
+
 	fn on_initialize_with_rewards() -> Weight {
 		Base::on_initialize_with_rewards()
 			.saturating_add(BlockSealerWeight::is_block_vote_seal())
@@ -100,6 +111,10 @@ where
 			.saturating_add(NotebookProviderWeight::notebooks_in_block())
 			.saturating_add(TickProviderWeight::current_tick())
 	}
+
+	fn split_mining_pool_payout(m: u32) -> Weight {
+		Base::split_mining_pool_payout(m)
+	}
 }
 
 // For backwards compatibility and tests.
@@ -112,7 +127,19 @@ impl WeightInfo for () {
 		Weight::zero()
 	}
 
+	fn set_mining_pool_shares(_m: u32) -> Weight {
+		Weight::zero()
+	}
+
+	fn close_mining_pool() -> Weight {
+		Weight::zero()
+	}
+
 	fn on_initialize_with_rewards() -> Weight {
 		Weight::zero()
 	}
+
+	fn split_mining_pool_payout(_m: u32) -> Weight {
+		Weight::zero()
+	}
 }
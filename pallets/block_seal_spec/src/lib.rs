@@ -422,28 +422,13 @@ pub mod pallet {
 			min_vote_minimum: VoteMinimum,
 			max_vote_minimum: VoteMinimum,
 		) -> VoteMinimum {
-			// Calculate the adjusted time span.
-			let mut adjusted_votes = match actual_period_votes {
-				x if x < target_period_votes / MAX_ADJUST_DOWN =>
-					target_period_votes / MAX_ADJUST_DOWN,
-				x if x > target_period_votes * MAX_ADJUST_UP => target_period_votes * MAX_ADJUST_UP,
-				x => x,
-			};
-			// don't divide by 0
-			if adjusted_votes == 0 {
-				adjusted_votes = 1;
-			}
-
-			// Compute the next block_seal_spec based on the current
-			// block_seal_spec and the ratio of target votes to adjusted votes.
-			let mut next_vote_minimum: u128 = U256::from(current_vote_minimum)
-				.saturating_mul(adjusted_votes.into())
-				.checked_div(target_period_votes.into())
-				.unwrap_or(0.into())
-				.unique_saturated_into();
-
-			next_vote_minimum = next_vote_minimum.clamp(min_vote_minimum, max_vote_minimum);
-			next_vote_minimum
+			argon_primitives::calculate_next_vote_minimum(
+				current_vote_minimum,
+				target_period_votes,
+				actual_period_votes,
+				min_vote_minimum,
+				max_vote_minimum,
+			)
 		}
 
 		pub fn calculate_next_difficulty(
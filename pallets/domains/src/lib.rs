@@ -1,7 +1,11 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 extern crate alloc;
 
-use argon_primitives::{notebook::NotebookHeader, NotebookEventHandler};
+use argon_primitives::{
+	notebook::NotebookHeader, Domain, DomainTopLevel, DomainTopLevelId, LocalchainRefundProvider,
+	NotebookEventHandler, BUILT_IN_DOMAIN_TOP_LEVELS, DOMAIN_LEASE_COST,
+	MAX_DOMAIN_TOP_LEVEL_LENGTH,
+};
 pub use pallet::*;
 use pallet_prelude::*;
 pub use weights::*;
@@ -18,7 +22,23 @@ pub mod weights;
 /// updated by the domain owner and are used to track the latest version of a domain and the
 /// host addresses where it can be accessed.
 ///
-/// If more than one domain registration is received in a tick, they are canceled out.
+/// If more than one account registers a domain in the same tick, the registration is contested
+/// and an ascending auction opens for `DomainAuctionTicks`. Every `LeaseDomain` note for the
+/// domain while the auction is open adds the lease cost to the account's bid (paid as localchain
+/// tax). The highest bid is registered when the auction closes and the other bids are refunded
+/// to their localchain through the notary they were notarized by. Leases notarized at or before
+/// the tick an auction settled can't contest its winner and are refunded.
+///
+/// Leases from the current owner of a registered domain are renewals and extend the expiration
+/// without changing the registration, so they can't be contested by a competing lease. Owners can
+/// transfer a domain to another account on the mainchain.
+///
+/// Top level domains are identified by a one byte id. The built in [`DomainTopLevel`] variants use
+/// their encoded index, and governance (root) can add new top level domains, which are assigned
+/// the following ids. Domains under an added top level domain embed its id in their hash (see
+/// [`Domain::hash_with_top_level_id`]), so leases under an unknown or retired top level domain are
+/// rejected and refunded. Built in top level domains can't be retired. Domains that were
+/// registered before their top level domain was retired stay registered until they expire.
 #[frame_support::pallet]
 pub mod pallet {
 	use argon_primitives::{DomainHash, ZoneRecord, MAX_DOMAINS_PER_NOTEBOOK, MAX_NOTARIES};
@@ -27,6 +47,7 @@ pub mod pallet {
 	use super::*;
 
 	type DomainRegistrationOf<T> = DomainRegistration<<T as frame_system::Config>::AccountId>;
+	type DomainAuctionOf<T> = DomainAuction<<T as frame_system::Config>::AccountId>;
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
@@ -41,6 +62,12 @@ pub mod pallet {
 		type NotebookTick: Get<Tick>;
 
 		type HistoricalPaymentAddressTicksToKeep: Get<Tick>;
+
+		/// How many ticks an auction for a contested domain stays open
+		type DomainAuctionTicks: Get<Tick>;
+
+		/// Refunds losing auction bids to their localchain
+		type LocalchainRefunds: LocalchainRefundProvider<Self::AccountId, Balance>;
 	}
 
 	#[pallet::storage]
@@ -59,6 +86,68 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The expiration of domains that have been renewed. Domains without an entry expire
+	/// `DomainExpirationTicks` after they were registered.
+	#[pallet::storage]
+	pub type RenewedDomainExpirations<T: Config> =
+		StorageMap<_, Blake2_128Concat, DomainHash, Tick, OptionQuery>;
+
+	/// The notary that notarized the lease for a domain registration (used to refund the lease if
+	/// the registration is contested in the same tick)
+	#[pallet::storage]
+	pub type RegistrationNotaries<T: Config> =
+		StorageMap<_, Blake2_128Concat, DomainHash, NotaryId, OptionQuery>;
+
+	/// Auctions for contested domains
+	#[pallet::storage]
+	pub type DomainAuctions<T: Config> =
+		StorageMap<_, Blake2_128Concat, DomainHash, DomainAuctionOf<T>, OptionQuery>;
+
+	/// The tick each auctioned domain's auction settled at. The winner is registered at this
+	/// tick, but leases notarized at or before it were part of the auction and can't contest the
+	/// winner.
+	#[pallet::storage]
+	pub type AuctionSettledAtTick<T: Config> =
+		StorageMap<_, Blake2_128Concat, DomainHash, Tick, OptionQuery>;
+
+	/// Auctions to settle by tick
+	#[pallet::storage]
+	pub type AuctionsClosingAtTick<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		Tick,
+		BoundedVec<DomainHash, ConstU32<{ MAX_DOMAINS_PER_NOTEBOOK * MAX_NOTARIES }>>,
+		ValueQuery,
+	>;
+
+	/// The next tick to settle closing auctions at. Settlement catches up on ticks that didn't
+	/// have a block (a bounded number of ticks per block).
+	#[pallet::storage]
+	pub type NextAuctionSettlementTick<T: Config> = StorageValue<_, Tick, OptionQuery>;
+
+	/// Top level domains added by governance (the built in top level domains are not stored)
+	#[pallet::storage]
+	pub type TopLevelDomains<T: Config> =
+		StorageMap<_, Twox64Concat, DomainTopLevelId, TopLevelDomainName, OptionQuery>;
+
+	#[pallet::storage]
+	pub type TopLevelDomainIdsByName<T: Config> =
+		StorageMap<_, Blake2_128Concat, TopLevelDomainName, DomainTopLevelId, OptionQuery>;
+
+	/// Top level domains that can no longer be leased, and the tick they were retired at
+	#[pallet::storage]
+	pub type RetiredTopLevelDomains<T: Config> =
+		StorageMap<_, Twox64Concat, DomainTopLevelId, Tick, OptionQuery>;
+
+	#[pallet::type_value]
+	pub fn DefaultNextTopLevelDomainId() -> DomainTopLevelId {
+		BUILT_IN_DOMAIN_TOP_LEVELS
+	}
+
+	#[pallet::storage]
+	pub type NextTopLevelDomainId<T: Config> =
+		StorageValue<_, DomainTopLevelId, ValueQuery, DefaultNextTopLevelDomainId>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -66,8 +155,10 @@ pub mod pallet {
 		ZoneRecordUpdated { domain_hash: DomainHash, zone_record: ZoneRecord<T::AccountId> },
 		/// A domain was registered
 		DomainRegistered { domain_hash: DomainHash, registration: DomainRegistration<T::AccountId> },
-		/// A domain was registered
+		/// A domain was renewed
 		DomainRenewed { domain_hash: DomainHash },
+		/// A domain was transferred to a new owner
+		DomainTransferred { domain_hash: DomainHash, from: T::AccountId, to: T::AccountId },
 		/// A domain was expired
 		DomainExpired { domain_hash: DomainHash },
 		/// A domain registration was canceled due to a conflicting registration in the same
@@ -76,6 +167,24 @@ pub mod pallet {
 			domain_hash: DomainHash,
 			registration: DomainRegistration<T::AccountId>,
 		},
+		/// Competing registrations in a tick opened an auction for a domain
+		DomainAuctionOpened { domain_hash: DomainHash, closes_at_tick: Tick },
+		/// A bid was added to a domain auction
+		DomainAuctionBid { domain_hash: DomainHash, account_id: T::AccountId, total_bid: Balance },
+		/// A domain auction closed and the winner was registered
+		DomainAuctionSettled { domain_hash: DomainHash, winner: T::AccountId, winning_bid: Balance },
+		/// A losing bid (or a lease that wasn't registered) could not be refunded
+		DomainAuctionRefundError {
+			domain_hash: DomainHash,
+			account_id: T::AccountId,
+			notary_id: NotaryId,
+			amount: Balance,
+			error: DispatchError,
+		},
+		/// A top level domain was added
+		TopLevelDomainAdded { top_level_id: DomainTopLevelId, name: TopLevelDomainName },
+		/// A top level domain was retired
+		TopLevelDomainRetired { top_level_id: DomainTopLevelId },
 		/// A domain registration failed due to an error
 		DomainRegistrationError {
 			domain_hash: DomainHash,
@@ -96,6 +205,36 @@ pub mod pallet {
 		FailedToAddExpiringDomain,
 		/// Error decoding account from notary
 		AccountDecodingError,
+		/// The domain is already owned by this account
+		AlreadyDomainOwner,
+		/// Too many bids were placed in a domain auction
+		TooManyAuctionBids,
+		/// Failed to schedule the close of a domain auction
+		FailedToScheduleAuction,
+		/// Top level domain names must be lowercase letters, digits and dashes
+		InvalidTopLevelDomain,
+		/// A top level domain with this name already exists
+		TopLevelDomainAlreadyExists,
+		/// No more top level domain ids are available
+		TooManyTopLevelDomains,
+		/// The top level domain doesn't exist
+		UnknownTopLevelDomain,
+		/// The top level domain is already retired
+		TopLevelDomainAlreadyRetired,
+		/// The registrant leased the domain more than once in the tick it was registered
+		DuplicateDomainLease,
+		/// Built in top level domains can't be retired
+		CannotRetireBuiltInTopLevelDomain,
+		/// The domain is under a top level domain that is retired or doesn't exist
+		InactiveTopLevelDomain,
+		/// The lease is from a tick the domain's auction already settled
+		DomainAuctionClosed,
+		/// The registration can't be contested since the notary of its lease isn't known
+		DomainNotContestable,
+		/// An auction couldn't be opened for a contested registration
+		DomainAuctionNotOpened,
+		/// A renewal lease doesn't move the domain's expiration past its current one
+		RenewalDoesNotExtendExpiration,
 	}
 
 	#[pallet::hooks]
@@ -107,10 +246,36 @@ pub mod pallet {
 			for domain_hash in expiring {
 				RegisteredDomains::<T>::remove(domain_hash);
 				ZoneRecordsByDomain::<T>::remove(domain_hash);
+				RenewedDomainExpirations::<T>::remove(domain_hash);
+				RegistrationNotaries::<T>::remove(domain_hash);
+				AuctionSettledAtTick::<T>::remove(domain_hash);
 				Self::deposit_event(Event::DomainExpired { domain_hash });
 			}
 
-			T::DbWeight::get().reads_writes(2 + (entries * 2), 2 + (entries * 2))
+			let mut settle_tick = NextAuctionSettlementTick::<T>::get().unwrap_or(tick);
+			let mut settled_ticks = 0u64;
+			let mut auctions = 0u64;
+			let mut refunds = 0u64;
+			while settle_tick <= tick && settled_ticks < MAX_AUCTION_SETTLEMENT_TICKS_PER_BLOCK {
+				for domain_hash in AuctionsClosingAtTick::<T>::take(settle_tick) {
+					auctions += 1;
+					refunds += Self::settle_auction(domain_hash, tick) as u64;
+				}
+				settle_tick.saturating_inc();
+				settled_ticks += 1;
+			}
+			NextAuctionSettlementTick::<T>::put(settle_tick);
+
+			let refund_weight = <T::LocalchainRefunds as LocalchainRefundProvider<
+				T::AccountId,
+				Balance,
+			>>::Weights::refund_to_localchain();
+			T::DbWeight::get()
+				.reads_writes(
+					4 + (entries * 2) + settled_ticks + (auctions * 2),
+					4 + (entries * 4) + settled_ticks + (auctions * 5),
+				)
+				.saturating_add(refund_weight.saturating_mul(refunds))
 		}
 	}
 
@@ -135,13 +300,284 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Transfer a domain to a new owner. The zone record is cleared since it points payments
+		/// at the previous owner. The expiration is unchanged. The notary of the registering lease
+		/// is cleared too since the new owner didn't pay it, so a transferred registration can't
+		/// be contested.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::transfer_domain())]
+		pub fn transfer_domain(
+			origin: OriginFor<T>,
+			domain_hash: DomainHash,
+			new_owner: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			RegisteredDomains::<T>::try_mutate(domain_hash, |registration| {
+				let Some(registration) = registration else {
+					return Err(Error::<T>::DomainNotRegistered);
+				};
+				ensure!(registration.account_id == who, Error::<T>::NotDomainOwner);
+				ensure!(registration.account_id != new_owner, Error::<T>::AlreadyDomainOwner);
+				registration.account_id = new_owner.clone();
+				Ok(())
+			})?;
+
+			ZoneRecordsByDomain::<T>::remove(domain_hash);
+			RegistrationNotaries::<T>::remove(domain_hash);
+			Self::deposit_event(Event::DomainTransferred { domain_hash, from: who, to: new_owner });
+
+			Ok(())
+		}
+
+		/// Add a top level domain that domains can be leased under. It's assigned the next
+		/// available id.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::add_top_level_domain())]
+		pub fn add_top_level_domain(
+			origin: OriginFor<T>,
+			name: TopLevelDomainName,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(DomainTopLevel::is_valid_name(&name), Error::<T>::InvalidTopLevelDomain);
+			ensure!(
+				Self::top_level_domain_id(&name).is_none(),
+				Error::<T>::TopLevelDomainAlreadyExists
+			);
+
+			let top_level_id = NextTopLevelDomainId::<T>::get();
+			let next_id = top_level_id.checked_add(1).ok_or(Error::<T>::TooManyTopLevelDomains)?;
+			NextTopLevelDomainId::<T>::put(next_id);
+			TopLevelDomains::<T>::insert(top_level_id, &name);
+			TopLevelDomainIdsByName::<T>::insert(&name, top_level_id);
+			Self::deposit_event(Event::TopLevelDomainAdded { top_level_id, name });
+
+			Ok(())
+		}
+
+		/// Retire a top level domain so no new domains are leased under it. Existing
+		/// registrations stay in place until they expire.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::retire_top_level_domain())]
+		pub fn retire_top_level_domain(
+			origin: OriginFor<T>,
+			top_level_id: DomainTopLevelId,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(
+				top_level_id >= BUILT_IN_DOMAIN_TOP_LEVELS,
+				Error::<T>::CannotRetireBuiltInTopLevelDomain
+			);
+			ensure!(
+				TopLevelDomains::<T>::contains_key(top_level_id),
+				Error::<T>::UnknownTopLevelDomain
+			);
+			ensure!(
+				!RetiredTopLevelDomains::<T>::contains_key(top_level_id),
+				Error::<T>::TopLevelDomainAlreadyRetired
+			);
+			RetiredTopLevelDomains::<T>::insert(top_level_id, T::NotebookTick::get());
+			Self::deposit_event(Event::TopLevelDomainRetired { top_level_id });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The id of a built in or added top level domain (including retired ones)
+		pub fn top_level_domain_id(name: &[u8]) -> Option<DomainTopLevelId> {
+			if let Some(built_in) =
+				core::str::from_utf8(name).ok().and_then(DomainTopLevel::from_name)
+			{
+				return Some(built_in.id());
+			}
+			let name = TopLevelDomainName::try_from(name.to_vec()).ok()?;
+			TopLevelDomainIdsByName::<T>::get(name)
+		}
+
+		/// Can new domains be leased under this top level domain
+		pub fn is_active_top_level_domain(top_level_id: DomainTopLevelId) -> bool {
+			(top_level_id < BUILT_IN_DOMAIN_TOP_LEVELS ||
+				TopLevelDomains::<T>::contains_key(top_level_id)) &&
+				!RetiredTopLevelDomains::<T>::contains_key(top_level_id)
+		}
+
+		/// The tick at which a registered domain expires
+		pub fn expiration_tick(
+			domain_hash: &DomainHash,
+			registration: &DomainRegistrationOf<T>,
+		) -> Tick {
+			RenewedDomainExpirations::<T>::get(domain_hash).unwrap_or(
+				registration.registered_at_tick.saturating_add(T::DomainExpirationTicks::get()),
+			)
+		}
+
+		fn open_auction(
+			domain_hash: &DomainHash,
+			registrant: (T::AccountId, NotaryId),
+			challenger: (T::AccountId, NotaryId),
+			tick: Tick,
+		) -> Result<(), Error<T>> {
+			// a notebook for an old tick can't schedule a close the settlement cursor has passed
+			let closes_at_tick = tick
+				.saturating_add(T::DomainAuctionTicks::get())
+				.max(NextAuctionSettlementTick::<T>::get().unwrap_or_default());
+			let bid = |(account_id, notary_id): (T::AccountId, NotaryId)| AuctionBid {
+				account_id,
+				notary_id,
+				amount: DOMAIN_LEASE_COST,
+				last_bid_tick: tick,
+			};
+			let auction = DomainAuction {
+				opened_at_tick: tick,
+				closes_at_tick,
+				bids: BoundedVec::truncate_from(vec![bid(registrant), bid(challenger)]),
+			};
+			<AuctionsClosingAtTick<T>>::try_mutate(closes_at_tick, |domains| {
+				domains.try_push(*domain_hash)
+			})
+			.map_err(|_| Error::<T>::FailedToScheduleAuction)?;
+			<DomainAuctions<T>>::insert(domain_hash, auction);
+			Self::deposit_event(Event::DomainAuctionOpened {
+				domain_hash: *domain_hash,
+				closes_at_tick,
+			});
+			Ok(())
+		}
+
+		/// Each lease adds the lease cost to the account's bid through the notebook's notary
+		fn add_bid(
+			auction: &mut DomainAuctionOf<T>,
+			account_id: &T::AccountId,
+			header: &NotebookHeader,
+		) -> Result<(), Error<T>> {
+			if let Some(bid) = auction
+				.bids
+				.iter_mut()
+				.find(|b| b.account_id == *account_id && b.notary_id == header.notary_id)
+			{
+				bid.amount.saturating_accrue(DOMAIN_LEASE_COST);
+				bid.last_bid_tick = bid.last_bid_tick.max(header.tick);
+				return Ok(());
+			}
+			auction
+				.bids
+				.try_push(AuctionBid {
+					account_id: account_id.clone(),
+					notary_id: header.notary_id,
+					amount: DOMAIN_LEASE_COST,
+					last_bid_tick: header.tick,
+				})
+				.map_err(|_| Error::<T>::TooManyAuctionBids)
+		}
+
+		/// Registers the winner of an auction and refunds the other bids. Returns the number of
+		/// refunds made.
+		fn settle_auction(domain_hash: DomainHash, tick: Tick) -> u32 {
+			let Some(auction) = <DomainAuctions<T>>::take(domain_hash) else {
+				return 0;
+			};
+			let Some((winner, winning_bid)) = auction.winner() else {
+				return 0;
+			};
+
+			let winner_notary = auction
+				.bids
+				.iter()
+				.find(|b| b.account_id == winner)
+				.map(|b| b.notary_id)
+				.unwrap_or_default();
+			let registration =
+				DomainRegistration { account_id: winner.clone(), registered_at_tick: tick };
+			let expiration = tick.saturating_add(T::DomainExpirationTicks::get());
+			let is_registered = <ExpiringDomainsByBlock<T>>::mutate(expiration, |domains| {
+				domains.try_push(domain_hash)
+			})
+			.is_ok();
+			if is_registered {
+				<RegisteredDomains<T>>::insert(domain_hash, registration.clone());
+				<RegistrationNotaries<T>>::insert(domain_hash, winner_notary);
+				<AuctionSettledAtTick<T>>::insert(domain_hash, tick);
+				Self::deposit_event(Event::DomainAuctionSettled {
+					domain_hash,
+					winner: winner.clone(),
+					winning_bid,
+				});
+				Self::deposit_event(Event::DomainRegistered { domain_hash, registration });
+			} else {
+				Self::deposit_event(Event::DomainRegistrationCanceled {
+					domain_hash,
+					registration,
+				});
+			}
+
+			// a canceled registration refunds the winner too
+			let mut refunds = 0;
+			for bid in auction.bids {
+				if is_registered && bid.account_id == winner {
+					continue;
+				}
+				refunds += 1;
+				Self::refund_lease(domain_hash, bid.notary_id, bid.account_id, bid.amount);
+			}
+			refunds
+		}
+
+		fn refund_lease(
+			domain_hash: DomainHash,
+			notary_id: NotaryId,
+			account_id: T::AccountId,
+			amount: Balance,
+		) {
+			if let Err(error) =
+				T::LocalchainRefunds::refund_to_localchain(notary_id, &account_id, amount)
+			{
+				Self::deposit_event(Event::DomainAuctionRefundError {
+					domain_hash,
+					account_id,
+					notary_id,
+					amount,
+					error,
+				});
+			}
+		}
+
+		/// Rejects a lease that can't register or renew a domain and refunds it
+		fn reject_lease(
+			domain_hash: DomainHash,
+			header: &NotebookHeader,
+			account: &AccountId32,
+			account_id: T::AccountId,
+			error: Error<T>,
+		) {
+			Self::deposit_event(Event::DomainRegistrationError {
+				domain_hash,
+				account_id: account.clone(),
+				error: error.into(),
+			});
+			Self::refund_lease(domain_hash, header.notary_id, account_id, DOMAIN_LEASE_COST);
+		}
+
+		fn move_expiration(
+			domain_hash: &DomainHash,
+			from_tick: Tick,
+			to_tick: Tick,
+		) -> Result<(), Error<T>> {
+			<ExpiringDomainsByBlock<T>>::try_mutate(to_tick, |domains| {
+				domains.try_push(*domain_hash)
+			})
+			.map_err(|_| Error::<T>::FailedToAddExpiringDomain)?;
+			<ExpiringDomainsByBlock<T>>::mutate(from_tick, |domains| {
+				domains.retain(|d| d != domain_hash);
+			});
+			Ok(())
+		}
 	}
 
 	impl<T: Config> NotebookEventHandler for Pallet<T> {
 		fn notebook_submitted(header: &NotebookHeader) {
 			let expiration_ticks = T::DomainExpirationTicks::get();
 			for (domain_hash, account) in header.domains.iter() {
-				let mut is_renewal = false;
 				let account_id = match T::AccountId::decode(&mut account.encode().as_slice()) {
 					Ok(account_id) => account_id,
 					Err(_) => {
@@ -154,37 +590,150 @@ pub mod pallet {
 					},
 				};
 
-				// if previous registration is at same tick, need to cancel it out
-				if let Some(registration) = <RegisteredDomains<T>>::get(domain_hash) {
-					let original_expiration = registration.registered_at_tick + expiration_ticks;
-					let remove_expired = || {
-						<ExpiringDomainsByBlock<T>>::mutate(original_expiration, |domains| {
-							domains.retain(|d| d != domain_hash);
+				if let Some(top_level_id) = Domain::added_top_level_id(domain_hash) &&
+					!Self::is_active_top_level_domain(top_level_id)
+				{
+					Self::reject_lease(
+						*domain_hash,
+						header,
+						account,
+						account_id,
+						Error::<T>::InactiveTopLevelDomain,
+					);
+					continue;
+				}
+
+				if let Some(mut auction) = <DomainAuctions<T>>::get(domain_hash) {
+					if let Err(error) = Self::add_bid(&mut auction, &account_id, header) {
+						Self::deposit_event(Event::DomainRegistrationError {
+							domain_hash: *domain_hash,
+							account_id: account.clone(),
+							error: error.into(),
 						});
-					};
+						continue;
+					}
+					let total_bid = auction.total_bid(&account_id);
+					<DomainAuctions<T>>::insert(domain_hash, auction);
+					Self::deposit_event(Event::DomainAuctionBid {
+						domain_hash: *domain_hash,
+						account_id,
+						total_bid,
+					});
+					continue;
+				}
+
+				// if previous registration is at same tick, the registration is contested
+				if let Some(registration) = <RegisteredDomains<T>>::get(domain_hash) {
+					let original_expiration = Self::expiration_tick(domain_hash, &registration);
 
 					if registration.registered_at_tick >= header.tick {
+						// a duplicate lease from the registrant isn't a competing registration
+						if registration.account_id == account_id {
+							Self::reject_lease(
+								*domain_hash,
+								header,
+								account,
+								account_id,
+								Error::<T>::DuplicateDomainLease,
+							);
+							continue;
+						}
+						// the winner of an auction is registered when it settles, so leases up to
+						// then were already bid (or arrived too late to be)
+						if <AuctionSettledAtTick<T>>::get(domain_hash)
+							.is_some_and(|settled_at_tick| header.tick <= settled_at_tick)
+						{
+							Self::reject_lease(
+								*domain_hash,
+								header,
+								account,
+								account_id,
+								Error::<T>::DomainAuctionClosed,
+							);
+							continue;
+						}
+						let Some(registrant_notary) = <RegistrationNotaries<T>>::get(domain_hash)
+						else {
+							Self::reject_lease(
+								*domain_hash,
+								header,
+								account,
+								account_id,
+								Error::<T>::DomainNotContestable,
+							);
+							continue;
+						};
 						<RegisteredDomains<T>>::remove(domain_hash);
-						remove_expired();
-						Self::deposit_event(Event::DomainRegistrationCanceled {
-							domain_hash: *domain_hash,
-							registration,
+						<RenewedDomainExpirations<T>>::remove(domain_hash);
+						<RegistrationNotaries<T>>::remove(domain_hash);
+						<ExpiringDomainsByBlock<T>>::mutate(original_expiration, |domains| {
+							domains.retain(|d| d != domain_hash);
 						});
+						if Self::open_auction(
+							domain_hash,
+							(registration.account_id.clone(), registrant_notary),
+							(account_id.clone(), header.notary_id),
+							header.tick,
+						)
+						.is_err()
+						{
+							// neither lease registers the domain, so both are refunded
+							Self::deposit_event(Event::DomainRegistrationCanceled {
+								domain_hash: *domain_hash,
+								registration: registration.clone(),
+							});
+							Self::refund_lease(
+								*domain_hash,
+								registrant_notary,
+								registration.account_id,
+								DOMAIN_LEASE_COST,
+							);
+							Self::reject_lease(
+								*domain_hash,
+								header,
+								account,
+								account_id,
+								Error::<T>::DomainAuctionNotOpened,
+							);
+						}
 						continue;
 					}
 
-					if registration.account_id == account_id {
-						remove_expired();
-						is_renewal = true;
-					} else {
-						// don't process if account is different
+					// don't process if account is different
+					if registration.account_id != account_id {
+						continue;
+					}
+
+					// a renewal keeps the registration (so it can't be canceled out by a
+					// competing lease) and only moves the expiration
+					let expiration = header.tick.saturating_add(expiration_ticks);
+					if expiration <= original_expiration {
+						Self::reject_lease(
+							*domain_hash,
+							header,
+							account,
+							account_id,
+							Error::<T>::RenewalDoesNotExtendExpiration,
+						);
+						continue;
+					}
+					if let Err(error) =
+						Self::move_expiration(domain_hash, original_expiration, expiration)
+					{
+						Self::deposit_event(Event::DomainRegistrationError {
+							domain_hash: *domain_hash,
+							account_id: account.clone(),
+							error: error.into(),
+						});
 						continue;
 					}
+					<RenewedDomainExpirations<T>>::insert(domain_hash, expiration);
+					Self::deposit_event(Event::DomainRenewed { domain_hash: *domain_hash });
+					continue;
 				}
 
 				let registration =
 					DomainRegistration { account_id, registered_at_tick: header.tick };
-				<RegisteredDomains<T>>::insert(domain_hash, registration.clone());
 				if <ExpiringDomainsByBlock<T>>::mutate(header.tick + expiration_ticks, |domains| {
 					domains.try_push(*domain_hash)
 				})
@@ -197,15 +746,12 @@ pub mod pallet {
 					});
 					continue;
 				}
-
-				if is_renewal {
-					Self::deposit_event(Event::DomainRenewed { domain_hash: *domain_hash });
-				} else {
-					Self::deposit_event(Event::DomainRegistered {
-						domain_hash: *domain_hash,
-						registration,
-					});
-				}
+				<RegisteredDomains<T>>::insert(domain_hash, registration.clone());
+				<RegistrationNotaries<T>>::insert(domain_hash, header.notary_id);
+				Self::deposit_event(Event::DomainRegistered {
+					domain_hash: *domain_hash,
+					registration,
+				});
 			}
 		}
 	}
@@ -221,3 +767,71 @@ where
 	pub account_id: AccountId,
 	pub registered_at_tick: Tick,
 }
+
+#[derive(
+	Clone, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub struct AuctionBid<AccountId>
+where
+	AccountId: MaxEncodedLen,
+{
+	pub account_id: AccountId,
+	/// The notary the bid's leases were notarized by (where a losing bid is refunded)
+	pub notary_id: NotaryId,
+	pub amount: Balance,
+	pub last_bid_tick: Tick,
+}
+
+pub const MAX_AUCTION_BIDS: u32 = 100;
+
+/// The most ticks of closing auctions settled in one block
+pub const MAX_AUCTION_SETTLEMENT_TICKS_PER_BLOCK: u64 = 10;
+
+pub type TopLevelDomainName = BoundedVec<u8, ConstU32<MAX_DOMAIN_TOP_LEVEL_LENGTH>>;
+
+#[derive(
+	Clone, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub struct DomainAuction<AccountId>
+where
+	AccountId: MaxEncodedLen,
+{
+	pub opened_at_tick: Tick,
+	pub closes_at_tick: Tick,
+	/// Bids by account and notary. An account's bid is the total over its notaries.
+	pub bids: BoundedVec<AuctionBid<AccountId>, ConstU32<MAX_AUCTION_BIDS>>,
+}
+
+impl<AccountId: MaxEncodedLen + PartialEq + Clone> DomainAuction<AccountId> {
+	pub fn total_bid(&self, account_id: &AccountId) -> Balance {
+		self.bids.iter().filter(|b| b.account_id == *account_id).map(|b| b.amount).sum()
+	}
+
+	/// The account with the highest total bid. Ties go to the account that reached its total
+	/// first, and then to the earliest bidder.
+	pub fn winner(&self) -> Option<(AccountId, Balance)> {
+		let mut winner: Option<(AccountId, Balance, Tick)> = None;
+		for bid in &self.bids {
+			if winner.as_ref().is_some_and(|(a, _, _)| *a == bid.account_id) {
+				continue;
+			}
+			let total = self.total_bid(&bid.account_id);
+			let last_bid_tick = self
+				.bids
+				.iter()
+				.filter(|b| b.account_id == bid.account_id)
+				.map(|b| b.last_bid_tick)
+				.max()
+				.unwrap_or_default();
+			let is_better = match &winner {
+				None => true,
+				Some((_, best, best_tick)) =>
+					total > *best || (total == *best && last_bid_tick < *best_tick),
+			};
+			if is_better {
+				winner = Some((bid.account_id.clone(), total, last_bid_tick));
+			}
+		}
+		winner.map(|(account_id, total, _)| (account_id, total))
+	}
+}
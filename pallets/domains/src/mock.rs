@@ -1,6 +1,7 @@
 use pallet_prelude::*;
 
 use crate as pallet_domains;
+use argon_primitives::{LocalchainRefundProvider, TransferToLocalchainId};
 
 pub(crate) type Block = frame_system::mocking::MockBlock<Test>;
 use sp_runtime::AccountId32;
@@ -26,6 +27,22 @@ parameter_types! {
 	pub static DomainExpirationTicks :u32 = 1000;
 	pub static NotebookTick: Tick = 0;
 	pub static HistoricalPaymentAddressTicksToKeep: u32 = 100;
+	pub static DomainAuctionTicks: Tick = 10;
+	pub static Refunds: Vec<(NotaryId, AccountId32, Balance)> = vec![];
+}
+
+pub struct MockRefunds;
+impl LocalchainRefundProvider<AccountId32, Balance> for MockRefunds {
+	type Weights = ();
+
+	fn refund_to_localchain(
+		notary_id: NotaryId,
+		account_id: &AccountId32,
+		microgons: Balance,
+	) -> Result<TransferToLocalchainId, DispatchError> {
+		Refunds::mutate(|x| x.push((notary_id, account_id.clone(), microgons)));
+		Ok(Refunds::get().len() as TransferToLocalchainId)
+	}
 }
 
 impl pallet_domains::Config for Test {
@@ -33,6 +50,8 @@ impl pallet_domains::Config for Test {
 	type NotebookTick = NotebookTick;
 	type DomainExpirationTicks = DomainExpirationTicks;
 	type HistoricalPaymentAddressTicksToKeep = HistoricalPaymentAddressTicksToKeep;
+	type DomainAuctionTicks = DomainAuctionTicks;
+	type LocalchainRefunds = MockRefunds;
 }
 
 pub fn new_test_ext() -> TestState {
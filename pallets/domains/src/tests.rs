@@ -1,15 +1,20 @@
 use crate::{
 	mock::{Domain as DomainPallet, *},
-	pallet::{ExpiringDomainsByBlock, RegisteredDomains},
-	DomainRegistration, Error, Event,
+	pallet::{
+		AuctionSettledAtTick, AuctionsClosingAtTick, DomainAuctions, ExpiringDomainsByBlock,
+		RegisteredDomains, RegistrationNotaries, RenewedDomainExpirations, RetiredTopLevelDomains,
+		TopLevelDomains, ZoneRecordsByDomain,
+	},
+	AuctionBid, DomainAuction, DomainRegistration, Error, Event,
 };
 use argon_primitives::{
 	notebook::NotebookHeader, tick::Tick, AccountId, Domain, DomainHash, DomainTopLevel,
-	NotebookEventHandler, Semver, VersionHost, ZoneRecord,
+	NotebookEventHandler, Semver, VersionHost, ZoneRecord, BUILT_IN_DOMAIN_TOP_LEVELS,
+	DOMAIN_LEASE_COST,
 };
 use frame_support::{assert_err, assert_ok, traits::Hooks};
 use pallet_prelude::*;
-use sp_keyring::Sr25519Keyring::{Alice, Bob};
+use sp_keyring::Sr25519Keyring::{Alice, Bob, Charlie};
 use sp_runtime::{testing::H256, BoundedVec};
 use std::collections::BTreeMap;
 
@@ -37,7 +42,7 @@ fn it_can_register_domains() {
 	});
 }
 #[test]
-fn it_cancels_conflicting_domains() {
+fn it_auctions_conflicting_domains() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		let domain = Domain { top_level: DomainTopLevel::Analytics, name: "test".into() }.hash();
@@ -46,8 +51,221 @@ fn it_cancels_conflicting_domains() {
 			vec![(domain, Bob.to_account_id()), (domain, Alice.to_account_id())],
 		));
 		assert_eq!(RegisteredDomains::<Test>::get(domain), None);
+		assert_eq!(RegistrationNotaries::<Test>::get(domain), None);
 		assert_eq!(ExpiringDomainsByBlock::<Test>::get(1001).len(), 0);
+		assert_eq!(AuctionsClosingAtTick::<Test>::get(11).to_vec(), vec![domain]);
 		System::assert_last_event(
+			Event::DomainAuctionOpened { domain_hash: domain, closes_at_tick: 11 }.into(),
+		);
+		let auction = DomainAuctions::<Test>::get(domain).expect("auction opened");
+		assert_eq!(auction.total_bid(&Bob.to_account_id()), DOMAIN_LEASE_COST);
+		assert_eq!(auction.total_bid(&Alice.to_account_id()), DOMAIN_LEASE_COST);
+
+		// Bob raises through another notary
+		System::set_block_number(5);
+		let mut notebook =
+			create_notebook(5, vec![(domain, Bob.to_account_id()), (domain, Bob.to_account_id())]);
+		notebook.notary_id = 2;
+		DomainPallet::notebook_submitted(&notebook);
+		System::assert_last_event(
+			Event::DomainAuctionBid {
+				domain_hash: domain,
+				account_id: Bob.to_account_id(),
+				total_bid: 3 * DOMAIN_LEASE_COST,
+			}
+			.into(),
+		);
+		DomainPallet::notebook_submitted(&create_notebook(
+			6,
+			vec![(domain, Alice.to_account_id())],
+		));
+
+		System::set_block_number(11);
+		NotebookTick::set(11);
+		DomainPallet::on_initialize(11);
+		assert_eq!(DomainAuctions::<Test>::get(domain), None);
+		assert_eq!(
+			RegisteredDomains::<Test>::get(domain),
+			Some(DomainRegistration { account_id: Bob.to_account_id(), registered_at_tick: 11 })
+		);
+		assert_eq!(RegistrationNotaries::<Test>::get(domain), Some(1));
+		assert_eq!(ExpiringDomainsByBlock::<Test>::get(1011).to_vec(), vec![domain]);
+		System::assert_has_event(
+			Event::DomainAuctionSettled {
+				domain_hash: domain,
+				winner: Bob.to_account_id(),
+				winning_bid: 3 * DOMAIN_LEASE_COST,
+			}
+			.into(),
+		);
+		assert_eq!(Refunds::get(), vec![(1, Alice.to_account_id(), 2 * DOMAIN_LEASE_COST)]);
+	});
+}
+
+#[test]
+fn it_refunds_duplicate_leases_from_the_registrant() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let domain = Domain { top_level: DomainTopLevel::Analytics, name: "test".into() }.hash();
+		DomainPallet::notebook_submitted(&create_notebook(
+			1,
+			vec![(domain, Bob.to_account_id()), (domain, Bob.to_account_id())],
+		));
+		assert_eq!(
+			RegisteredDomains::<Test>::get(domain),
+			Some(DomainRegistration { account_id: Bob.to_account_id(), registered_at_tick: 1 })
+		);
+		assert_eq!(DomainAuctions::<Test>::get(domain), None);
+		System::assert_last_event(
+			Event::DomainRegistrationError {
+				domain_hash: domain,
+				account_id: Bob.to_account_id(),
+				error: Error::<Test>::DuplicateDomainLease.into(),
+			}
+			.into(),
+		);
+		assert_eq!(Refunds::get(), vec![(1, Bob.to_account_id(), DOMAIN_LEASE_COST)]);
+	});
+}
+
+#[test]
+fn it_settles_auctions_closing_at_ticks_without_a_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		NotebookTick::set(1);
+		DomainPallet::on_initialize(1);
+		let domain = Domain { top_level: DomainTopLevel::Analytics, name: "test".into() }.hash();
+		DomainPallet::notebook_submitted(&create_notebook(
+			1,
+			vec![(domain, Bob.to_account_id()), (domain, Alice.to_account_id())],
+		));
+		assert_eq!(AuctionsClosingAtTick::<Test>::get(11).to_vec(), vec![domain]);
+
+		// no block lands on the closing tick
+		System::set_block_number(2);
+		NotebookTick::set(13);
+		DomainPallet::on_initialize(2);
+		assert_eq!(DomainAuctions::<Test>::get(domain), None);
+		assert_eq!(AuctionsClosingAtTick::<Test>::get(11).len(), 0);
+		assert_eq!(
+			RegisteredDomains::<Test>::get(domain),
+			Some(DomainRegistration { account_id: Bob.to_account_id(), registered_at_tick: 13 })
+		);
+		assert_eq!(Refunds::get(), vec![(1, Alice.to_account_id(), DOMAIN_LEASE_COST)]);
+	});
+}
+
+#[test]
+fn it_refunds_the_auction_winner_if_registration_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let domain = Domain { top_level: DomainTopLevel::Analytics, name: "test".into() }.hash();
+		DomainPallet::notebook_submitted(&create_notebook(
+			1,
+			vec![(domain, Bob.to_account_id()), (domain, Alice.to_account_id())],
+		));
+		// fill the expirations of the settlement tick
+		ExpiringDomainsByBlock::<Test>::mutate(1011, |domains| {
+			while domains.try_push(H256::repeat_byte(1)).is_ok() {}
+		});
+
+		System::set_block_number(11);
+		NotebookTick::set(11);
+		DomainPallet::on_initialize(11);
+		assert_eq!(RegisteredDomains::<Test>::get(domain), None);
+		System::assert_has_event(
+			Event::DomainRegistrationCanceled {
+				domain_hash: domain,
+				registration: DomainRegistration {
+					account_id: Bob.to_account_id(),
+					registered_at_tick: 11,
+				},
+			}
+			.into(),
+		);
+		assert_eq!(
+			Refunds::get(),
+			vec![
+				(1, Bob.to_account_id(), DOMAIN_LEASE_COST),
+				(1, Alice.to_account_id(), DOMAIN_LEASE_COST)
+			]
+		);
+	});
+}
+
+#[test]
+fn it_keeps_the_auction_winner_against_leases_up_to_the_settlement() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let domain = Domain { top_level: DomainTopLevel::Analytics, name: "test".into() }.hash();
+		DomainPallet::notebook_submitted(&create_notebook(
+			1,
+			vec![(domain, Bob.to_account_id()), (domain, Alice.to_account_id())],
+		));
+		DomainPallet::notebook_submitted(&create_notebook(5, vec![(domain, Bob.to_account_id())]));
+
+		System::set_block_number(11);
+		NotebookTick::set(11);
+		DomainPallet::on_initialize(11);
+		let registered_to_bob =
+			DomainRegistration { account_id: Bob.to_account_id(), registered_at_tick: 11 };
+		assert_eq!(RegisteredDomains::<Test>::get(domain), Some(registered_to_bob.clone()));
+		assert_eq!(AuctionSettledAtTick::<Test>::get(domain), Some(11));
+		assert_eq!(Refunds::get(), vec![(1, Alice.to_account_id(), DOMAIN_LEASE_COST)]);
+
+		// leases in the settlement tick (or late ones from the auction) can't re-open it
+		for tick in [11, 10] {
+			let mut notebook = create_notebook(tick, vec![(domain, Alice.to_account_id())]);
+			notebook.notary_id = 2;
+			DomainPallet::notebook_submitted(&notebook);
+			System::assert_last_event(
+				Event::DomainRegistrationError {
+					domain_hash: domain,
+					account_id: Alice.to_account_id(),
+					error: Error::<Test>::DomainAuctionClosed.into(),
+				}
+				.into(),
+			);
+		}
+		assert_eq!(RegisteredDomains::<Test>::get(domain), Some(registered_to_bob));
+		assert_eq!(DomainAuctions::<Test>::get(domain), None);
+		assert_eq!(ExpiringDomainsByBlock::<Test>::get(1011).to_vec(), vec![domain]);
+		assert_eq!(
+			Refunds::get(),
+			vec![
+				(1, Alice.to_account_id(), DOMAIN_LEASE_COST),
+				(2, Alice.to_account_id(), DOMAIN_LEASE_COST),
+				(2, Alice.to_account_id(), DOMAIN_LEASE_COST)
+			]
+		);
+
+		System::set_block_number(1011);
+		NotebookTick::set(1011);
+		DomainPallet::on_initialize(1011);
+		assert_eq!(RegisteredDomains::<Test>::get(domain), None);
+		assert_eq!(AuctionSettledAtTick::<Test>::get(domain), None);
+	});
+}
+
+#[test]
+fn it_refunds_both_leases_if_a_contest_cannot_open_an_auction() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let domain = Domain { top_level: DomainTopLevel::Analytics, name: "test".into() }.hash();
+		// fill the auctions closing at the auction's close tick
+		AuctionsClosingAtTick::<Test>::mutate(11, |domains| {
+			while domains.try_push(H256::repeat_byte(1)).is_ok() {}
+		});
+		DomainPallet::notebook_submitted(&create_notebook(1, vec![(domain, Bob.to_account_id())]));
+		let mut notebook = create_notebook(1, vec![(domain, Alice.to_account_id())]);
+		notebook.notary_id = 2;
+		DomainPallet::notebook_submitted(&notebook);
+
+		assert_eq!(RegisteredDomains::<Test>::get(domain), None);
+		assert_eq!(RegistrationNotaries::<Test>::get(domain), None);
+		assert_eq!(DomainAuctions::<Test>::get(domain), None);
+		assert_eq!(ExpiringDomainsByBlock::<Test>::get(1001).len(), 0);
+		System::assert_has_event(
 			Event::DomainRegistrationCanceled {
 				domain_hash: domain,
 				registration: DomainRegistration {
@@ -57,8 +275,74 @@ fn it_cancels_conflicting_domains() {
 			}
 			.into(),
 		);
+		System::assert_last_event(
+			Event::DomainRegistrationError {
+				domain_hash: domain,
+				account_id: Alice.to_account_id(),
+				error: Error::<Test>::DomainAuctionNotOpened.into(),
+			}
+			.into(),
+		);
+		assert_eq!(
+			Refunds::get(),
+			vec![
+				(1, Bob.to_account_id(), DOMAIN_LEASE_COST),
+				(2, Alice.to_account_id(), DOMAIN_LEASE_COST)
+			]
+		);
+	});
+}
+
+#[test]
+fn it_refunds_contests_of_registrations_without_a_lease_notary() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let domain = Domain { top_level: DomainTopLevel::Analytics, name: "test".into() }.hash();
+		DomainPallet::notebook_submitted(&create_notebook(1, vec![(domain, Bob.to_account_id())]));
+		RegistrationNotaries::<Test>::remove(domain);
+
+		DomainPallet::notebook_submitted(&create_notebook(
+			1,
+			vec![(domain, Alice.to_account_id())],
+		));
+		assert_eq!(
+			RegisteredDomains::<Test>::get(domain),
+			Some(DomainRegistration { account_id: Bob.to_account_id(), registered_at_tick: 1 })
+		);
+		assert_eq!(DomainAuctions::<Test>::get(domain), None);
+		System::assert_last_event(
+			Event::DomainRegistrationError {
+				domain_hash: domain,
+				account_id: Alice.to_account_id(),
+				error: Error::<Test>::DomainNotContestable.into(),
+			}
+			.into(),
+		);
+		assert_eq!(Refunds::get(), vec![(1, Alice.to_account_id(), DOMAIN_LEASE_COST)]);
 	});
 }
+
+#[test]
+fn it_breaks_auction_ties_by_who_reached_the_bid_first() {
+	let bid = |account_id: AccountId, notary_id, leases: u128, last_bid_tick| AuctionBid {
+		account_id,
+		notary_id,
+		amount: leases * DOMAIN_LEASE_COST,
+		last_bid_tick,
+	};
+	let auction = DomainAuction {
+		opened_at_tick: 1,
+		closes_at_tick: 11,
+		bids: BoundedVec::truncate_from(vec![
+			bid(Bob.to_account_id(), 1, 1, 1),
+			bid(Alice.to_account_id(), 1, 2, 4),
+			bid(Bob.to_account_id(), 2, 1, 3),
+		]),
+	};
+	assert_eq!(auction.winner(), Some((Bob.to_account_id(), 2 * DOMAIN_LEASE_COST)));
+	assert_eq!(auction.total_bid(&Alice.to_account_id()), 2 * DOMAIN_LEASE_COST);
+}
+
 #[test]
 fn it_renews_domains() {
 	new_test_ext().execute_with(|| {
@@ -75,7 +359,153 @@ fn it_renews_domains() {
 		));
 		assert_eq!(ExpiringDomainsByBlock::<Test>::get(1001).len(), 0);
 		assert_eq!(ExpiringDomainsByBlock::<Test>::get(1100).len(), 1);
+		assert_eq!(RenewedDomainExpirations::<Test>::get(domain), Some(1100));
+		assert_eq!(
+			RegisteredDomains::<Test>::get(domain),
+			Some(DomainRegistration { account_id: Bob.to_account_id(), registered_at_tick: 1 })
+		);
+		System::assert_last_event(Event::DomainRenewed { domain_hash: domain }.into());
+
+		System::set_block_number(1100);
+		NotebookTick::set(1100);
+		DomainPallet::on_initialize(1100);
+		assert_eq!(RegisteredDomains::<Test>::get(domain), None);
+		assert_eq!(RenewedDomainExpirations::<Test>::get(domain), None);
+	});
+}
+
+#[test]
+fn it_does_not_cancel_renewals_with_competing_leases() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let domain = Domain { top_level: DomainTopLevel::Analytics, name: "test".into() }.hash();
+		DomainPallet::notebook_submitted(&create_notebook(1, vec![(domain, Bob.to_account_id())]));
+
+		System::set_block_number(100);
+		NotebookTick::set(100);
+		DomainPallet::notebook_submitted(&create_notebook(
+			100,
+			vec![(domain, Bob.to_account_id()), (domain, Alice.to_account_id())],
+		));
+		// a second renewal in the same tick (eg, from another notary) is refunded
+		let mut notebook = create_notebook(100, vec![(domain, Bob.to_account_id())]);
+		notebook.notary_id = 2;
+		DomainPallet::notebook_submitted(&notebook);
+		System::assert_last_event(
+			Event::DomainRegistrationError {
+				domain_hash: domain,
+				account_id: Bob.to_account_id(),
+				error: Error::<Test>::RenewalDoesNotExtendExpiration.into(),
+			}
+			.into(),
+		);
+		assert_eq!(Refunds::get(), vec![(2, Bob.to_account_id(), DOMAIN_LEASE_COST)]);
+		assert_eq!(
+			RegisteredDomains::<Test>::get(domain),
+			Some(DomainRegistration { account_id: Bob.to_account_id(), registered_at_tick: 1 })
+		);
+		assert_eq!(ExpiringDomainsByBlock::<Test>::get(1100).to_vec(), vec![domain]);
+		assert_eq!(
+			System::events()
+				.iter()
+				.filter(|e| e.event ==
+					RuntimeEvent::Domain(Event::DomainRenewed { domain_hash: domain }))
+				.count(),
+			1
+		);
+	});
+}
+
+#[test]
+fn it_can_transfer_domains() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		NotebookTick::set(2);
+		let domain = Domain { top_level: DomainTopLevel::Analytics, name: "test".into() }.hash();
+		DomainPallet::notebook_submitted(&create_notebook(1, vec![(domain, Bob.to_account_id())]));
+		ZoneRecordsByDomain::<Test>::insert(
+			domain,
+			ZoneRecord {
+				payment_account: Bob.to_account_id(),
+				notary_id: 1,
+				versions: Default::default(),
+			},
+		);
+
+		assert_err!(
+			DomainPallet::transfer_domain(
+				RuntimeOrigin::signed(Alice.to_account_id()),
+				domain,
+				Alice.to_account_id()
+			),
+			Error::<Test>::NotDomainOwner
+		);
+		assert_err!(
+			DomainPallet::transfer_domain(
+				RuntimeOrigin::signed(Bob.to_account_id()),
+				domain,
+				Bob.to_account_id()
+			),
+			Error::<Test>::AlreadyDomainOwner
+		);
+		assert_ok!(DomainPallet::transfer_domain(
+			RuntimeOrigin::signed(Bob.to_account_id()),
+			domain,
+			Alice.to_account_id()
+		));
+		System::assert_last_event(
+			Event::DomainTransferred {
+				domain_hash: domain,
+				from: Bob.to_account_id(),
+				to: Alice.to_account_id(),
+			}
+			.into(),
+		);
+		assert_eq!(
+			RegisteredDomains::<Test>::get(domain),
+			Some(DomainRegistration { account_id: Alice.to_account_id(), registered_at_tick: 1 })
+		);
+		assert_eq!(ZoneRecordsByDomain::<Test>::get(domain), None);
+		assert_eq!(RegistrationNotaries::<Test>::get(domain), None);
+		assert_eq!(ExpiringDomainsByBlock::<Test>::get(1001).to_vec(), vec![domain]);
+
+		// a competing lease in the registration tick can't take the domain from the new owner
+		let mut notebook = create_notebook(1, vec![(domain, Charlie.to_account_id())]);
+		notebook.notary_id = 2;
+		DomainPallet::notebook_submitted(&notebook);
+		System::assert_last_event(
+			Event::DomainRegistrationError {
+				domain_hash: domain,
+				account_id: Charlie.to_account_id(),
+				error: Error::<Test>::DomainNotContestable.into(),
+			}
+			.into(),
+		);
+		assert_eq!(
+			RegisteredDomains::<Test>::get(domain),
+			Some(DomainRegistration { account_id: Alice.to_account_id(), registered_at_tick: 1 })
+		);
+		assert_eq!(DomainAuctions::<Test>::get(domain), None);
+		assert_eq!(Refunds::get(), vec![(2, Charlie.to_account_id(), DOMAIN_LEASE_COST)]);
+
+		// the new owner renews with their own leases
+		System::set_block_number(10);
+		NotebookTick::set(10);
+		DomainPallet::notebook_submitted(&create_notebook(
+			10,
+			vec![(domain, Alice.to_account_id())],
+		));
 		System::assert_last_event(Event::DomainRenewed { domain_hash: domain }.into());
+		assert_eq!(ExpiringDomainsByBlock::<Test>::get(1010).to_vec(), vec![domain]);
+
+		assert_err!(
+			DomainPallet::transfer_domain(
+				RuntimeOrigin::signed(Bob.to_account_id()),
+				Domain { top_level: DomainTopLevel::Automotive, name: "test".into() }.hash(),
+				Alice.to_account_id()
+			),
+			Error::<Test>::DomainNotRegistered
+		);
 	});
 }
 #[test]
@@ -181,3 +611,117 @@ fn create_notebook(tick: Tick, domains: Vec<(DomainHash, AccountId)>) -> Noteboo
 		domains: BoundedVec::truncate_from(domains),
 	}
 }
+
+#[test]
+fn it_can_add_and_retire_top_level_domains() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		NotebookTick::set(5);
+		let name = |x: &str| BoundedVec::truncate_from(x.as_bytes().to_vec());
+		assert_err!(
+			DomainPallet::add_top_level_domain(
+				RuntimeOrigin::signed(Bob.to_account_id()),
+				name("games")
+			),
+			DispatchError::BadOrigin
+		);
+		assert_err!(
+			DomainPallet::add_top_level_domain(RuntimeOrigin::root(), name("Games")),
+			Error::<Test>::InvalidTopLevelDomain
+		);
+		assert_err!(
+			DomainPallet::add_top_level_domain(RuntimeOrigin::root(), name("real-estate")),
+			Error::<Test>::TopLevelDomainAlreadyExists
+		);
+
+		assert_ok!(DomainPallet::add_top_level_domain(RuntimeOrigin::root(), name("games")));
+		let games = BUILT_IN_DOMAIN_TOP_LEVELS;
+		System::assert_last_event(
+			Event::TopLevelDomainAdded { top_level_id: games, name: name("games") }.into(),
+		);
+		assert_eq!(TopLevelDomains::<Test>::get(games), Some(name("games")));
+		assert_eq!(DomainPallet::top_level_domain_id(b"games"), Some(games));
+		assert_eq!(DomainPallet::top_level_domain_id(b"cars"), Some(DomainTopLevel::Cars.id()));
+		assert!(DomainPallet::is_active_top_level_domain(games));
+		assert!(!DomainPallet::is_active_top_level_domain(games + 1));
+		assert_err!(
+			DomainPallet::add_top_level_domain(RuntimeOrigin::root(), name("games")),
+			Error::<Test>::TopLevelDomainAlreadyExists
+		);
+
+		// domains under an added top level hash by id
+		let domain_hash = Domain::hash_with_top_level_id("arcade", games);
+		DomainPallet::notebook_submitted(&create_notebook(
+			1,
+			vec![(domain_hash, Bob.to_account_id())],
+		));
+		assert!(RegisteredDomains::<Test>::get(domain_hash).is_some());
+
+		assert_ok!(DomainPallet::retire_top_level_domain(RuntimeOrigin::root(), games));
+		System::assert_last_event(Event::TopLevelDomainRetired { top_level_id: games }.into());
+		assert_eq!(RetiredTopLevelDomains::<Test>::get(games), Some(5));
+		assert!(!DomainPallet::is_active_top_level_domain(games));
+		assert_eq!(DomainPallet::top_level_domain_id(b"games"), Some(games));
+		assert_err!(
+			DomainPallet::retire_top_level_domain(RuntimeOrigin::root(), games),
+			Error::<Test>::TopLevelDomainAlreadyRetired
+		);
+		assert_err!(
+			DomainPallet::retire_top_level_domain(RuntimeOrigin::root(), games + 1),
+			Error::<Test>::UnknownTopLevelDomain
+		);
+		assert_err!(
+			DomainPallet::retire_top_level_domain(
+				RuntimeOrigin::root(),
+				DomainTopLevel::Weather.id()
+			),
+			Error::<Test>::CannotRetireBuiltInTopLevelDomain
+		);
+		assert!(DomainPallet::is_active_top_level_domain(DomainTopLevel::Weather.id()));
+
+		// existing registrations stay in place
+		assert!(RegisteredDomains::<Test>::get(domain_hash).is_some());
+	});
+}
+
+#[test]
+fn it_rejects_and_refunds_leases_under_inactive_top_levels() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		NotebookTick::set(5);
+		let name = |x: &str| BoundedVec::truncate_from(x.as_bytes().to_vec());
+		assert_ok!(DomainPallet::add_top_level_domain(RuntimeOrigin::root(), name("games")));
+		let games = BUILT_IN_DOMAIN_TOP_LEVELS;
+		assert_ok!(DomainPallet::retire_top_level_domain(RuntimeOrigin::root(), games));
+
+		let retired = Domain::hash_with_top_level_id("arcade", games);
+		let unknown = Domain::hash_with_top_level_id("arcade", games + 1);
+		let built_in = Domain { top_level: DomainTopLevel::Cars, name: "arcade".into() }.hash();
+		DomainPallet::notebook_submitted(&create_notebook(
+			5,
+			vec![
+				(retired, Bob.to_account_id()),
+				(unknown, Alice.to_account_id()),
+				(built_in, Bob.to_account_id()),
+			],
+		));
+		assert!(RegisteredDomains::<Test>::get(retired).is_none());
+		assert!(RegisteredDomains::<Test>::get(unknown).is_none());
+		assert!(RegisteredDomains::<Test>::get(built_in).is_some());
+		System::assert_has_event(
+			Event::DomainRegistrationError {
+				domain_hash: retired,
+				account_id: Bob.to_account_id(),
+				error: Error::<Test>::InactiveTopLevelDomain.into(),
+			}
+			.into(),
+		);
+		assert_eq!(
+			Refunds::get(),
+			vec![
+				(1, Bob.to_account_id(), DOMAIN_LEASE_COST),
+				(1, Alice.to_account_id(), DOMAIN_LEASE_COST)
+			]
+		);
+	});
+}
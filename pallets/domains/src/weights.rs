@@ -4,6 +4,9 @@ use pallet_prelude::*;
 pub trait WeightInfo {
 	// Actual extrinsics
 	fn set_zone_record() -> Weight;
+	fn transfer_domain() -> Weight;
+	fn add_top_level_domain() -> Weight;
+	fn retire_top_level_domain() -> Weight;
 
 	// Hooks with variance
 	fn on_initialize_with_expiring_domains(n: u32) -> Weight;
@@ -18,6 +21,18 @@ impl WeightInfo for () {
 		Weight::zero()
 	}
 
+	fn transfer_domain() -> Weight {
+		Weight::zero()
+	}
+
+	fn add_top_level_domain() -> Weight {
+		Weight::zero()
+	}
+
+	fn retire_top_level_domain() -> Weight {
+		Weight::zero()
+	}
+
 	fn on_initialize_with_expiring_domains(_n: u32) -> Weight {
 		Weight::zero()
 	}
@@ -8,7 +8,7 @@ use argon_primitives::{
 	notary::NotaryProvider,
 	notebook::{ChainTransfer, NotebookHeader},
 	providers::ChainTransferLookup,
-	NotebookEventHandler, TransferToLocalchainId,
+	LocalchainRefundProvider, NotebookEventHandler, TransferToLocalchainId,
 };
 use pallet_prelude::benchmarking::{
 	benchmark_notebook_provider_call_counters, reset_benchmark_notebook_provider_call_counters,
@@ -143,6 +143,31 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn provider_refund_to_localchain() -> Result<(), BenchmarkError> {
+		reset_benchmark_notebook_provider_state();
+		reset_benchmark_notebook_provider_call_counters();
+		let notary_id = T::NotaryProvider::active_notaries()
+			.first()
+			.copied()
+			.ok_or(BenchmarkError::Stop("missing active benchmark notary"))?;
+		let account_id: T::AccountId = account("refund-account", 0, 0);
+		let amount: T::Balance = 1_000_000u128.into();
+
+		#[block]
+		{
+			<LocalchainTransferPallet<T> as LocalchainRefundProvider<
+				T::AccountId,
+				T::Balance,
+			>>::refund_to_localchain(notary_id, &account_id, amount)
+			.map_err(|_| BenchmarkError::Stop("refund failed"))?;
+		}
+
+		assert!(PendingTransfersOut::<T>::iter().next().is_some());
+		assert_provider_calls(Default::default());
+		Ok(())
+	}
+
 	#[benchmark]
 	fn notebook_submitted_event_handler(t: Linear<1, 1_000>) -> Result<(), BenchmarkError> {
 		reset_benchmark_notebook_provider_state();
@@ -23,8 +23,8 @@ pub mod pallet {
 	use argon_primitives::{
 		notary::NotaryProvider,
 		notebook::{ChainTransfer, NotebookHeader},
-		BurnEventHandler, ChainTransferLookup, NotebookEventHandler, NotebookProvider,
-		TickProvider, TransferToLocalchainId,
+		BurnEventHandler, ChainTransferLookup, LocalchainRefundProvider, NotebookEventHandler,
+		NotebookProvider, TickProvider, TransferToLocalchainId,
 	};
 
 	use sp_core::crypto::AccountId32;
@@ -417,6 +417,52 @@ pub mod pallet {
 		}
 	}
 
+	impl<T: Config>
+		LocalchainRefundProvider<<T as frame_system::Config>::AccountId, <T as Config>::Balance>
+		for Pallet<T>
+	{
+		type Weights = crate::weights::ProviderWeightAdapter<T>;
+
+		/// Refunds localchain tax (which was burned from the notary escrow when the notebook was
+		/// submitted) by minting it back into the escrow as a pending transfer to the localchain.
+		/// The mint is reported as a refunded burn so the mint totals follow it.
+		fn refund_to_localchain(
+			notary_id: NotaryId,
+			account_id: &<T as frame_system::Config>::AccountId,
+			microgons: <T as Config>::Balance,
+		) -> Result<TransferToLocalchainId, DispatchError> {
+			ensure!(!microgons.is_zero(), Error::<T>::InsufficientFunds);
+			let transfer_id = Self::next_transfer_id()?;
+			let expiration_tick =
+				T::TickProvider::current_tick() + T::TransferExpirationTicks::get();
+			ExpiringTransfersOutByNotary::<T>::try_append(notary_id, expiration_tick, transfer_id)
+				.map_err(|_| Error::<T>::MaxBlockTransfersExceeded)?;
+
+			let escrow = Self::notary_account_id(notary_id);
+			Self::ensure_escrow_provider(&escrow);
+			T::Argon::mint_into(&escrow, microgons)?;
+			T::EventHandler::on_argon_burn_refunded(&microgons);
+
+			PendingTransfersOut::<T>::insert(
+				transfer_id,
+				QueuedTransferOut {
+					account_id: account_id.clone(),
+					amount: microgons,
+					expiration_tick,
+					notary_id,
+				},
+			);
+			Self::deposit_event(Event::TransferToLocalchain {
+				account_id: account_id.clone(),
+				amount: microgons,
+				transfer_id,
+				notary_id,
+				expiration_tick,
+			});
+			Ok(transfer_id)
+		}
+	}
+
 	impl<T: Config> Pallet<T> {
 		pub fn transfer_funds_to_mainchain(
 			notary_pallet_account_id: &<T as frame_system::Config>::AccountId,
@@ -1,12 +1,12 @@
 use crate::{
 	mock::{LocalchainTransfer as LocalchainTransferPallet, *},
-	pallet::{ExpiringTransfersOutByNotary, NextTransferId},
+	pallet::{ExpiringTransfersOutByNotary, NextTransferId, PendingTransfersOut},
 	Error, Event,
 };
 use argon_primitives::{
 	notebook::{AccountOrigin, ChainTransfer, NotebookHeader},
 	tick::Tick,
-	NotebookEventHandler,
+	LocalchainRefundProvider, NotebookEventHandler,
 };
 use frame_support::{assert_noop, assert_ok};
 use pallet_prelude::*;
@@ -134,6 +134,50 @@ fn it_expires_transfers_when_notebook_tick_advances_past_expiration() {
 	});
 }
 
+#[test]
+fn it_can_refund_to_a_localchain() {
+	new_test_ext().execute_with(|| {
+		let who = Bob.to_account_id();
+		System::set_block_number(1);
+		let escrow = LocalchainTransferPallet::notary_account_id(1);
+		assert_eq!(LocalchainTransferPallet::refund_to_localchain(1, &who, 1500), Ok(1));
+		assert_eq!(Balances::free_balance(&escrow), 1500);
+		assert_eq!(Balances::total_issuance(), 1500);
+		let expires_tick: Tick = 1 + TransferExpirationTicks::get();
+		assert_eq!(PendingTransfersOut::<Test>::get(1).map(|x| x.amount), Some(1500));
+		System::assert_last_event(
+			Event::TransferToLocalchain {
+				account_id: who.clone(),
+				amount: 1500,
+				transfer_id: 1,
+				notary_id: 1,
+				expiration_tick: expires_tick,
+			}
+			.into(),
+		);
+
+		// an unclaimed refund goes to the mainchain account
+		LocalchainTransferPallet::notebook_submitted(&NotebookHeader {
+			notary_id: 1,
+			notebook_number: 1,
+			tick: expires_tick + 1,
+			chain_transfers: bounded_vec![],
+			changed_accounts_root: H256::random(),
+			changed_account_origins: bounded_vec![],
+			version: 1,
+			tax: 0,
+			block_voting_power: 0,
+			blocks_with_votes: Default::default(),
+			block_votes_root: H256::random(),
+			secret_hash: H256::random(),
+			parent_secret: None,
+			block_votes_count: 0,
+			domains: Default::default(),
+		});
+		assert_eq!(Balances::free_balance(&who), 1500);
+	});
+}
+
 #[test]
 fn it_does_not_expire_transfers_without_notebook_submission() {
 	new_test_ext().execute_with(|| {
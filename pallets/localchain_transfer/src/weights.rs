@@ -1,8 +1,8 @@
 use argon_primitives::{
 	notary::{NotaryProvider, NotaryProviderWeightInfo},
 	providers::{
-		ChainTransferLookupWeightInfo, NotebookProvider, NotebookProviderWeightInfo, TickProvider,
-		TickProviderWeightInfo,
+		ChainTransferLookupWeightInfo, LocalchainRefundProviderWeightInfo, NotebookProvider,
+		NotebookProviderWeightInfo, TickProvider, TickProviderWeightInfo,
 	},
 };
 use pallet_prelude::*;
@@ -16,6 +16,7 @@ pub trait WeightInfo {
 	fn notebook_submitted_event_handler(t: u32) -> Weight;
 	fn process_expired_transfers(e: u32) -> Weight;
 	fn provider_is_valid_transfer_to_localchain() -> Weight;
+	fn provider_refund_to_localchain() -> Weight;
 }
 
 type NotebookProviderWeights<T> =
@@ -62,6 +63,10 @@ where
 	fn provider_is_valid_transfer_to_localchain() -> Weight {
 		Base::provider_is_valid_transfer_to_localchain()
 	}
+
+	fn provider_refund_to_localchain() -> Weight {
+		Base::provider_refund_to_localchain().saturating_add(TickProviderWeight::current_tick())
+	}
 }
 
 pub struct ProviderWeightAdapter<T>(PhantomData<T>);
@@ -71,6 +76,12 @@ impl<T: crate::Config> ChainTransferLookupWeightInfo for ProviderWeightAdapter<T
 	}
 }
 
+impl<T: crate::Config> LocalchainRefundProviderWeightInfo for ProviderWeightAdapter<T> {
+	fn refund_to_localchain() -> Weight {
+		<T as crate::Config>::WeightInfo::provider_refund_to_localchain()
+	}
+}
+
 // For backwards compatibility and tests.
 impl WeightInfo for () {
 	fn send_to_localchain() -> Weight {
@@ -88,6 +99,10 @@ impl WeightInfo for () {
 	fn provider_is_valid_transfer_to_localchain() -> Weight {
 		Weight::zero()
 	}
+
+	fn provider_refund_to_localchain() -> Weight {
+		Weight::zero()
+	}
 }
 
 // NotebookEventHandlerWeightInfo implementation will be generated by runtime
@@ -434,6 +434,23 @@ pub mod pallet {
 				MintedBitcoinMicrogons::<T>::mutate(|mint| mint.saturating_reduce(microgons));
 			}
 		}
+
+		/// Credits burned argons that were minted back to the mint totals, by the same prorata a
+		/// burn is debited with.
+		pub fn on_argon_burn_refunded(amount: T::Balance) {
+			let bitcoin_utxos = MintedBitcoinMicrogons::<T>::get();
+			let mining_mint = MintedMiningMicrogons::<T>::get();
+			let total_minted = mining_mint + bitcoin_utxos;
+			let mining_prorata = (amount * mining_mint).checked_div(&total_minted);
+			if let Some(microgons) = mining_prorata {
+				MintedMiningMicrogons::<T>::mutate(|mint| mint.saturating_accrue(microgons));
+			}
+
+			let bitcoin_prorata = (amount * bitcoin_utxos).checked_div(&total_minted);
+			if let Some(microgons) = bitcoin_prorata {
+				MintedBitcoinMicrogons::<T>::mutate(|mint| mint.saturating_accrue(microgons));
+			}
+		}
 	}
 
 	impl<T: Config> UtxoLockEvents<T::AccountId, T::Balance> for Pallet<T>
@@ -503,6 +520,10 @@ pub mod pallet {
 		fn on_argon_burn(microgons: &T::Balance) {
 			Self::on_argon_burn(*microgons);
 		}
+
+		fn on_argon_burn_refunded(microgons: &T::Balance) {
+			Self::on_argon_burn_refunded(*microgons);
+		}
 	}
 
 	#[derive(
@@ -54,6 +54,30 @@ fn it_records_burnt_argons_by_prorata() {
 	});
 }
 
+#[test]
+fn it_credits_refunded_burns_by_prorata() {
+	new_test_ext().execute_with(|| {
+		MintedMiningMicrogons::<Test>::set(100);
+		MintedBitcoinMicrogons::<Test>::set(100);
+		Mint::on_argon_burn(50);
+		Mint::on_argon_burn_refunded(50);
+		assert_eq!(MintedMiningMicrogons::<Test>::get(), 100);
+		assert_eq!(MintedBitcoinMicrogons::<Test>::get(), 100);
+
+		MintedMiningMicrogons::<Test>::set(200);
+		MintedBitcoinMicrogons::<Test>::set(0);
+		Mint::on_argon_burn_refunded(50);
+		assert_eq!(MintedMiningMicrogons::<Test>::get(), 250);
+		assert_eq!(MintedBitcoinMicrogons::<Test>::get(), 0);
+
+		// nothing is tracked, so there is nothing to credit the refund against
+		MintedMiningMicrogons::<Test>::set(0);
+		Mint::on_argon_burn_refunded(50);
+		assert_eq!(MintedMiningMicrogons::<Test>::get(), 0);
+		assert_eq!(MintedBitcoinMicrogons::<Test>::get(), 0);
+	});
+}
+
 #[test]
 fn it_tracks_block_rewards() {
 	new_test_ext().execute_with(|| {
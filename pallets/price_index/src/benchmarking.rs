@@ -11,22 +11,44 @@ use polkadot_sdk::frame_benchmarking::v2::*;
 mod benchmarks {
 	use super::*;
 
+	// Every operator has a fresh submission, and the minority that deviates from the median is
+	// reported.
 	#[benchmark]
 	fn submit() -> Result<(), BenchmarkError> {
+		let max = T::MaxPriceOperators::get();
 		let caller: T::AccountId = whitelisted_caller();
-		let index = benchmark_price_index(1);
+		let index = benchmark_price_index(2);
 		let ethereum = EthereumPriceIndex {
 			ethereum_usd_price: FixedU128::from_u32(3_000),
 			ethereum_gas_price_wei: 1_000_000_000,
 			tick: index.tick,
 		};
-		Operator::<T>::put(caller.clone());
+		let deviating = max.saturating_sub(max / 2 + 1);
+		let mut operators = BoundedBTreeSet::<T::AccountId, T::MaxPriceOperators>::new();
+		let _ = operators.try_insert(caller.clone());
+		for i in 1..max {
+			let operator: T::AccountId = account("operator", i, 0);
+			let mut submission = index;
+			if i <= deviating {
+				submission.btc_usd_price =
+					index.btc_usd_price.saturating_mul(FixedU128::from_u32(2));
+			}
+			OperatorSubmissions::<T>::insert(
+				&operator,
+				OperatorSubmission { index: submission, ethereum: Some(ethereum) },
+			);
+			let _ = operators.try_insert(operator);
+		}
+		Operators::<T>::put(operators);
+		Current::<T>::put(benchmark_price_index(1));
 
 		#[extrinsic_call]
 		submit(RawOrigin::Signed(caller.clone()), index, Some(ethereum));
 
-		assert_eq!(Current::<T>::get(), Some(index));
+		assert_eq!(Current::<T>::get().map(|x| x.tick), Some(index.tick));
+		assert_eq!(LastValid::<T>::get(), Some(benchmark_price_index(1)));
 		assert_eq!(CurrentEthereumPrice::<T>::get(), Some(ethereum));
+		assert_eq!(OperatorDeviationReportedTick::<T>::iter().count() as u32, deviating);
 		assert!(!HistoricEthereumPricesByFrame::<T>::get().is_empty());
 
 		Ok(())
@@ -44,6 +66,32 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_operators() -> Result<(), BenchmarkError> {
+		let max = T::MaxPriceOperators::get();
+		let index = benchmark_price_index(1);
+		let mut previous = BoundedBTreeSet::<T::AccountId, T::MaxPriceOperators>::new();
+		for i in 0..max {
+			let operator: T::AccountId = account("previous", i, 0);
+			OperatorSubmissions::<T>::insert(
+				&operator,
+				OperatorSubmission { index, ethereum: None },
+			);
+			OperatorDeviationReportedTick::<T>::insert(&operator, index.tick);
+			let _ = previous.try_insert(operator);
+		}
+		Operators::<T>::put(previous);
+		let operators = (0..max).map(|i| account("operator", i, 0)).collect::<Vec<T::AccountId>>();
+		let operators = BoundedVec::<T::AccountId, T::MaxPriceOperators>::truncate_from(operators);
+
+		#[extrinsic_call]
+		set_operators(RawOrigin::Root, operators.clone());
+
+		assert_eq!(Operators::<T>::get().len(), operators.len());
+
+		Ok(())
+	}
+
 	#[benchmark]
 	fn on_initialize() -> Result<(), BenchmarkError> {
 		let index = benchmark_price_index(10);
@@ -172,6 +172,34 @@ where
 	pub sample_count: u32,
 }
 
+/// The median of the values, averaging the middle two for an even count
+fn median(mut values: Vec<u128>) -> u128 {
+	values.sort_unstable();
+	let mid = values.len() / 2;
+	if values.len() % 2 == 0 && mid > 0 {
+		return values[mid - 1].saturating_add(values[mid]) / 2;
+	}
+	values.get(mid).copied().unwrap_or_default()
+}
+
+/// The latest prices submitted by a single operator
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	Clone,
+	Copy,
+	Debug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct OperatorSubmission {
+	pub index: PriceIndex,
+	pub ethereum: Option<EthereumPriceIndex>,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use sp_arithmetic::FixedPointNumber;
@@ -243,6 +271,19 @@ pub mod pallet {
 		type MaxArgonChangePerTickAwayFromTarget: Get<FixedU128>;
 		#[pallet::constant]
 		type MaxArgonTargetChangePerTick: Get<FixedU128>;
+
+		/// The maximum number of registered price index operators
+		#[pallet::constant]
+		type MaxPriceOperators: Get<u32>;
+
+		/// Operator submissions older than this many ticks are left out of the aggregated index
+		#[pallet::constant]
+		type MaxOperatorSubmissionAgeTicks: Get<Tick>;
+
+		/// A submission is dropped as an outlier if its bitcoin, argon or argonot price is further
+		/// than this ratio from the median of all operators
+		#[pallet::constant]
+		type MaxOperatorPriceDeviation: Get<FixedU128>;
 	}
 
 	#[pallet::event]
@@ -253,6 +294,17 @@ pub mod pallet {
 		OperatorChanged {
 			operator_id: T::AccountId,
 		},
+		/// The set of price index operators was replaced
+		OperatorsChanged {
+			operators: BoundedVec<T::AccountId, T::MaxPriceOperators>,
+		},
+		/// An operator submission was left out of the index because it strayed too far from the
+		/// median of the other operators
+		OperatorDeviated {
+			operator_id: T::AccountId,
+			tick: Tick,
+			deviation: FixedU128,
+		},
 	}
 
 	#[pallet::error]
@@ -319,6 +371,23 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Operator<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
 
+	/// The registered price index operators. The index is the median of their submissions. When
+	/// empty, the single `Operator` is used.
+	#[pallet::storage]
+	pub type Operators<T: Config> =
+		StorageValue<_, BoundedBTreeSet<T::AccountId, T::MaxPriceOperators>, ValueQuery>;
+
+	/// The latest submission of each operator
+	#[pallet::storage]
+	pub type OperatorSubmissions<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, OperatorSubmission, OptionQuery>;
+
+	/// The tick of the last submission each operator was reported as deviating for, so a
+	/// submission is only reported once
+	#[pallet::storage]
+	pub type OperatorDeviationReportedTick<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, Tick, OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -359,7 +428,11 @@ pub mod pallet {
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// Submit the latest price index. Only valid for the configured operator account
+		/// Submit the latest price index. Only valid for a configured operator account.
+		///
+		/// The stored index is the per-field median of the latest fresh submission of each
+		/// operator. It only advances once a majority of operators have submitted, and
+		/// submissions deviating too far from the median are dropped.
 		#[pallet::call_index(0)]
 		#[pallet::weight((T::WeightInfo::submit(), DispatchClass::Operational))]
 		#[pallet::feeless_if(
@@ -367,18 +440,17 @@ pub mod pallet {
 				let Ok(who) = ensure_signed(origin.clone()) else {
 					return false;
 				};
-				Some(who) == Operator::<T>::get()
+				Pallet::<T>::is_operator(&who)
 			}
 		)]
 		pub fn submit(
 			origin: OriginFor<T>,
-			mut index: PriceIndex,
+			index: PriceIndex,
 			ethereum: Option<EthereumPriceIndex>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			let operator = Operator::<T>::get().ok_or(Error::<T>::NotAuthorizedOperator)?;
-			ensure!(operator == who, Error::<T>::NotAuthorizedOperator);
+			ensure!(Self::is_operator(&who), Error::<T>::NotAuthorizedOperator);
 			ensure!(
 				ethereum.is_none_or(|ethereum| {
 					ethereum.tick == index.tick &&
@@ -394,6 +466,17 @@ pub mod pallet {
 				return Ok(());
 			}
 
+			if OperatorSubmissions::<T>::get(&who)
+				.is_some_and(|submission| submission.index.tick >= index.tick)
+			{
+				return Ok(());
+			}
+			OperatorSubmissions::<T>::insert(&who, OperatorSubmission { index, ethereum });
+
+			let Some((mut index, ethereum)) = Self::aggregate_submissions() else {
+				return Ok(());
+			};
+
 			if let Some(current) = Current::<T>::get() {
 				if index.tick <= current.tick {
 					return Ok(());
@@ -455,9 +538,157 @@ pub mod pallet {
 			Self::deposit_event(Event::OperatorChanged { operator_id: account_id });
 			Ok(())
 		}
+
+		/// Replaces the set of price index operators (only executable by the Root account). An
+		/// empty set falls back to the single `Operator`.
+		///
+		/// # Arguments
+		/// * `operators` - the account ids of the operators
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::set_operators())]
+		pub fn set_operators(
+			origin: OriginFor<T>,
+			operators: BoundedVec<T::AccountId, T::MaxPriceOperators>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			let mut next = BoundedBTreeSet::<T::AccountId, T::MaxPriceOperators>::new();
+			for operator in operators.iter() {
+				let _ = next.try_insert(operator.clone());
+			}
+			for previous in Operators::<T>::get() {
+				if !next.contains(&previous) {
+					OperatorSubmissions::<T>::remove(&previous);
+					OperatorDeviationReportedTick::<T>::remove(&previous);
+				}
+			}
+			Operators::<T>::put(next);
+			Self::deposit_event(Event::OperatorsChanged { operators });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		pub fn is_operator(who: &T::AccountId) -> bool {
+			let operators = Operators::<T>::get();
+			if operators.is_empty() {
+				return Operator::<T>::get().as_ref() == Some(who);
+			}
+			operators.contains(who)
+		}
+
+		fn active_operators() -> Vec<T::AccountId> {
+			let operators = Operators::<T>::get();
+			if operators.is_empty() {
+				return Operator::<T>::get().into_iter().collect();
+			}
+			operators.into_iter().collect()
+		}
+
+		/// Combines the fresh operator submissions into a single index. Returns `None` until a
+		/// majority of operators have a fresh submission that is within the allowed deviation.
+		/// A deviation event is emitted for every operator left out, once per submission.
+		pub(crate) fn aggregate_submissions() -> Option<(PriceIndex, Option<EthereumPriceIndex>)> {
+			let operators = Self::active_operators();
+			let required = operators.len() / 2 + 1;
+			let oldest_tick =
+				T::CurrentTick::get().saturating_sub(T::MaxOperatorSubmissionAgeTicks::get());
+			let mut submissions = operators
+				.into_iter()
+				.filter_map(|operator_id| {
+					let submission = OperatorSubmissions::<T>::get(&operator_id)?;
+					(submission.index.tick >= oldest_tick).then_some((operator_id, submission))
+				})
+				.collect::<Vec<_>>();
+			if submissions.len() < required {
+				return None;
+			}
+
+			let median = Self::median_index(&submissions);
+			let max_deviation = T::MaxOperatorPriceDeviation::get();
+			submissions.retain(|(operator_id, submission)| {
+				let deviation = Self::price_deviation(&median, &submission.index);
+				if deviation <= max_deviation {
+					return true;
+				}
+				let tick = submission.index.tick;
+				if OperatorDeviationReportedTick::<T>::get(operator_id) != Some(tick) {
+					OperatorDeviationReportedTick::<T>::insert(operator_id, tick);
+					Self::deposit_event(Event::OperatorDeviated {
+						operator_id: operator_id.clone(),
+						tick,
+						deviation,
+					});
+				}
+				false
+			});
+			if submissions.len() < required {
+				return None;
+			}
+
+			let index = Self::median_index(&submissions);
+			let ethereum_prices = submissions
+				.iter()
+				.filter_map(|(_, submission)| submission.ethereum)
+				.collect::<Vec<_>>();
+			let ethereum = (!ethereum_prices.is_empty()).then(|| EthereumPriceIndex {
+				ethereum_usd_price: FixedU128::from_inner(median(
+					ethereum_prices.iter().map(|a| a.ethereum_usd_price.into_inner()).collect(),
+				)),
+				ethereum_gas_price_wei: median(
+					ethereum_prices.iter().map(|a| a.ethereum_gas_price_wei).collect(),
+				),
+				tick: index.tick,
+			});
+			Some((index, ethereum))
+		}
+
+		fn median_index(submissions: &[(T::AccountId, OperatorSubmission)]) -> PriceIndex {
+			let field_median = |f: fn(&PriceIndex) -> u128| {
+				median(submissions.iter().map(|(_, submission)| f(&submission.index)).collect())
+			};
+			// use the lower median tick so the index never claims prices newer than most operators
+			let mut ticks = submissions
+				.iter()
+				.map(|(_, submission)| submission.index.tick)
+				.collect::<Vec<_>>();
+			ticks.sort_unstable();
+			PriceIndex {
+				btc_usd_price: FixedU128::from_inner(field_median(|a| {
+					a.btc_usd_price.into_inner()
+				})),
+				argonot_usd_price: FixedU128::from_inner(field_median(|a| {
+					a.argonot_usd_price.into_inner()
+				})),
+				argon_usd_price: FixedU128::from_inner(field_median(|a| {
+					a.argon_usd_price.into_inner()
+				})),
+				argon_usd_target_price: FixedU128::from_inner(field_median(|a| {
+					a.argon_usd_target_price.into_inner()
+				})),
+				argon_time_weighted_average_liquidity: field_median(|a| {
+					a.argon_time_weighted_average_liquidity
+				}),
+				tick: ticks.get(ticks.len().saturating_sub(1) / 2).copied().unwrap_or_default(),
+			}
+		}
+
+		/// The largest relative distance of the bitcoin, argon or argonot price from the median
+		pub(crate) fn price_deviation(median: &PriceIndex, index: &PriceIndex) -> FixedU128 {
+			[
+				(median.btc_usd_price, index.btc_usd_price),
+				(median.argon_usd_price, index.argon_usd_price),
+				(median.argonot_usd_price, index.argonot_usd_price),
+			]
+			.into_iter()
+			.filter(|(median, _)| !median.is_zero())
+			.map(|(median, price)| {
+				let diff = if price > median { price - median } else { median - price };
+				diff.checked_div(&median).unwrap_or_default()
+			})
+			.max()
+			.unwrap_or_default()
+		}
+
 		fn get_current() -> Option<PriceIndex> {
 			let price = <Current<T>>::get()?;
 			if price.tick <
@@ -56,6 +56,9 @@ parameter_types! {
 	pub const MaxEthereumPriceHistoryFrames: u32 = 10;
 	pub const MaxArgonChangePerTickAwayFromTarget: FixedU128 = FixedU128::from_rational(1, 100);
 	pub const MaxArgonTargetChangePerTick: FixedU128 = FixedU128::from_rational(1, 100);
+	pub const MaxPriceOperators: u32 = 5;
+	pub static MaxOperatorSubmissionAgeTicks: Tick = 1440;
+	pub const MaxOperatorPriceDeviation: FixedU128 = FixedU128::from_rational(10, 100);
 }
 
 pub struct StaticMiningFrameTransitionProvider;
@@ -82,6 +85,9 @@ impl pallet_price_index::Config for Test {
 	type MaxEthereumPriceHistoryFrames = MaxEthereumPriceHistoryFrames;
 	type MaxArgonChangePerTickAwayFromTarget = MaxArgonChangePerTickAwayFromTarget;
 	type MaxArgonTargetChangePerTick = MaxArgonTargetChangePerTick;
+	type MaxPriceOperators = MaxPriceOperators;
+	type MaxOperatorSubmissionAgeTicks = MaxOperatorSubmissionAgeTicks;
+	type MaxOperatorPriceDeviation = MaxOperatorPriceDeviation;
 	type Currency = Balances;
 }
 
@@ -89,6 +95,7 @@ pub fn new_test_ext(operator: Option<u64>) -> TestState {
 	CurrentTick::set(0);
 	CurrentFrameId::set(0);
 	NewlyStartedFrameId::set(None);
+	MaxOperatorSubmissionAgeTicks::set(1440);
 	new_test_with_genesis::<Test>(|t: &mut Storage| {
 		pallet_price_index::GenesisConfig::<Test> { operator }
 			.assimilate_storage(t)
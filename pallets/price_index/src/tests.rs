@@ -7,7 +7,8 @@ use argon_primitives::{
 use crate::{
 	mock::*, CpiMeasurementBucket, Current, CurrentEthereumPrice, EthereumPriceIndex,
 	HistoricArgonCPI, HistoricArgonotAverageByFrame, HistoricArgonotFloorByFrame,
-	HistoricEthereumPricesByFrame, Operator, PriceIndex as PriceIndexEntry,
+	HistoricEthereumPricesByFrame, Operator, OperatorDeviationReportedTick, OperatorSubmissions,
+	Operators, PriceIndex as PriceIndexEntry,
 };
 
 type Event = crate::Event<Test>;
@@ -55,6 +56,166 @@ fn can_set_an_operator() {
 	});
 }
 
+#[test]
+fn can_set_multiple_operators() {
+	new_test_ext(Some(1)).execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PriceIndex::submit(RuntimeOrigin::signed(1), create_index(), None));
+		assert!(OperatorSubmissions::<Test>::contains_key(1));
+
+		let operators = BoundedVec::truncate_from(vec![2, 3, 4]);
+		assert_ok!(PriceIndex::set_operators(RuntimeOrigin::root(), operators.clone()));
+		System::assert_last_event(Event::OperatorsChanged { operators }.into());
+		assert_eq!(Operators::<Test>::get().into_iter().collect::<Vec<_>>(), vec![2, 3, 4]);
+
+		// the single operator is no longer used once a set is registered
+		assert_err!(
+			PriceIndex::submit(RuntimeOrigin::signed(1), create_index(), None),
+			Error::NotAuthorizedOperator
+		);
+
+		assert_ok!(PriceIndex::set_operators(
+			RuntimeOrigin::root(),
+			BoundedVec::truncate_from(vec![3])
+		));
+		assert_err!(
+			PriceIndex::submit(RuntimeOrigin::signed(2), create_index(), None),
+			Error::NotAuthorizedOperator
+		);
+	});
+}
+
+#[test]
+fn uses_the_median_of_a_majority_of_operators() {
+	new_test_ext(None).execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PriceIndex::set_operators(
+			RuntimeOrigin::root(),
+			BoundedVec::truncate_from(vec![1, 2, 3])
+		));
+		CurrentTick::set(2);
+
+		let mut first = create_index();
+		first.tick = 1;
+		first.btc_usd_price = FixedU128::from_u32(60_000);
+		first.argon_time_weighted_average_liquidity = 100;
+		let ethereum = EthereumPriceIndex {
+			ethereum_usd_price: FixedU128::from_u32(3_000),
+			ethereum_gas_price_wei: 1_000,
+			tick: 1,
+		};
+		assert_ok!(PriceIndex::submit(RuntimeOrigin::signed(1), first, Some(ethereum)));
+		// a single operator can't move the index
+		assert_eq!(Current::<Test>::get(), None);
+
+		let mut second = first;
+		second.btc_usd_price = FixedU128::from_u32(62_000);
+		second.argon_time_weighted_average_liquidity = 300;
+		assert_ok!(PriceIndex::submit(RuntimeOrigin::signed(2), second, None));
+		let current = Current::<Test>::get().expect("majority submitted");
+		assert_eq!(current.tick, 1);
+		assert_eq!(current.btc_usd_price, FixedU128::from_u32(61_000));
+		assert_eq!(current.argon_time_weighted_average_liquidity, 200);
+		assert_eq!(CurrentEthereumPrice::<Test>::get(), Some(ethereum));
+		System::assert_last_event(Event::NewIndex.into());
+
+		// the last operator for the same tick doesn't create a new index
+		let mut third = first;
+		third.btc_usd_price = FixedU128::from_u32(63_000);
+		assert_ok!(PriceIndex::submit(RuntimeOrigin::signed(3), third, None));
+		assert_eq!(Current::<Test>::get(), Some(current));
+
+		// the index follows once most operators move to the next tick
+		let mut next = second;
+		next.tick = 2;
+		assert_ok!(PriceIndex::submit(RuntimeOrigin::signed(1), next, None));
+		assert_eq!(Current::<Test>::get().map(|a| a.tick), Some(1));
+		assert_ok!(PriceIndex::submit(RuntimeOrigin::signed(3), next, None));
+		let current = Current::<Test>::get().unwrap();
+		assert_eq!(current.tick, 2);
+		assert_eq!(current.btc_usd_price, FixedU128::from_u32(62_000));
+	});
+}
+
+#[test]
+fn drops_outlier_operators() {
+	new_test_ext(None).execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PriceIndex::set_operators(
+			RuntimeOrigin::root(),
+			BoundedVec::truncate_from(vec![1, 2, 3])
+		));
+		CurrentTick::set(1);
+
+		let mut index = create_index();
+		index.tick = 1;
+		assert_ok!(PriceIndex::submit(RuntimeOrigin::signed(1), index, None));
+
+		let mut outlier = index;
+		outlier.argonot_usd_price = FixedU128::from_u32(20);
+		assert_ok!(PriceIndex::submit(RuntimeOrigin::signed(2), outlier, None));
+		System::assert_last_event(
+			Event::OperatorDeviated {
+				operator_id: 2,
+				tick: 1,
+				deviation: FixedU128::from_rational(9, 11),
+			}
+			.into(),
+		);
+		// both submissions stray from the median of two, so no majority remains
+		assert_eq!(Current::<Test>::get(), None);
+		let deviated = |operator| {
+			System::events()
+				.iter()
+				.filter(|e| {
+					matches!(
+						e.event,
+						RuntimeEvent::PriceIndex(Event::OperatorDeviated { operator_id, .. })
+							if operator_id == operator
+					)
+				})
+				.count()
+		};
+		assert_eq!(deviated(1), 1);
+		assert_eq!(deviated(2), 1);
+
+		let mut close = index;
+		close.argonot_usd_price = FixedU128::from_rational(201, 100);
+		assert_ok!(PriceIndex::submit(RuntimeOrigin::signed(3), close, None));
+		let current = Current::<Test>::get().expect("two operators agree");
+		assert_eq!(current.argonot_usd_price, FixedU128::from_rational(2005, 1000));
+		System::assert_last_event(Event::NewIndex.into());
+		// the outlier is only reported once per submission
+		assert_eq!(deviated(2), 1);
+		assert_eq!(OperatorDeviationReportedTick::<Test>::get(2), Some(1));
+	});
+}
+
+#[test]
+fn ignores_stale_operator_submissions() {
+	new_test_ext(None).execute_with(|| {
+		System::set_block_number(1);
+		MaxOperatorSubmissionAgeTicks::set(5);
+		assert_ok!(PriceIndex::set_operators(
+			RuntimeOrigin::root(),
+			BoundedVec::truncate_from(vec![1, 2, 3])
+		));
+
+		CurrentTick::set(1);
+		let mut index = create_index();
+		index.tick = 1;
+		assert_ok!(PriceIndex::submit(RuntimeOrigin::signed(1), index, None));
+
+		CurrentTick::set(10);
+		index.tick = 10;
+		assert_ok!(PriceIndex::submit(RuntimeOrigin::signed(2), index, None));
+		assert_eq!(Current::<Test>::get(), None);
+
+		assert_ok!(PriceIndex::submit(RuntimeOrigin::signed(3), index, None));
+		assert_eq!(Current::<Test>::get().map(|a| a.tick), Some(10));
+	});
+}
+
 #[test]
 fn can_set_a_price_index() {
 	new_test_ext(Some(1)).execute_with(|| {
@@ -7,6 +7,7 @@ pub trait WeightInfo {
 	fn on_initialize() -> Weight;
 	fn submit() -> Weight;
 	fn set_operator() -> Weight;
+	fn set_operators() -> Weight;
 	fn provider_get_lowest_microgons_per_argonot() -> Weight;
 	fn provider_get_average_microgons_per_argonot() -> Weight;
 	fn provider_get_ethereum_prices() -> Weight;
@@ -43,6 +44,9 @@ impl WeightInfo for () {
 	fn set_operator() -> Weight {
 		Weight::zero()
 	}
+	fn set_operators() -> Weight {
+		Weight::zero()
+	}
 	fn provider_get_lowest_microgons_per_argonot() -> Weight {
 		Weight::zero()
 	}
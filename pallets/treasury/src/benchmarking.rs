@@ -3,7 +3,7 @@
 use super::*;
 use argon_primitives::{
 	bitcoin::Satoshis,
-	vault::{TreasuryBonusApprovalProof, Vault, VaultTerms},
+	vault::{TreasuryBonusApprovalProof, TreasuryFrameYield, Vault, VaultTerms},
 	Signature, TreasuryPoolProvider, MICROGONS_PER_ARGON,
 };
 use frame_benchmarking::v2::*;
@@ -171,6 +171,85 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn transfer_bond_lot() -> Result<(), BenchmarkError> {
+		reset_benchmark_state::<T>();
+
+		let active_lot_count = T::MaxActiveArgonotBondLots::get().saturating_sub(1);
+		let split_bonds = minimum_purchase_bonds::<T>();
+		let lot_bonds = split_bonds.saturating_mul(2);
+		let first_bond_lot_id = seed_active_argonot_state::<T>(
+			active_lot_count,
+			lot_bonds,
+			lot_bonds,
+			BENCHMARK_FRAME_ID.saturating_sub(1),
+		)?;
+		Pallet::<T>::lock_in_argonot_bond_participants(BENCHMARK_FRAME_ID);
+		let caller = benchmark_argonot_holder::<T>(active_lot_count.saturating_sub(1));
+		let bond_lot_id =
+			first_bond_lot_id.saturating_add(active_lot_count.saturating_sub(1) as BondLotId);
+		let recipient: T::AccountId = account("bond-lot-recipient", 0, 0);
+		for _ in 0..T::MaxBondLotTransferHistory::get() {
+			BondLotTransfersById::<T>::try_append(
+				bond_lot_id,
+				BondLotTransfer::<T> {
+					frame_id: BENCHMARK_FRAME_ID.saturating_sub(1),
+					from_bond_lot_id: bond_lot_id,
+					from: recipient.clone(),
+					to: caller.clone(),
+					bonds: lot_bonds,
+				},
+			)
+			.map_err(|_| BenchmarkError::Stop("failed to seed bond lot transfer history"))?;
+		}
+
+		whitelist_account!(caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), bond_lot_id, recipient, split_bonds);
+
+		assert_eq!(ArgonotBondLots::<T>::get().len(), active_lot_count.saturating_add(1) as usize);
+		assert_eq!(
+			BondLotById::<T>::get(bond_lot_id).map(|bond_lot| bond_lot.bonds),
+			Some(lot_bonds.saturating_sub(split_bonds)),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_auto_reinvest() -> Result<(), BenchmarkError> {
+		reset_benchmark_state::<T>();
+		// Turning off a single lot reads the lot and clears its pending earnings. Turning off the
+		// account default only removes the default, since pending earnings are dropped lazily.
+		let bonds = minimum_purchase_bonds::<T>();
+		let caller: T::AccountId = account("auto-reinvest-caller", 0, 0);
+		let bond_lot_id: BondLotId = 1;
+		insert_bond_lot::<T, T::Currency>(
+			bond_lot_id,
+			&caller,
+			BondProgram::Vault {
+				vault_id: 1,
+				sharing_percent: Permill::from_percent(20),
+				bonus_percent: Permill::zero(),
+			},
+			bonds,
+			BENCHMARK_FRAME_ID.saturating_sub(1),
+			None,
+			None,
+			false,
+		)?;
+		PendingReinvestmentByBondLot::<T>::insert(bond_lot_id, bonds_to_balance::<T>(bonds));
+		AutoReinvestByAccount::<T>::insert(&caller, true);
+		whitelist_account!(caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), Some(bond_lot_id), false);
+
+		assert_eq!(AutoReinvestByBondLot::<T>::get(bond_lot_id), Some(false));
+		assert!(!PendingReinvestmentByBondLot::<T>::contains_key(bond_lot_id));
+		Ok(())
+	}
+
 	#[benchmark]
 	fn set_bond_lot_flexible() -> Result<(), BenchmarkError> {
 		reset_benchmark_state::<T>();
@@ -478,6 +557,7 @@ mod benchmarks {
 	fn on_frame_transition() -> Result<(), BenchmarkError> {
 		reset_benchmark_state::<T>();
 		seed_on_frame_transition_state::<T>(BENCHMARK_FRAME_ID)?;
+		let first_reinvested_bond_lot_id = NextBondLotId::<T>::get();
 
 		#[block]
 		{
@@ -488,6 +568,10 @@ mod benchmarks {
 			PendingBondReleasesByFrame::<T>::get(BENCHMARK_FRAME_ID).is_empty(),
 			"expected benchmark frame release queue to be emptied",
 		);
+		assert!(
+			BondLotById::<T>::contains_key(first_reinvested_bond_lot_id),
+			"expected frame earnings to be reinvested into new bond lots",
+		);
 		assert_eq!(
 			CurrentFrameVaultCapital::<T>::get()
 				.ok_or(BenchmarkError::Stop("missing current frame capital"))?
@@ -553,6 +637,7 @@ where
 		frame_id.saturating_sub(1),
 	)?;
 	Pallet::<T>::lock_in_argonot_bond_participants(frame_id);
+	seed_full_frame_yield_histories::<T>()?;
 
 	let bid_pool_account = T::MiningBidPoolAccount::get();
 	T::Currency::mint_into(&bid_pool_account, balance::<T>(10_000_000_000_000))
@@ -561,6 +646,28 @@ where
 	Ok(())
 }
 
+/// Fills the yield history of every vault in the locked frame capital, and of the Argonot bond
+/// program, so distribution rewrites the largest histories.
+fn seed_full_frame_yield_histories<T: Config>() -> Result<(), BenchmarkError> {
+	let frame_capital = CurrentFrameVaultCapital::<T>::get()
+		.ok_or(BenchmarkError::Stop("missing frame capital for yield history"))?;
+	let history = BoundedVec::<_, FrameYieldHistory>::truncate_from(
+		(0..FrameYieldHistory::get())
+			.map(|frame_id| TreasuryFrameYield {
+				frame_id: frame_id.into(),
+				bonds: minimum_purchase_bonds::<T>(),
+				capital: bonds_to_balance::<T>(minimum_purchase_bonds::<T>()),
+				earnings: balance::<T>(1_000_000),
+			})
+			.collect(),
+	);
+	for vault_id in frame_capital.vaults.keys() {
+		FrameYieldsByVault::<T>::insert(vault_id, history.clone());
+	}
+	ArgonotFrameYields::<T>::put(history);
+	Ok(())
+}
+
 fn seed_on_frame_transition_state<T: Config>(frame_id: FrameId) -> Result<(), BenchmarkError>
 where
 	T::AccountId: Ord,
@@ -568,7 +675,60 @@ where
 	T::OwnershipCurrency: Mutate<T::AccountId, Balance = T::Balance>,
 {
 	seed_distribution_state::<T>(frame_id.saturating_sub(1))?;
+	seed_reinvesting_vault_bond_lots::<T>()?;
 	seed_pending_bond_releases::<T>(frame_id)?;
+	seed_stale_frame_yield_histories::<T>();
+
+	Ok(())
+}
+
+/// Adds aged out yield histories for as many vaults as a pool holds (vaults that are no longer
+/// open), so the frame transition prunes them.
+fn seed_stale_frame_yield_histories<T: Config>() {
+	let history = BoundedVec::<_, FrameYieldHistory>::truncate_from(vec![TreasuryFrameYield {
+		frame_id: 0,
+		bonds: minimum_purchase_bonds::<T>(),
+		capital: bonds_to_balance::<T>(minimum_purchase_bonds::<T>()),
+		earnings: balance::<T>(1_000_000),
+	}]);
+	for i in 0..T::MaxVaultsPerPool::get() {
+		FrameYieldsByVault::<T>::insert(VaultId::MAX - i, history.clone());
+	}
+}
+
+/// Makes every vault bond lot in the locked frame capital reinvest its earnings, which is the
+/// most expensive payout path. Each lot's pending earnings sit just under a minimum purchase, and
+/// the vault payout sets are emptied and their security raised so every purchase finds space.
+fn seed_reinvesting_vault_bond_lots<T: Config>() -> Result<(), BenchmarkError>
+where
+	T::AccountId: Ord,
+	T::Currency: Mutate<T::AccountId, Balance = T::Balance>,
+{
+	let frame_capital = CurrentFrameVaultCapital::<T>::get()
+		.ok_or(BenchmarkError::Stop("missing frame capital to reinvest"))?;
+	let purchase_amount = bonds_to_balance::<T>(minimum_purchase_bonds::<T>());
+	let pending_amount = purchase_amount.saturating_sub(balance::<T>(1));
+	let mut vault_state =
+		benchmark_bitcoin_vault_provider_state::<T::AccountId, TreasuryBalanceOf<T>>();
+
+	for (vault_id, vault_capital) in frame_capital.vaults.iter() {
+		for allocation in vault_capital.regular_bond_allocations.iter() {
+			let owner = BondLotById::<T>::get(allocation.bond_lot_id)
+				.ok_or(BenchmarkError::Stop("missing reinvesting bond lot"))?
+				.owner;
+			T::Currency::mint_into(&owner, purchase_amount.saturating_mul(balance::<T>(2)))
+				.map_err(|_| BenchmarkError::Stop("failed to fund reinvesting bond holder"))?;
+			AutoReinvestByBondLot::<T>::insert(allocation.bond_lot_id, true);
+			PendingReinvestmentByBondLot::<T>::insert(allocation.bond_lot_id, pending_amount);
+		}
+		BondLotsByVault::<T>::mutate(vault_id, |vault_bonds| {
+			vault_bonds.regular_bond_lots = BoundedVec::default();
+		});
+		if let Some(vault) = vault_state.vaults.get_mut(vault_id) {
+			vault.securitized_satoshis = vault.securitized_satoshis.saturating_mul(1_000);
+		}
+	}
+	set_benchmark_bitcoin_vault_provider_state(vault_state);
 
 	Ok(())
 }
@@ -578,11 +738,12 @@ where
 	T::Currency: Mutate<T::AccountId, Balance = T::Balance>,
 {
 	let lot_bonds = minimum_purchase_bonds::<T>();
+	let first_bond_lot_id = NextBondLotId::<T>::get();
 	let mut pending_releases = BoundedVec::default();
 
 	for liquidation_index in 0..T::MaxPendingUnlocksPerFrame::get() {
 		let owner: T::AccountId = account("pending-liquidation", liquidation_index, 0);
-		let bond_lot_id = liquidation_index as BondLotId;
+		let bond_lot_id = first_bond_lot_id.saturating_add(liquidation_index as BondLotId);
 		let vault_id = 10_000u32.saturating_add(liquidation_index);
 		insert_bond_lot::<T, T::Currency>(
 			bond_lot_id,
@@ -604,7 +765,9 @@ where
 	}
 
 	PendingBondReleasesByFrame::<T>::insert(frame_id, pending_releases);
-	NextBondLotId::<T>::put(T::MaxPendingUnlocksPerFrame::get() as BondLotId);
+	NextBondLotId::<T>::put(
+		first_bond_lot_id.saturating_add(T::MaxPendingUnlocksPerFrame::get() as BondLotId),
+	);
 
 	Ok(())
 }
@@ -651,7 +814,11 @@ where
 			)?;
 
 			accepted_lots
-				.try_push(BondLotSummary { bond_lot_id: next_bond_lot_id, bonds: lot_bonds })
+				.try_push(BondLotSummary {
+					bond_lot_id: next_bond_lot_id,
+					bonds: lot_bonds,
+					transfers: 0,
+				})
 				.map_err(|_| BenchmarkError::Stop("failed to seed accepted bond-lot list"))?;
 			next_bond_lot_id = next_bond_lot_id.saturating_add(1);
 		}
@@ -680,7 +847,7 @@ where
 	let bonds = minimum_purchase_bonds::<T>();
 	let mut summaries = BoundedVec::default();
 	summaries
-		.try_push(BondLotSummary { bond_lot_id: 0, bonds })
+		.try_push(BondLotSummary { bond_lot_id: 0, bonds, transfers: 0 })
 		.map_err(|_| BenchmarkError::Stop("failed to seed benchmark bond-lot summary"))?;
 
 	insert_bond_lot::<T, T::Currency>(
@@ -737,7 +904,7 @@ where
 			true,
 		)?;
 		active_lots
-			.try_push(BondLotSummary { bond_lot_id: next_bond_lot_id, bonds })
+			.try_push(BondLotSummary { bond_lot_id: next_bond_lot_id, bonds, transfers: 0 })
 			.map_err(|_| BenchmarkError::Stop("failed to seed Argonot active set"))?;
 		total_bonds = total_bonds.saturating_add(bonds as u128);
 		next_bond_lot_id = next_bond_lot_id.saturating_add(1);
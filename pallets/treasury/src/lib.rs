@@ -20,7 +20,16 @@ pub use pallet::*;
 /// This pallet allows users to buy whole `1 ARGON` bonds into a Vault's Treasury Pool. Treasury
 /// pools serve as instant liquidity for LockedBitcoins. Each purchase becomes a purchase-level
 /// bond lot that participates in frame payouts until it is liquidated and later released, and
-/// earnings are paid directly instead of compounding back into principal.
+/// earnings are paid directly instead of compounding back into principal. Owners can opt in to
+/// reinvesting vault bond earnings, which buys new bond lots once the earnings add up to whole
+/// bonds.
+///
+/// A bond lot that is not releasing can be transferred to another account, whole or split into a
+/// smaller lot, without leaving frame payouts. Each lot keeps its recent ownership changes in
+/// `BondLotTransfersById`.
+///
+/// The earnings of each bond program are kept for recent frames so the `TreasuryApis` runtime api
+/// can report per-frame APYs and project the yield of a new purchase.
 ///
 /// The current treasury pallet used to model a vault contribution as one aggregated held balance
 /// per `(vault_id, account_id)`. That worked for a "single rolling funder" model, but it breaks
@@ -86,7 +95,11 @@ pub mod pallet {
 	use alloc::vec::Vec;
 	use argon_primitives::{
 		providers::PriceProvider,
-		vault::{TreasuryBonusApprovalProof, TreasuryVaultProvider, VaultTreasuryFrameEarnings},
+		vault::{
+			TreasuryBondYieldProjection, TreasuryBonusApprovalProof, TreasuryFrameApy,
+			TreasuryFrameYield, TreasuryProgramYields, TreasuryVaultProvider,
+			VaultTreasuryFrameEarnings,
+		},
 		BlockSealAuthorityId, OnNewSlot, TreasuryPoolProvider, MICROGONS_PER_ARGON,
 	};
 	use pallet_prelude::argon_primitives::{
@@ -97,7 +110,13 @@ pub mod pallet {
 	};
 	use tracing::info;
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(7);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(8);
+
+	/// Frames are a mining day, so a year is 365 frames.
+	const FRAMES_PER_YEAR: u128 = 365;
+
+	/// The number of recent frames kept in each bond program's yield history.
+	pub type FrameYieldHistory = ConstU32<30>;
 
 	pub type BondLotId = u64;
 	pub type Bonds = u32;
@@ -195,6 +214,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type TreasuryExitDelayFrames: Get<FrameId>;
 
+		/// The maximum number of ownership changes kept in a bond lot's transfer history.
+		#[pallet::constant]
+		type MaxBondLotTransferHistory: Get<u32>;
+
 		/// Provider for the current mining frame id.
 		type MiningFrameTransitionProvider: MiningFrameTransitionProvider;
 
@@ -237,6 +260,18 @@ pub mod pallet {
 	pub type BondLotIdsByAccount<T: Config> =
 		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, BondLotId, (), OptionQuery>;
 
+	/// The most recent ownership changes of each bond lot, oldest first.
+	///
+	/// A lot split off another lot starts with the history of the lot it was split from.
+	#[pallet::storage]
+	pub type BondLotTransfersById<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BondLotId,
+		BoundedVec<BondLotTransfer<T>, T::MaxBondLotTransferHistory>,
+		ValueQuery,
+	>;
+
 	#[pallet::storage]
 	pub type LastBonusApprovalNonceByVaultAndAccount<T: Config> = StorageDoubleMap<
 		_,
@@ -248,6 +283,21 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Accounts whose vault bond lots reinvest their frame earnings by default.
+	#[pallet::storage]
+	pub type AutoReinvestByAccount<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, bool, ValueQuery>;
+
+	/// Per-lot overrides of the owner's auto-reinvest default.
+	#[pallet::storage]
+	pub type AutoReinvestByBondLot<T: Config> =
+		StorageMap<_, Twox64Concat, BondLotId, bool, OptionQuery>;
+
+	/// Earnings paid to an auto-reinvesting bond lot that do not yet cover a reinvested purchase.
+	#[pallet::storage]
+	pub type PendingReinvestmentByBondLot<T: Config> =
+		StorageMap<_, Twox64Concat, BondLotId, T::Balance, ValueQuery>;
+
 	/// Exact treasury bond backing reserved for crosschain minting authorities by account.
 	///
 	/// This is an exact microgon claim, not a mirror of the account's active whole-bond lots.
@@ -291,6 +341,22 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type TotalActiveArgonotBonds<T: Config> = StorageValue<_, Bonds, ValueQuery>;
 
+	/// The payouts made to each vault's regular bond lots in recent frames, newest first. Frames
+	/// older than the history window and closed vaults are pruned at each frame transition.
+	#[pallet::storage]
+	pub type FrameYieldsByVault<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		VaultId,
+		BoundedVec<TreasuryFrameYield<T::Balance>, FrameYieldHistory>,
+		ValueQuery,
+	>;
+
+	/// The payouts made to the Argonot bond program in recent frames, newest first.
+	#[pallet::storage]
+	pub type ArgonotFrameYields<T: Config> =
+		StorageValue<_, BoundedVec<TreasuryFrameYield<T::Balance>, FrameYieldHistory>, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -371,6 +437,38 @@ pub mod pallet {
 			account_id: T::AccountId,
 			bonds: Bonds,
 		},
+		/// Bonds of a lot moved to a new owner. When only part of the lot moved, `bond_lot_id` is
+		/// the new lot split off `from_bond_lot_id`.
+		BondLotTransferred {
+			program_id: BondProgramId,
+			from_bond_lot_id: BondLotId,
+			bond_lot_id: BondLotId,
+			from_account_id: T::AccountId,
+			account_id: T::AccountId,
+			bonds: Bonds,
+		},
+		/// An account changed whether its vault bond earnings are reinvested. A `bond_lot_id`
+		/// means the change only overrides the account default for that lot.
+		AutoReinvestChanged {
+			account_id: T::AccountId,
+			bond_lot_id: Option<BondLotId>,
+			enabled: bool,
+		},
+		/// Frame earnings of a bond lot were reinvested into a new bond lot in the same program.
+		BondLotEarningsReinvested {
+			vault_id: VaultId,
+			from_bond_lot_id: BondLotId,
+			bond_lot_id: BondLotId,
+			account_id: T::AccountId,
+			bonds: Bonds,
+		},
+		/// Frame earnings could not be reinvested and remain paid out to the owner.
+		CouldNotReinvestBondLotEarnings {
+			bond_lot_id: BondLotId,
+			account_id: T::AccountId,
+			amount: T::Balance,
+			dispatch_error: DispatchError,
+		},
 		BondLotFlexibilityChanged {
 			vault_id: VaultId,
 			bond_lot_id: BondLotId,
@@ -434,6 +532,18 @@ pub mod pallet {
 		BondLotCannotBeFlexible,
 		/// The caller does not have permission to perform this action.
 		NoPermissions,
+		/// A bond lot cannot be transferred to its current owner.
+		CannotTransferBondLotToSelf,
+		/// Flexible bond lots stay with the vault operator and cannot be transferred.
+		FlexibleBondLotNotTransferable,
+		/// The transfer must move at least one and at most all of the lot's bonds.
+		InvalidBondLotTransferAmount,
+		/// Both parts of a split bond lot must hold at least the minimum purchase.
+		BondLotSplitBelowMinimum,
+		/// The Argonot active set already has the maximum number of bond lots.
+		MaxActiveArgonotBondLotsExceeded,
+		/// Only vault bond lots can reinvest their earnings.
+		BondLotCannotAutoReinvest,
 	}
 
 	#[pallet::call]
@@ -487,26 +597,11 @@ pub mod pallet {
 			vault_bonds.reserved_bond_space =
 				vault_bonds.reserved_bond_space.saturating_sub(bond_space_to_unreserve);
 
-			let available_bond_space = vault_bonds.available_bond_space(bond_capacity);
-
-			let evicted_summary = if vault_bonds.regular_bond_lots.len() <
-				T::MaxTreasuryContributors::get() as usize
-			{
-				ensure!(bonds <= available_bond_space, Error::<T>::InsufficientBondSpace);
-				None
-			} else {
-				let evicted_summary =
-					vault_bonds.regular_bond_lots.pop().ok_or(Error::<T>::BondPurchaseRejected)?;
-
-				ensure!(bonds > evicted_summary.bonds, Error::<T>::BondPurchaseRejected);
-				ensure!(
-					bonds.saturating_sub(evicted_summary.bonds) <= available_bond_space,
-					Error::<T>::InsufficientBondSpace
-				);
-				Some(evicted_summary)
-			};
+			let evicted_summary =
+				Self::vault_bond_purchase_eviction(&vault_bonds, bonds, bond_capacity)?;
 
 			if let Some(evicted_summary) = evicted_summary {
+				vault_bonds.regular_bond_lots.pop();
 				let evicted_owner = BondLotById::<T>::get(evicted_summary.bond_lot_id)
 					.ok_or(Error::<T>::BondLotNotFound)?
 					.owner
@@ -529,7 +624,7 @@ pub mod pallet {
 				.unwrap_or(vault_bonds.regular_bond_lots.len());
 			vault_bonds
 				.regular_bond_lots
-				.try_insert(insert_index, BondLotSummary { bond_lot_id, bonds })
+				.try_insert(insert_index, BondLotSummary { bond_lot_id, bonds, transfers: 0 })
 				.map_err(|_| Error::<T>::MaxAcceptedBondLotsExceeded)?;
 
 			let program = BondProgram::Vault { vault_id, sharing_percent, bonus_percent };
@@ -620,26 +715,8 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 			ensure!(bonds >= Self::minimum_purchase_bonds(), Error::<T>::BondPurchaseBelowMinimum);
 
-			let current_total_bonds = TotalActiveArgonotBonds::<T>::get();
-			let max_active_bonds = Self::maximum_active_argonot_bonds();
-			let active_lots = ArgonotBondLots::<T>::get();
-			let active_lot_count = active_lots.len() as u32;
-			let mut evicted_bond_lot_id = None;
-
-			let next_total_bonds = if active_lot_count < T::MaxActiveArgonotBondLots::get() {
-				current_total_bonds.checked_add(bonds).ok_or(ArithmeticError::Overflow)?
-			} else {
-				let floor_lot = active_lots.first().ok_or(Error::<T>::InternalError)?;
-				ensure!(bonds > floor_lot.bonds, Error::<T>::ArgonotBondPurchaseBelowCutoff);
-				evicted_bond_lot_id = Some(floor_lot.bond_lot_id);
-
-				current_total_bonds
-					.checked_sub(floor_lot.bonds)
-					.ok_or(ArithmeticError::Underflow)?
-					.checked_add(bonds)
-					.ok_or(ArithmeticError::Overflow)?
-			};
-			ensure!(next_total_bonds <= max_active_bonds, Error::<T>::ArgonotBondPurchaseAboveCap);
+			let (next_total_bonds, evicted_summary) = Self::argonot_bond_purchase_eviction(bonds)?;
+			let evicted_bond_lot_id = evicted_summary.map(|summary| summary.bond_lot_id);
 
 			let program = BondProgram::Argonot;
 			let program_id = program.id();
@@ -678,7 +755,7 @@ pub mod pallet {
 					})
 					.unwrap_or(active_lots.len());
 				active_lots
-					.try_insert(insert_index, BondLotSummary { bond_lot_id, bonds })
+					.try_insert(insert_index, BondLotSummary { bond_lot_id, bonds, transfers: 0 })
 					.map_err(|_| Error::<T>::InternalError)?;
 				Ok(())
 			})?;
@@ -756,7 +833,7 @@ pub mod pallet {
 						.regular_bond_lots
 						.try_insert(
 							insert_index,
-							BondLotSummary { bond_lot_id, bonds: bond_lot.bonds },
+							BondLotSummary { bond_lot_id, bonds: bond_lot.bonds, transfers: 0 },
 						)
 						.map_err(|_| Error::<T>::MaxAcceptedBondLotsExceeded)?;
 					vault_bonds.flexible_bonds = new_flexible_bonds;
@@ -797,6 +874,172 @@ pub mod pallet {
 			Self::deposit_event(Event::ReservedBondSpaceChanged { vault_id, reserved_bond_space });
 			Ok(())
 		}
+
+		/// Transfer bonds of a lot to another account while they keep earning.
+		///
+		/// Moving all of the lot's bonds hands over the lot itself. Moving fewer splits them off
+		/// into a new lot owned by the recipient, and both parts must hold at least the minimum
+		/// purchase. Split lots keep the original program, purchase frame and participated frames,
+		/// and take over their share of the frame already locked in when it has room for another
+		/// lot. Releasing and flexible lots cannot be transferred.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::transfer_bond_lot())]
+		pub fn transfer_bond_lot(
+			origin: OriginFor<T>,
+			bond_lot_id: BondLotId,
+			recipient: T::AccountId,
+			bonds: Bonds,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(who != recipient, Error::<T>::CannotTransferBondLotToSelf);
+			let mut bond_lot =
+				BondLotById::<T>::get(bond_lot_id).ok_or(Error::<T>::BondLotNotFound)?;
+			ensure!(bond_lot.owner == who, Error::<T>::NotBondLotOwner);
+			ensure!(bond_lot.release_reason.is_none(), Error::<T>::BondLotAlreadyReleasing);
+			ensure!(!bond_lot.is_flexible, Error::<T>::FlexibleBondLotNotTransferable);
+			ensure!(bonds > 0 && bonds <= bond_lot.bonds, Error::<T>::InvalidBondLotTransferAmount);
+			let remaining_bonds = bond_lot.bonds.saturating_sub(bonds);
+			if remaining_bonds > 0 {
+				let minimum_bonds = Self::minimum_purchase_bonds();
+				ensure!(
+					bonds >= minimum_bonds && remaining_bonds >= minimum_bonds,
+					Error::<T>::BondLotSplitBelowMinimum
+				);
+			}
+
+			let amount = Self::bonds_to_balance(bonds);
+			match bond_lot.program {
+				BondProgram::Vault { .. } => {
+					let (_, current_hold) = Self::account_vault_bond_status(&who)?;
+					ensure!(
+						current_hold.saturating_sub(amount) >=
+							Self::encumbered_bond_microgons(&who),
+						Error::<T>::ActiveBondAmountBelowEncumberedBacking,
+					);
+					Self::transfer_hold::<T::Currency>(&who, &recipient, amount)?;
+				},
+				BondProgram::Argonot =>
+					Self::transfer_hold::<T::OwnershipCurrency>(&who, &recipient, amount)?,
+			}
+
+			let mut transfers = BondLotTransfersById::<T>::get(bond_lot_id);
+			if transfers.is_full() {
+				transfers.remove(0);
+			}
+			transfers
+				.try_push(BondLotTransfer {
+					frame_id: T::MiningFrameTransitionProvider::get_current_frame_id(),
+					from_bond_lot_id: bond_lot_id,
+					from: who.clone(),
+					to: recipient.clone(),
+					bonds,
+				})
+				.map_err(|_| Error::<T>::InternalError)?;
+
+			let program = bond_lot.program;
+			let recipient_bond_lot_id = if remaining_bonds == 0 {
+				Self::record_bond_lot_summary_transfer(program, bond_lot_id, None)?;
+				bond_lot.owner = recipient.clone();
+				BondLotIdsByAccount::<T>::remove(&who, bond_lot_id);
+				AutoReinvestByBondLot::<T>::remove(bond_lot_id);
+				PendingReinvestmentByBondLot::<T>::remove(bond_lot_id);
+				BondLotById::<T>::insert(bond_lot_id, bond_lot);
+				BondLotTransfersById::<T>::insert(bond_lot_id, transfers);
+				bond_lot_id
+			} else {
+				let split_bond_lot_id = Self::next_bond_lot_id()?;
+				Self::record_bond_lot_summary_transfer(
+					program,
+					bond_lot_id,
+					Some((split_bond_lot_id, bonds)),
+				)?;
+				Self::split_current_frame_participation(
+					program,
+					bond_lot_id,
+					bond_lot.bonds,
+					split_bond_lot_id,
+					bonds,
+				);
+				BondLotById::<T>::insert(
+					split_bond_lot_id,
+					BondLot {
+						owner: recipient.clone(),
+						bonds,
+						last_frame_earnings_frame_id: None,
+						last_frame_earnings: None,
+						cumulative_earnings: T::Balance::zero(),
+						..bond_lot.clone()
+					},
+				);
+				bond_lot.bonds = remaining_bonds;
+				BondLotById::<T>::insert(bond_lot_id, bond_lot);
+				BondLotTransfersById::<T>::insert(split_bond_lot_id, transfers);
+				split_bond_lot_id
+			};
+			BondLotIdsByAccount::<T>::insert(&recipient, recipient_bond_lot_id, ());
+
+			if matches!(program, BondProgram::Vault { .. }) {
+				Self::update_account_vault_bond_total(&who)?;
+				Self::update_account_vault_bond_total(&recipient)?;
+			}
+			Self::deposit_event(Event::<T>::BondLotTransferred {
+				program_id: program.id(),
+				from_bond_lot_id: bond_lot_id,
+				bond_lot_id: recipient_bond_lot_id,
+				from_account_id: who,
+				account_id: recipient,
+				bonds,
+			});
+			Ok(())
+		}
+
+		/// Opt in or out of reinvesting vault bond earnings.
+		///
+		/// Without a `bond_lot_id` this sets the default for all of the caller's vault bond lots,
+		/// and with one it overrides the default for that lot. Earnings are still paid out each
+		/// frame. Once a lot's paid earnings cover a minimum purchase of whole bonds, those bonds
+		/// are held from the owner and bought as a new lot in the same program if the vault has
+		/// available bond space. Otherwise the earnings simply stay paid out.
+		///
+		/// Turning reinvestment off for a lot drops the earnings it still has pending toward a
+		/// purchase. Turning off the account default drops them lazily, at the next payout of each
+		/// lot that is no longer reinvesting.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::set_auto_reinvest())]
+		pub fn set_auto_reinvest(
+			origin: OriginFor<T>,
+			bond_lot_id: Option<BondLotId>,
+			enabled: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			match bond_lot_id {
+				Some(bond_lot_id) => {
+					let bond_lot =
+						BondLotById::<T>::get(bond_lot_id).ok_or(Error::<T>::BondLotNotFound)?;
+					ensure!(bond_lot.owner == who, Error::<T>::NotBondLotOwner);
+					ensure!(
+						matches!(bond_lot.program, BondProgram::Vault { .. }),
+						Error::<T>::BondLotCannotAutoReinvest
+					);
+					AutoReinvestByBondLot::<T>::insert(bond_lot_id, enabled);
+					if !enabled {
+						PendingReinvestmentByBondLot::<T>::remove(bond_lot_id);
+					}
+				},
+				None =>
+					if enabled {
+						AutoReinvestByAccount::<T>::insert(&who, true);
+					} else {
+						AutoReinvestByAccount::<T>::remove(&who);
+					},
+			}
+			Self::deposit_event(Event::<T>::AutoReinvestChanged {
+				account_id: who,
+				bond_lot_id,
+				enabled,
+			});
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -842,6 +1085,38 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Moves held bond funds to another account, keeping them on hold.
+		fn transfer_hold<C>(
+			from: &T::AccountId,
+			to: &T::AccountId,
+			amount: T::Balance,
+		) -> DispatchResult
+		where
+			C: MutateHold<T::AccountId, Reason = T::RuntimeHoldReason, Balance = T::Balance>,
+		{
+			if amount.is_zero() {
+				return Ok(());
+			}
+			let reason = HoldReason::ContributedToTreasury;
+			if C::balance_on_hold(&reason.into(), to).is_zero() {
+				frame_system::Pallet::<T>::inc_providers(to);
+			}
+			C::transfer_on_hold(
+				&reason.into(),
+				from,
+				to,
+				amount,
+				Precision::Exact,
+				Restriction::OnHold,
+				Fortitude::Polite,
+			)?;
+
+			if C::balance_on_hold(&reason.into(), from).is_zero() {
+				frame_system::Pallet::<T>::dec_providers(from)?;
+			}
+			Ok(())
+		}
+
 		fn validate_bonus_approval(
 			vault_id: VaultId,
 			beneficiary: &T::AccountId,
@@ -1000,7 +1275,9 @@ pub mod pallet {
 				remaining_bid_pool.saturating_reduce(gross_vault_earnings);
 
 				let mut gross_lot_yield_total = T::Balance::zero();
+				let mut paid_payout_total = T::Balance::zero();
 				let mut earnings_for_vault = T::Balance::zero();
+				let mut regular_bonds: Bonds = 0;
 
 				for allocation in vault_capital.regular_bond_allocations.iter() {
 					let Some(bond_lot) = BondLotById::<T>::get(allocation.bond_lot_id) else {
@@ -1015,6 +1292,7 @@ pub mod pallet {
 					let gross_lot_yield =
 						allocation.prorata.saturating_mul_int(gross_vault_earnings);
 					gross_lot_yield_total.saturating_accrue(gross_lot_yield);
+					regular_bonds.saturating_accrue(bond_lot.bonds);
 
 					let bonder_percent = Permill::from_parts(
 						sharing_percent.deconstruct().saturating_add(bonus_percent.deconstruct()),
@@ -1034,7 +1312,7 @@ pub mod pallet {
 							frame_id,
 							vault_id: *vault_id,
 							bond_lot_id: allocation.bond_lot_id,
-							account_id: bond_lot.owner,
+							account_id: bond_lot.owner.clone(),
 							amount: paid_payout,
 							dispatch_error: e,
 						});
@@ -1042,7 +1320,27 @@ pub mod pallet {
 						paid_payout = T::Balance::zero();
 					}
 
+					paid_payout_total.saturating_accrue(paid_payout);
 					Self::record_bond_lot_earnings(allocation.bond_lot_id, frame_id, paid_payout);
+					Self::reinvest_bond_lot_earnings(
+						allocation.bond_lot_id,
+						&bond_lot,
+						paid_payout,
+					);
+				}
+
+				if regular_bonds > 0 {
+					FrameYieldsByVault::<T>::mutate(vault_id, |yields| {
+						let _ = yields.force_insert_keep_left(
+							0,
+							TreasuryFrameYield {
+								frame_id,
+								bonds: regular_bonds,
+								capital: Self::bonds_to_balance(regular_bonds),
+								earnings: paid_payout_total,
+							},
+						);
+					});
 				}
 
 				let flexible_bond_yield =
@@ -1149,10 +1447,11 @@ pub mod pallet {
 					}
 				}
 
-				let regular_bonds_eligible = bond_capacity.min(regular_bonds);
-				let flexible_bonds_eligible = vault_bonds
-					.flexible_bonds
-					.min(bond_capacity.saturating_sub(regular_bonds_eligible));
+				let (regular_bonds_eligible, flexible_bonds_eligible) = Self::frame_eligible_bonds(
+					regular_bonds,
+					vault_bonds.flexible_bonds,
+					bond_capacity,
+				);
 				let eligible_bonds = regular_bonds_eligible.saturating_add(flexible_bonds_eligible);
 				let flexible_prorata = FixedU128::from_rational(
 					flexible_bonds_eligible as u128,
@@ -1202,10 +1501,28 @@ pub mod pallet {
 			info!("Starting treasury bond frame {frame_id}. Distributing frame {payout_frame}.");
 			Self::release_pending_bond_lots(frame_id);
 			Self::distribute_bid_pool(payout_frame);
+			Self::prune_frame_yields(payout_frame);
 			Self::lock_in_argonot_bond_participants(frame_id);
 			Self::lock_in_vault_capital(frame_id);
 		}
 
+		/// Drops vault yields that have aged out of the history window (eg, vaults that stopped
+		/// earning) and the yields of closed vaults.
+		pub(crate) fn prune_frame_yields(payout_frame: FrameId) {
+			let oldest_frame_id =
+				payout_frame.saturating_sub(FrameYieldHistory::get().saturating_sub(1) as FrameId);
+			FrameYieldsByVault::<T>::translate(
+				|vault_id,
+				 mut yields: BoundedVec<TreasuryFrameYield<T::Balance>, FrameYieldHistory>| {
+					if !T::TreasuryVaultProvider::is_vault_open(vault_id) {
+						return None;
+					}
+					yields.retain(|x| x.frame_id >= oldest_frame_id);
+					(!yields.is_empty()).then_some(yields)
+				},
+			);
+		}
+
 		fn distribute_argonot_bond_pool(
 			frame_id: FrameId,
 			bid_pool_account: &T::AccountId,
@@ -1228,6 +1545,7 @@ pub mod pallet {
 			argonot_bond_pool_distributed = capped_argonot_bond_pool;
 
 			let mut gross_argonot_yield_total = T::Balance::zero();
+			let mut paid_payout_total = T::Balance::zero();
 			for participant in argonot_participants.bond_lots.iter() {
 				let Some(bond_lot) = BondLotById::<T>::get(participant.bond_lot_id) else {
 					continue;
@@ -1262,12 +1580,24 @@ pub mod pallet {
 					paid_payout = T::Balance::zero();
 				}
 
+				paid_payout_total.saturating_accrue(paid_payout);
 				Self::record_bond_lot_earnings(participant.bond_lot_id, frame_id, paid_payout);
 			}
 
 			treasury_refund_total.saturating_accrue(
 				capped_argonot_bond_pool.saturating_sub(gross_argonot_yield_total),
 			);
+			ArgonotFrameYields::<T>::mutate(|yields| {
+				let _ = yields.force_insert_keep_left(
+					0,
+					TreasuryFrameYield {
+						frame_id,
+						bonds: argonot_participants.total_bonds,
+						capital: Self::argonot_bonds_to_microgons(argonot_participants.total_bonds),
+						earnings: paid_payout_total,
+					},
+				);
+			});
 
 			(remaining_bid_pool, treasury_refund_total, argonot_bond_pool_distributed)
 		}
@@ -1320,6 +1650,9 @@ pub mod pallet {
 
 					BondLotIdsByAccount::<T>::remove(&bond_lot.owner, bond_lot_id);
 					BondLotById::<T>::remove(bond_lot_id);
+					BondLotTransfersById::<T>::remove(bond_lot_id);
+					AutoReinvestByBondLot::<T>::remove(bond_lot_id);
+					PendingReinvestmentByBondLot::<T>::remove(bond_lot_id);
 					Self::deposit_event(Event::<T>::BondLotReleased {
 						frame_id: due_frame,
 						program_id,
@@ -1384,6 +1717,135 @@ pub mod pallet {
 			});
 		}
 
+		fn is_auto_reinvesting(account_id: &T::AccountId, bond_lot_id: BondLotId) -> bool {
+			AutoReinvestByBondLot::<T>::get(bond_lot_id)
+				.unwrap_or_else(|| AutoReinvestByAccount::<T>::get(account_id))
+		}
+
+		/// Tracks earnings just paid to an auto-reinvesting vault bond lot, and reinvests them once
+		/// they cover a minimum purchase of whole bonds. Leftover microgons stay pending for later
+		/// frames. If the purchase fails, the earnings stay paid out. A lot that is no longer
+		/// reinvesting drops its pending earnings.
+		fn reinvest_bond_lot_earnings(
+			bond_lot_id: BondLotId,
+			bond_lot: &BondLot<T>,
+			paid_payout: T::Balance,
+		) {
+			let BondProgram::Vault { vault_id, .. } = bond_lot.program else {
+				return;
+			};
+			if paid_payout.is_zero() || bond_lot.release_reason.is_some() {
+				return;
+			}
+			if !Self::is_auto_reinvesting(&bond_lot.owner, bond_lot_id) {
+				// reinvestment was turned off after these earnings started pending
+				if PendingReinvestmentByBondLot::<T>::contains_key(bond_lot_id) {
+					PendingReinvestmentByBondLot::<T>::remove(bond_lot_id);
+				}
+				return;
+			}
+
+			let pending =
+				PendingReinvestmentByBondLot::<T>::get(bond_lot_id).saturating_add(paid_payout);
+			let bonds = Self::balance_to_bonds(pending);
+			if bonds < Self::minimum_purchase_bonds() {
+				PendingReinvestmentByBondLot::<T>::insert(bond_lot_id, pending);
+				return;
+			}
+			PendingReinvestmentByBondLot::<T>::remove(bond_lot_id);
+
+			let result = with_storage_layer(|| {
+				Self::buy_reinvested_bond_lot(vault_id, bond_lot_id, bond_lot, bonds)
+			});
+			match result {
+				Ok(()) => {
+					let remainder = pending.saturating_sub(Self::bonds_to_balance(bonds));
+					if !remainder.is_zero() {
+						PendingReinvestmentByBondLot::<T>::insert(bond_lot_id, remainder);
+					}
+				},
+				Err(e) => {
+					Self::deposit_event(Event::<T>::CouldNotReinvestBondLotEarnings {
+						bond_lot_id,
+						account_id: bond_lot.owner.clone(),
+						amount: pending,
+						dispatch_error: e,
+					});
+				},
+			}
+		}
+
+		fn buy_reinvested_bond_lot(
+			vault_id: VaultId,
+			from_bond_lot_id: BondLotId,
+			from_bond_lot: &BondLot<T>,
+			bonds: Bonds,
+		) -> DispatchResult {
+			ensure!(
+				T::TreasuryVaultProvider::is_vault_open(vault_id),
+				Error::<T>::VaultNotAcceptingBondPurchases
+			);
+			let bond_capacity =
+				Self::balance_to_bonds(Self::get_vault_securitized_funds_cap(vault_id));
+			let owner = &from_bond_lot.owner;
+			let bond_lot_id = Self::next_bond_lot_id()?;
+			BondLotsByVault::<T>::try_mutate(vault_id, |vault_bonds| -> DispatchResult {
+				ensure!(
+					bonds <= vault_bonds.available_bond_space(bond_capacity),
+					Error::<T>::InsufficientBondSpace
+				);
+				let insert_index = vault_bonds
+					.regular_bond_lots
+					.iter()
+					.position(|summary| summary.bonds < bonds)
+					.unwrap_or(vault_bonds.regular_bond_lots.len());
+				vault_bonds
+					.regular_bond_lots
+					.try_insert(insert_index, BondLotSummary { bond_lot_id, bonds, transfers: 0 })
+					.map_err(|_| Error::<T>::MaxAcceptedBondLotsExceeded)?;
+				Ok(())
+			})?;
+			Self::create_hold::<T::Currency>(owner, Self::bonds_to_balance(bonds))?;
+
+			// a reinvestment is a new purchase, so it takes the vault's current terms and no bonus
+			let sharing_percent =
+				T::TreasuryVaultProvider::get_vault_profit_sharing_percent(vault_id)
+					.unwrap_or_default();
+			BondLotById::<T>::insert(
+				bond_lot_id,
+				BondLot {
+					owner: owner.clone(),
+					program: BondProgram::Vault {
+						vault_id,
+						sharing_percent,
+						bonus_percent: Permill::zero(),
+					},
+					bonds,
+					is_flexible: false,
+					created_frame_id: T::MiningFrameTransitionProvider::get_current_frame_id(),
+					participated_frames: 0,
+					last_frame_earnings_frame_id: None,
+					last_frame_earnings: None,
+					cumulative_earnings: T::Balance::zero(),
+					release_frame_id: None,
+					release_reason: None,
+				},
+			);
+			BondLotIdsByAccount::<T>::insert(owner, bond_lot_id, ());
+			if let Some(enabled) = AutoReinvestByBondLot::<T>::get(from_bond_lot_id) {
+				AutoReinvestByBondLot::<T>::insert(bond_lot_id, enabled);
+			}
+			Self::update_account_vault_bond_total(owner)?;
+			Self::deposit_event(Event::<T>::BondLotEarningsReinvested {
+				vault_id,
+				from_bond_lot_id,
+				bond_lot_id,
+				account_id: owner.clone(),
+				bonds,
+			});
+			Ok(())
+		}
+
 		fn account_vault_bond_status(
 			account_id: &T::AccountId,
 		) -> Result<(T::Balance, T::Balance), Error<T>> {
@@ -1446,6 +1908,247 @@ pub mod pallet {
 			EncumberedBondMicrogonsByAccount::<T>::get(account_id)
 		}
 
+		/// The recent frame yields of the Argonot bond program and of each vault's bond program,
+		/// from the payouts bond holders actually received.
+		pub fn bond_program_yields() -> Vec<TreasuryProgramYields<T::Balance>> {
+			let mut program_yields = vec![TreasuryProgramYields {
+				vault_id: None,
+				sharing_percent: Permill::from_percent(100),
+				frames: ArgonotFrameYields::<T>::get().into_iter().map(Self::frame_apy).collect(),
+			}];
+
+			let mut vault_frame_yields = FrameYieldsByVault::<T>::iter().collect::<Vec<_>>();
+			vault_frame_yields.sort_by_key(|(vault_id, _)| *vault_id);
+			for (vault_id, frame_yields) in vault_frame_yields {
+				let sharing_percent =
+					T::TreasuryVaultProvider::get_vault_profit_sharing_percent(vault_id)
+						.unwrap_or_default();
+				program_yields.push(TreasuryProgramYields {
+					vault_id: Some(vault_id),
+					sharing_percent,
+					frames: frame_yields.into_iter().map(Self::frame_apy).collect(),
+				});
+			}
+			program_yields
+		}
+
+		/// Projects the yield of buying `bonds` for one frame, using the current bid pool as the
+		/// estimate of the pool the purchase will share. The purchase is checked like a real one,
+		/// so it may bump the smallest accepted lot, and a vault that falls outside the top
+		/// `MaxVaultsPerPool` by eligible bonds earns nothing.
+		pub fn project_bond_yield(
+			vault_id: Option<VaultId>,
+			bonds: Bonds,
+		) -> Result<TreasuryBondYieldProjection<T::Balance>, DispatchError> {
+			ensure!(bonds >= Self::minimum_purchase_bonds(), Error::<T>::BondPurchaseBelowMinimum);
+
+			let Some(vault_id) = vault_id else {
+				let (next_total_bonds, bumped_summary) =
+					Self::argonot_bond_purchase_eviction(bonds)?;
+				let (argonot_bond_pool, _) = Self::projected_bid_pools(true);
+				let earnings = Perbill::from_rational(bonds as u128, next_total_bonds as u128)
+					.mul_floor(argonot_bond_pool);
+				let capital = Self::argonot_bonds_to_microgons(bonds);
+				return Ok(TreasuryBondYieldProjection {
+					vault_id: None,
+					bonds,
+					bumped_bond_lot_id: bumped_summary.as_ref().map(|summary| summary.bond_lot_id),
+					bumped_bonds: bumped_summary.map(|summary| summary.bonds).unwrap_or_default(),
+					is_participating: true,
+					capital,
+					earnings,
+					apy: Self::annualized_yield(earnings, capital),
+				});
+			};
+
+			ensure!(
+				T::TreasuryVaultProvider::is_vault_open(vault_id),
+				Error::<T>::VaultNotAcceptingBondPurchases
+			);
+			let bond_capacity =
+				Self::balance_to_bonds(Self::get_vault_securitized_funds_cap(vault_id));
+			ensure!(!bond_capacity.is_zero(), Error::<T>::VaultNotAcceptingBondPurchases);
+
+			let mut vault_bonds = BondLotsByVault::<T>::get(vault_id);
+			let bumped_summary =
+				Self::vault_bond_purchase_eviction(&vault_bonds, bonds, bond_capacity)?;
+			if bumped_summary.is_some() {
+				vault_bonds.regular_bond_lots.pop();
+			}
+			let regular_bonds = vault_bonds.regular_bonds().saturating_add(bonds);
+			let (regular_bonds_eligible, flexible_bonds_eligible) = Self::frame_eligible_bonds(
+				regular_bonds,
+				vault_bonds.flexible_bonds,
+				bond_capacity,
+			);
+			let eligible_bonds = regular_bonds_eligible.saturating_add(flexible_bonds_eligible);
+
+			// rank the vaults the way the next frame's capital lock-in will
+			let mut vault_candidates = vec![(vault_id, eligible_bonds)];
+			for (other_vault_id, other_vault_bonds) in BondLotsByVault::<T>::iter() {
+				if other_vault_id == vault_id {
+					continue;
+				}
+				let other_bond_capacity =
+					Self::balance_to_bonds(Self::get_vault_securitized_funds_cap(other_vault_id));
+				let (regular, flexible) = Self::frame_eligible_bonds(
+					other_vault_bonds.regular_bonds(),
+					other_vault_bonds.flexible_bonds,
+					other_bond_capacity,
+				);
+				let other_eligible_bonds = regular.saturating_add(flexible);
+				if !other_eligible_bonds.is_zero() {
+					vault_candidates.push((other_vault_id, other_eligible_bonds));
+				}
+			}
+			vault_candidates.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+			vault_candidates.truncate(T::MaxVaultsPerPool::get() as usize);
+			let is_participating = vault_candidates.iter().any(|(id, _)| *id == vault_id);
+
+			let mut earnings = T::Balance::zero();
+			if is_participating {
+				let total_eligible_bonds = vault_candidates
+					.iter()
+					.fold(0u128, |acc, (_, eligible)| acc.saturating_add(*eligible as u128));
+				let (_, vault_bid_pool) =
+					Self::projected_bid_pools(!TotalActiveArgonotBonds::<T>::get().is_zero());
+				let gross_vault_earnings =
+					Perbill::from_rational(eligible_bonds as u128, total_eligible_bonds)
+						.mul_floor(vault_bid_pool);
+				let gross_lot_yield = FixedU128::from_rational(
+					bonds as u128,
+					bond_capacity.max(regular_bonds) as u128,
+				)
+				.saturating_mul_int(gross_vault_earnings);
+				let sharing_percent =
+					T::TreasuryVaultProvider::get_vault_profit_sharing_percent(vault_id)
+						.unwrap_or_default();
+				earnings = sharing_percent.mul_floor(gross_lot_yield);
+			}
+
+			let capital = Self::bonds_to_balance(bonds);
+			Ok(TreasuryBondYieldProjection {
+				vault_id: Some(vault_id),
+				bonds,
+				bumped_bond_lot_id: bumped_summary.as_ref().map(|summary| summary.bond_lot_id),
+				bumped_bonds: bumped_summary.map(|summary| summary.bonds).unwrap_or_default(),
+				is_participating,
+				capital,
+				earnings,
+				apy: Self::annualized_yield(earnings, capital),
+			})
+		}
+
+		fn frame_apy(frame_yield: TreasuryFrameYield<T::Balance>) -> TreasuryFrameApy<T::Balance> {
+			TreasuryFrameApy {
+				frame_id: frame_yield.frame_id,
+				bonds: frame_yield.bonds,
+				capital: frame_yield.capital,
+				earnings: frame_yield.earnings,
+				apy: Self::annualized_yield(frame_yield.earnings, frame_yield.capital),
+			}
+		}
+
+		fn annualized_yield(earnings: T::Balance, capital: T::Balance) -> FixedU128 {
+			if capital.is_zero() {
+				return FixedU128::zero();
+			}
+			FixedU128::from_rational(earnings.into(), capital.into())
+				.saturating_mul(FixedU128::saturating_from_integer(FRAMES_PER_YEAR))
+		}
+
+		/// Splits the current bid pool the way the next distribution would, into the Argonot bond
+		/// pool and the pool shared by vaults.
+		fn projected_bid_pools(has_argonot_bonds: bool) -> (T::Balance, T::Balance) {
+			let full_bid_pool_amount = T::Currency::balance(&T::MiningBidPoolAccount::get());
+			let bid_pool_after_reserves = full_bid_pool_amount.saturating_sub(
+				T::PercentForTreasuryReserves::get().mul_ceil(full_bid_pool_amount),
+			);
+			let argonot_bond_pool = if has_argonot_bonds {
+				T::PercentForArgonotBondPool::get()
+					.mul_floor(full_bid_pool_amount)
+					.min(bid_pool_after_reserves)
+			} else {
+				T::Balance::zero()
+			};
+			(argonot_bond_pool, bid_pool_after_reserves.saturating_sub(argonot_bond_pool))
+		}
+
+		/// The accepted lot a vault bond purchase would bump if the accepted lots are full. The
+		/// purchase must fit in the available bond space once the bumped lot's bonds are freed.
+		fn vault_bond_purchase_eviction(
+			vault_bonds: &VaultBondState<T>,
+			bonds: Bonds,
+			bond_capacity: Bonds,
+		) -> Result<Option<BondLotSummary>, Error<T>> {
+			let available_bond_space = vault_bonds.available_bond_space(bond_capacity);
+			if vault_bonds.regular_bond_lots.len() < T::MaxTreasuryContributors::get() as usize {
+				ensure!(bonds <= available_bond_space, Error::<T>::InsufficientBondSpace);
+				return Ok(None);
+			}
+
+			let evicted_summary = vault_bonds
+				.regular_bond_lots
+				.last()
+				.cloned()
+				.ok_or(Error::<T>::BondPurchaseRejected)?;
+			ensure!(bonds > evicted_summary.bonds, Error::<T>::BondPurchaseRejected);
+			ensure!(
+				bonds.saturating_sub(evicted_summary.bonds) <= available_bond_space,
+				Error::<T>::InsufficientBondSpace
+			);
+			Ok(Some(evicted_summary))
+		}
+
+		/// The active Argonot bond total after a purchase, and the floor lot the purchase would
+		/// bump if the active lots are full.
+		fn argonot_bond_purchase_eviction(
+			bonds: Bonds,
+		) -> Result<(Bonds, Option<BondLotSummary>), DispatchError> {
+			let current_total_bonds = TotalActiveArgonotBonds::<T>::get();
+			let active_lots = ArgonotBondLots::<T>::get();
+
+			let (next_total_bonds, evicted_summary) = if (active_lots.len() as u32) <
+				T::MaxActiveArgonotBondLots::get()
+			{
+				(current_total_bonds.checked_add(bonds).ok_or(ArithmeticError::Overflow)?, None)
+			} else {
+				let floor_lot = active_lots.first().cloned().ok_or(Error::<T>::InternalError)?;
+				ensure!(bonds > floor_lot.bonds, Error::<T>::ArgonotBondPurchaseBelowCutoff);
+				let next_total_bonds = current_total_bonds
+					.checked_sub(floor_lot.bonds)
+					.ok_or(ArithmeticError::Underflow)?
+					.checked_add(bonds)
+					.ok_or(ArithmeticError::Overflow)?;
+				(next_total_bonds, Some(floor_lot))
+			};
+			ensure!(
+				next_total_bonds <= Self::maximum_active_argonot_bonds(),
+				Error::<T>::ArgonotBondPurchaseAboveCap
+			);
+			Ok((next_total_bonds, evicted_summary))
+		}
+
+		/// The vault bonds that earn in a frame: regular bonds up to the Bitcoin-backed capacity,
+		/// then flexible bonds in the capacity left over.
+		fn frame_eligible_bonds(
+			regular_bonds: Bonds,
+			flexible_bonds: Bonds,
+			bond_capacity: Bonds,
+		) -> (Bonds, Bonds) {
+			let regular_bonds_eligible = bond_capacity.min(regular_bonds);
+			let flexible_bonds_eligible =
+				flexible_bonds.min(bond_capacity.saturating_sub(regular_bonds_eligible));
+			(regular_bonds_eligible, flexible_bonds_eligible)
+		}
+
+		/// The microgon value of Argonot bonds at the current argonot price (zero without a price).
+		fn argonot_bonds_to_microgons(bonds: Bonds) -> T::Balance {
+			T::PriceProvider::get_microgons_per_argonot()
+				.unwrap_or_default()
+				.saturating_mul(T::Balance::from(bonds as u128))
+		}
+
 		fn maximum_active_argonot_bonds() -> Bonds {
 			let circulation = T::OwnershipCurrency::total_issuance();
 			let cap_balance = T::MaxArgonotBondedPercentOfCirculation::get().mul_floor(circulation);
@@ -1489,6 +2192,134 @@ pub mod pallet {
 			});
 		}
 
+		/// Counts a transfer on the lot's active-set summary. A split also adds the split lot to
+		/// the active set, carrying over the transfer count of the lot it came from.
+		fn record_bond_lot_summary_transfer(
+			program: BondProgram,
+			bond_lot_id: BondLotId,
+			split: Option<(BondLotId, Bonds)>,
+		) -> Result<(), Error<T>> {
+			let record = |summaries: &mut Vec<BondLotSummary>| -> Result<(), Error<T>> {
+				let summary = summaries
+					.iter_mut()
+					.find(|summary| summary.bond_lot_id == bond_lot_id)
+					.ok_or(Error::<T>::BondLotNotFound)?;
+				let transfers = summary.transfers.saturating_add(1);
+				match split {
+					Some((split_bond_lot_id, bonds)) => {
+						summary.bonds = summary.bonds.saturating_sub(bonds);
+						summaries.push(BondLotSummary {
+							bond_lot_id: split_bond_lot_id,
+							bonds,
+							transfers,
+						});
+					},
+					None => summary.transfers = transfers,
+				}
+				Ok(())
+			};
+
+			match program {
+				BondProgram::Vault { vault_id, .. } =>
+					BondLotsByVault::<T>::try_mutate(vault_id, |vault_bonds| {
+						let mut summaries = vault_bonds.regular_bond_lots.to_vec();
+						record(&mut summaries)?;
+						summaries.sort_by(|left, right| {
+							right
+								.bonds
+								.cmp(&left.bonds)
+								.then_with(|| left.bond_lot_id.cmp(&right.bond_lot_id))
+						});
+						vault_bonds.regular_bond_lots = BoundedVec::try_from(summaries)
+							.map_err(|_| Error::<T>::MaxAcceptedBondLotsExceeded)?;
+						Ok(())
+					}),
+				BondProgram::Argonot => ArgonotBondLots::<T>::try_mutate(|active_lots| {
+					let mut summaries = active_lots.to_vec();
+					record(&mut summaries)?;
+					summaries.sort_by(|left, right| {
+						left.bonds
+							.cmp(&right.bonds)
+							.then_with(|| left.bond_lot_id.cmp(&right.bond_lot_id))
+					});
+					*active_lots = BoundedVec::try_from(summaries)
+						.map_err(|_| Error::<T>::MaxActiveArgonotBondLotsExceeded)?;
+					Ok(())
+				}),
+			}
+		}
+
+		/// Gives a split lot its share of the frame that is already locked in. If the frame has no
+		/// room for another lot, the original lot keeps the whole share until the next frame.
+		fn split_current_frame_participation(
+			program: BondProgram,
+			bond_lot_id: BondLotId,
+			lot_bonds: Bonds,
+			split_bond_lot_id: BondLotId,
+			split_bonds: Bonds,
+		) {
+			match program {
+				BondProgram::Vault { vault_id, .. } =>
+					CurrentFrameVaultCapital::<T>::mutate(|maybe_frame_capital| {
+						let Some(vault_capital) = maybe_frame_capital
+							.as_mut()
+							.and_then(|frame_capital| frame_capital.vaults.get_mut(&vault_id))
+						else {
+							return;
+						};
+						let Some(index) = vault_capital
+							.regular_bond_allocations
+							.iter()
+							.position(|allocation| allocation.bond_lot_id == bond_lot_id)
+						else {
+							return;
+						};
+						let prorata = vault_capital.regular_bond_allocations[index].prorata;
+						let split_prorata = prorata.saturating_mul(FixedU128::from_rational(
+							split_bonds as u128,
+							lot_bonds as u128,
+						));
+						if vault_capital
+							.regular_bond_allocations
+							.try_push(BondLotAllocation {
+								bond_lot_id: split_bond_lot_id,
+								prorata: split_prorata,
+							})
+							.is_ok()
+						{
+							vault_capital.regular_bond_allocations[index].prorata =
+								prorata.saturating_sub(split_prorata);
+						}
+					}),
+				BondProgram::Argonot =>
+					CurrentFrameArgonotBondParticipants::<T>::mutate(|maybe_participants| {
+						let Some(participants) = maybe_participants.as_mut() else {
+							return;
+						};
+						let Some(index) = participants
+							.bond_lots
+							.iter()
+							.position(|summary| summary.bond_lot_id == bond_lot_id)
+						else {
+							return;
+						};
+						let transfers = participants.bond_lots[index].transfers.saturating_add(1);
+						if participants
+							.bond_lots
+							.try_push(BondLotSummary {
+								bond_lot_id: split_bond_lot_id,
+								bonds: split_bonds,
+								transfers,
+							})
+							.is_ok()
+						{
+							let summary = &mut participants.bond_lots[index];
+							summary.bonds = summary.bonds.saturating_sub(split_bonds);
+						}
+					}),
+			}
+		}
+
 		fn schedule_bond_lot_release(
 			bond_lot_id: BondLotId,
 			reason: BondReleaseReason,
@@ -1719,6 +2550,9 @@ pub mod pallet {
 					if remaining_bonds == 0 {
 						BondLotById::<T>::remove(bond_lot_id);
 						BondLotIdsByAccount::<T>::remove(account_id, bond_lot_id);
+						BondLotTransfersById::<T>::remove(bond_lot_id);
+						AutoReinvestByBondLot::<T>::remove(bond_lot_id);
+						PendingReinvestmentByBondLot::<T>::remove(bond_lot_id);
 						Self::remove_bond_lot_from_vault(vault_id, bond_lot_id, &bond_lot);
 					} else {
 						BondLotById::<T>::mutate_exists(bond_lot_id, |maybe_bond_lot| {
@@ -1887,6 +2721,30 @@ pub mod pallet {
 		/// The number of bonds in the accepted lot.
 		#[codec(compact)]
 		pub bonds: Bonds,
+		/// How many times the lot changed owner. The changes are kept in `BondLotTransfersById`.
+		#[codec(compact)]
+		pub transfers: u32,
+	}
+
+	/// One change of ownership in a bond lot's history.
+	#[derive(
+		Encode, Decode, Clone, PartialEqNoBound, Eq, DebugNoBound, TypeInfo, MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	pub struct BondLotTransfer<T: Config> {
+		/// The frame the transfer happened in.
+		#[codec(compact)]
+		pub frame_id: FrameId,
+		/// The lot the bonds were moved out of.
+		#[codec(compact)]
+		pub from_bond_lot_id: BondLotId,
+		/// The previous owner.
+		pub from: T::AccountId,
+		/// The new owner.
+		pub to: T::AccountId,
+		/// The number of bonds that moved.
+		#[codec(compact)]
+		pub bonds: Bonds,
 	}
 
 	/// Active vault bond participation and its flexible accounting.
@@ -1 +1,249 @@
+use crate::{
+	ArgonotBondLots, BondLotId, BondLotSummary, BondLotsByVault, Bonds, Config,
+	CurrentFrameArgonotBondParticipants, FrameArgonotBondParticipants, Pallet, VaultBondState,
+};
+use alloc::vec::Vec;
+use frame_support::{storage_alias, traits::UncheckedOnRuntimeUpgrade};
+use pallet_prelude::*;
 
+#[cfg(feature = "try-runtime")]
+use frame_support::ensure;
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+mod v7 {
+	use super::*;
+
+	#[derive(Clone, Encode, Decode)]
+	pub struct BondLotSummary {
+		#[codec(compact)]
+		pub bond_lot_id: BondLotId,
+		#[codec(compact)]
+		pub bonds: Bonds,
+	}
+
+	#[derive(Encode, Decode)]
+	pub struct VaultBondState {
+		pub regular_bond_lots: Vec<BondLotSummary>,
+		#[codec(compact)]
+		pub flexible_bonds: Bonds,
+		#[codec(compact)]
+		pub reserved_bond_space: Bonds,
+	}
+
+	#[derive(Encode, Decode)]
+	pub struct FrameArgonotBondParticipants {
+		#[codec(compact)]
+		pub frame_id: FrameId,
+		#[codec(compact)]
+		pub total_bonds: Bonds,
+		pub bond_lots: Vec<BondLotSummary>,
+	}
+
+	#[storage_alias]
+	pub type BondLotsByVault<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, VaultId, VaultBondState, OptionQuery>;
+
+	#[storage_alias]
+	pub type ArgonotBondLots<T: Config> = StorageValue<Pallet<T>, Vec<BondLotSummary>, ValueQuery>;
+
+	#[storage_alias]
+	pub type CurrentFrameArgonotBondParticipants<T: Config> =
+		StorageValue<Pallet<T>, FrameArgonotBondParticipants, OptionQuery>;
+
+	impl From<BondLotSummary> for crate::BondLotSummary {
+		fn from(value: BondLotSummary) -> Self {
+			Self { bond_lot_id: value.bond_lot_id, bonds: value.bonds, transfers: 0 }
+		}
+	}
+
+	/// The lots in these sets were already bounded by the current limits, so truncation never
+	/// drops an entry.
+	pub fn migrate_summaries<S: Get<u32>>(
+		summaries: Vec<BondLotSummary>,
+	) -> BoundedVec<crate::BondLotSummary, S> {
+		BoundedVec::truncate_from(summaries.into_iter().map(Into::into).collect())
+	}
+}
+
+#[cfg(feature = "try-runtime")]
+type LotAmounts = Vec<(BondLotId, Bonds)>;
+
+#[cfg(feature = "try-runtime")]
+fn lot_amounts<'a>(summaries: impl IntoIterator<Item = &'a BondLotSummary>) -> LotAmounts {
+	summaries.into_iter().map(|lot| (lot.bond_lot_id, lot.bonds)).collect()
+}
+
+/// Adds the `transfers` counter to every stored `BondLotSummary`. No lot has changed owner
+/// before this version, so every counter starts at zero.
+pub struct AddBondLotSummaryTransfers<T>(core::marker::PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for AddBondLotSummaryTransfers<T> {
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		let vault_lots = v7::BondLotsByVault::<T>::iter()
+			.map(|(vault_id, state)| {
+				let lots = state
+					.regular_bond_lots
+					.iter()
+					.map(|lot| (lot.bond_lot_id, lot.bonds))
+					.collect();
+				(vault_id, lots)
+			})
+			.collect::<Vec<(VaultId, LotAmounts)>>();
+		let argonot_lots = v7::ArgonotBondLots::<T>::get()
+			.iter()
+			.map(|lot| (lot.bond_lot_id, lot.bonds))
+			.collect::<LotAmounts>();
+		let participant_lots = v7::CurrentFrameArgonotBondParticipants::<T>::get().map(|frame| {
+			(
+				frame.frame_id,
+				frame
+					.bond_lots
+					.iter()
+					.map(|lot| (lot.bond_lot_id, lot.bonds))
+					.collect::<LotAmounts>(),
+			)
+		});
+
+		Ok((vault_lots, argonot_lots, participant_lots).encode())
+	}
+
+	fn on_runtime_upgrade() -> Weight {
+		let mut vault_count = 0u64;
+		BondLotsByVault::<T>::translate::<v7::VaultBondState, _>(|_, state| {
+			vault_count.saturating_accrue(1);
+			Some(VaultBondState {
+				regular_bond_lots: v7::migrate_summaries(state.regular_bond_lots),
+				flexible_bonds: state.flexible_bonds,
+				reserved_bond_space: state.reserved_bond_space,
+			})
+		});
+
+		let _ = ArgonotBondLots::<T>::translate::<Vec<v7::BondLotSummary>, _>(|maybe_lots| {
+			maybe_lots.map(v7::migrate_summaries)
+		});
+
+		let _ = CurrentFrameArgonotBondParticipants::<T>::translate::<
+			v7::FrameArgonotBondParticipants,
+			_,
+		>(|maybe_frame| {
+			maybe_frame.map(|frame| FrameArgonotBondParticipants {
+				frame_id: frame.frame_id,
+				total_bonds: frame.total_bonds,
+				bond_lots: v7::migrate_summaries(frame.bond_lots),
+			})
+		});
+
+		T::DbWeight::get()
+			.reads_writes(vault_count.saturating_add(2), vault_count.saturating_add(2))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let (expected_vault_lots, expected_argonot_lots, expected_participant_lots) =
+			<(Vec<(VaultId, LotAmounts)>, LotAmounts, Option<(FrameId, LotAmounts)>)>::decode(
+				&mut state.as_slice(),
+			)
+			.map_err(|_| TryRuntimeError::Other("could not decode bond lot summary state"))?;
+
+		let vault_count =
+			BondLotsByVault::<T>::iter_keys().fold(0u64, |count, _| count.saturating_add(1));
+		ensure!(
+			vault_count == expected_vault_lots.len() as u64,
+			TryRuntimeError::Other("vault bond state count changed during summary migration"),
+		);
+		for (vault_id, expected_lots) in expected_vault_lots {
+			let state = BondLotsByVault::<T>::get(vault_id);
+			ensure!(
+				lot_amounts(state.regular_bond_lots.iter()) == expected_lots,
+				TryRuntimeError::Other("vault bond lots changed during summary migration"),
+			);
+			ensure!(
+				state.regular_bond_lots.iter().all(|lot| lot.transfers == 0),
+				TryRuntimeError::Other("migrated vault bond lots must start with no transfers"),
+			);
+		}
+
+		let argonot_lots = ArgonotBondLots::<T>::get();
+		ensure!(
+			lot_amounts(argonot_lots.iter()) == expected_argonot_lots,
+			TryRuntimeError::Other("Argonot bond lots changed during summary migration"),
+		);
+		ensure!(
+			argonot_lots.iter().all(|lot| lot.transfers == 0),
+			TryRuntimeError::Other("migrated Argonot bond lots must start with no transfers"),
+		);
+
+		let participant_lots = CurrentFrameArgonotBondParticipants::<T>::get()
+			.map(|frame| (frame.frame_id, lot_amounts(frame.bond_lots.iter())));
+		ensure!(
+			participant_lots == expected_participant_lots,
+			TryRuntimeError::Other("Argonot bond participants changed during summary migration"),
+		);
+		Ok(())
+	}
+}
+
+pub type AddBondLotSummaryTransfersMigration<T> = frame_support::migrations::VersionedMigration<
+	7,
+	8,
+	AddBondLotSummaryTransfers<T>,
+	Pallet<T>,
+	<T as frame_system::Config>::DbWeight,
+>;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::*;
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+	#[test]
+	fn adds_zero_transfer_counts_to_stored_bond_lots() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(7).put::<Pallet<Test>>();
+			let old_lot = |bond_lot_id, bonds| v7::BondLotSummary { bond_lot_id, bonds };
+			let new_lot = |bond_lot_id, bonds| BondLotSummary { bond_lot_id, bonds, transfers: 0 };
+			v7::BondLotsByVault::<Test>::insert(
+				1,
+				v7::VaultBondState {
+					regular_bond_lots: vec![old_lot(2, 50), old_lot(1, 20)],
+					flexible_bonds: 7,
+					reserved_bond_space: 3,
+				},
+			);
+			v7::ArgonotBondLots::<Test>::put(vec![old_lot(3, 5), old_lot(4, 9)]);
+			v7::CurrentFrameArgonotBondParticipants::<Test>::put(
+				v7::FrameArgonotBondParticipants {
+					frame_id: 4,
+					total_bonds: 14,
+					bond_lots: vec![old_lot(3, 5), old_lot(4, 9)],
+				},
+			);
+
+			#[cfg(feature = "try-runtime")]
+			let state = AddBondLotSummaryTransfersMigration::<Test>::pre_upgrade()
+				.expect("pre-upgrade checks");
+			AddBondLotSummaryTransfersMigration::<Test>::on_runtime_upgrade();
+			#[cfg(feature = "try-runtime")]
+			AddBondLotSummaryTransfersMigration::<Test>::post_upgrade(state)
+				.expect("post-upgrade checks");
+
+			let vault_bonds = BondLotsByVault::<Test>::get(1);
+			assert_eq!(
+				vault_bonds.regular_bond_lots.to_vec(),
+				vec![new_lot(2, 50), new_lot(1, 20)]
+			);
+			assert_eq!(vault_bonds.flexible_bonds, 7);
+			assert_eq!(vault_bonds.reserved_bond_space, 3);
+			assert_eq!(ArgonotBondLots::<Test>::get().to_vec(), vec![new_lot(3, 5), new_lot(4, 9)]);
+			let participants =
+				CurrentFrameArgonotBondParticipants::<Test>::get().expect("participants migrated");
+			assert_eq!(participants.frame_id, 4);
+			assert_eq!(participants.total_bonds, 14);
+			assert_eq!(participants.bond_lots.to_vec(), vec![new_lot(3, 5), new_lot(4, 9)]);
+			assert_eq!(StorageVersion::get::<Pallet<Test>>(), 8);
+		});
+	}
+}
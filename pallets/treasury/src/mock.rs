@@ -161,6 +161,7 @@ parameter_types! {
 	pub static MaxVaultsPerPool: u32 = 100;
 	pub static MaxPendingUnlocksPerFrame: u32 = 100;
 	pub static TreasuryExitDelayFrames: FrameId = 10;
+	pub static MaxBondLotTransferHistory: u32 = 3;
 	pub const VaultPalletId: PalletId = PalletId(*b"bidPools");
 
 	pub const PercentForTreasuryReserves: Percent = Percent::from_percent(20);
@@ -297,6 +298,7 @@ impl pallet_treasury::Config for Test {
 	type MaxVaultsPerPool = MaxVaultsPerPool;
 	type MaxPendingUnlocksPerFrame = MaxPendingUnlocksPerFrame;
 	type TreasuryExitDelayFrames = TreasuryExitDelayFrames;
+	type MaxBondLotTransferHistory = MaxBondLotTransferHistory;
 	type MiningFrameTransitionProvider = StaticMiningFrameTransitionProvider;
 	type OperationalAccountsHook = TestOperationalAccountsHook;
 }
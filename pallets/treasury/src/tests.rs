@@ -1,7 +1,9 @@
 use super::{
-	ArgonotBondLots, BondLot, BondLotById, BondLotIdsByAccount, BondLotSummary, BondLotsByVault,
+	ArgonotBondLots, ArgonotFrameYields, AutoReinvestByBondLot, BondLot, BondLotById,
+	BondLotIdsByAccount, BondLotSummary, BondLotTransfer, BondLotTransfersById, BondLotsByVault,
 	BondProgram, BondReleaseReason, CurrentFrameArgonotBondParticipants, CurrentFrameVaultCapital,
-	HoldReason, PendingBondReleaseRetryCursor, PendingBondReleasesByFrame, TotalActiveArgonotBonds,
+	FrameYieldsByVault, HoldReason, PendingBondReleaseRetryCursor, PendingBondReleasesByFrame,
+	PendingReinvestmentByBondLot, TotalActiveArgonotBonds,
 };
 use crate::{
 	mock::{
@@ -15,7 +17,10 @@ use crate::{
 	pallet::{BondLotAllocation, Bonds, Error, FrameVaultCapital, VaultCapital},
 };
 use argon_primitives::{
-	vault::{TreasuryBonusApprovalProof, TREASURY_BONUS_APPROVAL_PROOF_MESSAGE_KEY},
+	vault::{
+		TreasuryBonusApprovalProof, TreasuryFrameApy, TreasuryFrameYield,
+		TREASURY_BONUS_APPROVAL_PROOF_MESSAGE_KEY,
+	},
 	OperationalRewardsPayer, Signature, TreasuryPoolProvider, MICROGONS_PER_ARGON,
 };
 use frame_support::{
@@ -521,6 +526,370 @@ fn liquidate_bond_lot_rejects_when_it_would_drop_below_encumbered_backing() {
 	});
 }
 
+#[test]
+fn transfer_bond_lot_moves_whole_vault_lot_and_keeps_earning() {
+	new_test_ext().execute_with(|| {
+		MinimumArgonsPerContributor::set(1);
+		CurrentFrameId::set(1);
+		insert_vault(
+			1,
+			test_vault(10, (10 * MICROGONS_PER_ARGON) as u64, Permill::from_percent(20)),
+		);
+		set_argons(2, 50 * MICROGONS_PER_ARGON);
+		assert_ok!(Treasury::buy_bonds(origin(2), 1, 4, None));
+		let bond_lot_id = account_bond_lot_ids(2)[0];
+
+		Treasury::lock_in_vault_capital(1);
+		assert_ok!(Treasury::transfer_bond_lot(origin(2), bond_lot_id, account(3), 4));
+
+		let bond_lot = BondLotById::<Test>::get(bond_lot_id).expect("transferred bond lot");
+		assert_eq!(bond_lot.owner, account(3));
+		assert_eq!(bond_lot.bonds, 4);
+		assert_eq!(bond_lot.release_reason, None);
+		assert!(account_bond_lot_ids(2).is_empty());
+		assert_eq!(account_bond_lot_ids(3), vec![bond_lot_id]);
+		let hold_reason = RuntimeHoldReason::from(HoldReason::ContributedToTreasury);
+		assert_eq!(Balances::balance_on_hold(&hold_reason, &account(2)), 0);
+		assert_eq!(Balances::balance_on_hold(&hold_reason, &account(3)), 4 * MICROGONS_PER_ARGON);
+		assert_eq!(
+			BondLotsByVault::<Test>::get(1).regular_bond_lots.into_inner(),
+			vec![BondLotSummary { bond_lot_id, bonds: 4, transfers: 1 }],
+		);
+		assert_eq!(
+			BondLotTransfersById::<Test>::get(bond_lot_id).into_inner(),
+			vec![BondLotTransfer {
+				frame_id: 1,
+				from_bond_lot_id: bond_lot_id,
+				from: account(2),
+				to: account(3),
+				bonds: 4,
+			}],
+		);
+		assert!(!<Treasury as TreasuryPoolProvider<TestAccountId>>::has_vault_bond_participation(
+			1,
+			&account(2)
+		));
+		assert!(<Treasury as TreasuryPoolProvider<TestAccountId>>::has_vault_bond_participation(
+			1,
+			&account(3)
+		));
+		System::assert_last_event(
+			crate::Event::<Test>::BondLotTransferred {
+				program_id: crate::BondProgramId::Vault { vault_id: 1 },
+				from_bond_lot_id: bond_lot_id,
+				bond_lot_id,
+				from_account_id: account(2),
+				account_id: account(3),
+				bonds: 4,
+			}
+			.into(),
+		);
+
+		let bid_pool_account = BidPoolAccountId::get();
+		assert_ok!(Balances::mint_into(&bid_pool_account, 100 * MICROGONS_PER_ARGON));
+		Treasury::distribute_bid_pool(1);
+
+		let bond_lot = BondLotById::<Test>::get(bond_lot_id).expect("transferred bond lot");
+		assert_eq!(bond_lot.participated_frames, 1);
+		assert_eq!(bond_lot.cumulative_earnings, 6_400_000);
+		assert_eq!(Balances::balance(&account(3)), 6_400_000);
+
+		assert_ok!(Treasury::transfer_bond_lot(origin(3), bond_lot_id, account(2), 4));
+		assert_ok!(Treasury::transfer_bond_lot(origin(2), bond_lot_id, account(3), 4));
+		assert_ok!(Treasury::transfer_bond_lot(origin(3), bond_lot_id, account(2), 4));
+		let transfers = BondLotTransfersById::<Test>::get(bond_lot_id);
+		assert_eq!(transfers.len(), 3);
+		assert_eq!((transfers[0].from.clone(), transfers[0].to.clone()), (account(3), account(2)));
+		assert_eq!(BondLotsByVault::<Test>::get(1).regular_bond_lots[0].transfers, 4);
+		assert_eq!(BondLotById::<Test>::get(bond_lot_id).expect("bond lot").participated_frames, 1);
+	});
+}
+
+#[test]
+fn transfer_bond_lot_splits_argonot_lot_and_its_locked_frame_share() {
+	new_test_ext().execute_with(|| {
+		MinimumArgonsPerContributor::set(1);
+		MaxArgonotBondedPercentOfCirculation::set(Percent::from_percent(100));
+		CurrentFrameId::set(1);
+		set_ownership(2, 50 * MICROGONS_PER_ARGON);
+
+		assert_ok!(Treasury::buy_argonot_bonds(origin(2), 5));
+		let bond_lot_id = account_bond_lot_ids(2)[0];
+		Treasury::lock_in_argonot_bond_participants(1);
+
+		assert_ok!(Treasury::transfer_bond_lot(origin(2), bond_lot_id, account(3), 2));
+
+		let split_bond_lot_id = account_bond_lot_ids(3)[0];
+		assert_ne!(split_bond_lot_id, bond_lot_id);
+		assert_eq!(account_bond_lot_ids(2), vec![bond_lot_id]);
+		let original = BondLotById::<Test>::get(bond_lot_id).expect("original bond lot");
+		let split = BondLotById::<Test>::get(split_bond_lot_id).expect("split bond lot");
+		assert_eq!(original.bonds, 3);
+		assert_eq!(split.owner, account(3));
+		assert_eq!(split.bonds, 2);
+		assert_eq!(split.program, BondProgram::Argonot);
+		assert_eq!(split.created_frame_id, original.created_frame_id);
+		assert_eq!(TotalActiveArgonotBonds::<Test>::get(), 5);
+		assert_eq!(
+			argonot_bond_lots(),
+			vec![
+				BondLotSummary { bond_lot_id: split_bond_lot_id, bonds: 2, transfers: 1 },
+				BondLotSummary { bond_lot_id, bonds: 3, transfers: 0 },
+			],
+		);
+		assert!(BondLotTransfersById::<Test>::get(bond_lot_id).is_empty());
+		assert_eq!(BondLotTransfersById::<Test>::get(split_bond_lot_id).len(), 1);
+		let hold_reason = RuntimeHoldReason::from(HoldReason::ContributedToTreasury);
+		assert_eq!(Ownership::balance_on_hold(&hold_reason, &account(2)), 3 * MICROGONS_PER_ARGON);
+		assert_eq!(Ownership::balance_on_hold(&hold_reason, &account(3)), 2 * MICROGONS_PER_ARGON);
+
+		let bid_pool_account = BidPoolAccountId::get();
+		assert_ok!(Balances::mint_into(&bid_pool_account, 100 * MICROGONS_PER_ARGON));
+		Treasury::distribute_bid_pool(1);
+
+		assert_eq!(Balances::balance(&account(2)), 6_000_000);
+		assert_eq!(Balances::balance(&account(3)), 4_000_000);
+		let split = BondLotById::<Test>::get(split_bond_lot_id).expect("split bond lot");
+		assert_eq!(split.participated_frames, 1);
+		assert_eq!(split.cumulative_earnings, 4_000_000);
+	});
+}
+
+#[test]
+fn transfer_bond_lot_rejects_releasing_flexible_and_undersized_lots() {
+	new_test_ext().execute_with(|| {
+		MinimumArgonsPerContributor::set(1);
+		CurrentFrameId::set(1);
+		insert_vault(
+			1,
+			test_vault(10, (100 * MICROGONS_PER_ARGON) as u64, Permill::from_percent(20)),
+		);
+		set_argons(2, 50 * MICROGONS_PER_ARGON);
+		set_argons(10, 50 * MICROGONS_PER_ARGON);
+		assert_ok!(Treasury::buy_bonds(origin(2), 1, 4, None));
+		assert_ok!(Treasury::buy_bonds(origin(2), 1, 3, None));
+		assert_ok!(Treasury::buy_bonds(origin(10), 1, 2, None));
+		let bond_lot_ids = account_bond_lot_ids(2);
+		let operator_bond_lot_id = account_bond_lot_ids(10)[0];
+
+		assert_noop!(
+			Treasury::transfer_bond_lot(origin(2), bond_lot_ids[0], account(2), 1),
+			Error::<Test>::CannotTransferBondLotToSelf
+		);
+		assert_noop!(
+			Treasury::transfer_bond_lot(origin(3), bond_lot_ids[0], account(4), 1),
+			Error::<Test>::NotBondLotOwner
+		);
+		assert_noop!(
+			Treasury::transfer_bond_lot(origin(2), bond_lot_ids[0], account(3), 0),
+			Error::<Test>::InvalidBondLotTransferAmount
+		);
+		let bonds = BondLotById::<Test>::get(bond_lot_ids[0]).expect("bond lot").bonds;
+		assert_noop!(
+			Treasury::transfer_bond_lot(origin(2), bond_lot_ids[0], account(3), bonds + 1),
+			Error::<Test>::InvalidBondLotTransferAmount
+		);
+
+		MinimumArgonsPerContributor::set(2 * MICROGONS_PER_ARGON);
+		assert_noop!(
+			Treasury::transfer_bond_lot(origin(2), bond_lot_ids[0], account(3), bonds - 1),
+			Error::<Test>::BondLotSplitBelowMinimum
+		);
+
+		assert_ok!(Treasury::set_bond_lot_flexible(origin(10), operator_bond_lot_id, true));
+		assert_noop!(
+			Treasury::transfer_bond_lot(origin(10), operator_bond_lot_id, account(3), 2),
+			Error::<Test>::FlexibleBondLotNotTransferable
+		);
+
+		assert_ok!(Treasury::liquidate_bond_lot(origin(2), bond_lot_ids[1]));
+		assert_noop!(
+			Treasury::transfer_bond_lot(origin(2), bond_lot_ids[1], account(3), 1),
+			Error::<Test>::BondLotAlreadyReleasing
+		);
+	});
+}
+
+#[test]
+fn auto_reinvest_buys_a_new_lot_from_whole_bonds_of_earnings() {
+	new_test_ext().execute_with(|| {
+		MinimumArgonsPerContributor::set(1);
+		CurrentFrameId::set(1);
+		insert_vault(
+			1,
+			test_vault(10, (10 * MICROGONS_PER_ARGON) as u64, Permill::from_percent(20)),
+		);
+		set_argons(2, 50 * MICROGONS_PER_ARGON);
+		assert_ok!(Treasury::buy_bonds(origin(2), 1, 4, None));
+		let bond_lot_id = account_bond_lot_ids(2)[0];
+		assert_ok!(Treasury::set_auto_reinvest(origin(2), None, true));
+
+		Treasury::lock_in_vault_capital(1);
+		let bid_pool_account = BidPoolAccountId::get();
+		assert_ok!(Balances::mint_into(&bid_pool_account, 100 * MICROGONS_PER_ARGON));
+		Treasury::distribute_bid_pool(1);
+
+		let bond_lot_ids = account_bond_lot_ids(2);
+		assert_eq!(bond_lot_ids.len(), 2);
+		let reinvested_bond_lot_id =
+			*bond_lot_ids.iter().find(|id| **id != bond_lot_id).expect("reinvested lot");
+		let original = BondLotById::<Test>::get(bond_lot_id).expect("original bond lot");
+		let reinvested = BondLotById::<Test>::get(reinvested_bond_lot_id).expect("reinvested lot");
+		assert_eq!(original.cumulative_earnings, 6_400_000);
+		assert_eq!(reinvested.program, original.program);
+		assert_eq!(reinvested.bonds, 6);
+		assert_eq!(reinvested.participated_frames, 0);
+		assert_eq!(PendingReinvestmentByBondLot::<Test>::get(bond_lot_id), 400_000);
+		assert_eq!(
+			BondLotsByVault::<Test>::get(1).regular_bond_lots.into_inner(),
+			vec![
+				BondLotSummary { bond_lot_id: reinvested_bond_lot_id, bonds: 6, transfers: 0 },
+				BondLotSummary { bond_lot_id, bonds: 4, transfers: 0 },
+			],
+		);
+		assert_eq!(
+			Balances::balance_on_hold(
+				&RuntimeHoldReason::from(HoldReason::ContributedToTreasury),
+				&account(2)
+			),
+			10 * MICROGONS_PER_ARGON,
+		);
+		assert_eq!(Balances::balance(&account(2)), 46_400_000);
+		System::assert_has_event(
+			crate::Event::<Test>::BondLotEarningsReinvested {
+				vault_id: 1,
+				from_bond_lot_id: bond_lot_id,
+				bond_lot_id: reinvested_bond_lot_id,
+				account_id: account(2),
+				bonds: 6,
+			}
+			.into(),
+		);
+
+		assert_ok!(Treasury::set_auto_reinvest(origin(2), Some(reinvested_bond_lot_id), true));
+		PendingReinvestmentByBondLot::<Test>::insert(reinvested_bond_lot_id, 300_000);
+		assert_ok!(Treasury::set_auto_reinvest(origin(2), None, false));
+		assert_eq!(PendingReinvestmentByBondLot::<Test>::get(reinvested_bond_lot_id), 300_000);
+		// pending earnings of lots that stopped reinvesting are dropped at their next payout
+		assert_eq!(PendingReinvestmentByBondLot::<Test>::get(bond_lot_id), 400_000);
+		Treasury::lock_in_vault_capital(2);
+		assert_ok!(Balances::mint_into(&bid_pool_account, 100 * MICROGONS_PER_ARGON));
+		Treasury::distribute_bid_pool(2);
+		assert_eq!(PendingReinvestmentByBondLot::<Test>::get(bond_lot_id), 0);
+	});
+}
+
+#[test]
+fn auto_reinvest_uses_the_current_vault_terms() {
+	new_test_ext().execute_with(|| {
+		MinimumArgonsPerContributor::set(1);
+		CurrentFrameId::set(1);
+		insert_vault(
+			1,
+			test_vault(10, (10 * MICROGONS_PER_ARGON) as u64, Permill::from_percent(20)),
+		);
+		set_argons(2, 50 * MICROGONS_PER_ARGON);
+		assert_ok!(Treasury::buy_bonds(origin(2), 1, 4, None));
+		let bond_lot_id = account_bond_lot_ids(2)[0];
+		BondLotById::<Test>::mutate(bond_lot_id, |bond_lot| {
+			let bond_lot = bond_lot.as_mut().expect("bond lot");
+			bond_lot.program = BondProgram::Vault {
+				vault_id: 1,
+				sharing_percent: Permill::from_percent(20),
+				bonus_percent: Permill::from_percent(5),
+			};
+		});
+		assert_ok!(Treasury::set_auto_reinvest(origin(2), None, true));
+
+		// the vault changes its terms after the original purchase
+		insert_vault(
+			1,
+			test_vault(10, (10 * MICROGONS_PER_ARGON) as u64, Permill::from_percent(30)),
+		);
+		Treasury::lock_in_vault_capital(1);
+		let bid_pool_account = BidPoolAccountId::get();
+		assert_ok!(Balances::mint_into(&bid_pool_account, 100 * MICROGONS_PER_ARGON));
+		Treasury::distribute_bid_pool(1);
+
+		let reinvested_bond_lot_id = *account_bond_lot_ids(2)
+			.iter()
+			.find(|id| **id != bond_lot_id)
+			.expect("reinvested lot");
+		let reinvested = BondLotById::<Test>::get(reinvested_bond_lot_id).expect("reinvested lot");
+		assert_eq!(
+			reinvested.program,
+			BondProgram::Vault {
+				vault_id: 1,
+				sharing_percent: Permill::from_percent(30),
+				bonus_percent: Permill::zero(),
+			}
+		);
+		assert_eq!(
+			BondLotById::<Test>::get(bond_lot_id).expect("original bond lot").program,
+			BondProgram::Vault {
+				vault_id: 1,
+				sharing_percent: Permill::from_percent(20),
+				bonus_percent: Permill::from_percent(5),
+			}
+		);
+	});
+}
+
+#[test]
+fn auto_reinvest_pays_out_without_bond_space_and_respects_lot_overrides() {
+	new_test_ext().execute_with(|| {
+		MinimumArgonsPerContributor::set(1);
+		MaxArgonotBondedPercentOfCirculation::set(Percent::from_percent(100));
+		CurrentFrameId::set(1);
+		insert_vault(
+			1,
+			test_vault(10, (5 * MICROGONS_PER_ARGON) as u64, Permill::from_percent(20)),
+		);
+		set_argons(2, 50 * MICROGONS_PER_ARGON);
+		assert_ok!(Treasury::buy_bonds(origin(2), 1, 4, None));
+		let bond_lot_id = account_bond_lot_ids(2)[0];
+		assert_ok!(Treasury::set_auto_reinvest(origin(2), None, true));
+
+		Treasury::lock_in_vault_capital(1);
+		let bid_pool_account = BidPoolAccountId::get();
+		assert_ok!(Balances::mint_into(&bid_pool_account, 100 * MICROGONS_PER_ARGON));
+		Treasury::distribute_bid_pool(1);
+
+		assert_eq!(account_bond_lot_ids(2), vec![bond_lot_id]);
+		assert_eq!(PendingReinvestmentByBondLot::<Test>::get(bond_lot_id), 0);
+		assert_eq!(Balances::balance(&account(2)), 58_800_000);
+		assert!(System::events().iter().any(|record| matches!(
+			&record.event,
+			RuntimeEvent::Treasury(crate::Event::CouldNotReinvestBondLotEarnings {
+				bond_lot_id: id,
+				amount: 12_800_000,
+				..
+			}) if *id == bond_lot_id
+		)));
+
+		assert_ok!(Treasury::set_auto_reinvest(origin(2), Some(bond_lot_id), false));
+		assert_eq!(AutoReinvestByBondLot::<Test>::get(bond_lot_id), Some(false));
+		Treasury::lock_in_vault_capital(2);
+		assert_ok!(Balances::mint_into(&bid_pool_account, 100 * MICROGONS_PER_ARGON));
+		Treasury::distribute_bid_pool(2);
+		assert_eq!(account_bond_lot_ids(2), vec![bond_lot_id]);
+		assert_eq!(PendingReinvestmentByBondLot::<Test>::get(bond_lot_id), 0);
+		assert!(Balances::balance(&account(2)) > 58_800_000);
+
+		set_ownership(5, 10 * MICROGONS_PER_ARGON);
+		assert_ok!(Treasury::buy_argonot_bonds(origin(5), 2));
+		let argonot_bond_lot_id = account_bond_lot_ids(5)[0];
+		assert_err!(
+			Treasury::set_auto_reinvest(origin(5), Some(argonot_bond_lot_id), true),
+			Error::<Test>::BondLotCannotAutoReinvest
+		);
+		assert_err!(
+			Treasury::set_auto_reinvest(origin(5), Some(bond_lot_id), true),
+			Error::<Test>::NotBondLotOwner
+		);
+	});
+}
+
 #[test]
 fn buy_argonot_bonds_allows_multiple_lots_per_account() {
 	new_test_ext().execute_with(|| {
@@ -644,8 +1013,8 @@ fn buy_argonot_bonds_evicts_floor_and_schedules_release() {
 		assert_eq!(
 			argonot_bond_lots(),
 			vec![
-				BondLotSummary { bond_lot_id: retained_bond_lot_id, bonds: 5 },
-				BondLotSummary { bond_lot_id: new_bond_lot_id, bonds: 6 },
+				BondLotSummary { bond_lot_id: retained_bond_lot_id, bonds: 5, transfers: 0 },
+				BondLotSummary { bond_lot_id: new_bond_lot_id, bonds: 6, transfers: 0 },
 			],
 		);
 	});
@@ -1050,6 +1419,180 @@ fn lock_in_vault_capital_selects_top_vaults_by_eligible_bonds() {
 	});
 }
 
+#[test]
+fn prunes_frame_yields_outside_the_history_window() {
+	new_test_ext().execute_with(|| {
+		insert_vault(1, test_vault(10, 0, Permill::from_percent(20)));
+		insert_vault(2, test_vault(11, 0, Permill::from_percent(20)));
+		let mut closed_vault = test_vault(12, 0, Permill::from_percent(20));
+		closed_vault.is_closed = true;
+		insert_vault(3, closed_vault);
+		let yields = |frame_ids: &[FrameId]| {
+			BoundedVec::truncate_from(
+				frame_ids
+					.iter()
+					.map(|&frame_id| TreasuryFrameYield {
+						frame_id,
+						bonds: 1,
+						capital: MICROGONS_PER_ARGON,
+						earnings: 1_000,
+					})
+					.collect::<Vec<_>>(),
+			)
+		};
+		FrameYieldsByVault::<Test>::insert(1, yields(&[40, 20, 11, 10]));
+		FrameYieldsByVault::<Test>::insert(2, yields(&[10, 9]));
+		FrameYieldsByVault::<Test>::insert(3, yields(&[40]));
+
+		// the 30 frame history ends at frame 11
+		Treasury::prune_frame_yields(40);
+		assert_eq!(
+			FrameYieldsByVault::<Test>::get(1)
+				.iter()
+				.map(|x| x.frame_id)
+				.collect::<Vec<_>>(),
+			vec![40, 20, 11]
+		);
+		assert!(!FrameYieldsByVault::<Test>::contains_key(2));
+		assert!(!FrameYieldsByVault::<Test>::contains_key(3));
+		assert_eq!(Treasury::bond_program_yields().len(), 2);
+	});
+}
+
+#[test]
+fn distribution_records_frame_yields_for_bond_program_apys() {
+	new_test_ext().execute_with(|| {
+		MinimumArgonsPerContributor::set(1);
+		MaxArgonotBondedPercentOfCirculation::set(Percent::from_percent(100));
+		CurrentFrameId::set(1);
+		insert_vault(
+			1,
+			test_vault(10, (10 * MICROGONS_PER_ARGON) as u64, Permill::from_percent(20)),
+		);
+		set_argons(2, 50 * MICROGONS_PER_ARGON);
+		set_ownership(5, 10 * MICROGONS_PER_ARGON);
+		assert_ok!(Treasury::buy_bonds(origin(2), 1, 4, None));
+		assert_ok!(Treasury::buy_argonot_bonds(origin(5), 2));
+
+		Treasury::lock_in_argonot_bond_participants(1);
+		Treasury::lock_in_vault_capital(1);
+		let bid_pool_account = BidPoolAccountId::get();
+		assert_ok!(Balances::mint_into(&bid_pool_account, 100 * MICROGONS_PER_ARGON));
+		Treasury::distribute_bid_pool(1);
+
+		assert_eq!(
+			FrameYieldsByVault::<Test>::get(1).into_inner(),
+			vec![TreasuryFrameYield {
+				frame_id: 1,
+				bonds: 4,
+				capital: 4 * MICROGONS_PER_ARGON,
+				earnings: 5_600_000,
+			}]
+		);
+		assert_eq!(
+			ArgonotFrameYields::<Test>::get().into_inner(),
+			vec![TreasuryFrameYield {
+				frame_id: 1,
+				bonds: 2,
+				capital: 2 * MICROGONS_PER_ARGON,
+				earnings: 10 * MICROGONS_PER_ARGON,
+			}]
+		);
+
+		let program_yields = Treasury::bond_program_yields();
+		assert_eq!(program_yields.len(), 2);
+		assert_eq!(program_yields[0].vault_id, None);
+		assert_eq!(
+			program_yields[0].frames,
+			vec![TreasuryFrameApy {
+				frame_id: 1,
+				bonds: 2,
+				capital: 2 * MICROGONS_PER_ARGON,
+				earnings: 10 * MICROGONS_PER_ARGON,
+				apy: FixedU128::saturating_from_integer(1825),
+			}]
+		);
+		assert_eq!(program_yields[1].vault_id, Some(1));
+		assert_eq!(program_yields[1].sharing_percent, Permill::from_percent(20));
+		assert_eq!(
+			program_yields[1].frames,
+			vec![TreasuryFrameApy {
+				frame_id: 1,
+				bonds: 4,
+				capital: 4 * MICROGONS_PER_ARGON,
+				earnings: 5_600_000,
+				apy: FixedU128::saturating_from_integer(511),
+			}]
+		);
+
+		// past frames keep the yield that was paid when the vault changes its terms
+		insert_vault(
+			1,
+			test_vault(10, (10 * MICROGONS_PER_ARGON) as u64, Permill::from_percent(50)),
+		);
+		let program_yields = Treasury::bond_program_yields();
+		assert_eq!(program_yields[1].sharing_percent, Permill::from_percent(50));
+		assert_eq!(program_yields[1].frames[0].earnings, 5_600_000);
+		assert_eq!(program_yields[1].frames[0].apy, FixedU128::saturating_from_integer(511));
+	});
+}
+
+#[test]
+fn project_bond_yield_accounts_for_bumping_and_the_vault_cutoff() {
+	new_test_ext().execute_with(|| {
+		MinimumArgonsPerContributor::set(1);
+		MaxTreasuryContributors::set(2);
+		MaxVaultsPerPool::set(1);
+		MaxArgonotBondedPercentOfCirculation::set(Percent::from_percent(100));
+		CurrentFrameId::set(1);
+		insert_vault(
+			1,
+			test_vault(11, (10 * MICROGONS_PER_ARGON) as u64, Permill::from_percent(20)),
+		);
+		insert_vault(
+			2,
+			test_vault(12, (20 * MICROGONS_PER_ARGON) as u64, Permill::from_percent(20)),
+		);
+		set_argons(2, 50 * MICROGONS_PER_ARGON);
+		set_argons(3, 50 * MICROGONS_PER_ARGON);
+		set_argons(4, 50 * MICROGONS_PER_ARGON);
+		set_ownership(5, 10 * MICROGONS_PER_ARGON);
+		assert_ok!(Treasury::buy_bonds(origin(2), 1, 3, None));
+		assert_ok!(Treasury::buy_bonds(origin(3), 1, 2, None));
+		assert_ok!(Treasury::buy_bonds(origin(4), 2, 8, None));
+		let smallest_bond_lot_id = account_bond_lot_ids(3)[0];
+		let bid_pool_account = BidPoolAccountId::get();
+		assert_ok!(Balances::mint_into(&bid_pool_account, 100 * MICROGONS_PER_ARGON));
+
+		assert_err!(Treasury::project_bond_yield(Some(1), 2), Error::<Test>::BondPurchaseRejected);
+
+		// the vault ends up with fewer eligible bonds than vault 2
+		let projection = Treasury::project_bond_yield(Some(1), 4).expect("projection");
+		assert_eq!(projection.bumped_bond_lot_id, Some(smallest_bond_lot_id));
+		assert_eq!(projection.bumped_bonds, 2);
+		assert!(!projection.is_participating);
+		assert_eq!(projection.earnings, 0);
+
+		let projection = Treasury::project_bond_yield(Some(1), 6).expect("projection");
+		assert_eq!(projection.bumped_bond_lot_id, Some(smallest_bond_lot_id));
+		assert!(projection.is_participating);
+		assert_eq!(projection.capital, 6 * MICROGONS_PER_ARGON);
+		assert_eq!(projection.earnings, 9_600_000);
+		assert_eq!(projection.apy, FixedU128::saturating_from_integer(584));
+
+		let projection = Treasury::project_bond_yield(None, 2).expect("argonot projection");
+		assert_eq!(projection.vault_id, None);
+		assert_eq!(projection.bumped_bond_lot_id, None);
+		assert_eq!(projection.capital, 2 * MICROGONS_PER_ARGON);
+		assert_eq!(projection.earnings, 10 * MICROGONS_PER_ARGON);
+		assert_eq!(projection.apy, FixedU128::saturating_from_integer(1825));
+
+		// projections don't change any bond state
+		assert_eq!(account_bond_lot_ids(3), vec![smallest_bond_lot_id]);
+		assert_eq!(BondLotsByVault::<Test>::get(1).regular_bonds(), 5);
+	});
+}
+
 #[test]
 fn locked_frame_still_pays_after_lot_is_liquidated() {
 	new_test_ext().execute_with(|| {
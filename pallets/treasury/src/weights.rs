@@ -14,6 +14,8 @@ pub trait WeightInfo {
 	fn liquidate_bond_lot() -> Weight;
 	fn set_bond_lot_flexible() -> Weight;
 	fn set_reserved_bond_space() -> Weight;
+	fn transfer_bond_lot() -> Weight;
+	fn set_auto_reinvest() -> Weight;
 	fn provider_has_vault_bond_participation() -> Weight;
 	fn provider_active_vault_bond_amount() -> Weight;
 	fn provider_active_account_vault_bond_amount() -> Weight;
@@ -30,7 +32,12 @@ where
 	Base: WeightInfo,
 {
 	fn on_frame_transition() -> Weight {
-		Base::on_frame_transition()
+		let reinvesting_bond_lots =
+			T::MaxVaultsPerPool::get().saturating_mul(T::MaxTreasuryContributors::get());
+		Base::on_frame_transition().saturating_add(
+			T::OperationalAccountsHook::account_vault_bond_total_updated_weight()
+				.saturating_mul(reinvesting_bond_lots as u64),
+		)
 	}
 
 	fn release_pending_bond_lots() -> Weight {
@@ -72,6 +79,16 @@ where
 		Base::set_reserved_bond_space()
 	}
 
+	fn transfer_bond_lot() -> Weight {
+		Base::transfer_bond_lot().saturating_add(
+			T::OperationalAccountsHook::account_vault_bond_total_updated_weight().saturating_mul(2),
+		)
+	}
+
+	fn set_auto_reinvest() -> Weight {
+		Base::set_auto_reinvest()
+	}
+
 	fn provider_has_vault_bond_participation() -> Weight {
 		Base::provider_has_vault_bond_participation()
 	}
@@ -158,6 +175,12 @@ impl WeightInfo for () {
 	fn set_reserved_bond_space() -> Weight {
 		Weight::zero()
 	}
+	fn transfer_bond_lot() -> Weight {
+		Weight::zero()
+	}
+	fn set_auto_reinvest() -> Weight {
+		Weight::zero()
+	}
 	fn provider_has_vault_bond_participation() -> Weight {
 		Weight::zero()
 	}
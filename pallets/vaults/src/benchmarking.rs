@@ -414,6 +414,55 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn provider_record_cosign() -> Result<(), BenchmarkError> {
+		let operator: T::AccountId = account("provider_record_cosign", 0, 0);
+		let vault_id = create_vault::<T>(&operator, 10, 100_000)?;
+		VaultReputationById::<T>::mutate(vault_id, |reputation| reputation.record_cosign(6));
+
+		#[block]
+		{
+			<Pallet<T> as BitcoinVaultProvider>::record_cosign(vault_id, 12);
+		}
+
+		assert_eq!(VaultReputationById::<T>::get(vault_id).timed_cosigns, 2);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn provider_record_missed_cosign() -> Result<(), BenchmarkError> {
+		let operator: T::AccountId = account("provider_record_missed_cosign", 0, 0);
+		let vault_id = create_vault::<T>(&operator, 10, 100_000)?;
+		VaultReputationById::<T>::mutate(vault_id, |reputation| reputation.record_cosign(6));
+
+		#[block]
+		{
+			<Pallet<T> as BitcoinVaultProvider>::record_missed_cosign(vault_id);
+		}
+
+		assert_eq!(VaultReputationById::<T>::get(vault_id).missed_cosigns, 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn provider_record_locked_satoshis() -> Result<(), BenchmarkError> {
+		let operator: T::AccountId = account("provider_record_locked_satoshis", 0, 0);
+		let vault_id = create_vault::<T>(&operator, 10, 100_000)?;
+
+		#[block]
+		{
+			assert!(<Pallet<T> as BitcoinVaultProvider>::add_securitized_satoshis(
+				vault_id,
+				100_000,
+				FixedU128::one(),
+			)
+			.is_ok());
+		}
+
+		assert_eq!(VaultReputationById::<T>::get(vault_id).lifetime_locked_satoshis, 100_000);
+		Ok(())
+	}
+
 	#[benchmark]
 	fn provider_encumber_argonots() -> Result<(), BenchmarkError> {
 		let caller: T::AccountId = account("provider_encumber_argonots", 0, 0);
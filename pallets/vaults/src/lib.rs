@@ -38,7 +38,8 @@ pub mod pallet {
 		},
 		vault::{
 			BitcoinVaultProvider, RegistrationVaultData, TreasuryVaultProvider, Vault,
-			VaultArgonotCommitment, VaultError, VaultLockRequest, VaultTerms,
+			VaultArgonotCommitment, VaultError, VaultLockRequest, VaultReputation,
+			VaultReputationSummary, VaultTerms,
 		},
 		CollectBlockerProvider, MiningFrameProvider, OperationalAccountProvider, TickProvider,
 	};
@@ -50,7 +51,7 @@ pub mod pallet {
 	};
 	use sp_runtime::traits::SaturatedConversion;
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(17);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(18);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -159,6 +160,11 @@ pub mod pallet {
 	pub type ArgonotCommitmentByVaultId<T: Config> =
 		StorageMap<_, Twox64Concat, VaultId, VaultArgonotCommitment<T::Balance>, OptionQuery>;
 
+	/// The lifetime track record of each vault with bitcoin lockers
+	#[pallet::storage]
+	pub type VaultReputationById<T: Config> =
+		StorageMap<_, Twox64Concat, VaultId, VaultReputation<T::Balance>, ValueQuery>;
+
 	/// Vault Bitcoin Xpub and current child counter by VaultId
 	#[pallet::storage]
 	pub type VaultXPubById<T: Config> =
@@ -1139,6 +1145,24 @@ pub mod pallet {
 			})
 		}
 
+		/// The reputation of every vault, for wallets ranking vaults to lock bitcoin with.
+		pub fn vault_reputations() -> Vec<VaultReputationSummary<T::Balance>> {
+			VaultsById::<T>::iter()
+				.map(|(vault_id, vault)| {
+					let reputation = VaultReputationById::<T>::get(vault_id);
+					VaultReputationSummary {
+						vault_id,
+						is_closed: vault.is_closed,
+						missed_cosigns: reputation.missed_cosigns,
+						timed_cosigns: reputation.timed_cosigns,
+						average_cosign_latency: reputation.average_cosign_latency(),
+						total_slashed: reputation.total_slashed,
+						lifetime_locked_satoshis: reputation.lifetime_locked_satoshis,
+					}
+				})
+				.collect()
+		}
+
 		fn track_vault_release_schedule(
 			vault_id: VaultId,
 			vault: &mut Vault<T::AccountId, T::Balance>,
@@ -1513,6 +1537,9 @@ pub mod pallet {
 			VaultsById::<T>::try_mutate(vault_id, |vault| {
 				let vault = vault.as_mut().ok_or(VaultError::VaultNotFound)?;
 				vault.add_securitized_satoshis(satoshis, securitization_ratio);
+				VaultReputationById::<T>::mutate(vault_id, |reputation| {
+					reputation.lifetime_locked_satoshis.saturating_accrue(satoshis);
+				});
 				Ok(())
 			})
 		}
@@ -1728,6 +1755,9 @@ pub mod pallet {
 				.map_err(|_| VaultError::UnrecoverableHold)?;
 			}
 
+			VaultReputationById::<T>::mutate(vault_id, |reputation| {
+				reputation.total_slashed.saturating_accrue(securitized_amount);
+			});
 			Self::deposit_event(Event::LostBitcoinCompensated {
 				vault_id,
 				beneficiary: beneficiary.clone(),
@@ -1889,6 +1919,18 @@ pub mod pallet {
 			}
 			Ok(())
 		}
+
+		fn record_cosign(vault_id: VaultId, cosign_latency: BitcoinHeight) {
+			VaultReputationById::<T>::mutate(vault_id, |reputation| {
+				reputation.record_cosign(cosign_latency);
+			});
+		}
+
+		fn record_missed_cosign(vault_id: VaultId) {
+			VaultReputationById::<T>::mutate(vault_id, |reputation| {
+				reputation.missed_cosigns.saturating_inc();
+			});
+		}
 	}
 
 	fn balance_to_i128<T: Config>(balance: T::Balance) -> i128 {
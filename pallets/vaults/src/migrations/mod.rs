@@ -7,7 +7,7 @@ use codec::{Decode, Encode};
 use frame_support::{storage_alias, traits::UncheckedOnRuntimeUpgrade};
 use pallet_prelude::*;
 
-use crate::{Config, Pallet, VaultsById};
+use crate::{Config, Pallet, VaultReputationById, VaultsById};
 
 type VaultName = BoundedVec<u8, ConstU32<18>>;
 
@@ -286,6 +286,55 @@ pub type MoveVaultNameToOperationalAccountProfileMigration<T> =
 		<T as frame_system::Config>::DbWeight,
 	>;
 
+/// Seeds each vault's `lifetime_locked_satoshis` with the satoshis it has locked when reputation
+/// tracking starts. Locks that were already released can't be recovered, so this is a lower bound
+/// for vaults opened before this version.
+pub struct SeedVaultReputationLockedSatoshis<T>(core::marker::PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for SeedVaultReputationLockedSatoshis<T> {
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		let locked_satoshis = VaultsById::<T>::iter()
+			.map(|(vault_id, vault)| (vault_id, vault.locked_satoshis))
+			.collect::<Vec<(VaultId, Satoshis)>>();
+		Ok(locked_satoshis.encode())
+	}
+
+	fn on_runtime_upgrade() -> Weight {
+		let mut vault_count = 0u64;
+		for (vault_id, vault) in VaultsById::<T>::iter() {
+			vault_count.saturating_accrue(1);
+			VaultReputationById::<T>::mutate(vault_id, |reputation| {
+				reputation.lifetime_locked_satoshis =
+					reputation.lifetime_locked_satoshis.max(vault.locked_satoshis);
+			});
+		}
+		T::DbWeight::get().reads_writes(vault_count.saturating_mul(2), vault_count)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let locked_satoshis = <Vec<(VaultId, Satoshis)>>::decode(&mut state.as_slice())
+			.map_err(|_| TryRuntimeError::Other("could not decode vault locked satoshis"))?;
+		for (vault_id, satoshis) in locked_satoshis {
+			ensure!(
+				VaultReputationById::<T>::get(vault_id).lifetime_locked_satoshis >= satoshis,
+				TryRuntimeError::Other("vault reputation was not seeded with its locked satoshis"),
+			);
+		}
+		Ok(())
+	}
+}
+
+pub type SeedVaultReputationLockedSatoshisMigration<T> =
+	frame_support::migrations::VersionedMigration<
+		17,
+		18,
+		SeedVaultReputationLockedSatoshis<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+
 #[cfg(all(feature = "try-runtime", test))]
 mod test {
 	use super::*;
@@ -419,4 +468,49 @@ mod test {
 			assert_eq!(StorageVersion::get::<pallet_operational_accounts::Pallet<Test>>(), 3);
 		});
 	}
+
+	#[test]
+	fn seeds_vault_reputation_with_locked_satoshis() {
+		new_test_ext().execute_with(|| {
+			let vault = |locked_satoshis| Vault {
+				operator_account_id: 1,
+				delegate_account_id: None,
+				securitization: 1_000,
+				securitization_target: 1_000,
+				securitization_locked: 0,
+				flexible_securitization_locked: 0,
+				reserved_securitization_space: 0,
+				securitization_pending_activation: 0,
+				locked_satoshis,
+				securitized_satoshis: locked_satoshis,
+				flexible_securitized_satoshis: 0,
+				securitization_release_schedule: BoundedBTreeMap::new(),
+				securitization_ratio: FixedU128::one(),
+				is_closed: false,
+				terms: VaultTerms {
+					bitcoin_annual_percent_rate: FixedU128::from_rational(1, 10),
+					bitcoin_base_fee: 0,
+					treasury_profit_sharing: Permill::zero(),
+				},
+				pending_terms: None,
+				opened_tick: 0,
+				operational_minimum_release_tick: None,
+			};
+			VaultsById::<Test>::insert(1, vault(50_000));
+			VaultsById::<Test>::insert(2, vault(0));
+			VaultsById::<Test>::insert(3, vault(10_000));
+			VaultReputationById::<Test>::mutate(3, |reputation| {
+				reputation.lifetime_locked_satoshis = 20_000;
+			});
+			StorageVersion::new(17).put::<Pallet<Test>>();
+
+			SeedVaultReputationLockedSatoshisMigration::<Test>::try_on_runtime_upgrade(true)
+				.expect("runtime upgrade checks");
+
+			assert_eq!(VaultReputationById::<Test>::get(1).lifetime_locked_satoshis, 50_000);
+			assert_eq!(VaultReputationById::<Test>::get(2).lifetime_locked_satoshis, 0);
+			assert_eq!(VaultReputationById::<Test>::get(3).lifetime_locked_satoshis, 20_000);
+			assert_eq!(StorageVersion::get::<Pallet<Test>>(), 18);
+		});
+	}
 }
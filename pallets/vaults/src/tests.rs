@@ -3,14 +3,16 @@ use crate::{
 	pallet::{
 		ArgonotCommitmentByVaultId, BitcoinLockUpdate, NextVaultId,
 		PendingTermsModificationsByTick, RevenuePerFrameByVault, RevenuePerFrameByVaultCount,
-		VaultFundsReleasingByHeight, VaultXPubById, VaultsById,
+		VaultFundsReleasingByHeight, VaultReputationById, VaultXPubById, VaultsById,
 	},
 	Error, Event, HoldReason, LastCollectFrameByVaultId, OrphanedUtxoAccountsByVaultId,
 	PendingCosignByVaultId, VaultConfig, VaultIdByOperator,
 };
 use argon_primitives::{
 	bitcoin::{CompressedBitcoinPubkey, OpaqueBitcoinXpub, Satoshis},
-	vault::{BitcoinVaultProvider, VaultError, VaultLockRequest, VaultTerms},
+	vault::{
+		BitcoinVaultProvider, VaultError, VaultLockRequest, VaultReputationSummary, VaultTerms,
+	},
 };
 use bitcoin::{
 	bip32::{ChildNumber, Xpriv, Xpub},
@@ -1222,6 +1224,63 @@ fn vault_equilibrium_scenario(scenario: VaultScenario) {
 		assert_eq!(vault.securitization_locked, 0, "argons locked");
 		assert_eq!(vault.securitization, securitization - vault_should_lose, "securitization");
 		assert_eq!(Balances::free_balance(bitcoin_locker), user_should_get, "locker free balance");
+		assert_eq!(
+			VaultReputationById::<Test>::get(1).total_slashed,
+			vault_should_lose,
+			"reputation slashed"
+		);
+	});
+}
+
+#[test]
+fn it_tracks_vault_reputation() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		set_argons(1, 100_010);
+		assert_ok!(Vaults::create(RuntimeOrigin::signed(1), default_vault()));
+		assert_eq!(
+			Vaults::vault_reputations(),
+			vec![VaultReputationSummary {
+				vault_id: 1,
+				is_closed: false,
+				missed_cosigns: 0,
+				timed_cosigns: 0,
+				average_cosign_latency: None,
+				total_slashed: 0,
+				lifetime_locked_satoshis: 0,
+			}]
+		);
+
+		assert_ok!(Vaults::lock(1, &1, &securitization(1_000), standard_lock_request(1_000)));
+		assert_ok!(Vaults::add_securitized_satoshis(1, 1_000, FixedU128::one()));
+		assert_ok!(Vaults::lock(1, &1, &securitization(500), standard_lock_request(500)));
+		assert_ok!(Vaults::add_securitized_satoshis(1, 500, FixedU128::one()));
+		// releases don't reduce the lifetime total
+		assert_ok!(Vaults::schedule_for_release(
+			1,
+			&securitization(1_000),
+			1_000,
+			&LockExtension::new(100),
+			false,
+		));
+
+		Vaults::record_cosign(1, 3);
+		Vaults::record_cosign(1, 6);
+		Vaults::record_missed_cosign(1);
+
+		assert_eq!(
+			Vaults::vault_reputations(),
+			vec![VaultReputationSummary {
+				vault_id: 1,
+				is_closed: false,
+				missed_cosigns: 1,
+				timed_cosigns: 2,
+				average_cosign_latency: Some(4),
+				total_slashed: 0,
+				lifetime_locked_satoshis: 1_500,
+			}]
+		);
 	});
 }
 
@@ -33,6 +33,9 @@ pub trait WeightInfo {
 	fn provider_burn_encumbered_argonots() -> Weight;
 	fn provider_account_became_operational() -> Weight;
 	fn provider_set_bitcoin_lock_flexible() -> Weight;
+	fn provider_record_cosign() -> Weight;
+	fn provider_record_missed_cosign() -> Weight;
+	fn provider_record_locked_satoshis() -> Weight;
 }
 
 type TickProviderWeights<T> = <<T as crate::Config>::TickProvider as TickProvider<
@@ -165,6 +168,18 @@ where
 	fn provider_set_bitcoin_lock_flexible() -> Weight {
 		Base::provider_set_bitcoin_lock_flexible()
 	}
+
+	fn provider_record_cosign() -> Weight {
+		Base::provider_record_cosign()
+	}
+
+	fn provider_record_missed_cosign() -> Weight {
+		Base::provider_record_missed_cosign()
+	}
+
+	fn provider_record_locked_satoshis() -> Weight {
+		Base::provider_record_locked_satoshis()
+	}
 }
 
 pub struct ProviderWeightAdapter<T>(PhantomData<T>);
@@ -200,6 +215,18 @@ impl<T: crate::Config> BitcoinVaultProviderWeightInfo for ProviderWeightAdapter<
 	fn set_bitcoin_lock_flexible() -> Weight {
 		<T as crate::Config>::WeightInfo::provider_set_bitcoin_lock_flexible()
 	}
+
+	fn record_cosign() -> Weight {
+		<T as crate::Config>::WeightInfo::provider_record_cosign()
+	}
+
+	fn record_missed_cosign() -> Weight {
+		<T as crate::Config>::WeightInfo::provider_record_missed_cosign()
+	}
+
+	fn record_locked_satoshis() -> Weight {
+		<T as crate::Config>::WeightInfo::provider_record_locked_satoshis()
+	}
 }
 
 // For backwards compatibility and tests.
@@ -271,4 +298,13 @@ impl WeightInfo for () {
 	fn provider_set_bitcoin_lock_flexible() -> Weight {
 		Weight::zero()
 	}
+	fn provider_record_cosign() -> Weight {
+		Weight::zero()
+	}
+	fn provider_record_missed_cosign() -> Weight {
+		Weight::zero()
+	}
+	fn provider_record_locked_satoshis() -> Weight {
+		Weight::zero()
+	}
 }
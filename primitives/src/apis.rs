@@ -3,7 +3,10 @@
 #![allow(clippy::multiple_bound_locations)]
 
 use crate::{
-	bitcoin::{BitcoinNetwork, BitcoinSyncStatus, Satoshis, UtxoAddress, UtxoRef, UtxoValue},
+	bitcoin::{
+		BitcoinNetwork, BitcoinSyncStatus, Satoshis, UtxoAddress, UtxoRef, UtxoValue,
+		VaultLockXPubSource, XPubFingerprint,
+	},
 	block_seal::{BlockPayout, ComputePuzzle, MiningAuthority},
 	notary::{
 		NotaryId, NotaryNotebookAuditSummary, NotaryNotebookDetails, NotaryNotebookRawVotes,
@@ -11,7 +14,8 @@ use crate::{
 	},
 	prelude::*,
 	tick::Ticker,
-	BestBlockVoteSeal, BlockSealDigest, BlockVoteDigest, NotebookAuditResult, VoteMinimum,
+	vault::{TreasuryBondYieldProjection, TreasuryProgramYields, VaultReputationSummary},
+	BestBlockVoteSeal, BlockSealDigest, BlockVoteDigest, NotebookAuditResult, VaultId, VoteMinimum,
 	VotingKey,
 };
 use alloc::{collections::btree_map::BTreeMap, vec::Vec};
@@ -117,5 +121,28 @@ sp_api::decl_runtime_apis! {
 		) -> Option<Balance>;
 		fn market_rate(satoshis: Satoshis) -> Option<Balance>;
 		fn get_bitcoin_network() -> BitcoinNetwork;
+		#[api_version(5)]
+		fn vault_lock_xpub_sources(vault_id: VaultId) -> BTreeMap<XPubFingerprint, Vec<VaultLockXPubSource>>;
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait TreasuryApis<Balance: Codec> {
+		/// The recent frame yields of the Argonot bond program and each vault's bond program.
+		fn bond_program_yields() -> Vec<TreasuryProgramYields<Balance>>;
+		/// Projects the next-frame yield of buying `bonds` in a vault's bond program (or the
+		/// Argonot bond program if no vault is given).
+		fn project_bond_yield(
+			vault_id: Option<VaultId>,
+			bonds: u32,
+		) -> Result<TreasuryBondYieldProjection<Balance>, DispatchError>;
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait VaultApis<Balance: Codec> {
+		/// The lifetime reputation of every vault with bitcoin lockers (missed cosigns, cosign
+		/// latency, slashed securitization and locked satoshis), for wallets ranking vaults.
+		fn vault_reputations() -> Vec<VaultReputationSummary<Balance>>;
 	}
 }
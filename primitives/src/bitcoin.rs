@@ -260,6 +260,29 @@ pub enum BitcoinNetwork {
 pub type XPubFingerprint = [u8; 4];
 pub type XPubChildNumber = u32;
 
+/// The vault keys a bitcoin lock was created with, and the xpub children they were derived from.
+/// Used by vault operators to confirm they can still reproduce every outstanding lock key.
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, TypeInfo, Debug)]
+pub struct VaultLockXPubSource {
+	#[codec(compact)]
+	pub utxo_id: UtxoId,
+	/// The vault pubkey used in the cosign script
+	pub vault_pubkey: CompressedBitcoinPubkey,
+	/// The vault pubkey used to claim the bitcoin after the lock expiration
+	pub vault_claim_pubkey: CompressedBitcoinPubkey,
+	/// The child number of the vault xpub used to derive the `vault_pubkey`
+	#[codec(compact)]
+	pub cosign_child_number: XPubChildNumber,
+	/// The child number of the vault xpub used to derive the `vault_claim_pubkey`
+	#[codec(compact)]
+	pub claim_child_number: XPubChildNumber,
+	/// Whether the lock has been funded on bitcoin
+	pub is_funded: bool,
+	/// The bitcoin height where the vault can claim the bitcoin
+	#[codec(compact)]
+	pub vault_claim_height: BitcoinHeight,
+}
+
 #[derive(
 	Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq, Debug,
 )]
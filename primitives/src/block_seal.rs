@@ -7,12 +7,61 @@ use serde::{Deserialize, Serialize};
 use sp_application_crypto::AppCrypto;
 use sp_core::{
 	crypto::{CryptoTypeId, KeyTypeId},
-	OpaquePeerId, H256,
+	OpaquePeerId, H256, U256,
 };
+use sp_crypto_hashing::blake2_256;
 use sp_runtime::traits::{Block, OpaqueKeys};
 
 pub const BLOCK_SEAL_KEY_TYPE: KeyTypeId = KeyTypeId(*b"seal");
 
+/// The maximum allowed deviation (offset) from the expected number of block wins for a miner in a
+/// frame. This limit helps ensure fairness by allowing minor statistical fluctuations, but prevents
+/// significant deviations that could indicate manipulation or unfair advantage. The value 5 was
+/// chosen given that normal usage stays within a 3-4% threshold and the max miners is 144.
+pub const MAX_MINER_BLOCK_OFFSET: i16 = 5;
+
+/// Scores a miner nonce against a vote seal proof. The lowest score closes the vote block.
+///
+/// The base score is random per seal proof and nonce. It's nudged by how many blocks the miner is
+/// ahead of (positive) or behind (negative) its expected share in the frame, plus a penalty if the
+/// miner won a block recently, so blocks stay evenly distributed between miners.
+pub fn closest_miner_score(
+	seal_proof: U256,
+	miner_nonce: U256,
+	wins_against_expected: i16,
+	recently_won: bool,
+) -> U256 {
+	// 1. Create a full U256 from the concatenation of the seal_proof and the miner_nonce
+	let hash_bytes = (seal_proof, miner_nonce).using_encoded(blake2_256);
+
+	let mut r2 = [0u8; 2];
+	for (i, b) in hash_bytes.iter().enumerate() {
+		r2[i & 1] ^= *b;
+	}
+	let random_base_score = u16::from_le_bytes(r2);
+
+	// Apply a penalty to recently closed blocks to prevent miners turning off until the end of
+	// a mining term and then turning back on to gain an advantage. Between the cap and this
+	// penalty for recency, it should continue to distribute evenly in normal cases, but avoid
+	// late-frame monopolization.
+	let recent_block_penalty = if recently_won { 2i16 } else { 0i16 };
+
+	let fairness_baseline = wins_against_expected.saturating_add(recent_block_penalty) as i64;
+	let fairness_score =
+		fairness_baseline.clamp(-(MAX_MINER_BLOCK_OFFSET as i64), MAX_MINER_BLOCK_OFFSET as i64);
+
+	// Apply a bounded, *additive* correction based on deviation. Do not scale by
+	// `random_base_score` or we risk R + R*multiplier == 0 (or negative wrap),
+	// which collapses the distribution. We keep the jitter (`random_base_score`)
+	// independent and nudge it by a fixed gain per win delta.
+	let multiplier = fairness_score.abs() << 10;
+	let score_adjustment = fairness_score.saturating_mul(multiplier);
+
+	let final_score = (random_base_score as i64).saturating_add(score_adjustment);
+	let shifted = final_score.saturating_add(i64::MAX / 2); // move negative region upward
+	U256::from(shifted as u64)
+}
+
 // sr25519 signatures are non-deterministic, so we use ed25519 for deterministic signatures since
 // these are part of the nonce hash
 pub mod app {
@@ -207,4 +256,6 @@ pub enum BlockRewardType {
 	Miner,
 	Voter,
 	ProfitShare,
+	/// A member's share of a compute mining pool's miner reward
+	MiningPoolShare,
 }
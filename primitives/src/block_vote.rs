@@ -9,10 +9,46 @@ use polkadot_sdk::sp_runtime::Permill;
 use serde::{Deserialize, Serialize};
 use sp_core::{H256, U256};
 use sp_crypto_hashing::blake2_256;
-use sp_runtime::{scale_info::TypeInfo, MultiSignature};
+use sp_runtime::{scale_info::TypeInfo, traits::UniqueSaturatedInto, MultiSignature};
 
 pub type VoteMinimum = u128;
 
+/// The most the vote minimum can adjust up (x4) or down (/4) in one period
+const MAX_VOTE_MINIMUM_ADJUST: u128 = 4;
+
+/// The next vote minimum given the votes cast in a period versus the target. The change is
+/// capped at 4x up or 1/4 down, and the result is clamped between the given minimum and maximum.
+pub fn calculate_next_vote_minimum(
+	current_vote_minimum: VoteMinimum,
+	target_period_votes: u128,
+	actual_period_votes: u128,
+	min_vote_minimum: VoteMinimum,
+	max_vote_minimum: VoteMinimum,
+) -> VoteMinimum {
+	// Calculate the adjusted time span.
+	let mut adjusted_votes = match actual_period_votes {
+		x if x < target_period_votes / MAX_VOTE_MINIMUM_ADJUST =>
+			target_period_votes / MAX_VOTE_MINIMUM_ADJUST,
+		x if x > target_period_votes * MAX_VOTE_MINIMUM_ADJUST =>
+			target_period_votes * MAX_VOTE_MINIMUM_ADJUST,
+		x => x,
+	};
+	// don't divide by 0
+	if adjusted_votes == 0 {
+		adjusted_votes = 1;
+	}
+
+	// Compute the next vote minimum based on the current one and the ratio of target votes to
+	// adjusted votes.
+	let next_vote_minimum: u128 = U256::from(current_vote_minimum)
+		.saturating_mul(adjusted_votes.into())
+		.checked_div(target_period_votes.into())
+		.unwrap_or(0.into())
+		.unique_saturated_into();
+
+	next_vote_minimum.clamp(min_vote_minimum, max_vote_minimum)
+}
+
 #[derive(
 	Clone,
 	PartialEq,
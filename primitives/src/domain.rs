@@ -8,7 +8,11 @@ use sp_core::{ConstU32, H256};
 use sp_crypto_hashing::blake2_256;
 use sp_runtime::{BoundedBTreeMap, BoundedVec};
 
-use crate::{domain_top_level::DomainTopLevel, host::Host, Balance, NotaryId};
+use crate::{
+	domain_top_level::{DomainTopLevel, DomainTopLevelId, BUILT_IN_DOMAIN_TOP_LEVELS},
+	host::Host,
+	Balance, NotaryId,
+};
 
 pub const MAX_DATASTORE_VERSIONS: u32 = 25;
 
@@ -18,6 +22,11 @@ pub const MIN_DOMAIN_NAME_LENGTH: usize = 2;
 
 pub type DomainHash = H256;
 
+/// Hashes of domains under top levels added through governance start with this marker followed
+/// by the top level id, so the mainchain can tell which top level a lease (which only contains
+/// the hash) is for.
+pub const ADDED_TOP_LEVEL_DOMAIN_HASH_PREFIX: [u8; 4] = *b"tld:";
+
 #[derive(
 	Clone,
 	PartialEq,
@@ -38,7 +47,30 @@ pub struct Domain {
 
 impl Domain {
 	pub fn hash(&self) -> DomainHash {
-		self.using_encoded(blake2_256).into()
+		Self::hash_with_top_level_id(&self.name, self.top_level.id())
+	}
+
+	/// Hash a domain by the id of its top level. Top levels added through governance don't have
+	/// a [`DomainTopLevel`] variant, so they can only be hashed by id.
+	pub fn hash_with_top_level_id(name: &str, top_level_id: DomainTopLevelId) -> DomainHash {
+		let mut hash = (name, top_level_id).using_encoded(blake2_256);
+		if top_level_id >= BUILT_IN_DOMAIN_TOP_LEVELS {
+			let prefix_len = ADDED_TOP_LEVEL_DOMAIN_HASH_PREFIX.len();
+			hash[..prefix_len].copy_from_slice(&ADDED_TOP_LEVEL_DOMAIN_HASH_PREFIX);
+			hash[prefix_len] = top_level_id;
+		}
+		hash.into()
+	}
+
+	/// The top level id of a domain hash under a top level added through governance (built in
+	/// top levels can't be read from a hash).
+	pub fn added_top_level_id(domain_hash: &DomainHash) -> Option<DomainTopLevelId> {
+		let prefix_len = ADDED_TOP_LEVEL_DOMAIN_HASH_PREFIX.len();
+		let bytes = domain_hash.as_bytes();
+		if bytes[..prefix_len] != ADDED_TOP_LEVEL_DOMAIN_HASH_PREFIX {
+			return None;
+		}
+		Some(bytes[prefix_len]).filter(|id| *id >= BUILT_IN_DOMAIN_TOP_LEVELS)
 	}
 }
 
@@ -71,7 +103,7 @@ impl Domain {
 	#[cfg(feature = "std")]
 	pub fn parse(domain: String) -> Result<Self, String> {
 		let parts: Vec<&str> = domain.split('.').collect();
-		if parts.len() < 2 {
+		if parts.len() < 2 || parts[0].is_empty() || parts[1].is_empty() {
 			return Err("Invalid domain".to_string());
 		}
 		let top_level = parts[1];
@@ -199,3 +231,48 @@ impl Ord for Semver {
 		self.patch.cmp(&other.patch)
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn hashes_built_in_top_levels_by_id() {
+		for id in 0..BUILT_IN_DOMAIN_TOP_LEVELS {
+			let top_level = DomainTopLevel::from_id(id).expect("built in top level");
+			assert_eq!(top_level.id(), id);
+			assert_eq!(DomainTopLevel::from_name(top_level.name()).map(|x| x.id()), Some(id));
+			let domain = Domain::from_string("delta".to_string(), top_level);
+			assert_eq!(domain.hash(), domain.using_encoded(blake2_256).into());
+			assert_eq!(domain.hash(), Domain::hash_with_top_level_id("delta", id));
+		}
+		assert!(DomainTopLevel::from_id(BUILT_IN_DOMAIN_TOP_LEVELS).is_none());
+		assert_ne!(
+			Domain::hash_with_top_level_id("delta", BUILT_IN_DOMAIN_TOP_LEVELS),
+			Domain::hash_with_top_level_id("delta", BUILT_IN_DOMAIN_TOP_LEVELS + 1)
+		);
+	}
+
+	#[test]
+	fn reads_added_top_level_ids_from_hashes() {
+		let id = BUILT_IN_DOMAIN_TOP_LEVELS + 3;
+		assert_eq!(
+			Domain::added_top_level_id(&Domain::hash_with_top_level_id("delta", id)),
+			Some(id)
+		);
+		assert_eq!(
+			Domain::added_top_level_id(&Domain::new("delta", DomainTopLevel::Cars).hash()),
+			None
+		);
+	}
+
+	#[test]
+	fn validates_top_level_names() {
+		assert!(DomainTopLevel::is_valid_name(b"real-estate"));
+		assert!(DomainTopLevel::is_valid_name(b"web3"));
+		assert!(!DomainTopLevel::is_valid_name(b""));
+		assert!(!DomainTopLevel::is_valid_name(b"Cars"));
+		assert!(!DomainTopLevel::is_valid_name(b"-cars"));
+		assert!(!DomainTopLevel::is_valid_name(b"ca.rs"));
+	}
+}
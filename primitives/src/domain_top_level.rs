@@ -42,3 +42,65 @@ pub enum DomainTopLevel {
 	Travel,
 	Weather,
 }
+
+/// The id a top level domain is hashed with. Ids of the built in [`DomainTopLevel`] variants are
+/// their encoded index, so domains hash the same whether they use the enum or a registered id.
+pub type DomainTopLevelId = u8;
+
+/// The number of built in top level domains. Top level domains added through governance are
+/// assigned ids after these.
+pub const BUILT_IN_DOMAIN_TOP_LEVELS: DomainTopLevelId = 20;
+
+/// The maximum length of a top level domain name
+pub const MAX_DOMAIN_TOP_LEVEL_LENGTH: u32 = 32;
+
+impl DomainTopLevel {
+	pub fn id(&self) -> DomainTopLevelId {
+		self.encode()[0]
+	}
+
+	pub fn from_id(id: DomainTopLevelId) -> Option<Self> {
+		Self::decode(&mut &[id][..]).ok()
+	}
+
+	/// The name used in a domain (eg, `real-estate`)
+	pub fn name(&self) -> &'static str {
+		match self {
+			DomainTopLevel::Analytics => "analytics",
+			DomainTopLevel::Automotive => "automotive",
+			DomainTopLevel::Bikes => "bikes",
+			DomainTopLevel::Business => "business",
+			DomainTopLevel::Cars => "cars",
+			DomainTopLevel::Communication => "communication",
+			DomainTopLevel::Entertainment => "entertainment",
+			DomainTopLevel::Finance => "finance",
+			DomainTopLevel::Flights => "flights",
+			DomainTopLevel::Health => "health",
+			DomainTopLevel::Hotels => "hotels",
+			DomainTopLevel::Jobs => "jobs",
+			DomainTopLevel::News => "news",
+			DomainTopLevel::RealEstate => "real-estate",
+			DomainTopLevel::Restaurants => "restaurants",
+			DomainTopLevel::Shopping => "shopping",
+			DomainTopLevel::Sports => "sports",
+			DomainTopLevel::Transportation => "transportation",
+			DomainTopLevel::Travel => "travel",
+			DomainTopLevel::Weather => "weather",
+		}
+	}
+
+	pub fn from_name(name: &str) -> Option<Self> {
+		(0..BUILT_IN_DOMAIN_TOP_LEVELS)
+			.filter_map(Self::from_id)
+			.find(|x| x.name().eq_ignore_ascii_case(name))
+	}
+
+	/// Top level domain names are lowercase ascii letters, digits and dashes
+	pub fn is_valid_name(name: &[u8]) -> bool {
+		!name.is_empty() &&
+			name.len() <= MAX_DOMAIN_TOP_LEVEL_LENGTH as usize &&
+			name.first() != Some(&b'-') &&
+			name.last() != Some(&b'-') &&
+			name.iter().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || *c == b'-')
+	}
+}
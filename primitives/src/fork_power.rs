@@ -130,33 +130,59 @@ impl PartialOrd for ForkPower {
 	/// - NOTE: notebook count is intentionally excluded from ordering to avoid private/withheld
 	///   notebook release influencing fork choice.
 	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.compare(other).0)
+	}
+}
+
+/// The field of [`ForkPower`] that decided a fork comparison
+#[derive(Clone, Copy, Debug, Eq, PartialEq, TypeInfo)]
+pub enum ForkChoiceReason {
+	VoteCreatedBlocks,
+	VotingPower,
+	SealStrength,
+	MinerNonceScore,
+	TotalComputeDifficulty,
+	/// All fork power inputs are equal
+	Equal,
+}
+
+impl ForkPower {
+	/// Compares two fork powers (see [`PartialOrd`]), also returning which input decided the
+	/// comparison.
+	pub fn compare(&self, other: &Self) -> (Ordering, ForkChoiceReason) {
 		// count forks with tax votes over compute
-		let mut cmp = self.vote_created_blocks.cmp(&other.vote_created_blocks);
-		if cmp == Ordering::Equal {
-			// total spend on vote tax
-			cmp = self.voting_power.cmp(&other.voting_power);
+		let cmp = self.vote_created_blocks.cmp(&other.vote_created_blocks);
+		if cmp != Ordering::Equal {
+			return (cmp, ForkChoiceReason::VoteCreatedBlocks);
+		}
+		// total spend on vote tax
+		let cmp = self.voting_power.cmp(&other.voting_power);
+		if cmp != Ordering::Equal {
+			return (cmp, ForkChoiceReason::VotingPower);
 		}
 
 		// we should only compare these when both are vote blocks since a compute block would lose
 		// this comparison with seal strength = u256::MAX
 		if self.is_latest_vote && other.is_latest_vote {
-			if cmp == Ordering::Equal {
-				// smaller vote proof is better
-				cmp = other.seal_strength.cmp(&self.seal_strength)
+			// smaller vote proof is better
+			let cmp = other.seal_strength.cmp(&self.seal_strength);
+			if cmp != Ordering::Equal {
+				return (cmp, ForkChoiceReason::SealStrength);
 			}
-			if cmp == Ordering::Equal {
-				let self_miner_nonce_score = self.miner_nonce_score.as_ref().unwrap_or(&U256::MAX);
-				let other_miner_nonce_score =
-					other.miner_nonce_score.as_ref().unwrap_or(&U256::MAX);
-				// smaller xor distance is better
-				cmp = other_miner_nonce_score.cmp(self_miner_nonce_score);
+			let self_miner_nonce_score = self.miner_nonce_score.as_ref().unwrap_or(&U256::MAX);
+			let other_miner_nonce_score = other.miner_nonce_score.as_ref().unwrap_or(&U256::MAX);
+			// smaller xor distance is better
+			let cmp = other_miner_nonce_score.cmp(self_miner_nonce_score);
+			if cmp != Ordering::Equal {
+				return (cmp, ForkChoiceReason::MinerNonceScore);
 			}
 		}
 
-		if cmp == Ordering::Equal {
-			cmp = self.total_compute_difficulty.cmp(&other.total_compute_difficulty)
+		let cmp = self.total_compute_difficulty.cmp(&other.total_compute_difficulty);
+		if cmp != Ordering::Equal {
+			return (cmp, ForkChoiceReason::TotalComputeDifficulty);
 		}
-		Some(cmp)
+		(Ordering::Equal, ForkChoiceReason::Equal)
 	}
 }
 
@@ -213,6 +239,27 @@ mod test {
 		assert!(fork_a > fork_b, "smaller xor distance should be better");
 	}
 
+	#[test]
+	fn it_reports_the_fork_choice_reason() {
+		let mut fork_a = ForkPower::default();
+		let mut fork_b = ForkPower::default();
+		assert_eq!(fork_a.compare(&fork_b), (Ordering::Equal, ForkChoiceReason::Equal));
+
+		fork_a.add_vote(10, 1, U256::from(5), None);
+		fork_b.add_vote(10, 1, U256::from(4), None);
+		assert_eq!(fork_a.compare(&fork_b), (Ordering::Less, ForkChoiceReason::SealStrength));
+
+		fork_b.add_compute(1, 0, 100);
+		assert_eq!(fork_a.compare(&fork_b), (Ordering::Less, ForkChoiceReason::VotingPower));
+
+		fork_a.add_compute(1, 0, 100);
+		fork_a.add_vote(0, 0, U256::one(), None);
+		assert_eq!(
+			fork_a.compare(&fork_b),
+			(Ordering::Greater, ForkChoiceReason::VoteCreatedBlocks)
+		);
+	}
+
 	#[test]
 	fn it_can_decode_old_fork_power() {
 		let hex = "01f25e0e00000000000000000000000000000000000000000000000000000000000000000028f6beac0bd41db02f6e1e3e74d0b81ee231ebc0fa8c03f2c101a14b6f55223956003c8c00000000000000000000000000000000000000000000000000000000b2c10300";
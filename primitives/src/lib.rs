@@ -19,7 +19,9 @@ pub use block_vote::*;
 use core::str::FromStr;
 pub use digests::{BlockSealDigest, AUTHOR_DIGEST_ID, BLOCK_SEAL_DIGEST_ID, *};
 pub use domain::*;
-pub use domain_top_level::DomainTopLevel;
+pub use domain_top_level::{
+	DomainTopLevel, DomainTopLevelId, BUILT_IN_DOMAIN_TOP_LEVELS, MAX_DOMAIN_TOP_LEVEL_LENGTH,
+};
 pub use ethereum::*;
 #[cfg(feature = "std")]
 pub use keystore_helper::*;
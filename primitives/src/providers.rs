@@ -66,6 +66,16 @@ impl ChainTransferLookupWeightInfo for () {
 	}
 }
 
+pub trait LocalchainRefundProviderWeightInfo {
+	fn refund_to_localchain() -> Weight;
+}
+
+impl LocalchainRefundProviderWeightInfo for () {
+	fn refund_to_localchain() -> Weight {
+		Weight::zero()
+	}
+}
+
 pub trait MiningSlotProviderWeightInfo {
 	fn has_active_rewards_account_seat() -> Weight;
 }
@@ -945,6 +955,31 @@ pub trait ChainTransferLookup<AccountId, Balance> {
 	) -> bool;
 }
 
+/// Returns funds to a localchain account through its notary
+pub trait LocalchainRefundProvider<AccountId, Balance> {
+	type Weights: LocalchainRefundProviderWeightInfo;
+
+	/// Queue a transfer to the account's localchain on the given notary. The localchain claims it
+	/// with the returned transfer id (and it returns to the mainchain account if never claimed).
+	fn refund_to_localchain(
+		notary_id: NotaryId,
+		account_id: &AccountId,
+		microgons: Balance,
+	) -> Result<TransferToLocalchainId, DispatchError>;
+}
+
+impl<AccountId, Balance> LocalchainRefundProvider<AccountId, Balance> for () {
+	type Weights = ();
+
+	fn refund_to_localchain(
+		_notary_id: NotaryId,
+		_account_id: &AccountId,
+		_microgons: Balance,
+	) -> Result<TransferToLocalchainId, DispatchError> {
+		Err(DispatchError::Other("localchain refunds unavailable"))
+	}
+}
+
 pub trait BlockSealSpecProvider<Block: BlockT> {
 	type Weights: BlockSealSpecProviderWeightInfo;
 
@@ -1084,6 +1119,9 @@ impl BlockSealEventHandler for Tuple {
 /// An event handler to listen for submitted notebook
 pub trait BurnEventHandler<Balance> {
 	fn on_argon_burn(microgons: &Balance);
+
+	/// Argons that were burned and later minted back (eg, refunded localchain tax)
+	fn on_argon_burn_refunded(microgons: &Balance);
 }
 
 #[impl_trait_for_tuples::impl_for_tuples(5)]
@@ -1091,6 +1129,10 @@ impl<Balance> BurnEventHandler<Balance> for Tuple {
 	fn on_argon_burn(microgons: &Balance) {
 		for_tuples!( #( Tuple::on_argon_burn(microgons); )* );
 	}
+
+	fn on_argon_burn_refunded(microgons: &Balance) {
+		for_tuples!( #( Tuple::on_argon_burn_refunded(microgons); )* );
+	}
 }
 
 pub trait BlockRewardsEventHandler<AccountId: Codec, Balance: Codec + MaxEncodedLen> {
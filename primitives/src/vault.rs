@@ -1,4 +1,4 @@
-use alloc::collections::BTreeSet;
+use alloc::{collections::BTreeSet, vec::Vec};
 use codec::{Codec, Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use core::iter::Sum;
 use frame_support::{weights::Weight, PalletError};
@@ -31,6 +31,9 @@ pub trait BitcoinVaultProviderWeightInfo {
 	fn burn_encumbered_argonots() -> Weight;
 	fn account_became_operational() -> Weight;
 	fn set_bitcoin_lock_flexible() -> Weight;
+	fn record_cosign() -> Weight;
+	fn record_missed_cosign() -> Weight;
+	fn record_locked_satoshis() -> Weight;
 }
 
 impl BitcoinVaultProviderWeightInfo for () {
@@ -65,6 +68,18 @@ impl BitcoinVaultProviderWeightInfo for () {
 	fn set_bitcoin_lock_flexible() -> Weight {
 		Weight::zero()
 	}
+
+	fn record_cosign() -> Weight {
+		Weight::zero()
+	}
+
+	fn record_missed_cosign() -> Weight {
+		Weight::zero()
+	}
+
+	fn record_locked_satoshis() -> Weight {
+		Weight::zero()
+	}
 }
 
 pub const TREASURY_BONUS_APPROVAL_PROOF_MESSAGE_KEY: &[u8] = b"treasury_bonus_approval";
@@ -135,6 +150,76 @@ pub struct VaultTreasuryFrameEarnings<Balance, AccountId> {
 	pub capital_contributed_by_vault: Balance,
 }
 
+/// The earnings of one treasury bond program in one frame.
+#[derive(
+	Clone, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub struct TreasuryFrameYield<Balance> {
+	#[codec(compact)]
+	pub frame_id: FrameId,
+	/// The bonds that shared the earnings.
+	#[codec(compact)]
+	pub bonds: u32,
+	/// The microgon value of the bonds (Argonot bonds are valued at the frame's argonot price).
+	#[codec(compact)]
+	pub capital: Balance,
+	/// The microgons paid to the bond holders (payouts that failed to transfer are not included).
+	#[codec(compact)]
+	pub earnings: Balance,
+}
+
+/// The recent frame yields of a treasury bond program, as seen by a bond holder.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, Debug, TypeInfo)]
+pub struct TreasuryProgramYields<Balance> {
+	/// The vault of a vault bond program, or `None` for the Argonot bond program.
+	pub vault_id: Option<VaultId>,
+	/// The share of gross earnings paid to bond holders. Vault programs show the vault's current
+	/// profit sharing (without bonuses); frame yields use what was actually paid.
+	pub sharing_percent: Permill,
+	/// Newest frame first.
+	pub frames: Vec<TreasuryFrameApy<Balance>>,
+}
+
+/// A bond holder's yield in one frame.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, Debug, TypeInfo)]
+pub struct TreasuryFrameApy<Balance> {
+	#[codec(compact)]
+	pub frame_id: FrameId,
+	#[codec(compact)]
+	pub bonds: u32,
+	#[codec(compact)]
+	pub capital: Balance,
+	/// The microgons paid to bond holders.
+	#[codec(compact)]
+	pub earnings: Balance,
+	/// The frame yield annualized over a year of frames (earnings are paid out, not compounded).
+	#[codec(compact)]
+	pub apy: FixedU128,
+}
+
+/// The projected next-frame yield of buying a number of bonds in a treasury bond program.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, Debug, TypeInfo)]
+pub struct TreasuryBondYieldProjection<Balance> {
+	/// The vault of a vault bond program, or `None` for the Argonot bond program.
+	pub vault_id: Option<VaultId>,
+	#[codec(compact)]
+	pub bonds: u32,
+	/// The bond lot the purchase would bump out of the accepted lots.
+	pub bumped_bond_lot_id: Option<u64>,
+	#[codec(compact)]
+	pub bumped_bonds: u32,
+	/// False if the vault would fall outside the vaults that share the next frame's bid pool.
+	pub is_participating: bool,
+	/// The microgon value of the purchased bonds.
+	#[codec(compact)]
+	pub capital: Balance,
+	/// The projected microgons paid to the purchase for one frame.
+	#[codec(compact)]
+	pub earnings: Balance,
+	#[codec(compact)]
+	pub apy: FixedU128,
+}
+
 #[derive(
 	Clone,
 	PartialEq,
@@ -160,6 +245,79 @@ where
 	pub encumbered_micronots: Balance,
 }
 
+/// A vault's lifetime track record with bitcoin lockers.
+#[derive(
+	Clone,
+	PartialEq,
+	Eq,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Debug,
+	TypeInfo,
+	MaxEncodedLen,
+	Default,
+)]
+pub struct VaultReputation<Balance>
+where
+	Balance: Codec + Copy + MaxEncodedLen + Default + AtLeast32BitUnsigned + TypeInfo,
+{
+	/// Release requests that were not cosigned before they were due.
+	#[codec(compact)]
+	pub missed_cosigns: u32,
+	/// Release requests cosigned with a known request height.
+	#[codec(compact)]
+	pub timed_cosigns: u32,
+	/// Bitcoin blocks between each timed cosign's request and its cosign, summed.
+	#[codec(compact)]
+	pub total_cosign_latency: BitcoinHeight,
+	/// Securitization taken from the vault to compensate lockers or burned as a penalty.
+	#[codec(compact)]
+	pub total_slashed: Balance,
+	/// Satoshis of every bitcoin lock ever funded in the vault. Vaults opened before reputation
+	/// tracking were seeded with the satoshis they had locked at the time.
+	#[codec(compact)]
+	pub lifetime_locked_satoshis: Satoshis,
+}
+
+impl<Balance> VaultReputation<Balance>
+where
+	Balance: Codec + Copy + MaxEncodedLen + Default + AtLeast32BitUnsigned + TypeInfo,
+{
+	pub fn record_cosign(&mut self, cosign_latency: BitcoinHeight) {
+		self.timed_cosigns.saturating_inc();
+		self.total_cosign_latency.saturating_accrue(cosign_latency);
+	}
+
+	/// The average bitcoin blocks a vault takes to cosign a release request.
+	pub fn average_cosign_latency(&self) -> Option<BitcoinHeight> {
+		if self.timed_cosigns == 0 {
+			return None;
+		}
+		Some(self.total_cosign_latency / self.timed_cosigns as BitcoinHeight)
+	}
+}
+
+/// A vault's reputation as exposed to wallets ranking vaults.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, Debug, TypeInfo)]
+pub struct VaultReputationSummary<Balance> {
+	#[codec(compact)]
+	pub vault_id: VaultId,
+	pub is_closed: bool,
+	#[codec(compact)]
+	pub missed_cosigns: u32,
+	/// Cosigned releases the average latency is measured over.
+	#[codec(compact)]
+	pub timed_cosigns: u32,
+	/// The average bitcoin blocks between a release request and its cosign. `None` until a
+	/// release has been cosigned.
+	pub average_cosign_latency: Option<BitcoinHeight>,
+	#[codec(compact)]
+	pub total_slashed: Balance,
+	#[codec(compact)]
+	pub lifetime_locked_satoshis: Satoshis,
+}
+
 pub trait TreasuryVaultProvider {
 	type Balance: Codec;
 	type AccountId: Codec;
@@ -381,6 +539,12 @@ pub trait BitcoinVaultProvider {
 		account_id: &Self::AccountId,
 		should_remove: bool,
 	) -> Result<(), VaultError>;
+
+	/// Record a release cosigned `cosign_latency` bitcoin blocks after it was requested.
+	fn record_cosign(_vault_id: VaultId, _cosign_latency: BitcoinHeight) {}
+
+	/// Record a release request that was not cosigned before it was due.
+	fn record_missed_cosign(_vault_id: VaultId) {}
 }
 
 #[derive(
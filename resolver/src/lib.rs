@@ -0,0 +1,331 @@
+use argon_client::{
+	api, api::storage, types::H256 as ApiH256, ArgonConfig, FetchAt, MainchainClient,
+	ReconnectingClient,
+};
+use argon_primitives::{
+	AccountId, Domain, DomainHash, DomainTopLevel, DomainTopLevelId, NotaryId, Semver, VersionHost,
+	ZoneRecord, ADDRESS_PREFIX,
+};
+use codec::{Decode, Encode};
+use futures::StreamExt;
+use polkadot_sdk::*;
+use schnellru::{ByLength, LruMap};
+use serde::Serialize;
+use sp_core::crypto::Ss58Codec;
+use std::sync::Arc;
+use subxt::{dynamic::Value, events::EventDetails};
+use tokio::sync::Mutex;
+use tracing::{info, trace};
+
+pub use semver::VersionReq;
+
+/// The most zone records (including domains without a record) to keep cached
+const ZONE_RECORD_CACHE_SIZE: u32 = 10_000;
+/// The most top level domains added through governance to keep cached
+const TOP_LEVEL_CACHE_SIZE: u32 = 1_000;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("Invalid domain: {0}")]
+	InvalidDomain(String),
+	#[error("Unknown top level domain: {0}")]
+	UnknownTopLevel(String),
+	#[error("Invalid version range: {0}")]
+	InvalidVersionRange(#[from] semver::Error),
+	#[error("The domain is not registered or has no zone record")]
+	NoZoneRecord,
+	#[error("No version of the domain matches {0}")]
+	NoMatchingVersion(VersionReq),
+	#[error("The zone record host is invalid: {0}")]
+	InvalidHost(String),
+	#[error(transparent)]
+	Mainchain(#[from] anyhow::Error),
+}
+
+/// The host and payment details to use for a domain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedDomain {
+	pub domain: String,
+	pub domain_hash: DomainHash,
+	pub version: String,
+	pub host: String,
+	pub datastore_id: String,
+	pub payment_address: String,
+	pub notary_id: NotaryId,
+}
+
+/// Resolves domains to the host of the best matching version in their zone record.
+///
+/// Zone records and top level domains added through governance are read from finalized mainchain
+/// state and kept in bounded caches (including domains without a record). Run
+/// [`DomainResolver::watch`] to keep the caches in sync with `ZoneRecordUpdated` events, domains
+/// that expire or change owner, and top level domains that are added.
+#[derive(Clone)]
+pub struct DomainResolver {
+	client: Arc<Mutex<ReconnectingClient>>,
+	cache: Arc<Mutex<LruMap<DomainHash, Option<ZoneRecord<AccountId>>>>>,
+	top_levels: Arc<Mutex<LruMap<String, Option<DomainTopLevelId>>>>,
+}
+
+impl DomainResolver {
+	pub fn new(client: ReconnectingClient) -> Self {
+		Self {
+			client: Arc::new(Mutex::new(client)),
+			cache: Arc::new(Mutex::new(LruMap::new(ByLength::new(ZONE_RECORD_CACHE_SIZE)))),
+			top_levels: Arc::new(Mutex::new(LruMap::new(ByLength::new(TOP_LEVEL_CACHE_SIZE)))),
+		}
+	}
+
+	async fn client(&self) -> anyhow::Result<MainchainClient> {
+		self.client.lock().await.get().await
+	}
+
+	/// Resolve a domain (eg, `delta.flights`) to the highest version matching the semver range
+	/// (eg, `^1.2`)
+	pub async fn resolve(&self, domain: &str, version_req: &str) -> Result<ResolvedDomain, Error> {
+		let version_req = VersionReq::parse(version_req)?;
+		let domain_hash = self.domain_hash(domain).await?;
+		let zone_record = self.zone_record(domain_hash).await?.ok_or(Error::NoZoneRecord)?;
+		let (version, host) = best_version(&zone_record, &version_req)
+			.ok_or(Error::NoMatchingVersion(version_req))?;
+
+		Ok(ResolvedDomain {
+			domain: domain.to_string(),
+			domain_hash,
+			version: format!("{}.{}.{}", version.major, version.minor, version.patch),
+			host: host
+				.host
+				.clone()
+				.try_into()
+				.map_err(|_| Error::InvalidHost(domain.to_string()))?,
+			datastore_id: String::from_utf8_lossy(&host.datastore_id).to_string(),
+			payment_address: zone_record
+				.payment_account
+				.to_ss58check_with_version(ADDRESS_PREFIX.into()),
+			notary_id: zone_record.notary_id,
+		})
+	}
+
+	/// Hash a domain, looking up top level domains added through governance in the mainchain
+	pub async fn domain_hash(&self, domain: &str) -> Result<DomainHash, Error> {
+		let (name, top_level) = match Domain::parse(domain.to_string()) {
+			Ok(parsed) => (parsed.name, parsed.top_level.name().to_string()),
+			Err(e) => domain
+				.split_once('.')
+				.filter(|(name, _)| !name.is_empty())
+				.map(|(name, top_level)| (name.to_lowercase(), top_level.to_lowercase()))
+				.ok_or(Error::InvalidDomain(e))?,
+		};
+		let top_level_id = match DomainTopLevel::from_name(&top_level) {
+			Some(built_in) => built_in.id(),
+			None =>
+				self.top_level_id(&top_level).await?.ok_or(Error::UnknownTopLevel(top_level))?,
+		};
+		Ok(Domain::hash_with_top_level_id(&name, top_level_id))
+	}
+
+	/// The id of a top level domain added through governance, loaded from the mainchain if it isn't
+	/// cached. Retired top level domains keep their id since only new registrations are blocked,
+	/// so their existing domains still resolve.
+	async fn top_level_id(&self, top_level: &str) -> Result<Option<DomainTopLevelId>, Error> {
+		if !DomainTopLevel::is_valid_name(top_level.as_bytes()) {
+			return Err(Error::InvalidDomain(format!("Invalid top level domain {top_level}")));
+		}
+		if let Some(cached) = self.top_levels.lock().await.get(top_level) {
+			return Ok(*cached);
+		}
+		let client = self.client().await?;
+		// read dynamically since top level domain storage is newer than the bundled metadata
+		let top_level_id = client
+			.fetch_storage(
+				&subxt::dynamic::storage(
+					"Domains",
+					"TopLevelDomainIdsByName",
+					vec![Value::from_bytes(top_level.as_bytes())],
+				),
+				FetchAt::Finalized,
+			)
+			.await?
+			.map(|x| x.as_type::<DomainTopLevelId>())
+			.transpose()
+			.map_err(|e| anyhow::anyhow!("Unable to decode top level domain id: {e:?}"))?;
+		self.top_levels.lock().await.insert(top_level.to_string(), top_level_id);
+		Ok(top_level_id)
+	}
+
+	/// The zone record for a domain, loaded from the mainchain if it isn't cached
+	pub async fn zone_record(
+		&self,
+		domain_hash: DomainHash,
+	) -> Result<Option<ZoneRecord<AccountId>>, Error> {
+		if let Some(cached) = self.cache.lock().await.get(&domain_hash) {
+			return Ok(cached.clone());
+		}
+		let record = self
+			.client()
+			.await?
+			.fetch_storage(
+				&storage().domains().zone_records_by_domain(ApiH256::from(domain_hash.0)),
+				FetchAt::Finalized,
+			)
+			.await?
+			.map(|x| ZoneRecord::<AccountId>::decode(&mut x.encode().as_slice()))
+			.transpose()
+			.map_err(|e| anyhow::anyhow!("Unable to decode zone record: {e:?}"))?;
+		self.cache.lock().await.insert(domain_hash, record.clone());
+		Ok(record)
+	}
+
+	pub async fn cached_domains(&self) -> usize {
+		self.cache.lock().await.len()
+	}
+
+	/// Follow finalized blocks and apply domain events to the caches. The caches are cleared when
+	/// starting since events may have been missed while disconnected. Returns when the
+	/// subscription ends (eg, the client disconnected).
+	pub async fn watch(&self) -> anyhow::Result<()> {
+		let mut blocks = self.client().await?.live.blocks().subscribe_finalized().await?;
+		self.cache.lock().await.clear();
+		self.top_levels.lock().await.clear();
+		while let Some(block) = blocks.next().await {
+			let block = block?;
+			let events = block.events().await?;
+			for event in events.iter().flatten() {
+				self.apply_event(&event).await;
+			}
+		}
+		info!("Finalized block subscription closed");
+		Ok(())
+	}
+
+	async fn apply_event(&self, event: &EventDetails<ArgonConfig>) {
+		if let Some(Ok(update)) =
+			event.as_event::<api::domains::events::ZoneRecordUpdated>().transpose()
+		{
+			let domain_hash = DomainHash::from(update.domain_hash.0);
+			match ZoneRecord::<AccountId>::decode(&mut update.zone_record.encode().as_slice()) {
+				Ok(record) => {
+					trace!(?domain_hash, "Zone record updated");
+					self.cache.lock().await.insert(domain_hash, Some(record));
+				},
+				Err(_) => {
+					self.cache.lock().await.remove(&domain_hash);
+				},
+			}
+			return;
+		}
+		// top level domains are rarely added, so the cache is simply reloaded. Retiring one doesn't
+		// change how its existing domains resolve.
+		if event.pallet_name() == "Domains" && event.variant_name() == "TopLevelDomainAdded" {
+			trace!(event = event.variant_name(), "Top level domains changed");
+			self.top_levels.lock().await.clear();
+			return;
+		}
+		// expired and transferred domains no longer have a zone record
+		if event.pallet_name() == "Domains" &&
+			matches!(event.variant_name(), "DomainExpired" | "DomainTransferred") &&
+			let Ok(domain_hash) = DomainHash::decode(&mut event.field_bytes())
+		{
+			trace!(?domain_hash, "Zone record cleared");
+			self.cache.lock().await.insert(domain_hash, None);
+		}
+	}
+}
+
+/// The highest version in a zone record that matches the range
+pub fn best_version<'a>(
+	zone_record: &'a ZoneRecord<AccountId>,
+	version_req: &VersionReq,
+) -> Option<(&'a Semver, &'a VersionHost)> {
+	zone_record
+		.versions
+		.iter()
+		.filter(|(version, _)| {
+			version_req.matches(&semver::Version::new(
+				version.major as u64,
+				version.minor as u64,
+				version.patch as u64,
+			))
+		})
+		.max_by_key(|(version, _)| *version)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use argon_primitives::BUILT_IN_DOMAIN_TOP_LEVELS;
+	use polkadot_sdk::sp_keyring::Sr25519Keyring::Bob;
+	use std::collections::BTreeMap;
+
+	fn zone_record(versions: Vec<(Semver, &str)>) -> ZoneRecord<AccountId> {
+		ZoneRecord {
+			payment_account: Bob.to_account_id(),
+			notary_id: 1,
+			versions: BTreeMap::from_iter(versions.into_iter().map(|(version, host)| {
+				(
+					version,
+					VersionHost {
+						host: host.into(),
+						datastore_id: b"flights".to_vec().try_into().unwrap(),
+					},
+				)
+			}))
+			.try_into()
+			.unwrap(),
+		}
+	}
+
+	#[test]
+	fn picks_the_highest_matching_version() {
+		let record = zone_record(vec![
+			(Semver::new(1, 0, 0), "ws://1.0.0"),
+			(Semver::new(1, 2, 5), "ws://1.2.5"),
+			(Semver::new(1, 3, 0), "ws://1.3.0"),
+			(Semver::new(2, 0, 0), "ws://2.0.0"),
+		]);
+		let best = |req: &str| {
+			best_version(&record, &VersionReq::parse(req).unwrap()).map(|(v, _)| v.clone())
+		};
+		assert_eq!(best("^1"), Some(Semver::new(1, 3, 0)));
+		assert_eq!(best("~1.2"), Some(Semver::new(1, 2, 5)));
+		assert_eq!(best("=1.0.0"), Some(Semver::new(1, 0, 0)));
+		assert_eq!(best("*"), Some(Semver::new(2, 0, 0)));
+		assert_eq!(best(">=1.1, <1.3"), Some(Semver::new(1, 2, 5)));
+		assert_eq!(best("^3"), None);
+	}
+
+	#[tokio::test]
+	async fn hashes_built_in_top_levels_without_the_mainchain() {
+		let resolver = DomainResolver::new(ReconnectingClient::new(vec![]));
+		assert_eq!(
+			resolver.domain_hash("Delta.flights").await.unwrap(),
+			Domain::parse("delta.flights".to_string()).unwrap().hash()
+		);
+		assert!(matches!(
+			resolver.domain_hash("delta.not_a_top_level").await,
+			Err(Error::InvalidDomain(_))
+		));
+		assert!(matches!(resolver.domain_hash(".flights").await, Err(Error::InvalidDomain(_))));
+	}
+
+	#[tokio::test]
+	async fn hashes_domains_under_retired_top_levels() {
+		let resolver = DomainResolver::new(ReconnectingClient::new(vec![]));
+		// a retired top level domain is loaded like any other, so its registrations still resolve
+		resolver
+			.top_levels
+			.lock()
+			.await
+			.insert("argon".to_string(), Some(BUILT_IN_DOMAIN_TOP_LEVELS));
+		assert_eq!(
+			resolver.domain_hash("Delta.argon").await.unwrap(),
+			Domain::hash_with_top_level_id("delta", BUILT_IN_DOMAIN_TOP_LEVELS)
+		);
+		resolver.top_levels.lock().await.insert("gone".to_string(), None);
+		assert!(matches!(
+			resolver.domain_hash("delta.gone").await,
+			Err(Error::UnknownTopLevel(top_level)) if top_level == "gone"
+		));
+	}
+}
@@ -0,0 +1,93 @@
+use argon_client::ReconnectingClient;
+use argon_domain_resolver::{DomainResolver, Error};
+use axum::{
+	extract::{Path, Query, State},
+	http::StatusCode,
+	response::{IntoResponse, Response},
+	routing::get,
+	Json, Router,
+};
+use clap::Parser;
+use dotenv::dotenv;
+use serde::Deserialize;
+use serde_json::json;
+use std::{net::SocketAddr, time::Duration};
+use tracing::{error, info, warn};
+
+#[derive(Parser, Debug)]
+#[command(author, version = env!("IMPL_VERSION"), about, long_about = None)]
+struct Cli {
+	/// What mainchain RPC websocket url(s) do you want to resolve zone records from?
+	#[clap(short, long, env, default_value = "ws://127.0.0.1:9944", value_delimiter = ',')]
+	trusted_rpc_url: Vec<String>,
+
+	/// The address to serve resolution requests on
+	#[clap(short, long, env = "RESOLVER_BIND", default_value = "127.0.0.1:9955")]
+	bind: SocketAddr,
+}
+
+#[derive(Deserialize)]
+struct ResolveQuery {
+	/// A semver range (eg, `^1.2`). Defaults to the newest version.
+	version: Option<String>,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+	let _ = tracing_subscriber::FmtSubscriber::builder()
+		.with_env_filter(
+			tracing_subscriber::EnvFilter::try_from_default_env()
+				.unwrap_or(tracing_subscriber::EnvFilter::from("info")),
+		)
+		.try_init();
+	color_backtrace::install();
+	dotenv().ok();
+
+	let Cli { trusted_rpc_url, bind } = Cli::parse();
+
+	let resolver = DomainResolver::new(ReconnectingClient::new(trusted_rpc_url));
+	let watcher = resolver.clone();
+	tokio::spawn(async move {
+		loop {
+			if let Err(e) = watcher.watch().await {
+				error!("Error watching zone record updates: {e:?}");
+			}
+			warn!("Restarting zone record watch in 5 seconds");
+			tokio::time::sleep(Duration::from_secs(5)).await;
+		}
+	});
+
+	let app = Router::new()
+		.route("/resolve/{domain}", get(resolve))
+		.route("/health", get(health))
+		.with_state(resolver);
+	let listener = tokio::net::TcpListener::bind(bind).await?;
+	info!("Domain resolver listening on {}", listener.local_addr()?);
+	axum::serve(listener, app).await?;
+	Ok(())
+}
+
+async fn resolve(
+	State(resolver): State<DomainResolver>,
+	Path(domain): Path<String>,
+	Query(query): Query<ResolveQuery>,
+) -> Response {
+	let version = query.version.unwrap_or_else(|| "*".to_string());
+	match resolver.resolve(&domain, &version).await {
+		Ok(resolved) => Json(resolved).into_response(),
+		Err(e) => {
+			let status = match e {
+				Error::InvalidDomain(_) | Error::InvalidVersionRange(_) => StatusCode::BAD_REQUEST,
+				Error::UnknownTopLevel(_) | Error::NoZoneRecord | Error::NoMatchingVersion(_) =>
+					StatusCode::NOT_FOUND,
+				Error::InvalidHost(_) => StatusCode::UNPROCESSABLE_ENTITY,
+				Error::Mainchain(_) => StatusCode::BAD_GATEWAY,
+			};
+			(status, Json(json!({ "error": e.to_string() }))).into_response()
+		},
+	}
+}
+
+async fn health(State(resolver): State<DomainResolver>) -> impl IntoResponse {
+	Json(json!({ "cachedDomains": resolver.cached_domains().await }))
+}
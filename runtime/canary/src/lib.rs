@@ -230,12 +230,14 @@ impl pallet_block_rewards::Config for Runtime {
 	type HalvingBeginTicks = HalvingBeginTick;
 	type MinerPayoutPercent = MinerPayoutPercent;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type EventHandler = use_unless_benchmark!(Mint, ());
 	type PayoutHistoryBlocks = PayoutHistoryBlocks;
 	type PriceProvider = PriceIndex;
 	type CohortBlockRewardsToKeep = BlockRewardsCohortHistoryToKeep;
 	type EpochTicks = EpochTicks;
 	type PerBlockArgonReducerPercent = BlockRewardsDampener;
+	type MiningPoolDeposit = MiningPoolDeposit;
 }
 
 impl pallet_domains::Config for Runtime {
@@ -243,6 +245,8 @@ impl pallet_domains::Config for Runtime {
 	type DomainExpirationTicks = DomainExpirationTicks;
 	type NotebookTick = NotebookTickProvider;
 	type HistoricalPaymentAddressTicksToKeep = HistoricalPaymentAddressTicksToKeep;
+	type DomainAuctionTicks = DomainAuctionTicks;
+	type LocalchainRefunds = LocalchainTransfer;
 }
 
 impl pallet_authorship::Config for Runtime {
@@ -446,6 +450,7 @@ impl pallet_treasury::Config for Runtime {
 	type MaxVaultsPerPool = MaxVaultsPerPool;
 	type MaxPendingUnlocksPerFrame = MaxPendingUnlocksPerFrame;
 	type TreasuryExitDelayFrames = TreasuryExitDelayFrames;
+	type MaxBondLotTransferHistory = MaxBondLotTransferHistory;
 	type MiningFrameTransitionProvider = MiningSlot;
 	type OperationalAccountsHook = use_unless_benchmark!(OperationalAccounts, ());
 }
@@ -624,6 +629,9 @@ impl pallet_price_index::Config for Runtime {
 	type MaxEthereumPriceHistoryFrames = MaxEthereumPriceHistoryFrames;
 	type MaxArgonChangePerTickAwayFromTarget = MaxArgonChangePerTickAwayFromTarget;
 	type MaxArgonTargetChangePerTick = MaxArgonTargetChangePerTick;
+	type MaxPriceOperators = MaxPriceOperators;
+	type MaxOperatorSubmissionAgeTicks = MaxOperatorSubmissionAgeTicks;
+	type MaxOperatorPriceDeviation = MaxOperatorPriceDeviation;
 }
 
 pub struct GetMinimumSatoshisPerLock;
@@ -640,6 +648,7 @@ impl pallet_bitcoin_utxos::Config for Runtime {
 	>;
 	type EventHandler = use_unless_benchmark!(BitcoinLocks, ());
 	type MaxUtxosPerLock = MaxUtxosPerLock;
+	type MaxTipOracleOperators = MaxTipOracleOperators;
 	type MinimumSatoshisPerUtxo = GetMinimumSatoshisPerLock;
 }
 
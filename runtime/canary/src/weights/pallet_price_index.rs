@@ -6,6 +6,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `Blakes-M2.local`, CPU: `<UNKNOWN>`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: 1024
+//!
+//! HAND-MAINTAINED: `submit` (multi-operator median) and `set_operators` were updated by hand and
+//! are estimates, not benchmark results. Re-run the benchmark command below to replace them.
 
 // Executed Command:
 // frame-omni-bencher
@@ -32,8 +35,12 @@ use core::marker::PhantomData;
 /// Weight functions for `pallet_price_index`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_price_index::WeightInfo for WeightInfo<T> {
-	/// Storage: `PriceIndex::Operator` (r:1 w:0)
-	/// Proof: `PriceIndex::Operator` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `PriceIndex::Operators` (r:1 w:0)
+	/// Proof: `PriceIndex::Operators` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	/// Storage: `PriceIndex::OperatorSubmissions` (r:10 w:1)
+	/// Proof: `PriceIndex::OperatorSubmissions` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
+	/// Storage: `PriceIndex::OperatorDeviationReportedTick` (r:4 w:4)
+	/// Proof: `PriceIndex::OperatorDeviationReportedTick` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
 	/// Storage: `PriceIndex::Current` (r:1 w:1)
 	/// Proof: `PriceIndex::Current` (`max_values`: Some(1), `max_size`: Some(88), added: 583, mode: `MaxEncodedLen`)
 	/// Storage: `PriceIndex::HistoricEthereumPricesByFrame` (r:1 w:1)
@@ -42,16 +49,18 @@ impl<T: frame_system::Config> pallet_price_index::WeightInfo for WeightInfo<T> {
 	/// Proof: `PriceIndex::HistoricArgonotFloorByFrame` (`max_values`: Some(1), `max_size`: Some(241), added: 736, mode: `MaxEncodedLen`)
 	/// Storage: `PriceIndex::HistoricArgonCPI` (r:1 w:1)
 	/// Proof: `PriceIndex::HistoricArgonCPI` (`max_values`: Some(1), `max_size`: Some(1729), added: 2224, mode: `MaxEncodedLen`)
+	/// Storage: `PriceIndex::LastValid` (r:0 w:1)
+	/// Proof: `PriceIndex::LastValid` (`max_values`: Some(1), `max_size`: Some(88), added: 583, mode: `MaxEncodedLen`)
 	/// Storage: `PriceIndex::CurrentEthereumPrice` (r:0 w:1)
 	/// Proof: `PriceIndex::CurrentEthereumPrice` (`max_values`: Some(1), `max_size`: Some(40), added: 535, mode: `MaxEncodedLen`)
 	fn submit() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `37`
-		//  Estimated: `3214`
-		// Minimum execution time: 11_000_000 picoseconds.
-		Weight::from_parts(14_000_000, 3214)
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(5))
+		//  Measured:  `1842`
+		//  Estimated: `27610`
+		// Minimum execution time: 63_000_000 picoseconds.
+		Weight::from_parts(63_000_000, 27610)
+			.saturating_add(T::DbWeight::get().reads(19))
+			.saturating_add(T::DbWeight::get().writes(11))
 		}
 	/// Storage: `PriceIndex::Operator` (r:0 w:1)
 	/// Proof: `PriceIndex::Operator` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
@@ -63,6 +72,21 @@ impl<T: frame_system::Config> pallet_price_index::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(4_000_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1))
 		}
+	/// Storage: `PriceIndex::Operators` (r:1 w:1)
+	/// Proof: `PriceIndex::Operators` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	/// Storage: `PriceIndex::OperatorSubmissions` (r:0 w:10)
+	/// Proof: `PriceIndex::OperatorSubmissions` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
+	/// Storage: `PriceIndex::OperatorDeviationReportedTick` (r:0 w:10)
+	/// Proof: `PriceIndex::OperatorDeviationReportedTick` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn set_operators() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `355`
+		//  Estimated: `1806`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 1806)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(21))
+		}
 	/// Storage: `PriceIndex::CurrentFrameArgonotAverage` (r:1 w:1)
 	/// Proof: `PriceIndex::CurrentFrameArgonotAverage` (`max_values`: Some(1), `max_size`: Some(28), added: 523, mode: `MaxEncodedLen`)
 	/// Storage: `PriceIndex::HistoricArgonotAverageByFrame` (r:1 w:1)
@@ -246,6 +246,25 @@ macro_rules! inject_common_apis {
             }
         }
 
+        impl argon_primitives::TreasuryApis<Block, Balance> for Runtime {
+            fn bond_program_yields() -> Vec<argon_primitives::vault::TreasuryProgramYields<Balance>> {
+                Treasury::bond_program_yields()
+            }
+
+            fn project_bond_yield(
+                vault_id: Option<VaultId>,
+                bonds: u32,
+            ) -> Result<argon_primitives::vault::TreasuryBondYieldProjection<Balance>, DispatchError> {
+                Treasury::project_bond_yield(vault_id, bonds)
+            }
+        }
+
+        impl argon_primitives::VaultApis<Block, Balance> for Runtime {
+            fn vault_reputations() -> Vec<argon_primitives::vault::VaultReputationSummary<Balance>> {
+                Vaults::vault_reputations()
+            }
+        }
+
         impl argon_primitives::NotebookApis<Block, NotebookVerifyError> for Runtime {
             fn audit_notebook_and_get_votes_v2(
                 version: u32,
@@ -280,7 +299,7 @@ macro_rules! inject_common_apis {
             }
         }
 
-        #[api_version(4)]
+        #[api_version(5)]
         impl argon_primitives::BitcoinApis<Block,Balance> for Runtime {
             fn get_sync_status() -> Option<BitcoinSyncStatus> {
                 BitcoinUtxos::get_sync_status()
@@ -320,6 +339,10 @@ macro_rules! inject_common_apis {
             fn get_bitcoin_network() -> BitcoinNetwork {
                 <BitcoinUtxos as Get<BitcoinNetwork>>::get()
             }
+
+            fn vault_lock_xpub_sources(vault_id: VaultId) -> BTreeMap<XPubFingerprint, Vec<VaultLockXPubSource>> {
+                BitcoinLocks::vault_lock_xpub_sources(vault_id)
+            }
         }
 
         impl sp_consensus_grandpa::GrandpaApi<Block> for Runtime {
@@ -87,15 +87,18 @@ parameter_types! {
 	pub const HalvingTicks: Tick = 2_100_000; // based on bitcoin, but 10x since we're 1 block per minute
 	pub const MinerPayoutPercent: FixedU128 = FixedU128::from_rational(75, 100);
 	pub const DomainExpirationTicks: Tick = 60 * 24 * 365; // 1 year
+	pub const DomainAuctionTicks: Tick = 60 * 24; // 1 day
 	pub const BlockRewardsCohortHistoryToKeep: u32 = FramesPerMiningTerm::get() + 1;
 	pub const EpochTicks: Tick = 10 * 1440; // 10 days
 	pub const PayoutHistoryBlocks: u32 = 5;
 	pub const BlockRewardsDampener: FixedU128 = FixedU128::from_rational(75, 100); // 75% dampener
+	pub const MiningPoolDeposit: Balance = 10 * ARGON;
 
 	// ### pallet_treasury
 	pub const MaxTreasuryContributors: u32 = 100;
 	pub const MaxActiveArgonotBondLots: u32 = 1_000;
 	pub const MaxVaultsPerPool: u32 = 100;
+	pub const MaxBondLotTransferHistory: u32 = 10;
 	pub const TreasuryInternalPalletId: PalletId = PalletId(*b"lqdPools");
 	pub TreasuryMiningBidPoolAccount: AccountId =
 		TreasuryInternalPalletId::get().into_account_truncating();
@@ -194,9 +197,13 @@ parameter_types! {
 	pub const MaxPriceAgeInTicks: Tick = 24 * 60; // 1 day
 	pub const MaxArgonChangePerTickAwayFromTarget: FixedU128 = FixedU128::from_rational(1, 100); // 1 centagon
 	pub const MaxArgonTargetChangePerTick: FixedU128 = FixedU128::from_rational(1, 100); // 1 centagon
+	pub const MaxPriceOperators: u32 = 10;
+	pub const MaxOperatorSubmissionAgeTicks: Tick = MaxDowntimeTicksBeforeReset::get();
+	pub const MaxOperatorPriceDeviation: FixedU128 = FixedU128::from_rational(5, 100); // 5%
 
 	pub const MaxPendingConfirmationBlocks: BitcoinHeight = 6 * 24; // 1 day of bitcoin blocks
 	pub const MaxUtxosPerLock: u32 = 100;
+	pub const MaxTipOracleOperators: u32 = 10;
 
 	// Fees
 	pub FeeMultiplier: Multiplier = Multiplier::one();
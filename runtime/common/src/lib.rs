@@ -158,6 +158,8 @@ macro_rules! inject_runtime_vars {
 		type Migrations = (
 			pallet_bitcoin_locks::migrations::MoveFundingUtxoRefMigration<Runtime>,
 			pallet_bitcoin_utxos::migrations::MigrateUtxoTrackingMigration<Runtime>,
+			pallet_treasury::migrations::AddBondLotSummaryTransfersMigration<Runtime>,
+			pallet_vaults::migrations::SeedVaultReputationLockedSatoshisMigration<Runtime>,
 		);
 
 		/// Unchecked extrinsic type as expected by this runtime.
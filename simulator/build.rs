@@ -0,0 +1,5 @@
+use argon_primitives::git_version::generate_git_cli_env_var;
+
+fn main() {
+	generate_git_cli_env_var();
+}
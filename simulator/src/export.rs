@@ -0,0 +1,197 @@
+use crate::recording::{RecordedMiner, RecordedNotebook, RecordedTick, TickRecording};
+use anyhow::{anyhow, Context};
+use argon_client::{api::storage, FetchAt, MainchainClient};
+use argon_notary_apis::ArchiveHost;
+use argon_primitives::{AccountId, BlockNumber, Notebook, VotingSchedule};
+use codec::Decode;
+use polkadot_sdk::*;
+use sp_core::{H256, U256};
+use std::time::Duration;
+use tracing::{info, warn};
+
+const NOTEBOOK_DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Exports the ticks of the blocks in `from_block..=to_block` from a node. Notebook votes are not
+/// stored on chain, so they are downloaded from `archive_host`.
+///
+/// Each tick is recorded the way the runtime evaluates a vote seal for a block at that tick: the
+/// voting key, vote minimum, eligible blocks and miners are read from the parent block, and the
+/// votes come from the notebooks of `VotingSchedule::when_evaluating_runtime_seals`.
+pub async fn export_ticks(
+	client: &MainchainClient,
+	archive_host: &ArchiveHost,
+	from_block: BlockNumber,
+	to_block: BlockNumber,
+) -> anyhow::Result<TickRecording> {
+	let mut recording = TickRecording::default();
+	let mut parent_hash = block_hash(client, from_block.saturating_sub(1)).await?;
+	for block_number in from_block.max(1)..=to_block {
+		let hash = block_hash(client, block_number).await?;
+		let tick = client
+			.fetch_storage(&storage().ticks().current_tick(), FetchAt::Block(hash))
+			.await?
+			.unwrap_or_default();
+		// only the first block of a tick is scored against its votes
+		if recording.ticks.last().is_some_and(|t| t.tick >= tick) {
+			parent_hash = hash;
+			continue;
+		}
+		let recorded = export_tick(client, archive_host, block_number, hash, parent_hash, tick)
+			.await
+			.with_context(|| format!("exporting block {block_number} at tick {tick}"))?;
+		if block_number % 100 == 0 {
+			info!("Exported block {block_number} (tick {tick})");
+		}
+		recording.ticks.push(recorded);
+		parent_hash = hash;
+	}
+	Ok(recording)
+}
+
+async fn export_tick(
+	client: &MainchainClient,
+	archive_host: &ArchiveHost,
+	block_number: BlockNumber,
+	hash: H256,
+	parent_hash: H256,
+	tick: argon_primitives::tick::Tick,
+) -> anyhow::Result<RecordedTick> {
+	let at_parent = FetchAt::Block(parent_hash);
+	let schedule = VotingSchedule::when_evaluating_runtime_seals(tick);
+
+	let voting_key = client
+		.fetch_storage(&storage().block_seal().parent_voting_key(), at_parent)
+		.await?
+		.flatten()
+		.map(|key| H256(key.0));
+	// the runtime checks votes against the minimum of the grandparent block
+	let vote_minimum_history = client
+		.fetch_storage(&storage().block_seal_spec().vote_minimum_history(), at_parent)
+		.await?
+		.map(|history| history.0)
+		.unwrap_or_default();
+	let vote_minimum = match vote_minimum_history.get(2) {
+		Some(minimum) => *minimum,
+		None => client
+			.fetch_storage(&storage().block_seal_spec().current_vote_minimum(), at_parent)
+			.await?
+			.unwrap_or_default(),
+	};
+	let compute_difficulty = client
+		.fetch_storage(&storage().block_seal_spec().current_compute_difficulty(), at_parent)
+		.await?
+		.unwrap_or_default();
+	let eligible_block_hashes = client
+		.fetch_storage(
+			&storage().ticks().recent_blocks_at_ticks(schedule.grandparent_votes_tick()),
+			at_parent,
+		)
+		.await?
+		.map(|blocks| blocks.0.into_iter().map(|hash| H256(hash.0)).collect())
+		.unwrap_or_default();
+	let block_reward = client
+		.fetch_storage(&storage().block_rewards().payouts_by_block(block_number), hash)
+		.await?
+		.map(|payouts| payouts.0.iter().map(|payout| payout.argons).sum())
+		.unwrap_or_default();
+
+	Ok(RecordedTick {
+		tick,
+		voting_key,
+		vote_minimum,
+		compute_difficulty,
+		block_reward,
+		eligible_block_hashes,
+		notebooks: export_notebooks(client, archive_host, at_parent, schedule.notebook_tick())
+			.await?,
+		miners: export_miners(client, at_parent).await?,
+	})
+}
+
+/// Downloads the votes of the notebooks at `notebook_tick` (mirrors
+/// `pallet_notebook::Pallet::notebooks_at_tick`)
+async fn export_notebooks(
+	client: &MainchainClient,
+	archive_host: &ArchiveHost,
+	at: FetchAt,
+	notebook_tick: argon_primitives::tick::Tick,
+) -> anyhow::Result<Vec<RecordedNotebook>> {
+	let notaries = client
+		.fetch_storage(&storage().notaries().active_notaries(), at)
+		.await?
+		.map(|notaries| notaries.0)
+		.unwrap_or_default();
+
+	let mut notebooks = vec![];
+	for notary_id in notaries.into_iter().map(|notary| notary.notary_id) {
+		let details = client
+			.fetch_storage(&storage().notebook().last_notebook_details_by_notary(notary_id), at)
+			.await?
+			.map(|details| details.0)
+			.unwrap_or_default();
+		for (details, _) in details {
+			if details.tick != notebook_tick {
+				continue;
+			}
+			let notebook_number = details.notebook_number;
+			let bytes = archive_host
+				.get_notebook(notary_id, notebook_number, NOTEBOOK_DOWNLOAD_TIMEOUT)
+				.await
+				.with_context(|| {
+					format!("downloading notary {notary_id}, notebook {notebook_number}")
+				})?;
+			let notebook = Notebook::decode(&mut &bytes.0[..])?;
+			notebooks.push(RecordedNotebook {
+				notary_id,
+				notebook_number,
+				votes: notebook
+					.notarizations
+					.iter()
+					.flat_map(|notarization| notarization.block_votes.iter().cloned())
+					.collect(),
+			});
+		}
+	}
+	notebooks.sort_by_key(|n| n.notary_id);
+	Ok(notebooks)
+}
+
+/// The registered miners with their nonces, in the order of `MinerNonceScoringByCohort`
+async fn export_miners(
+	client: &MainchainClient,
+	at: FetchAt,
+) -> anyhow::Result<Vec<RecordedMiner>> {
+	let nonces_by_frame = client
+		.fetch_storage(&storage().mining_slot().miner_nonce_scoring_by_cohort(), at)
+		.await?
+		.map(|nonces| nonces.0.0)
+		.unwrap_or_default();
+
+	let mut miners = vec![];
+	for (frame_id, scorings) in nonces_by_frame {
+		let registrations = client
+			.fetch_storage(&storage().mining_slot().miners_by_cohort(frame_id), at)
+			.await?
+			.map(|miners| miners.0)
+			.unwrap_or_default();
+		for (index, scoring) in scorings.0.into_iter().enumerate() {
+			let Some(registration) = registrations.get(index) else {
+				warn!("No miner registered for frame {frame_id}, index {index}");
+				continue;
+			};
+			miners.push(RecordedMiner {
+				account_id: AccountId::from(registration.account_id.0),
+				nonce: U256(scoring.nonce.0),
+			});
+		}
+	}
+	Ok(miners)
+}
+
+async fn block_hash(client: &MainchainClient, block_number: BlockNumber) -> anyhow::Result<H256> {
+	client
+		.methods
+		.chain_get_block_hash(Some(block_number.into()))
+		.await?
+		.ok_or_else(|| anyhow!("No block found at height {block_number}"))
+}
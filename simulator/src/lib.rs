@@ -0,0 +1,15 @@
+//! An offline simulator for vote-seal block production strategies.
+//!
+//! Block voting is an economic game (see `docs/technical/vote-seal-known-attack-vectors.md`). This
+//! crate replays ticks exported from a chain (see [`export`] and [`recording`]) and layers
+//! alternative voter, miner and notary behaviors on top of them (see [`strategy`]). Each tick is
+//! scored with the same [`argon_primitives::BlockVote`] seal strength the runtime uses, and the
+//! vote minimum is re-adjusted as the runtime would, so strategies that change vote volume feel the
+//! consequences.
+//!
+//! The [`simulation::SimulationReport`] summarizes the expected rewards of each strategy and the
+//! forks it caused.
+pub mod export;
+pub mod recording;
+pub mod simulation;
+pub mod strategy;
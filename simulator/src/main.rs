@@ -0,0 +1,176 @@
+use anyhow::{ensure, Context};
+use argon_client::MainchainClient;
+use argon_notary_apis::ArchiveHost;
+use argon_primitives::{tick::Tick, BlockNumber};
+use argon_vote_simulator::{
+	export::export_ticks,
+	recording::TickRecording,
+	simulation::{Simulation, SimulationConfig},
+	strategy::StrategyConfig,
+};
+use clap::Parser;
+use dotenv::dotenv;
+use polkadot_sdk::*;
+use sp_runtime::Percent;
+use std::path::PathBuf;
+use tracing::info;
+
+#[derive(Parser, Debug)]
+#[command(author, version = env!("IMPL_VERSION"), about, long_about = None)]
+struct Cli {
+	#[command(subcommand)]
+	subcommand: Subcommand,
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum Subcommand {
+	/// Export the ticks of a block range from a node into a recording
+	Export {
+		/// The mainchain RPC websocket url to read blocks from
+		#[clap(short, long, env, default_value = "ws://127.0.0.1:9944")]
+		trusted_rpc_url: String,
+
+		/// The notebook archive host to download notebook votes from
+		/// (eg, https://archives.argonprotocol.org)
+		#[clap(short, long, env, verbatim_doc_comment)]
+		archive_host: String,
+
+		/// The first block to export
+		#[clap(long)]
+		from_block: BlockNumber,
+
+		/// The last block to export. Defaults to the latest finalized block.
+		#[clap(long)]
+		to_block: Option<BlockNumber>,
+
+		/// The json file to write the recording to
+		#[clap(short, long)]
+		output: PathBuf,
+	},
+	/// Simulate strategies against a recording
+	Simulate {
+		/// A json file of ticks exported from a chain
+		#[clap(short, long)]
+		recording: PathBuf,
+
+		/// A json file with a list of strategies to layer onto the recording
+		#[clap(short, long)]
+		strategies: Option<PathBuf>,
+
+		/// The share of block rewards paid to miners
+		#[clap(long, default_value = "75")]
+		miner_payout_percent: u8,
+
+		/// The desired votes per tick
+		#[clap(long, default_value = "50000")]
+		target_block_votes: u128,
+
+		/// The number of ticks of votes used to adjust the vote minimum
+		#[clap(long, default_value = "1440")]
+		vote_history_len: usize,
+
+		/// The ticks in a mining frame. Miner block counts used for fairness reset each frame.
+		#[clap(long, default_value = "1440")]
+		frame_ticks: Tick,
+
+		/// Print the full report as json
+		#[clap(long)]
+		json: bool,
+	},
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+	let _ = tracing_subscriber::FmtSubscriber::builder()
+		.with_env_filter(
+			tracing_subscriber::EnvFilter::try_from_default_env()
+				.unwrap_or(tracing_subscriber::EnvFilter::from("info")),
+		)
+		.try_init();
+	color_backtrace::install();
+	dotenv().ok();
+
+	match Cli::parse().subcommand {
+		Subcommand::Export { trusted_rpc_url, archive_host, from_block, to_block, output } => {
+			let client = MainchainClient::from_url(&trusted_rpc_url).await?;
+			let to_block = match to_block {
+				Some(to_block) => to_block,
+				None => client.latest_finalized_block().await?,
+			};
+			ensure!(from_block <= to_block, "from-block must not be after to-block");
+			info!("Exporting blocks {from_block} to {to_block} from {trusted_rpc_url}");
+			let recording =
+				export_ticks(&client, &ArchiveHost::new(archive_host)?, from_block, to_block)
+					.await?;
+			recording.save(&output)?;
+			info!("Exported {} ticks to {}", recording.ticks.len(), output.display());
+			Ok(())
+		},
+		Subcommand::Simulate {
+			recording,
+			strategies,
+			miner_payout_percent,
+			target_block_votes,
+			vote_history_len,
+			frame_ticks,
+			json,
+		} => simulate(
+			recording,
+			strategies,
+			SimulationConfig {
+				miner_payout_percent: Percent::from_percent(miner_payout_percent),
+				target_block_votes,
+				vote_history_len,
+				frame_ticks,
+			},
+			json,
+		),
+	}
+}
+
+fn simulate(
+	recording: PathBuf,
+	strategies: Option<PathBuf>,
+	config: SimulationConfig,
+	json: bool,
+) -> anyhow::Result<()> {
+	let recording = TickRecording::load(recording)?;
+	let strategies = match strategies {
+		Some(path) => {
+			let strategies = std::fs::read_to_string(&path)
+				.with_context(|| format!("reading strategies {}", path.display()))?;
+			serde_json::from_str::<Vec<StrategyConfig>>(&strategies)
+				.with_context(|| format!("parsing strategies {}", path.display()))?
+		},
+		None => vec![],
+	};
+	info!("Simulating {} ticks with {} strategies", recording.ticks.len(), strategies.len());
+
+	let report = Simulation::new(config, strategies.into_iter().map(|s| s.build()).collect())
+		.run(&recording);
+
+	if json {
+		println!("{}", serde_json::to_string_pretty(&report)?);
+		return Ok(());
+	}
+
+	println!(
+		"Ticks: {}, vote blocks: {}, compute ticks: {}, forks: {}",
+		report.ticks, report.vote_blocks, report.compute_ticks, report.forks
+	);
+	println!(
+		"Rejected votes: {}, vote minimum adjustments: {}, final vote minimum: {}",
+		report.rejected_votes, report.vote_minimum_adjustments, report.final_vote_minimum
+	);
+	for strategy in &report.strategies {
+		println!(
+			"{}: rewards {}, vote seals {}, vote blocks mined {}, forks {}",
+			strategy.name,
+			strategy.rewards,
+			strategy.vote_seals,
+			strategy.vote_blocks_mined,
+			strategy.forks
+		);
+	}
+	Ok(())
+}
@@ -0,0 +1,99 @@
+use anyhow::Context;
+use argon_primitives::{
+	tick::Tick, AccountId, Balance, BlockVote, ComputeDifficulty, NotaryId, NotebookNumber,
+	VoteMinimum,
+};
+use polkadot_sdk::*;
+use serde::{Deserialize, Serialize};
+use sp_core::{H256, U256};
+use std::path::Path;
+
+/// Ticks exported from a chain. Each tick holds everything needed to re-score its vote seals.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TickRecording {
+	pub ticks: Vec<RecordedTick>,
+}
+
+impl TickRecording {
+	/// Loads a json recording, sorting the ticks in order
+	pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+		let path = path.as_ref();
+		let json = std::fs::read_to_string(path)
+			.with_context(|| format!("reading tick recording {}", path.display()))?;
+		let mut recording: Self = serde_json::from_str(&json)
+			.with_context(|| format!("parsing tick recording {}", path.display()))?;
+		recording.ticks.sort_by_key(|t| t.tick);
+		Ok(recording)
+	}
+
+	/// Writes the recording as json, in the format read by [`TickRecording::load`]
+	pub fn save(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
+		let path = path.as_ref();
+		let json = serde_json::to_string(self)?;
+		std::fs::write(path, json)
+			.with_context(|| format!("writing tick recording {}", path.display()))
+	}
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordedTick {
+	pub tick: Tick,
+	/// The key votes of this tick are sealed against. Without one, only compute blocks are
+	/// possible.
+	pub voting_key: Option<H256>,
+	/// The vote minimum at the start of the tick
+	pub vote_minimum: VoteMinimum,
+	pub compute_difficulty: ComputeDifficulty,
+	/// The argons minted for the block of this tick
+	pub block_reward: Balance,
+	/// The blocks votes in this tick are allowed to vote for (the grandparent tick blocks)
+	pub eligible_block_hashes: Vec<H256>,
+	pub notebooks: Vec<RecordedNotebook>,
+	/// The registered miners that can be chosen to close a vote block
+	pub miners: Vec<RecordedMiner>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordedNotebook {
+	pub notary_id: NotaryId,
+	pub notebook_number: NotebookNumber,
+	pub votes: Vec<BlockVote>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordedMiner {
+	pub account_id: AccountId,
+	/// The nonce used to score how close a miner is to a vote seal
+	pub nonce: U256,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn it_loads_recordings_in_tick_order() {
+		let path = std::env::temp_dir().join("argon-vote-simulator-recording.json");
+		let tick = |tick: Tick| RecordedTick {
+			tick,
+			voting_key: Some(H256::random()),
+			vote_minimum: 1_000,
+			compute_difficulty: 10_000,
+			block_reward: 500_000,
+			eligible_block_hashes: vec![H256::random()],
+			notebooks: vec![],
+			miners: vec![],
+		};
+		let recording = TickRecording { ticks: vec![tick(2), tick(1)] };
+		recording.save(&path).unwrap();
+
+		let loaded = TickRecording::load(&path).unwrap();
+		assert_eq!(loaded.ticks.iter().map(|t| t.tick).collect::<Vec<_>>(), vec![1, 2]);
+		assert_eq!(loaded.ticks[0], recording.ticks[1]);
+		let _ = std::fs::remove_file(path);
+	}
+}
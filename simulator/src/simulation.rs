@@ -0,0 +1,609 @@
+use crate::{
+	recording::{RecordedMiner, RecordedTick, TickRecording},
+	strategy::{Strategy, TickContext},
+};
+use argon_primitives::{
+	block_seal::{closest_miner_score, MAX_MINER_BLOCK_OFFSET},
+	calculate_next_vote_minimum,
+	tick::Tick,
+	AccountId, Balance, BlockVote, NotaryId, VoteMinimum, ABSOLUTE_TAX_VOTE_MINIMUM,
+};
+use polkadot_sdk::*;
+use serde::{Deserialize, Serialize};
+use sp_core::{H256, U256};
+use sp_runtime::{helpers_128bit::multiply_by_rational_with_rounding, Percent, Rounding};
+use std::collections::BTreeMap;
+
+/// The runtime settings a simulation applies on top of the recording
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulationConfig {
+	/// The share of a block reward paid to its miner. The vote rewards account gets the rest.
+	pub miner_payout_percent: Percent,
+	/// The desired votes per tick
+	pub target_block_votes: u128,
+	/// The number of ticks of votes used to adjust the vote minimum
+	pub vote_history_len: usize,
+	/// The ticks in a mining frame. Miner block counts used for fairness reset each frame.
+	pub frame_ticks: Tick,
+}
+
+impl Default for SimulationConfig {
+	fn default() -> Self {
+		Self {
+			miner_payout_percent: Percent::from_percent(75),
+			target_block_votes: 50_000,
+			vote_history_len: 24 * 60,
+			frame_ticks: 24 * 60,
+		}
+	}
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulationReport {
+	pub ticks: u32,
+	pub vote_blocks: u32,
+	/// Ticks without a vote seal. These can only be closed with compute.
+	pub compute_ticks: u32,
+	/// Ticks where a withheld notebook was revealed late to displace the honest block
+	pub forks: u32,
+	/// Votes rejected for falling below the vote minimum
+	pub rejected_votes: u64,
+	pub vote_minimum_adjustments: u32,
+	pub final_vote_minimum: VoteMinimum,
+	pub accounts: BTreeMap<AccountId, AccountOutcome>,
+	pub strategies: Vec<StrategyOutcome>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountOutcome {
+	/// The expected argons earned. Compute rewards are weighted by the odds of finding a block.
+	pub rewards: Balance,
+	/// Blocks sealed with a vote paying this account
+	pub vote_seals: u32,
+	/// Vote blocks closed by this account as the miner
+	pub vote_blocks_mined: u32,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StrategyOutcome {
+	pub name: String,
+	pub rewards: Balance,
+	pub vote_seals: u32,
+	pub vote_blocks_mined: u32,
+	pub forks: u32,
+}
+
+/// The winning vote of a tick and the miner chosen to close it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VoteSeal {
+	pub notary_id: NotaryId,
+	pub seal_strength: U256,
+	pub vote_rewards_account: AccountId,
+	pub miner: AccountId,
+	pub miner_nonce: U256,
+}
+
+/// The vote blocks a miner seat has closed, tracked the way `pallet_mining_slot` does to spread
+/// blocks evenly between miners
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MinerScoring {
+	pub last_win_tick: Option<Tick>,
+	/// Number of blocks won in the current frame
+	pub blocks_won_in_frame: u16,
+	/// Number of blocks off target set at the start of each frame
+	pub frame_start_blocks_won_surplus: i16,
+}
+
+/// Mirrors `pallet_mining_slot::Pallet::get_average_vote_blocks_won_per_miner`
+fn average_vote_blocks_won_per_miner(
+	miner_scoring: &BTreeMap<U256, MinerScoring>,
+	active_miners: usize,
+) -> u16 {
+	if active_miners == 0 {
+		return 0;
+	}
+	let total_blocks_won = miner_scoring
+		.values()
+		.fold(0u128, |sum, x| sum.saturating_add(x.blocks_won_in_frame as u128));
+	let active_miners = active_miners as u128;
+	// round half up
+	((total_blocks_won * 2 + active_miners) / (active_miners * 2)).min(u16::MAX as u128) as u16
+}
+
+/// Finds the strongest (lowest) vote seal the same way the runtime ranks votes. The miner is the
+/// one with the lowest `closest_miner_score`, including the fairness adjustments for miners that
+/// are ahead of their share of blocks in the frame.
+pub fn find_best_vote_seal<'a>(
+	votes: impl IntoIterator<Item = &'a (NotaryId, BlockVote)>,
+	voting_key: H256,
+	miners: &[RecordedMiner],
+	miner_scoring: &BTreeMap<U256, MinerScoring>,
+	tick: Tick,
+) -> Option<VoteSeal> {
+	let (notary_id, vote, seal_proof, seal_strength) = votes
+		.into_iter()
+		.map(|(notary_id, vote)| {
+			let seal_proof = vote.get_seal_proof(*notary_id, voting_key);
+			let seal_strength = BlockVote::calculate_seal_strength(vote.power, seal_proof);
+			(*notary_id, vote, seal_proof, seal_strength)
+		})
+		.min_by_key(|(_, _, _, seal_strength)| *seal_strength)?;
+
+	let expected_wins = average_vote_blocks_won_per_miner(miner_scoring, miners.len());
+	let recent_ticks = Percent::from_percent(10).mul_floor(miners.len() as Tick);
+	let miner = miners.iter().min_by_key(|miner| {
+		let scoring = miner_scoring.get(&miner.nonce).cloned().unwrap_or_default();
+		let wins_against_expected = (scoring.blocks_won_in_frame as i16)
+			.saturating_sub(expected_wins as i16)
+			.saturating_add(scoring.frame_start_blocks_won_surplus);
+		let recently_won = scoring
+			.last_win_tick
+			.is_some_and(|last_win_tick| tick.saturating_sub(last_win_tick) <= recent_ticks);
+		closest_miner_score(seal_proof, miner.nonce, wins_against_expected, recently_won)
+	})?;
+
+	Some(VoteSeal {
+		notary_id,
+		seal_strength,
+		vote_rewards_account: vote.block_rewards_account_id.clone(),
+		miner: miner.account_id.clone(),
+		miner_nonce: miner.nonce,
+	})
+}
+
+/// Replays a recording with a set of strategies
+pub struct Simulation {
+	config: SimulationConfig,
+	strategies: Vec<Box<dyn Strategy>>,
+	strategy_forks: Vec<u32>,
+	vote_minimum: Option<VoteMinimum>,
+	past_block_votes: Vec<(Tick, u128)>,
+	miner_scoring: BTreeMap<U256, MinerScoring>,
+	frame_start_tick: Option<Tick>,
+	report: SimulationReport,
+}
+
+impl Simulation {
+	pub fn new(config: SimulationConfig, strategies: Vec<Box<dyn Strategy>>) -> Self {
+		Self {
+			config,
+			strategy_forks: vec![0; strategies.len()],
+			strategies,
+			vote_minimum: None,
+			past_block_votes: vec![],
+			miner_scoring: BTreeMap::new(),
+			frame_start_tick: None,
+			report: SimulationReport::default(),
+		}
+	}
+
+	pub fn run(mut self, recording: &TickRecording) -> SimulationReport {
+		for tick in &recording.ticks {
+			self.simulate_tick(tick);
+		}
+
+		let mut report = self.report;
+		report.final_vote_minimum = self.vote_minimum.unwrap_or_default();
+		report.strategies = self
+			.strategies
+			.iter()
+			.zip(self.strategy_forks)
+			.map(|(strategy, forks)| {
+				let mut outcome =
+					StrategyOutcome { name: strategy.name(), forks, ..Default::default() };
+				for account in strategy.accounts() {
+					let Some(account) = report.accounts.get(&account) else {
+						continue;
+					};
+					outcome.rewards = outcome.rewards.saturating_add(account.rewards);
+					outcome.vote_seals += account.vote_seals;
+					outcome.vote_blocks_mined += account.vote_blocks_mined;
+				}
+				outcome
+			})
+			.collect();
+		report
+	}
+
+	fn simulate_tick(&mut self, recorded: &RecordedTick) {
+		self.report.ticks += 1;
+		let vote_minimum = *self.vote_minimum.get_or_insert(recorded.vote_minimum);
+		let context = TickContext {
+			tick: recorded.tick,
+			vote_minimum,
+			eligible_block_hashes: &recorded.eligible_block_hashes,
+			notary_ids: recorded.notebooks.iter().map(|n| n.notary_id).collect(),
+		};
+
+		let mut votes = recorded
+			.notebooks
+			.iter()
+			.flat_map(|n| n.votes.iter().map(|vote| (n.notary_id, vote.clone())))
+			.collect::<Vec<_>>();
+		let mut miners = recorded.miners.clone();
+		let mut compute_hashes = vec![];
+		let mut withheld_by_strategy = vec![];
+		for (index, strategy) in self.strategies.iter_mut().enumerate() {
+			votes.extend(strategy.votes(&context));
+			miners.extend(strategy.miners(&context));
+			compute_hashes.extend(strategy.compute_hashes(&context));
+			let withheld = strategy.withheld_notaries();
+			if !withheld.is_empty() {
+				withheld_by_strategy.push((index, withheld));
+			}
+		}
+
+		self.start_frame_if_due(recorded.tick, miners.len());
+
+		let before = votes.len();
+		votes.retain(|(_, vote)| vote.is_default_vote() || vote.power >= vote_minimum);
+		self.report.rejected_votes += (before - votes.len()) as u64;
+		let total_votes = votes.iter().filter(|(_, vote)| !vote.is_default_vote()).count();
+		self.track_votes(recorded.tick, total_votes as u128);
+
+		let Some(voting_key) = recorded.voting_key else {
+			self.close_compute_tick(recorded, compute_hashes);
+			return;
+		};
+
+		let withheld = withheld_by_strategy
+			.iter()
+			.flat_map(|(_, n)| n.clone())
+			.collect::<Vec<NotaryId>>();
+		let sealable = votes
+			.iter()
+			.filter(|(_, vote)| {
+				vote.is_proxy_vote() || recorded.eligible_block_hashes.contains(&vote.block_hash)
+			})
+			.collect::<Vec<_>>();
+
+		let mut seal = find_best_vote_seal(
+			sealable.iter().copied().filter(|(notary_id, _)| !withheld.contains(notary_id)),
+			voting_key,
+			&miners,
+			&self.miner_scoring,
+			recorded.tick,
+		);
+		// Each withholding notary can reveal on a fork once it has seen the honest block. The fork
+		// has more notebooks than the honest block, so it wins fork choice.
+		for (index, notaries) in withheld_by_strategy {
+			let fork_seal = find_best_vote_seal(
+				sealable.iter().copied().filter(|(notary_id, _)| {
+					notaries.contains(notary_id) || !withheld.contains(notary_id)
+				}),
+				voting_key,
+				&miners,
+				&self.miner_scoring,
+				recorded.tick,
+			);
+			let accounts = self.strategies[index].accounts();
+			let block_reward = recorded.block_reward;
+			if self.rewards_for(&fork_seal, block_reward, &accounts) >
+				self.rewards_for(&seal, block_reward, &accounts)
+			{
+				seal = fork_seal;
+				self.report.forks += 1;
+				self.strategy_forks[index] += 1;
+			}
+		}
+
+		let Some(seal) = seal else {
+			self.close_compute_tick(recorded, compute_hashes);
+			return;
+		};
+		self.report.vote_blocks += 1;
+		let scoring = self.miner_scoring.entry(seal.miner_nonce).or_default();
+		scoring.blocks_won_in_frame = scoring.blocks_won_in_frame.saturating_add(1);
+		scoring.last_win_tick = Some(recorded.tick);
+		let (miner_argons, voter_argons) = self.split_reward(recorded.block_reward);
+		let miner = self.report.accounts.entry(seal.miner).or_default();
+		miner.rewards = miner.rewards.saturating_add(miner_argons);
+		miner.vote_blocks_mined += 1;
+		let voter = self.report.accounts.entry(seal.vote_rewards_account).or_default();
+		voter.rewards = voter.rewards.saturating_add(voter_argons);
+		voter.vote_seals += 1;
+	}
+
+	/// Compute miners race the difficulty, so a tick pays each of them the miner share weighted
+	/// by their odds of finding the block.
+	fn close_compute_tick(
+		&mut self,
+		recorded: &RecordedTick,
+		compute_hashes: Vec<(AccountId, u128)>,
+	) {
+		self.report.compute_ticks += 1;
+		let total_hashes = compute_hashes.iter().fold(0u128, |sum, (_, h)| sum.saturating_add(*h));
+		if total_hashes == 0 {
+			return;
+		}
+		let (miner_argons, _) = self.split_reward(recorded.block_reward);
+		let odds_denominator = total_hashes.max(recorded.compute_difficulty);
+		for (account_id, hashes) in compute_hashes {
+			let expected = multiply_by_rational_with_rounding(
+				miner_argons,
+				hashes,
+				odds_denominator,
+				Rounding::Down,
+			)
+			.unwrap_or_default();
+			let account = self.report.accounts.entry(account_id).or_default();
+			account.rewards = account.rewards.saturating_add(expected);
+		}
+	}
+
+	/// Mirrors `pallet_mining_slot::Pallet::reset_miner_nonce_scoring` at the start of each frame.
+	/// Miners carry how far they were off their share of blocks into the next frame.
+	fn start_frame_if_due(&mut self, tick: Tick, active_miners: usize) {
+		let frame_start_tick = *self.frame_start_tick.get_or_insert(tick);
+		if tick < frame_start_tick.saturating_add(self.config.frame_ticks) {
+			return;
+		}
+		self.frame_start_tick = Some(tick);
+		let expected_blocks = average_vote_blocks_won_per_miner(&self.miner_scoring, active_miners);
+		for scoring in self.miner_scoring.values_mut() {
+			scoring.frame_start_blocks_won_surplus = scoring
+				.frame_start_blocks_won_surplus
+				.saturating_add(scoring.blocks_won_in_frame as i16 - expected_blocks as i16)
+				.clamp(-MAX_MINER_BLOCK_OFFSET, MAX_MINER_BLOCK_OFFSET);
+			scoring.blocks_won_in_frame = 0;
+		}
+	}
+
+	/// Mirrors the rolling vote history `pallet_block_seal_spec` uses to adjust the vote minimum
+	fn track_votes(&mut self, tick: Tick, total_votes: u128) {
+		if let Some(entry) = self.past_block_votes.last_mut() &&
+			entry.0 == tick
+		{
+			entry.1 = entry.1.saturating_add(total_votes);
+			return;
+		}
+		if self.past_block_votes.len() < self.config.vote_history_len {
+			self.past_block_votes.push((tick, total_votes));
+			return;
+		}
+
+		let expected_block_votes =
+			self.config.target_block_votes * self.past_block_votes.len() as u128;
+		let actual_block_votes = self
+			.past_block_votes
+			.iter()
+			.fold(0u128, |votes, (_, v)| votes.saturating_add(*v));
+		let start_vote_minimum = self.vote_minimum.unwrap_or(ABSOLUTE_TAX_VOTE_MINIMUM);
+		let vote_minimum = calculate_next_vote_minimum(
+			start_vote_minimum,
+			expected_block_votes,
+			actual_block_votes,
+			ABSOLUTE_TAX_VOTE_MINIMUM,
+			u128::MAX,
+		);
+		self.past_block_votes = vec![(tick, total_votes)];
+		if vote_minimum != start_vote_minimum {
+			self.vote_minimum = Some(vote_minimum);
+			self.report.vote_minimum_adjustments += 1;
+		}
+	}
+
+	fn split_reward(&self, block_reward: Balance) -> (Balance, Balance) {
+		let miner_argons = self.config.miner_payout_percent.mul_floor(block_reward);
+		(miner_argons, block_reward.saturating_sub(miner_argons))
+	}
+
+	fn rewards_for(
+		&self,
+		seal: &Option<VoteSeal>,
+		block_reward: Balance,
+		accounts: &[AccountId],
+	) -> Balance {
+		let Some(seal) = seal else {
+			return 0;
+		};
+		let (miner_argons, voter_argons) = self.split_reward(block_reward);
+		let mut rewards = 0;
+		if accounts.contains(&seal.miner) {
+			rewards += miner_argons;
+		}
+		if accounts.contains(&seal.vote_rewards_account) {
+			rewards += voter_argons;
+		}
+		rewards
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		recording::RecordedNotebook,
+		strategy::{LateRevealNotary, Miner},
+	};
+	use sp_runtime::MultiSignature;
+
+	fn vote(account: u8, power: u128, block_hash: H256, tick: Tick) -> BlockVote {
+		BlockVote {
+			account_id: AccountId::new([account; 32]),
+			block_hash,
+			index: 0,
+			power,
+			signature: MultiSignature::Sr25519([0; 64].into()),
+			block_rewards_account_id: AccountId::new([account; 32]),
+			tick,
+		}
+	}
+
+	fn recorded_tick(
+		tick: Tick,
+		voting_key: Option<H256>,
+		notebooks: Vec<RecordedNotebook>,
+	) -> RecordedTick {
+		RecordedTick {
+			tick,
+			voting_key,
+			vote_minimum: 1_000,
+			compute_difficulty: 1_000,
+			block_reward: 1_000,
+			eligible_block_hashes: vec![H256::repeat_byte(1)],
+			notebooks,
+			miners: vec![RecordedMiner { account_id: AccountId::new([9; 32]), nonce: U256::one() }],
+		}
+	}
+
+	#[test]
+	fn it_clamps_vote_minimum_adjustments() {
+		let next = |current, target, actual| {
+			calculate_next_vote_minimum(
+				current,
+				target,
+				actual,
+				ABSOLUTE_TAX_VOTE_MINIMUM,
+				u128::MAX,
+			)
+		};
+		assert_eq!(next(10_000, 100, 100), 10_000);
+		assert_eq!(next(10_000, 100, 200), 20_000);
+		assert_eq!(next(10_000, 100, 10_000), 40_000);
+		assert_eq!(next(10_000, 100, 0), 2_500);
+		assert_eq!(next(1_000, 100, 0), ABSOLUTE_TAX_VOTE_MINIMUM);
+	}
+
+	#[test]
+	fn it_picks_the_strongest_eligible_vote() {
+		let block_hash = H256::repeat_byte(1);
+		let votes =
+			vec![(1, vote(1, 1_000, block_hash, 1)), (2, vote(2, 1_000_000, block_hash, 1))];
+		let miners = [RecordedMiner { account_id: AccountId::new([9; 32]), nonce: U256::one() }];
+		let voting_key = H256::repeat_byte(2);
+		let scoring = BTreeMap::new();
+		let seal = find_best_vote_seal(&votes, voting_key, &miners, &scoring, 1).unwrap();
+		let strengths = votes
+			.iter()
+			.map(|(notary_id, vote)| vote.get_seal_strength(*notary_id, voting_key))
+			.collect::<Vec<_>>();
+		assert_eq!(seal.seal_strength, *strengths.iter().min().unwrap());
+		assert_eq!(seal.miner, AccountId::new([9; 32]));
+		assert_eq!(find_best_vote_seal(&votes, voting_key, &[], &scoring, 1), None);
+	}
+
+	#[test]
+	fn it_scores_miners_with_their_block_share() {
+		let block_hash = H256::repeat_byte(1);
+		let votes = vec![(1, vote(1, 1_000, block_hash, 1))];
+		let voting_key = H256::repeat_byte(2);
+		let miners = (0..4u64)
+			.map(|i| RecordedMiner { account_id: AccountId::new([i as u8; 32]), nonce: i.into() })
+			.collect::<Vec<_>>();
+		// the first miner is well ahead of its share of blocks
+		let mut scoring = BTreeMap::new();
+		scoring.insert(
+			miners[0].nonce,
+			MinerScoring {
+				last_win_tick: Some(5),
+				blocks_won_in_frame: 8,
+				frame_start_blocks_won_surplus: 0,
+			},
+		);
+		let seal_proof = votes[0].1.get_seal_proof(1, voting_key);
+		let expected = miners
+			.iter()
+			.min_by_key(|miner| {
+				let (wins_against_expected, recently_won) =
+					if miner.nonce == miners[0].nonce { (6, false) } else { (-2, false) };
+				closest_miner_score(seal_proof, miner.nonce, wins_against_expected, recently_won)
+			})
+			.unwrap();
+
+		let seal = find_best_vote_seal(&votes, voting_key, &miners, &scoring, 10).unwrap();
+		assert_eq!(seal.miner, expected.account_id);
+		assert_eq!(seal.miner_nonce, expected.nonce);
+	}
+
+	#[test]
+	fn it_rejects_votes_below_the_minimum() {
+		let block_hash = H256::repeat_byte(1);
+		let notebook = RecordedNotebook {
+			notary_id: 1,
+			notebook_number: 1,
+			votes: vec![vote(1, 999, block_hash, 1), vote(2, 1_000, block_hash, 1)],
+		};
+		let recording = TickRecording {
+			ticks: vec![recorded_tick(1, Some(H256::repeat_byte(2)), vec![notebook])],
+		};
+		let report = Simulation::new(Default::default(), vec![]).run(&recording);
+		assert_eq!(report.rejected_votes, 1);
+		assert_eq!(report.vote_blocks, 1);
+		assert_eq!(report.accounts[&AccountId::new([2; 32])].vote_seals, 1);
+		assert_eq!(report.accounts[&AccountId::new([2; 32])].rewards, 250);
+		assert_eq!(report.accounts[&AccountId::new([9; 32])].rewards, 750);
+	}
+
+	#[test]
+	fn it_pays_expected_compute_rewards() {
+		let recording = TickRecording { ticks: vec![recorded_tick(1, None, vec![])] };
+		let miner = Miner {
+			name: "compute".into(),
+			account_id: AccountId::new([5; 32]),
+			seats: 0,
+			hashes_per_tick: 500,
+		};
+		let report = Simulation::new(Default::default(), vec![Box::new(miner)]).run(&recording);
+		assert_eq!(report.compute_ticks, 1);
+		// half the difficulty, so half the miner share
+		assert_eq!(report.strategies[0].rewards, 375);
+	}
+
+	#[test]
+	fn late_reveal_notaries_fork_when_it_pays() {
+		let block_hash = H256::repeat_byte(1);
+		let honest_vote = vote(1, 1_000, block_hash, 1);
+		let attacker_vote = vote(2, 1_000, block_hash, 1);
+		// find one tick where the attacker's notebook wins and one where it doesn't
+		let (winning_key, losing_key) = {
+			let mut winning = None;
+			let mut losing = None;
+			for i in 0..100u64 {
+				let key = H256::from_low_u64_be(i);
+				let attacker_wins =
+					attacker_vote.get_seal_strength(2, key) < honest_vote.get_seal_strength(1, key);
+				if attacker_wins {
+					winning.get_or_insert(key);
+				} else {
+					losing.get_or_insert(key);
+				}
+			}
+			(winning.unwrap(), losing.unwrap())
+		};
+		let notebooks = || {
+			vec![
+				RecordedNotebook {
+					notary_id: 1,
+					notebook_number: 1,
+					votes: vec![honest_vote.clone()],
+				},
+				RecordedNotebook {
+					notary_id: 2,
+					notebook_number: 1,
+					votes: vec![attacker_vote.clone()],
+				},
+			]
+		};
+		let recording = TickRecording {
+			ticks: vec![
+				recorded_tick(1, Some(winning_key), notebooks()),
+				recorded_tick(2, Some(losing_key), notebooks()),
+			],
+		};
+		let attacker = LateRevealNotary {
+			name: "late reveal".into(),
+			notary_id: 2,
+			coalition: vec![AccountId::new([2; 32])],
+		};
+		let report = Simulation::new(Default::default(), vec![Box::new(attacker)]).run(&recording);
+		assert_eq!(report.forks, 1);
+		assert_eq!(report.strategies[0].forks, 1);
+		assert_eq!(report.strategies[0].vote_seals, 1);
+		assert_eq!(report.accounts[&AccountId::new([1; 32])].vote_seals, 1);
+	}
+}
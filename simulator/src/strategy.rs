@@ -0,0 +1,204 @@
+use crate::recording::RecordedMiner;
+use argon_primitives::{tick::Tick, AccountId, BlockVote, BlockVotingPower, NotaryId, VoteMinimum};
+use codec::Encode;
+use polkadot_sdk::*;
+use serde::{Deserialize, Serialize};
+use sp_core::{blake2_256, H256, U256};
+use sp_runtime::MultiSignature;
+
+/// What a strategy can see before a tick is sealed. The voting key is not known until the
+/// notebooks of the tick are revealed, so it is not included.
+#[derive(Clone, Debug)]
+pub struct TickContext<'a> {
+	pub tick: Tick,
+	pub vote_minimum: VoteMinimum,
+	pub eligible_block_hashes: &'a [H256],
+	pub notary_ids: Vec<NotaryId>,
+}
+
+/// A behavior layered onto a recording. Every hook defaults to doing nothing, so a strategy only
+/// implements the roles it plays.
+pub trait Strategy {
+	fn name(&self) -> String;
+
+	/// The accounts whose rewards are attributed to this strategy
+	fn accounts(&self) -> Vec<AccountId>;
+
+	/// Votes to add to the notebooks of a tick
+	fn votes(&mut self, _context: &TickContext) -> Vec<(NotaryId, BlockVote)> {
+		vec![]
+	}
+
+	/// Mining seats to add to the registered miners of a tick
+	fn miners(&mut self, _context: &TickContext) -> Vec<RecordedMiner> {
+		vec![]
+	}
+
+	/// The account and hashes this strategy can compute during a tick without a vote seal
+	fn compute_hashes(&mut self, _context: &TickContext) -> Option<(AccountId, u128)> {
+		None
+	}
+
+	/// Notaries that hold back their notebooks until the honest block of a tick is known, and
+	/// only reveal them if a fork including them pays this strategy more.
+	fn withheld_notaries(&self) -> Vec<NotaryId> {
+		vec![]
+	}
+}
+
+/// A json description of a strategy
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum StrategyConfig {
+	/// Spends a fixed voting power every tick, split evenly over a number of votes
+	Voter {
+		name: String,
+		account_id: AccountId,
+		power_per_tick: BlockVotingPower,
+		votes_per_tick: u32,
+	},
+	/// Adds mining seats and compute hashes
+	Miner { name: String, account_id: AccountId, seats: u32, hashes_per_tick: u128 },
+	/// A notary that withholds its notebook to grind a better seal for a coalition
+	LateRevealNotary { name: String, notary_id: NotaryId, coalition: Vec<AccountId> },
+}
+
+impl StrategyConfig {
+	pub fn build(self) -> Box<dyn Strategy> {
+		match self {
+			StrategyConfig::Voter { name, account_id, power_per_tick, votes_per_tick } =>
+				Box::new(Voter { name, account_id, power_per_tick, votes_per_tick }),
+			StrategyConfig::Miner { name, account_id, seats, hashes_per_tick } =>
+				Box::new(Miner { name, account_id, seats, hashes_per_tick }),
+			StrategyConfig::LateRevealNotary { name, notary_id, coalition } =>
+				Box::new(LateRevealNotary { name, notary_id, coalition }),
+		}
+	}
+}
+
+pub struct Voter {
+	pub name: String,
+	pub account_id: AccountId,
+	pub power_per_tick: BlockVotingPower,
+	pub votes_per_tick: u32,
+}
+
+impl Strategy for Voter {
+	fn name(&self) -> String {
+		self.name.clone()
+	}
+
+	fn accounts(&self) -> Vec<AccountId> {
+		vec![self.account_id.clone()]
+	}
+
+	fn votes(&mut self, context: &TickContext) -> Vec<(NotaryId, BlockVote)> {
+		let Some(block_hash) = context.eligible_block_hashes.first() else {
+			return vec![];
+		};
+		if context.notary_ids.is_empty() {
+			return vec![];
+		}
+		// never split below the vote minimum, or every vote would be rejected
+		let max_votes = self.power_per_tick / context.vote_minimum.max(1);
+		let votes = (self.votes_per_tick.max(1) as u128).min(max_votes);
+		if votes == 0 {
+			return vec![];
+		}
+		let power = self.power_per_tick / votes;
+		(0..votes as u32)
+			.map(|index| {
+				let notary_id = context.notary_ids[index as usize % context.notary_ids.len()];
+				let vote = BlockVote {
+					account_id: self.account_id.clone(),
+					block_hash: *block_hash,
+					index,
+					power,
+					signature: MultiSignature::Sr25519([0; 64].into()),
+					block_rewards_account_id: self.account_id.clone(),
+					tick: context.tick,
+				};
+				(notary_id, vote)
+			})
+			.collect()
+	}
+}
+
+pub struct Miner {
+	pub name: String,
+	pub account_id: AccountId,
+	pub seats: u32,
+	pub hashes_per_tick: u128,
+}
+
+impl Strategy for Miner {
+	fn name(&self) -> String {
+		self.name.clone()
+	}
+
+	fn accounts(&self) -> Vec<AccountId> {
+		vec![self.account_id.clone()]
+	}
+
+	fn miners(&mut self, _context: &TickContext) -> Vec<RecordedMiner> {
+		(0..self.seats)
+			.map(|seat| RecordedMiner {
+				account_id: self.account_id.clone(),
+				nonce: U256::from_big_endian(&(&self.account_id, seat).using_encoded(blake2_256)),
+			})
+			.collect()
+	}
+
+	fn compute_hashes(&mut self, _context: &TickContext) -> Option<(AccountId, u128)> {
+		Some((self.account_id.clone(), self.hashes_per_tick))
+	}
+}
+
+pub struct LateRevealNotary {
+	pub name: String,
+	pub notary_id: NotaryId,
+	pub coalition: Vec<AccountId>,
+}
+
+impl Strategy for LateRevealNotary {
+	fn name(&self) -> String {
+		self.name.clone()
+	}
+
+	fn accounts(&self) -> Vec<AccountId> {
+		self.coalition.clone()
+	}
+
+	fn withheld_notaries(&self) -> Vec<NotaryId> {
+		vec![self.notary_id]
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn voters_never_split_below_the_vote_minimum() {
+		let mut voter = Voter {
+			name: "split".into(),
+			account_id: AccountId::new([1; 32]),
+			power_per_tick: 10_000,
+			votes_per_tick: 20,
+		};
+		let block_hashes = [H256::repeat_byte(1)];
+		let context = TickContext {
+			tick: 10,
+			vote_minimum: 1_000,
+			eligible_block_hashes: &block_hashes,
+			notary_ids: vec![1, 2],
+		};
+		let votes = voter.votes(&context);
+		assert_eq!(votes.len(), 10);
+		assert!(votes.iter().all(|(_, v)| v.power == 1_000 && v.tick == 10));
+		assert_eq!(votes.iter().filter(|(notary_id, _)| *notary_id == 2).count(), 5);
+
+		let context = TickContext { vote_minimum: 20_000, ..context };
+		assert!(voter.votes(&context).is_empty());
+	}
+}
@@ -1,7 +1,7 @@
 use std::{env, fs::File, str::FromStr};
 
 use anyhow::anyhow;
-use bitcoind::{downloaded_exe_path, BitcoinD, Conf};
+use bitcoind::{downloaded_exe_path, BitcoinD, Conf, P2P};
 use fs2::FileExt;
 use lazy_static::lazy_static;
 use rand::Rng;
@@ -30,6 +30,9 @@ pub fn start_bitcoind() -> anyhow::Result<(BitcoinD, url::Url, bitcoin::Network)
 	let mut conf = Conf::default();
 	conf.args.push("-blockfilterindex");
 	conf.args.push("-txindex");
+	// serve compact block filters to light clients over p2p
+	conf.args.push("-peerblockfilters");
+	conf.p2p = P2P::Yes;
 
 	println!("Bitcoin path {}", downloaded_exe_path().unwrap());
 	let bitcoind = match BitcoinD::with_conf(downloaded_exe_path().unwrap(), &conf) {